@@ -1,8 +1,15 @@
 //! Auction mechanisms for alternative price discovery.
 //!
-//! This module provides simple auction functionality as an alternative to bilateral trading.
-//! Currently implements English (ascending-price) auctions where buyers submit bids and the
-//! highest bidder wins.
+//! This module provides auction functionality as an alternative to bilateral trading.
+//! When `enable_auctions` is set, the engine sells contested skills (more demand than
+//! supply) by auction at the start of the trade phase. Four mechanisms are available:
+//!
+//! - [`AuctionType::English`]: open ascending bidding; the winner pays their last bid
+//! - [`AuctionType::Dutch`]: a descending price clock; the first bidder to accept wins
+//! - [`AuctionType::FirstPriceSealedBid`]: the highest sealed bid wins and pays its bid
+//! - [`AuctionType::Vickrey`]: the highest sealed bid wins and pays the second-highest bid
+//!
+//! Bids below the reserve price are ignored, and ties go to the lowest person ID.
 //!
 //! # Examples
 //!
@@ -20,15 +27,29 @@
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use strum_macros::EnumString;
 
 /// Types of auction mechanisms available.
-///
-/// Currently only English auctions are implemented. This enum allows for
-/// future expansion to other auction types (Dutch, Vickrey, etc.).
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, EnumString)]
 pub enum AuctionType {
     /// English auction: ascending-price, highest bidder wins
     English,
+    /// Dutch auction: descending price clock, the first bidder to accept wins at the clock price
+    Dutch,
+    /// First-price sealed-bid auction: highest bid wins and pays its own bid
+    FirstPriceSealedBid,
+    /// Vickrey auction: highest sealed bid wins and pays the second-highest bid
+    Vickrey,
+}
+
+impl AuctionType {
+    /// All auction types, in reporting order.
+    pub const ALL: [AuctionType; 4] = [
+        AuctionType::English,
+        AuctionType::Dutch,
+        AuctionType::FirstPriceSealedBid,
+        AuctionType::Vickrey,
+    ];
 }
 
 /// Represents a single auction for a specific skill.
@@ -62,6 +83,15 @@ pub struct Auction {
 
     /// Bids submitted: PersonId -> bid amount
     pub bids: HashMap<usize, f64>,
+
+    /// Lowest price the seller accepts; bids below it are ignored
+    #[serde(default)]
+    pub reserve_price: Option<f64>,
+
+    /// Bid increment of English auctions and price decrement of the Dutch clock
+    /// (0.0 = continuous prices)
+    #[serde(default)]
+    pub tick_size: f64,
 }
 
 impl Auction {
@@ -80,7 +110,85 @@ impl Auction {
     /// let auction = Auction::new("Gardening".to_string(), AuctionType::English);
     /// ```
     pub fn new(skill_id: String, auction_type: AuctionType) -> Self {
-        Self { skill_id, auction_type, bids: HashMap::new() }
+        Self {
+            skill_id,
+            auction_type,
+            bids: HashMap::new(),
+            reserve_price: None,
+            tick_size: 0.0,
+        }
+    }
+
+    /// Sets the seller's reserve price.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use community_simulation::auction::{Auction, AuctionType};
+    ///
+    /// let mut auction =
+    ///     Auction::new("Gardening".to_string(), AuctionType::English).with_reserve_price(30.0);
+    /// auction.add_bid(1, 25.0);
+    /// assert_eq!(auction.resolve(), None); // Below the reserve
+    /// ```
+    pub fn with_reserve_price(mut self, reserve_price: f64) -> Self {
+        self.reserve_price = Some(reserve_price);
+        self
+    }
+
+    /// Sets the bid increment (English) or clock decrement (Dutch).
+    pub fn with_tick_size(mut self, tick_size: f64) -> Self {
+        self.tick_size = tick_size.max(0.0);
+        self
+    }
+
+    /// Places the bids of risk-neutral bidders with the given valuations, following the
+    /// auction type's bidding rule.
+    ///
+    /// - English: bidders raise by one tick until the price passes their valuation, so
+    ///   the winner's last bid is the runner-up's valuation plus one tick (at least the
+    ///   reserve, at most their own valuation).
+    /// - Vickrey: bidding the valuation is a dominant strategy.
+    /// - Dutch and first-price sealed-bid: bidders shade their bid to
+    ///   `reserve + (valuation - reserve) * (n - 1) / n` for `n` bidders, the
+    ///   equilibrium bid for valuations spread evenly above the reserve.
+    ///
+    /// Bidders valuing the skill below the reserve price stay out.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use community_simulation::auction::{Auction, AuctionType};
+    ///
+    /// let mut auction = Auction::new("Plumbing".to_string(), AuctionType::English)
+    ///     .with_reserve_price(10.0)
+    ///     .with_tick_size(1.0);
+    /// auction.bid_with_valuations(&[(1, 30.0), (2, 20.0)]);
+    /// assert_eq!(auction.resolve(), Some((1, 21.0)));
+    /// ```
+    pub fn bid_with_valuations(&mut self, valuations: &[(usize, f64)]) {
+        let reserve = self.reserve_price.unwrap_or(0.0);
+        let mut bidders: Vec<(usize, f64)> = valuations
+            .iter()
+            .copied()
+            .filter(|(_, valuation)| valuation.is_finite() && *valuation >= reserve)
+            .collect();
+        bidders.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+
+        let n = bidders.len() as f64;
+        for (rank, &(person_id, valuation)) in bidders.iter().enumerate() {
+            let bid = match self.auction_type {
+                AuctionType::English if rank == 0 => {
+                    let runner_up = bidders.get(1).map_or(reserve, |b| b.1 + self.tick_size);
+                    runner_up.max(reserve).min(valuation)
+                },
+                AuctionType::English | AuctionType::Vickrey => valuation,
+                AuctionType::Dutch | AuctionType::FirstPriceSealedBid => {
+                    reserve + (valuation - reserve) * (n - 1.0) / n
+                },
+            };
+            self.add_bid(person_id, bid);
+        }
     }
 
     /// Adds a bid to the auction.
@@ -115,18 +223,24 @@ impl Auction {
         self.bids.insert(person_id, amount);
     }
 
-    /// Resolves the auction and determines the winner.
+    /// Resolves the auction and determines the winner and the price they pay.
     ///
-    /// For English auctions, returns the person with the highest bid and their bid amount.
-    /// Returns `None` if there are no bids.
+    /// The highest bid wins in every auction type; ties go to the lowest person ID.
+    /// The price depends on the type:
     ///
-    /// Only considers finite bid amounts. Non-finite bids (if any bypass validation)
-    /// are automatically excluded from consideration.
+    /// - English and first-price sealed-bid: the winning bid
+    /// - Dutch: the first clock price at or below the winning bid, with the clock
+    ///   falling in `tick_size` steps counted from the reserve price
+    /// - Vickrey: the second-highest bid, at least the reserve price (a single bidder
+    ///   pays the reserve, or their bid if there is none)
+    ///
+    /// Only considers finite bid amounts at or above the reserve price. Non-finite bids
+    /// (if any bypass validation) are automatically excluded from consideration.
     ///
     /// # Returns
     ///
-    /// * `Some((winner_id, winning_bid))` - The winning person ID and their bid
-    /// * `None` - If no bids were submitted or all bids are non-finite
+    /// * `Some((winner_id, price))` - The winning person ID and the price they pay
+    /// * `None` - If no valid bids were submitted
     ///
     /// # Examples
     ///
@@ -140,22 +254,42 @@ impl Auction {
     ///
     /// let winner = auction.resolve();
     /// assert_eq!(winner, Some((8, 45.0)));
+    ///
+    /// let mut vickrey = Auction::new("Carpentry".to_string(), AuctionType::Vickrey);
+    /// vickrey.add_bid(7, 40.0);
+    /// vickrey.add_bid(8, 45.0);
+    /// assert_eq!(vickrey.resolve(), Some((8, 40.0)));
     /// ```
     pub fn resolve(&self) -> Option<(usize, f64)> {
-        match self.auction_type {
-            AuctionType::English => {
-                // Find the highest bidder among finite bids
-                // Filter ensures we only compare valid (finite) bids
-                self.bids
-                    .iter()
-                    .filter(|(_id, amount)| amount.is_finite())
-                    .max_by(|a, b| {
-                        // Safety: all values are finite due to filter, so partial_cmp always returns Some
-                        a.1.partial_cmp(b.1).unwrap_or(std::cmp::Ordering::Equal)
-                    })
-                    .map(|(person_id, amount)| (*person_id, *amount))
+        // Rank valid bids: highest first, lowest person ID on ties
+        let mut ranked: Vec<(usize, f64)> = self
+            .bids
+            .iter()
+            .filter(|(_id, amount)| {
+                amount.is_finite() && self.reserve_price.is_none_or(|reserve| **amount >= reserve)
+            })
+            .map(|(person_id, amount)| (*person_id, *amount))
+            .collect();
+        ranked.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+        let &(winner, winning_bid) = ranked.first()?;
+
+        let price = match self.auction_type {
+            AuctionType::English | AuctionType::FirstPriceSealedBid => winning_bid,
+            AuctionType::Dutch => {
+                if self.tick_size > 0.0 {
+                    let start = self.reserve_price.unwrap_or(0.0);
+                    start + ((winning_bid - start) / self.tick_size).floor() * self.tick_size
+                } else {
+                    winning_bid
+                }
             },
-        }
+            AuctionType::Vickrey => match (ranked.get(1), self.reserve_price) {
+                (Some(&(_, second)), reserve) => second.max(reserve.unwrap_or(second)),
+                (None, Some(reserve)) => reserve,
+                (None, None) => winning_bid,
+            },
+        };
+        Some((winner, price))
     }
 
     /// Returns the number of bids submitted to this auction.
@@ -275,6 +409,100 @@ mod tests {
         assert_eq!(winner.unwrap().1, 50.0);
     }
 
+    #[test]
+    fn test_resolve_tie_goes_to_lowest_id() {
+        for auction_type in AuctionType::ALL {
+            let mut auction = Auction::new("TestSkill".to_string(), auction_type);
+            auction.add_bid(9, 50.0);
+            auction.add_bid(4, 50.0);
+            assert_eq!(auction.resolve().map(|(id, _)| id), Some(4));
+        }
+    }
+
+    #[test]
+    fn test_reserve_price_excludes_low_bids() {
+        let mut auction =
+            Auction::new("TestSkill".to_string(), AuctionType::Vickrey).with_reserve_price(40.0);
+        auction.add_bid(1, 30.0);
+        assert_eq!(auction.resolve(), None);
+
+        // The only valid bid pays the reserve, not the ignored lower bid
+        auction.add_bid(2, 60.0);
+        assert_eq!(auction.resolve(), Some((2, 40.0)));
+    }
+
+    #[test]
+    fn test_first_price_pays_own_bid() {
+        let mut auction = Auction::new("TestSkill".to_string(), AuctionType::FirstPriceSealedBid);
+        auction.add_bid(1, 50.0);
+        auction.add_bid(2, 70.0);
+        assert_eq!(auction.resolve(), Some((2, 70.0)));
+    }
+
+    #[test]
+    fn test_dutch_clock_stops_at_tick() {
+        let mut auction = Auction::new("TestSkill".to_string(), AuctionType::Dutch)
+            .with_reserve_price(10.0)
+            .with_tick_size(4.0);
+        auction.add_bid(1, 25.0);
+        auction.add_bid(2, 21.0);
+        // Clock prices: ..., 26, 22, 18, ...; bidder 1 accepts at 22
+        assert_eq!(auction.resolve(), Some((1, 22.0)));
+    }
+
+    #[test]
+    fn test_vickrey_pays_second_price() {
+        let mut auction =
+            Auction::new("TestSkill".to_string(), AuctionType::Vickrey).with_reserve_price(10.0);
+        auction.add_bid(1, 50.0);
+        auction.add_bid(2, 70.0);
+        auction.add_bid(3, 60.0);
+        assert_eq!(auction.resolve(), Some((2, 60.0)));
+    }
+
+    #[test]
+    fn test_english_bidding_stops_one_tick_above_runner_up() {
+        let mut auction = Auction::new("TestSkill".to_string(), AuctionType::English)
+            .with_reserve_price(10.0)
+            .with_tick_size(2.0);
+        auction.bid_with_valuations(&[(1, 40.0), (2, 30.0), (3, 5.0)]);
+        assert_eq!(auction.bid_count(), 2); // Bidder 3 values it below the reserve
+        assert_eq!(auction.resolve(), Some((1, 32.0)));
+
+        // A lone bidder pays the reserve
+        let mut single = Auction::new("TestSkill".to_string(), AuctionType::English)
+            .with_reserve_price(10.0)
+            .with_tick_size(2.0);
+        single.bid_with_valuations(&[(1, 40.0)]);
+        assert_eq!(single.resolve(), Some((1, 10.0)));
+    }
+
+    #[test]
+    fn test_sealed_first_price_bids_are_shaded() {
+        let mut auction = Auction::new("TestSkill".to_string(), AuctionType::FirstPriceSealedBid)
+            .with_reserve_price(10.0);
+        auction.bid_with_valuations(&[(1, 50.0), (2, 30.0)]);
+        // Two bidders shade half of the value above the reserve
+        assert_eq!(auction.bids.get(&1), Some(&30.0));
+        assert_eq!(auction.resolve(), Some((1, 30.0)));
+
+        let mut vickrey =
+            Auction::new("TestSkill".to_string(), AuctionType::Vickrey).with_reserve_price(10.0);
+        vickrey.bid_with_valuations(&[(1, 50.0), (2, 30.0)]);
+        assert_eq!(vickrey.bids.get(&1), Some(&50.0));
+        assert_eq!(vickrey.resolve(), Some((1, 30.0)));
+    }
+
+    #[test]
+    fn test_auction_type_from_str() {
+        assert_eq!("Vickrey".parse::<AuctionType>().unwrap(), AuctionType::Vickrey);
+        assert_eq!(
+            "FirstPriceSealedBid".parse::<AuctionType>().unwrap(),
+            AuctionType::FirstPriceSealedBid
+        );
+        assert!("Sealed".parse::<AuctionType>().is_err());
+    }
+
     #[test]
     fn test_clear_bids() {
         let mut auction = Auction::new("TestSkill".to_string(), AuctionType::English);
@@ -0,0 +1,216 @@
+//! # Central Bank Module
+//!
+//! A central bank that runs monetary policy when enabled. Each step it sets a policy
+//! rate, which becomes the base loan interest rate that credit scores scale, and it
+//! can create or withdraw money:
+//!
+//! - [`MonetaryPolicyRule::TaylorRule`]: the rate follows the neutral rate, inflation
+//!   and the output gap; money operations lean against the gap between inflation and
+//!   its target
+//! - [`MonetaryPolicyRule::Scripted`]: the rate and money operations follow a path of
+//!   [`PolicyPathEntry`] values given in the configuration
+//!
+//! Money reaches persons through [`MoneyOperation::OpenMarket`] purchases, in
+//! proportion to their wealth, or as [`MoneyOperation::Helicopter`] money, in equal
+//! shares. Withdrawals reverse them. The money conservation invariant accounts for
+//! the net money the central bank has issued.
+//!
+//! # Examples
+//!
+//! ```
+//! use community_simulation::central_bank::taylor_rate;
+//!
+//! // Inflation above target raises the rate by more than the inflation gap
+//! let rate = taylor_rate(0.01, 0.02, 0.0, 1.5, 0.5, 0.0);
+//! assert!((rate - 0.06).abs() < 1e-12);
+//!
+//! // The rate never falls below zero
+//! assert_eq!(taylor_rate(0.01, -0.05, 0.0, 1.5, 0.5, 0.0), 0.0);
+//! ```
+
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumString;
+
+/// Largest money operation in one step, as a share of the money supply.
+pub const MAX_OPERATION_SHARE: f64 = 0.05;
+
+/// Number of previous steps the output gap compares the current trade count against.
+pub const OUTPUT_GAP_WINDOW: usize = 10;
+
+/// How the central bank sets its policy.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, EnumString, PartialEq, Eq, Default)]
+pub enum MonetaryPolicyRule {
+    /// Set the rate from inflation and the output gap (default)
+    #[default]
+    TaylorRule,
+    /// Follow the configured policy path
+    Scripted,
+}
+
+/// How the central bank creates and withdraws money.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, EnumString, PartialEq, Eq, Default)]
+pub enum MoneyOperation {
+    /// Only set the policy rate (default)
+    #[default]
+    None,
+    /// Buy or sell bonds, moving money in proportion to each person's wealth
+    OpenMarket,
+    /// Give or levy equal amounts of money to or from every active person
+    Helicopter,
+}
+
+/// One point of a scripted monetary policy path.
+///
+/// An entry is in force from its step until the step of the next entry.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PolicyPathEntry {
+    /// Step from which the entry applies
+    pub step: usize,
+    /// Policy rate per step
+    pub policy_rate: f64,
+    /// Money created per step (negative values withdraw money), in the base currency
+    #[serde(default)]
+    pub money_injection: f64,
+}
+
+/// Returns the path entry in force at the given step (None before the first entry).
+pub fn scripted_entry(path: &[PolicyPathEntry], step: usize) -> Option<&PolicyPathEntry> {
+    path.iter().filter(|entry| entry.step <= step).max_by_key(|entry| entry.step)
+}
+
+/// Returns the policy rate of the Taylor rule, which is never negative.
+///
+/// `rate = neutral + inflation + inflation_weight * (inflation - target) + output_weight * output_gap`
+pub fn taylor_rate(
+    neutral_rate: f64,
+    inflation: f64,
+    inflation_target: f64,
+    inflation_weight: f64,
+    output_weight: f64,
+    output_gap: f64,
+) -> f64 {
+    (neutral_rate
+        + inflation
+        + inflation_weight * (inflation - inflation_target)
+        + output_weight * output_gap)
+        .max(0.0)
+}
+
+/// Returns the relative gap between the current trade count and the average of the
+/// previous [`OUTPUT_GAP_WINDOW`] steps (0.0 without previous trades).
+pub fn output_gap(current_trades: usize, trades_per_step: &[usize]) -> f64 {
+    let window = &trades_per_step[trades_per_step.len().saturating_sub(OUTPUT_GAP_WINDOW)..];
+    if window.is_empty() {
+        return 0.0;
+    }
+    let average = window.iter().sum::<usize>() as f64 / window.len() as f64;
+    if average == 0.0 {
+        0.0
+    } else {
+        (current_trades as f64 - average) / average
+    }
+}
+
+/// Monetary policy at the end of one step.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct MonetarySnapshot {
+    /// The simulation step
+    pub step: usize,
+    /// Policy rate set in the step
+    pub policy_rate: f64,
+    /// Change of the average market price since the previous step
+    pub inflation: f64,
+    /// Relative gap between the step's trades and their recent average
+    pub output_gap: f64,
+    /// Net money created in the step (negative when money was withdrawn)
+    pub money_created: f64,
+    /// Money and savings held by persons after the operation
+    pub money_supply: f64,
+}
+
+/// The central bank's state and running totals.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CentralBank {
+    /// Rate the Taylor rule returns to at target inflation and no output gap
+    pub neutral_rate: f64,
+    /// Current policy rate
+    pub policy_rate: f64,
+    /// Average market price observed in the previous step
+    pub price_level: Option<f64>,
+    /// Bonds bought in open-market operations less bonds sold (negative when the
+    /// central bank has sold more than it bought)
+    pub bond_holdings: f64,
+    /// Money created so far
+    pub money_created: f64,
+    /// Money withdrawn so far
+    pub money_withdrawn: f64,
+    /// Policy at the end of each step
+    pub history: Vec<MonetarySnapshot>,
+}
+
+impl CentralBank {
+    /// Creates a central bank whose neutral and starting policy rate is the given rate.
+    pub fn new(neutral_rate: f64) -> Self {
+        CentralBank { neutral_rate, policy_rate: neutral_rate, ..Default::default() }
+    }
+
+    /// Records the current price level and returns the inflation since the previous
+    /// observation (0.0 for the first observation or a zero price level).
+    pub fn observe_price_level(&mut self, price_level: f64) -> f64 {
+        let inflation = match self.price_level {
+            Some(previous) if previous > 0.0 => price_level / previous - 1.0,
+            _ => 0.0,
+        };
+        self.price_level = Some(price_level);
+        inflation
+    }
+
+    /// Returns the net money issued so far (created less withdrawn).
+    pub fn net_money_issued(&self) -> f64 {
+        self.money_created - self.money_withdrawn
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_taylor_rate_responds_to_output_gap() {
+        assert_eq!(taylor_rate(0.01, 0.0, 0.0, 1.5, 0.5, 0.0), 0.01);
+        assert!((taylor_rate(0.01, 0.0, 0.0, 1.5, 0.5, 0.2) - 0.11).abs() < 1e-12);
+        assert_eq!(taylor_rate(0.01, 0.0, 0.0, 1.5, 0.5, -0.5), 0.0);
+    }
+
+    #[test]
+    fn test_output_gap_uses_recent_window() {
+        assert_eq!(output_gap(10, &[]), 0.0);
+        assert_eq!(output_gap(10, &[0, 0]), 0.0);
+        assert_eq!(output_gap(15, &[10, 10]), 0.5);
+        // Only the last OUTPUT_GAP_WINDOW steps count
+        let mut history = vec![1000];
+        history.extend([20; OUTPUT_GAP_WINDOW]);
+        assert_eq!(output_gap(10, &history), -0.5);
+    }
+
+    #[test]
+    fn test_scripted_entry_in_force() {
+        let entry = |step, policy_rate| PolicyPathEntry { step, policy_rate, money_injection: 0.0 };
+        let path = vec![entry(10, 0.05), entry(0, 0.02), entry(20, 0.0)];
+        assert_eq!(scripted_entry(&path, 0).unwrap().policy_rate, 0.02);
+        assert_eq!(scripted_entry(&path, 15).unwrap().policy_rate, 0.05);
+        assert_eq!(scripted_entry(&path, 99).unwrap().policy_rate, 0.0);
+        assert!(scripted_entry(&path[..1], 5).is_none());
+    }
+
+    #[test]
+    fn test_observe_price_level() {
+        let mut bank = CentralBank::new(0.01);
+        assert_eq!(bank.policy_rate, 0.01);
+        assert_eq!(bank.observe_price_level(10.0), 0.0);
+        assert!((bank.observe_price_level(11.0) - 0.1).abs() < 1e-12);
+        bank.money_created = 30.0;
+        bank.money_withdrawn = 12.5;
+        assert_eq!(bank.net_money_issued(), 17.5);
+    }
+}
@@ -0,0 +1,565 @@
+//! Checkpoint schema versioning and incremental (delta) checkpoints.
+//!
+//! # Schema versions
+//!
+//! Every checkpoint records the [`CHECKPOINT_SCHEMA_VERSION`] it was written with.
+//! Files without a version predate versioning and are treated as version 1. When a
+//! checkpoint is loaded, [`migrate_checkpoint`] upgrades it one version at a time
+//! through the migration chain before it is deserialized, so checkpoints of long
+//! experiments stay loadable across upgrades. Checkpoints of a newer version, or that
+//! still do not match the engine state after migration, are rejected with
+//! [`SimulationError::CheckpointIncompatible`].
+//!
+//! A change to `SimulationCheckpoint` that old files cannot be deserialized into
+//! (a new field without `#[serde(default)]`, a renamed or restructured field) bumps
+//! the version and appends a migration that rewrites the previous version's JSON.
+//!
+//! # Incremental checkpoints
+//!
+//! A full checkpoint serializes the whole simulation state, including every
+//! transaction history and per-step series, which grows with the length of the run.
+//! Incremental checkpointing writes one full base snapshot and then appends a compact
+//! binary delta per checkpoint to a log next to it (`<checkpoint>.deltas`). Each delta
+//! only holds what changed since the previous checkpoint: new elements of growing
+//! series, changed fields and replaced values.
+//!
+//! Deltas only shrink what is written to disk. Each checkpoint still serializes the
+//! full state to JSON and diffs it against the previous one, and the writer keeps that
+//! previous state in memory, so the time and memory a checkpoint takes are about the
+//! same as for a full checkpoint.
+//!
+//! [`rebuild_checkpoint`] replays the log onto the base to restore any checkpointed
+//! step; `SimulationEngine::load_checkpoint` does this automatically for the latest one.
+//! Deltas are applied to the stored JSON before migration.
+//!
+//! ## Delta log format
+//!
+//! The log starts with an 8-byte magic, the FNV-1a hash of the base file and the base
+//! step (both little-endian `u64`). Each record is the step, the payload length and a
+//! gzip-compressed JSON [`JsonDelta`]. A truncated trailing record (e.g. from a crash
+//! mid-write) is ignored.
+
+use crate::error::{Result, SimulationError};
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::fs::{File, OpenOptions};
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+
+/// Schema version of checkpoints written by this version of the simulation.
+pub const CHECKPOINT_SCHEMA_VERSION: u32 = 1;
+
+/// Schema version assumed for checkpoints written before versioning was introduced.
+///
+/// Their format is that of version 1: state added since then has serde defaults.
+const LEGACY_SCHEMA_VERSION: u32 = 1;
+
+/// Upgrade of a checkpoint from one schema version to the next.
+struct Migration {
+    /// Version the migration upgrades from (to `from + 1`)
+    from: u32,
+    description: &'static str,
+    apply: fn(&mut Map<String, Value>) -> std::result::Result<(), String>,
+}
+
+/// Migration chain, ordered by `from`; one entry per version before the current one.
+const MIGRATIONS: &[Migration] = &[];
+
+/// Schema version recorded in a checkpoint's JSON.
+pub fn checkpoint_schema_version(state: &Value) -> u32 {
+    state
+        .get("schema_version")
+        .and_then(Value::as_u64)
+        .map_or(LEGACY_SCHEMA_VERSION, |version| version as u32)
+}
+
+/// Upgrades a checkpoint's JSON to [`CHECKPOINT_SCHEMA_VERSION`] in place and returns
+/// the version it was written with.
+///
+/// # Errors
+///
+/// Returns [`SimulationError::CheckpointIncompatible`] if the checkpoint is not a JSON
+/// object, was written by a newer version, or a migration fails.
+pub fn migrate_checkpoint(state: &mut Value) -> Result<u32> {
+    let found = checkpoint_schema_version(state);
+    let incompatible =
+        |reason: String| SimulationError::CheckpointIncompatible { version: found, reason };
+    if found > CHECKPOINT_SCHEMA_VERSION {
+        return Err(incompatible(format!(
+            "written by a newer version (this version reads up to schema version {})",
+            CHECKPOINT_SCHEMA_VERSION
+        )));
+    }
+    let map = state
+        .as_object_mut()
+        .ok_or_else(|| incompatible("checkpoint is not a JSON object".to_string()))?;
+
+    for version in found..CHECKPOINT_SCHEMA_VERSION {
+        let migration = MIGRATIONS
+            .iter()
+            .find(|migration| migration.from == version)
+            .ok_or_else(|| incompatible(format!("no migration from schema version {}", version)))?;
+        info!(
+            "Migrating checkpoint from schema version {} to {}: {}",
+            version,
+            version + 1,
+            migration.description
+        );
+        (migration.apply)(map).map_err(|e| {
+            incompatible(format!("migration to schema version {} failed: {}", version + 1, e))
+        })?;
+    }
+    // Files from before versioning only lack the version itself
+    map.insert("schema_version".to_string(), Value::from(CHECKPOINT_SCHEMA_VERSION));
+    Ok(found)
+}
+
+/// Magic bytes at the start of every delta log.
+const DELTA_LOG_MAGIC: &[u8; 8] = b"CSIMDLT1";
+
+/// Structural difference between two JSON values.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum JsonDelta {
+    /// The value was replaced entirely
+    Replace(Value),
+    /// Some fields of an object changed
+    Object { changed: Vec<(String, JsonDelta)>, removed: Vec<String> },
+    /// Some elements of an array changed; the array was truncated or extended to `len`
+    Array { len: usize, changed: Vec<(usize, JsonDelta)>, appended: Vec<Value> },
+}
+
+/// Computes the delta that turns `old` into `new`, or `None` if they are equal.
+pub fn diff(old: &Value, new: &Value) -> Option<JsonDelta> {
+    match (old, new) {
+        (Value::Object(old_map), Value::Object(new_map)) => {
+            let changed: Vec<(String, JsonDelta)> = new_map
+                .iter()
+                .filter_map(|(key, new_value)| match old_map.get(key) {
+                    Some(old_value) => diff(old_value, new_value).map(|d| (key.clone(), d)),
+                    None => Some((key.clone(), JsonDelta::Replace(new_value.clone()))),
+                })
+                .collect();
+            let removed: Vec<String> =
+                old_map.keys().filter(|key| !new_map.contains_key(*key)).cloned().collect();
+            if changed.is_empty() && removed.is_empty() {
+                None
+            } else {
+                Some(JsonDelta::Object { changed, removed })
+            }
+        },
+        (Value::Array(old_items), Value::Array(new_items)) => {
+            let common = old_items.len().min(new_items.len());
+            let changed: Vec<(usize, JsonDelta)> = (0..common)
+                .filter_map(|i| diff(&old_items[i], &new_items[i]).map(|d| (i, d)))
+                .collect();
+            let appended = new_items[common..].to_vec();
+            if changed.is_empty() && appended.is_empty() && old_items.len() == new_items.len() {
+                None
+            } else {
+                Some(JsonDelta::Array { len: new_items.len(), changed, appended })
+            }
+        },
+        _ if old == new => None,
+        _ => Some(JsonDelta::Replace(new.clone())),
+    }
+}
+
+/// Applies a delta produced by [`diff`] to `target`.
+///
+/// # Errors
+///
+/// Returns an `InvalidData` error if the delta does not match the shape of `target`,
+/// which means it was computed against a different base.
+pub fn apply(target: &mut Value, delta: &JsonDelta) -> io::Result<()> {
+    match (target, delta) {
+        (target, JsonDelta::Replace(value)) => {
+            *target = value.clone();
+        },
+        (Value::Object(map), JsonDelta::Object { changed, removed }) => {
+            for key in removed {
+                map.remove(key);
+            }
+            for (key, child) in changed {
+                match child {
+                    JsonDelta::Replace(value) => {
+                        map.insert(key.clone(), value.clone());
+                    },
+                    _ => {
+                        let entry = map.get_mut(key).ok_or_else(|| mismatch(key))?;
+                        apply(entry, child)?;
+                    },
+                }
+            }
+        },
+        (Value::Array(items), JsonDelta::Array { len, changed, appended }) => {
+            items.truncate(*len);
+            for (index, child) in changed {
+                let item = items.get_mut(*index).ok_or_else(|| mismatch(&index.to_string()))?;
+                apply(item, child)?;
+            }
+            items.extend(appended.iter().cloned());
+            if items.len() != *len {
+                return Err(mismatch("array length"));
+            }
+        },
+        _ => return Err(mismatch("value type")),
+    }
+    Ok(())
+}
+
+fn mismatch(what: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("checkpoint delta does not match its base ({})", what),
+    )
+}
+
+/// FNV-1a hash, used to tie a delta log to the exact base file it extends.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// Path of the delta log belonging to a base checkpoint.
+pub fn delta_log_path(base: &Path) -> PathBuf {
+    let mut path = base.as_os_str().to_owned();
+    path.push(".deltas");
+    PathBuf::from(path)
+}
+
+/// Writes a base snapshot and starts an empty delta log for it.
+pub(crate) fn write_base(base: &Path, state: &Value, step: usize) -> io::Result<()> {
+    let bytes = serde_json::to_vec(state)?;
+    File::create(base)?.write_all(&bytes)?;
+
+    let mut log = BufWriter::new(File::create(delta_log_path(base))?);
+    log.write_all(DELTA_LOG_MAGIC)?;
+    log.write_all(&fnv1a(&bytes).to_le_bytes())?;
+    log.write_all(&(step as u64).to_le_bytes())?;
+    log.flush()
+}
+
+/// Appends the delta of one checkpointed step to the log of `base`.
+pub(crate) fn append_delta(base: &Path, step: usize, delta: &JsonDelta) -> io::Result<()> {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    serde_json::to_writer(&mut encoder, delta)?;
+    let payload = encoder.finish()?;
+
+    let mut log = OpenOptions::new().append(true).open(delta_log_path(base))?;
+    let mut record = Vec::with_capacity(payload.len() + 16);
+    record.extend_from_slice(&(step as u64).to_le_bytes());
+    record.extend_from_slice(&(payload.len() as u64).to_le_bytes());
+    record.extend_from_slice(&payload);
+    log.write_all(&record)
+}
+
+/// A delta log read from disk.
+struct DeltaLog {
+    base_step: usize,
+    records: Vec<(usize, Vec<u8>)>,
+}
+
+fn read_u64(reader: &mut impl Read) -> io::Result<Option<u64>> {
+    let mut buf = [0u8; 8];
+    match reader.read_exact(&mut buf) {
+        Ok(()) => Ok(Some(u64::from_le_bytes(buf))),
+        Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+/// Reads the delta log of `base` if it exists and belongs to the base file.
+fn read_log(base: &Path, base_bytes: &[u8]) -> io::Result<Option<DeltaLog>> {
+    let log_path = delta_log_path(base);
+    if !log_path.exists() {
+        return Ok(None);
+    }
+    let mut reader = BufReader::new(File::open(&log_path)?);
+
+    let mut magic = [0u8; 8];
+    reader.read_exact(&mut magic)?;
+    if &magic != DELTA_LOG_MAGIC {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("{:?} is not a checkpoint delta log", log_path),
+        ));
+    }
+    let hash = read_u64(&mut reader)?.ok_or_else(|| mismatch("log header"))?;
+    let base_step = read_u64(&mut reader)?.ok_or_else(|| mismatch("log header"))? as usize;
+    if hash != fnv1a(base_bytes) {
+        warn!("Ignoring {:?}: it was written for a different base checkpoint", log_path);
+        return Ok(None);
+    }
+
+    let mut records = Vec::new();
+    while let Some(step) = read_u64(&mut reader)? {
+        let Some(len) = read_u64(&mut reader)? else {
+            warn!("Ignoring truncated delta record for step {} in {:?}", step, log_path);
+            break;
+        };
+        let mut payload = vec![0u8; len as usize];
+        if let Err(e) = reader.read_exact(&mut payload) {
+            if e.kind() == io::ErrorKind::UnexpectedEof {
+                warn!("Ignoring truncated delta record for step {} in {:?}", step, log_path);
+                break;
+            }
+            return Err(e);
+        }
+        records.push((step as usize, payload));
+    }
+    Ok(Some(DeltaLog { base_step, records }))
+}
+
+fn decode_delta(payload: &[u8]) -> io::Result<JsonDelta> {
+    Ok(serde_json::from_reader(GzDecoder::new(payload))?)
+}
+
+/// Steps that can be restored from the checkpoint at `base`, in ascending order.
+///
+/// A plain full checkpoint without delta log yields just its own step.
+pub fn checkpoint_steps(base: &Path) -> io::Result<Vec<usize>> {
+    let bytes = std::fs::read(base)?;
+    match read_log(base, &bytes)? {
+        Some(log) => Ok(std::iter::once(log.base_step)
+            .chain(log.records.iter().map(|(step, _)| *step))
+            .collect()),
+        None => {
+            let state: Value = serde_json::from_slice(&bytes)?;
+            let step = state.get("current_step").and_then(Value::as_u64).unwrap_or(0);
+            Ok(vec![step as usize])
+        },
+    }
+}
+
+/// Rebuilds the checkpoint state at `step` (or the latest checkpointed step) from a
+/// base snapshot and its delta log.
+///
+/// # Errors
+///
+/// Returns a `NotFound` error if `step` was never checkpointed, or an `InvalidData`
+/// error if the files are corrupt.
+pub fn rebuild_checkpoint(base: &Path, step: Option<usize>) -> io::Result<Value> {
+    rebuild(base, step).map(|(state, _)| state)
+}
+
+/// Rebuilds like [`rebuild_checkpoint`] and also returns the number of deltas applied,
+/// or `None` if the base has no valid delta log.
+pub(crate) fn rebuild(base: &Path, step: Option<usize>) -> io::Result<(Value, Option<usize>)> {
+    let bytes = std::fs::read(base)?;
+    let mut state: Value = serde_json::from_slice(&bytes)?;
+    let Some(log) = read_log(base, &bytes)? else {
+        let current = state.get("current_step").and_then(Value::as_u64).map(|s| s as usize);
+        return match step {
+            Some(wanted) if Some(wanted) != current => Err(missing_step(wanted)),
+            _ => Ok((state, None)),
+        };
+    };
+
+    if step == Some(log.base_step) {
+        return Ok((state, Some(0)));
+    }
+    for (applied, (record_step, payload)) in log.records.iter().enumerate() {
+        if step.is_some_and(|wanted| *record_step > wanted) {
+            break;
+        }
+        apply(&mut state, &decode_delta(payload)?)?;
+        if step == Some(*record_step) {
+            return Ok((state, Some(applied + 1)));
+        }
+    }
+    match step {
+        Some(wanted) => Err(missing_step(wanted)),
+        None => Ok((state, Some(log.records.len()))),
+    }
+}
+
+fn missing_step(step: usize) -> io::Error {
+    io::Error::new(io::ErrorKind::NotFound, format!("no checkpoint was saved at step {}", step))
+}
+
+/// Removes the delta log of `base`, if any, so a full checkpoint is not extended by
+/// deltas of an earlier run.
+pub(crate) fn remove_delta_log(base: &Path) -> io::Result<()> {
+    match std::fs::remove_file(delta_log_path(base)) {
+        Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e),
+        _ => Ok(()),
+    }
+}
+
+/// Writes incremental checkpoints of one run: a base snapshot followed by deltas.
+pub(crate) struct IncrementalCheckpointer {
+    base: PathBuf,
+    /// Full serialized state of the previous checkpoint, which the next one is diffed
+    /// against
+    last_state: Value,
+    deltas_since_base: usize,
+}
+
+impl IncrementalCheckpointer {
+    /// Writes `state` as a new base snapshot at `base`, discarding any old delta log.
+    pub(crate) fn start(base: &Path, state: Value, step: usize) -> io::Result<Self> {
+        write_base(base, &state, step)?;
+        Ok(Self { base: base.to_path_buf(), last_state: state, deltas_since_base: 0 })
+    }
+
+    /// Continues an existing base and delta log whose latest state is `state`.
+    pub(crate) fn resume(base: &Path, state: Value, deltas_since_base: usize) -> Self {
+        Self { base: base.to_path_buf(), last_state: state, deltas_since_base }
+    }
+
+    /// Whether the next checkpoint to `base` can be appended as a delta, given the
+    /// maximum number of deltas per base (0 = unlimited).
+    pub(crate) fn can_append(&self, base: &Path, rebase_interval: usize) -> bool {
+        self.base == base && (rebase_interval == 0 || self.deltas_since_base < rebase_interval)
+    }
+
+    /// Appends the delta from the previous checkpoint to `state`.
+    pub(crate) fn append(&mut self, state: Value, step: usize) -> io::Result<()> {
+        let delta = diff(&self.last_state, &state)
+            .unwrap_or(JsonDelta::Object { changed: Vec::new(), removed: Vec::new() });
+        append_delta(&self.base, step, &delta)?;
+        self.last_state = state;
+        self.deltas_since_base += 1;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_migration_chain_covers_every_version() {
+        for (index, migration) in MIGRATIONS.iter().enumerate() {
+            assert_eq!(migration.from, LEGACY_SCHEMA_VERSION + index as u32);
+        }
+        assert_eq!(LEGACY_SCHEMA_VERSION + MIGRATIONS.len() as u32, CHECKPOINT_SCHEMA_VERSION);
+    }
+
+    #[test]
+    fn test_migrate_legacy_checkpoint() {
+        // Checkpoints from before versioning already have the current format
+        let mut state = json!({"current_step": 3, "investments": {"1": {}}});
+        assert_eq!(migrate_checkpoint(&mut state).unwrap(), LEGACY_SCHEMA_VERSION);
+        assert_eq!(
+            state,
+            json!({"current_step": 3, "investments": {"1": {}}, "schema_version": 1})
+        );
+
+        // Migrating a current checkpoint changes nothing
+        let before = state.clone();
+        assert_eq!(migrate_checkpoint(&mut state).unwrap(), CHECKPOINT_SCHEMA_VERSION);
+        assert_eq!(state, before);
+    }
+
+    #[test]
+    fn test_migrate_rejects_newer_version() {
+        let mut state = json!({"schema_version": CHECKPOINT_SCHEMA_VERSION + 1});
+        match migrate_checkpoint(&mut state) {
+            Err(SimulationError::CheckpointIncompatible { version, .. }) => {
+                assert_eq!(version, CHECKPOINT_SCHEMA_VERSION + 1)
+            },
+            other => panic!("expected CheckpointIncompatible, got {:?}", other),
+        }
+        assert!(migrate_checkpoint(&mut json!([1, 2])).is_err());
+    }
+
+    #[test]
+    fn test_diff_and_apply_round_trip() {
+        let old = json!({"step": 1, "series": [1, 2], "nested": {"a": 1, "b": [true]}, "gone": 0});
+        let new =
+            json!({"step": 2, "series": [1, 3, 4], "nested": {"a": 1, "b": []}, "added": "x"});
+
+        let delta = diff(&old, &new).unwrap();
+        let mut patched = old.clone();
+        apply(&mut patched, &delta).unwrap();
+        assert_eq!(patched, new);
+        assert_eq!(diff(&new, &new), None);
+    }
+
+    #[test]
+    fn test_appended_series_only_stores_new_elements() {
+        let old = json!({"series": [1, 2, 3]});
+        let new = json!({"series": [1, 2, 3, 4]});
+        let delta = diff(&old, &new).unwrap();
+        assert_eq!(
+            delta,
+            JsonDelta::Object {
+                changed: vec![(
+                    "series".to_string(),
+                    JsonDelta::Array { len: 4, changed: vec![], appended: vec![json!(4)] }
+                )],
+                removed: vec![],
+            }
+        );
+    }
+
+    #[test]
+    fn test_apply_rejects_mismatched_base() {
+        let delta = diff(&json!({"a": [1]}), &json!({"a": [2]})).unwrap();
+        assert!(apply(&mut json!({"b": 1}), &delta).is_err());
+    }
+
+    #[test]
+    fn test_rebuild_any_step_from_log() {
+        let dir = tempdir().unwrap();
+        let base = dir.path().join("checkpoint.json");
+        let states: Vec<Value> =
+            (0..4).map(|i| json!({"current_step": i * 10, "series": vec![i; i]})).collect();
+
+        write_base(&base, &states[0], 0).unwrap();
+        for pair in states.windows(2) {
+            let step = pair[1]["current_step"].as_u64().unwrap() as usize;
+            append_delta(&base, step, &diff(&pair[0], &pair[1]).unwrap()).unwrap();
+        }
+
+        assert_eq!(checkpoint_steps(&base).unwrap(), vec![0, 10, 20, 30]);
+        for state in &states {
+            let step = state["current_step"].as_u64().unwrap() as usize;
+            assert_eq!(&rebuild_checkpoint(&base, Some(step)).unwrap(), state);
+        }
+        assert_eq!(rebuild_checkpoint(&base, None).unwrap(), states[3]);
+        assert_eq!(
+            rebuild_checkpoint(&base, Some(15)).unwrap_err().kind(),
+            io::ErrorKind::NotFound
+        );
+    }
+
+    #[test]
+    fn test_truncated_record_is_ignored() {
+        let dir = tempdir().unwrap();
+        let base = dir.path().join("checkpoint.json");
+        write_base(&base, &json!({"current_step": 0}), 0).unwrap();
+        append_delta(
+            &base,
+            5,
+            &diff(&json!({"current_step": 0}), &json!({"current_step": 5})).unwrap(),
+        )
+        .unwrap();
+        let log = delta_log_path(&base);
+        let len = std::fs::metadata(&log).unwrap().len();
+        OpenOptions::new().write(true).open(&log).unwrap().set_len(len - 3).unwrap();
+
+        assert_eq!(checkpoint_steps(&base).unwrap(), vec![0]);
+        assert_eq!(rebuild_checkpoint(&base, None).unwrap(), json!({"current_step": 0}));
+    }
+
+    #[test]
+    fn test_log_for_other_base_is_ignored() {
+        let dir = tempdir().unwrap();
+        let base = dir.path().join("checkpoint.json");
+        write_base(&base, &json!({"current_step": 0}), 0).unwrap();
+        append_delta(&base, 5, &JsonDelta::Replace(json!({"current_step": 5}))).unwrap();
+
+        // A full checkpoint written over the base invalidates the old log
+        std::fs::write(&base, br#"{"current_step": 7}"#).unwrap();
+        assert_eq!(checkpoint_steps(&base).unwrap(), vec![7]);
+    }
+}
@@ -1,5 +1,13 @@
+use crate::central_bank::{MonetaryPolicyRule, MoneyOperation, PolicyPathEntry};
+use crate::crisis::CrisisDefinition;
+use crate::environment::Resource;
 use crate::error::{Result, SimulationError};
+use crate::geography::{Region, TransportLink};
+use crate::government::TaxBracket;
+use crate::person::SocialClass;
 use crate::scenario::{DemandStrategy, Scenario};
+use crate::timeline::{TimelineAction, TimelineEvent};
+use crate::transfers::{TransferCondition, TransferFunding, TransferScheme};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs;
@@ -298,6 +306,32 @@ pub struct SimulationConfig {
     #[serde(default = "default_min_money_to_lend")]
     pub min_money_to_lend: f64,
 
+    /// Number of missed payments after which a loan defaults.
+    ///
+    /// On default the lender seizes the borrower's assets as collateral, the
+    /// borrower's credit insurance covers what it can, and the rest is written off
+    /// (or settled through bankruptcy when enable_bankruptcy is true).
+    /// Only used when enable_loans is true. Must be greater than 0.
+    #[serde(default = "default_loan_default_threshold")]
+    pub loan_default_threshold: usize,
+
+    /// Enable personal bankruptcy for borrowers whose loans default.
+    ///
+    /// A person's first bankruptcy restructures all of their debt: lenders forgive
+    /// `bankruptcy_haircut` of the outstanding balance and the rest becomes an
+    /// interest-free loan over `loan_repayment_period` steps. Any later bankruptcy
+    /// discharges all remaining debt. Without bankruptcy only the defaulted loan is
+    /// written off.
+    /// Requires enable_loans.
+    #[serde(default)]
+    pub enable_bankruptcy: bool,
+
+    /// Share of outstanding debt forgiven when a bankruptcy restructures it (0.0-1.0).
+    ///
+    /// Only used when enable_bankruptcy is true.
+    #[serde(default = "default_bankruptcy_haircut")]
+    pub bankruptcy_haircut: f64,
+
     /// Enable peer-to-peer lending marketplace for decentralized credit.
     ///
     /// When enabled, creates a marketplace where persons can directly lend to each other
@@ -310,6 +344,10 @@ pub struct SimulationConfig {
     /// - Automatic matching: Algorithm matches borrowers with suitable lenders
     /// - Platform fees: Optional transaction fees for marketplace operation
     ///
+    /// Every step, persons above `min_money_to_lend` post offers whose minimum credit
+    /// score follows their strategy, and persons who cannot afford their unmet needs
+    /// borrow the shortfall from the cheapest offer they qualify for.
+    ///
     /// Only has effect when enable_loans is also true.
     /// Set to false to disable P2P lending marketplace (default).
     #[serde(default)]
@@ -327,9 +365,10 @@ pub struct SimulationConfig {
 
     /// Enable investment system where persons can invest money for returns.
     ///
-    /// When enabled, persons can make investments in education (other persons' learning)
-    /// or production capacity enhancements. Investments provide periodic returns over time
-    /// based on the configured return rate.
+    /// When enabled, persons can invest in other persons' education (the investee learns a
+    /// new skill) or existing skills (working capital). Investees repay principal plus
+    /// returns over time based on the configured return rate; if an investee cannot pay,
+    /// the investment defaults and the investor absorbs the unrecovered principal.
     /// Set to false to disable investments (default).
     #[serde(default)]
     pub enable_investments: bool,
@@ -396,6 +435,27 @@ pub struct SimulationConfig {
     #[serde(default)]
     pub resume_from_checkpoint: bool,
 
+    /// Save automatic checkpoints incrementally.
+    ///
+    /// When true, the first automatic checkpoint is a full base snapshot and every later
+    /// one only appends the changes since the previous checkpoint to a compact binary
+    /// delta log next to it (`<checkpoint_file>.deltas`). Any checkpointed step can be
+    /// restored with the `checkpoint rebuild` subcommand. Set to false to write a full
+    /// JSON snapshot at every interval (default).
+    ///
+    /// This saves disk space only: each checkpoint still serializes and diffs the full
+    /// state, so it takes about as long as a full snapshot.
+    #[serde(default)]
+    pub incremental_checkpoints: bool,
+
+    /// Number of deltas after which an incremental checkpoint writes a new base snapshot.
+    ///
+    /// Rebasing bounds the time needed to restore the latest step, but discards the
+    /// earlier checkpointed steps. Set to 0 to never rebase (default).
+    /// Only used when incremental_checkpoints is true.
+    #[serde(default)]
+    pub checkpoint_rebase_interval: usize,
+
     /// Tax rate as a percentage of trade income (0.0-1.0, e.g., 0.10 = 10% tax).
     ///
     /// This represents an income tax collected on seller's proceeds from trades.
@@ -408,13 +468,67 @@ pub struct SimulationConfig {
 
     /// Enable redistribution of collected taxes to all persons.
     ///
-    /// When enabled, taxes collected during each step are distributed equally
-    /// among all persons at the end of each step. This simulates basic income
-    /// or wealth redistribution policies.
+    /// When enabled, taxes collected during each step are paid out to persons at
+    /// the end of each step according to `transfer_scheme` (by default equally
+    /// among all persons). This simulates basic income or wealth redistribution
+    /// policies.
     /// Set to false to collect taxes without redistribution (default).
     #[serde(default)]
     pub enable_tax_redistribution: bool,
 
+    /// Who receives the redistributed taxes (EqualShare, UniversalBasicIncome,
+    /// NegativeIncomeTax, MeansTested, Conditional).
+    ///
+    /// EqualShare shares the step's taxes equally among all persons; the other schemes
+    /// pay fixed amounts to the persons they target (see `transfer_amount`).
+    /// Only used when enable_tax_redistribution is true.
+    /// Default: EqualShare
+    #[serde(default)]
+    pub transfer_scheme: TransferScheme,
+
+    /// How transfers are paid for (Taxes, MoneyCreation).
+    ///
+    /// Taxes pays transfers out of the step's taxes, scaling them down when the taxes
+    /// fall short; MoneyCreation pays them in full with newly created money.
+    /// Only used when enable_tax_redistribution is true.
+    /// Default: Taxes
+    #[serde(default)]
+    pub transfer_funding: TransferFunding,
+
+    /// Transfer per step to each recipient of the UniversalBasicIncome, MeansTested
+    /// and Conditional schemes.
+    ///
+    /// Default: 5.0
+    #[serde(default = "default_transfer_amount")]
+    pub transfer_amount: f64,
+
+    /// Income per step below which the NegativeIncomeTax scheme pays out.
+    ///
+    /// Income is the value of the skills a person sold in the step.
+    /// Default: 50.0
+    #[serde(default = "default_negative_income_tax_threshold")]
+    pub negative_income_tax_threshold: f64,
+
+    /// Share of the gap between income and the threshold that the NegativeIncomeTax
+    /// scheme pays out (0.0-1.0).
+    ///
+    /// Default: 0.5
+    #[serde(default = "default_negative_income_tax_rate")]
+    pub negative_income_tax_rate: f64,
+
+    /// Wealth percentile below which persons receive MeansTested and Conditional
+    /// transfers (0.0-1.0).
+    ///
+    /// Default: 0.25 (the poorest quarter)
+    #[serde(default = "default_means_test_percentile")]
+    pub means_test_percentile: f64,
+
+    /// What the Conditional scheme requires of its recipients (Education, Health).
+    ///
+    /// Default: Education
+    #[serde(default)]
+    pub transfer_condition: TransferCondition,
+
     /// Number of skills each person can provide.
     ///
     /// Determines how many different skills each person possesses and can offer to others.
@@ -542,23 +656,33 @@ pub struct SimulationConfig {
 
     /// Enable auction-based price discovery as an alternative to bilateral trading.
     ///
-    /// When enabled, some or all trades can occur through auctions where multiple
-    /// buyers submit bids and the highest bidder wins. This provides an alternative
-    /// price discovery mechanism to traditional bilateral negotiation.
-    /// Auctions can lead to more efficient price discovery, especially for scarce skills.
+    /// When enabled, contested skills (demand above supply in the current step) are
+    /// sold by auction at the start of the trade phase: each provider auctions one unit
+    /// to the participating buyers, with its bilateral price as reserve. Buyers bid
+    /// according to their urgency and budget; winners skip bilateral trading for that
+    /// skill. Auctions can lead to more efficient price discovery for scarce skills.
     /// Set to false to use only bilateral trading (default).
     #[serde(default)]
     pub enable_auctions: bool,
 
-    /// Percentage of trades routed through auctions (0.0-1.0).
+    /// Fraction of buyers of a contested skill who take part in its auctions (0.0-1.0).
     ///
-    /// Determines what fraction of trades use auction mechanism instead of
-    /// bilateral trading. For example, 0.3 means 30% of trades go through auctions.
+    /// Buyers who stay out trade bilaterally as usual. For example, 0.3 means 30% of
+    /// the buyers of each contested skill bid in its auctions.
     /// Only used when enable_auctions is true.
-    /// Default: 0.2 (20% of trades)
+    /// Default: 0.2 (20% of buyers)
     #[serde(default = "default_auction_participation_rate")]
     pub auction_participation_rate: f64,
 
+    /// Auction mechanisms to use; each auction picks one of them at random.
+    ///
+    /// Listing several types runs them side by side in the same market so their
+    /// clearing prices and seller surplus can be compared in the auction statistics.
+    /// Only used when enable_auctions is true.
+    /// Default: [English]
+    #[serde(default = "default_auction_types")]
+    pub auction_types: Vec<crate::auction::AuctionType>,
+
     /// Enable contract system for long-term agreements between persons.
     ///
     /// When enabled, persons can form long-term contracts that lock in prices
@@ -744,6 +868,35 @@ pub struct SimulationConfig {
     #[serde(default = "default_crisis_severity")]
     pub crisis_severity: f64,
 
+    /// User-defined crises, in addition to the built-in crisis events.
+    ///
+    /// Each crisis targets skills, groups and currencies, lasts `duration` steps with
+    /// a decay profile, and affects demand, prices, money and health. It can spread to
+    /// neighboring groups. The results report how long GDP took to recover from each
+    /// crisis. See `CrisisDefinition` for all fields.
+    ///
+    /// Example (YAML):
+    /// ```yaml
+    /// custom_crises:
+    ///   - name: Harvest Failure
+    ///     start_step: 100
+    ///     duration: 30
+    ///     decay: Linear
+    ///     targets: { skills: [Skill1, Skill2], groups: [0] }
+    ///     effects: { demand: 0.3, prices: 0.5 }
+    ///     contagion: 0.2
+    ///   - name: Bank Run
+    ///     probability: 0.01
+    ///     duration: 10
+    ///     decay: Exponential
+    ///     effects: { money: -0.02 }
+    /// ```
+    ///
+    /// Applied whether or not enable_crisis_events is set.
+    /// Default: empty
+    #[serde(default)]
+    pub custom_crises: Vec<CrisisDefinition>,
+
     /// Currency system configuration for multi-currency simulations.
     ///
     /// Contains the set of currencies available in the simulation and their exchange rates.
@@ -759,10 +912,10 @@ pub struct SimulationConfig {
     ///       exchange_rate: 1.0
     ///     EUR:
     ///       id: "EUR"
-    ///       exchange_rate: 1.2    # 1 EUR = 1.2 USD
+    ///       exchange_rate: 1.2    # 1 USD = 1.2 EUR
     ///     JPY:
     ///       id: "JPY"
-    ///       exchange_rate: 0.01   # 1 JPY = 0.01 USD
+    ///       exchange_rate: 150.0  # 1 USD = 150 JPY
     /// ```
     ///
     /// This enables modeling:
@@ -776,14 +929,31 @@ pub struct SimulationConfig {
 
     /// Enable multi-currency features where persons can hold and trade in different currencies.
     ///
-    /// When enabled, each person has a currency_id that determines their denomination.
-    /// Cross-currency trades automatically apply exchange rate conversions.
+    /// When enabled, persons are assigned round-robin to the currencies of `currency_system`
+    /// and hold their money in that currency. Prices are denominated in the base
+    /// currency; at settlement the buyer pays the price converted into their currency at
+    /// the live exchange rate and the seller receives it converted into theirs. Rates
+    /// float each step with the trade balance of each currency zone (see
+    /// `fx_adjustment_rate`), and wealth statistics convert every balance into the base
+    /// currency.
+    /// Currency devaluation crises then hit a single currency instead of everyone's money.
     /// When disabled (default), all persons use the base currency with no conversion.
     ///
     /// Set to false to disable multi-currency (default).
     #[serde(default)]
     pub enable_multi_currency: bool,
 
+    /// How strongly exchange rates react to trade imbalances each step (0.0-1.0).
+    ///
+    /// Each step, every non-base currency's rate is multiplied by
+    /// `1 - fx_adjustment_rate * balance`, where balance is the zone's normalized
+    /// net exports in [-1, 1]. Surplus zones appreciate, deficit zones depreciate.
+    /// 0.0 keeps exchange rates fixed.
+    /// Only used when enable_multi_currency is true.
+    /// Default: 0.05
+    #[serde(default = "default_fx_adjustment_rate")]
+    pub fx_adjustment_rate: f64,
+
     /// Enable insurance system where persons can purchase coverage against economic risks.
     ///
     /// When enabled, persons can buy insurance policies that protect against various risks:
@@ -1076,6 +1246,52 @@ pub struct SimulationConfig {
     #[serde(default)]
     pub distance_cost_factor: f64,
 
+    /// Named regions of the map, by name and center (0.0-100.0 on both axes).
+    ///
+    /// Every person belongs to the region whose center is nearest to their location.
+    /// Regions are reported with their population, per-region Gini coefficient and a
+    /// price index of what their residents paid relative to market prices.
+    ///
+    /// Example (YAML):
+    /// ```yaml
+    /// regions:
+    ///   - { name: Port, x: 20.0, y: 50.0 }
+    ///   - { name: Capital, x: 50.0, y: 50.0 }
+    ///   - { name: Hills, x: 80.0, y: 50.0 }
+    /// transport_links:
+    ///   - { from: Port, to: Capital, cost: 0.05 }
+    ///   - { from: Capital, to: Hills, cost: 0.2 }
+    /// ```
+    ///
+    /// Default: empty (no regions)
+    #[serde(default)]
+    pub regions: Vec<Region>,
+
+    /// Two-way transport links between regions, each with a cost added to the price
+    /// of skills bought along it (0.1 = +10%).
+    ///
+    /// Costs add up along the cheapest route, and regions that no route connects
+    /// cannot trade with each other. Without links, every region trades with every
+    /// other at no cost. Applied on top of `distance_cost_factor`.
+    /// Default: empty
+    #[serde(default)]
+    pub transport_links: Vec<TransportLink>,
+
+    /// Probability per step that a person considers moving to another region (0.0-1.0).
+    ///
+    /// A person moves to the reachable region where residents earned the most in the
+    /// step, net of the transport cost to it, if that beats their own region by more
+    /// than `migration_threshold`. Requires regions.
+    /// Default: 0.0 (no migration)
+    #[serde(default)]
+    pub migration_rate: f64,
+
+    /// How much better another region's prospects must be for a person to move there
+    /// (0.2 = 20% higher average income).
+    /// Default: 0.2
+    #[serde(default = "default_migration_threshold")]
+    pub migration_threshold: f64,
+
     /// Price elasticity factor controlling sensitivity to supply/demand imbalances.
     ///
     /// This factor determines how dramatically prices change when supply doesn't match demand.
@@ -1129,6 +1345,17 @@ pub struct SimulationConfig {
     #[serde(default)]
     pub enable_events: bool,
 
+    /// Enable the per-phase performance profiler.
+    ///
+    /// When enabled, the engine records the wall-clock time of every step phase (and of
+    /// trade matching and execution) plus allocation counts, if the binary installs
+    /// `profiler::CountingAllocator`. The report is available as `profile` in the
+    /// simulation results.
+    ///
+    /// Set to false to disable profiling (default).
+    #[serde(default)]
+    pub enable_profiling: bool,
+
     /// Enable production system where persons can combine skills to create new skills.
     ///
     /// When enabled, persons can use recipes to combine two skills they possess into
@@ -1192,6 +1419,70 @@ pub struct SimulationConfig {
     #[serde(default)]
     pub custom_resource_reserves: Option<std::collections::HashMap<String, f64>>,
 
+    /// Regeneration rate per step for each resource type.
+    ///
+    /// Water and Land regrow logistically toward their carrying capacity: the rate is
+    /// the growth rate, so regeneration is fastest at half the capacity and an
+    /// exhausted resource does not recover. Energy and Materials regain `rate` times
+    /// their initial reserves each step, up to the initial reserves.
+    ///
+    /// Example in YAML:
+    /// ```yaml
+    /// resource_regeneration_rates:
+    ///   water: 0.05
+    ///   energy: 0.001
+    /// ```
+    ///
+    /// Only used when enable_environment is true.
+    /// Default: Empty (resources do not regenerate)
+    /// Valid range: 0.0 to 1.0 per resource
+    #[serde(default)]
+    pub resource_regeneration_rates: HashMap<String, f64>,
+
+    /// Carrying capacity of Water and Land.
+    ///
+    /// The level logistic regeneration converges to. Resources without an entry use
+    /// their initial reserves. Only `water` and `land` have a carrying capacity.
+    ///
+    /// Only used when enable_environment is true.
+    /// Default: Empty (capacity equals the initial reserves)
+    #[serde(default)]
+    pub resource_carrying_capacities: HashMap<String, f64>,
+
+    /// Maximum consumption per step for each resource type.
+    ///
+    /// Trades that would push a resource's consumption in the current step above its
+    /// quota do not take place. Resources without an entry are unlimited.
+    ///
+    /// Only used when enable_environment is true.
+    /// Default: Empty (no quotas)
+    #[serde(default)]
+    pub resource_quotas: HashMap<String, f64>,
+
+    /// Stop consumption once a resource's reserves are exhausted.
+    ///
+    /// When enabled, trades that need more of a resource than remains do not take
+    /// place, so reserves can no longer be overconsumed.
+    ///
+    /// Only used when enable_environment is true.
+    /// Default: false (reserves can be overconsumed)
+    #[serde(default)]
+    pub enforce_resource_reserves: bool,
+
+    /// Price increase of a skill with average resource intensity at full scarcity.
+    ///
+    /// Each skill uses resources with its own intensity (drawn at the start of the
+    /// simulation). As reserves are depleted, trade prices are multiplied by
+    /// `1 + factor * weighted scarcity`, so resource-intensive skills get more
+    /// expensive first. A value of 1.0 doubles the cost of an average skill once all
+    /// reserves are exhausted; 0.0 disables the feedback.
+    ///
+    /// Only used when enable_environment is true.
+    /// Default: 1.0
+    /// Valid range: 0.0 to 10.0
+    #[serde(default = "default_resource_scarcity_price_factor")]
+    pub resource_scarcity_price_factor: f64,
+
     /// Enable voting system for governance and collective decision-making.
     ///
     /// When enabled, persons can create proposals and vote on them using the specified voting method.
@@ -1491,10 +1782,6 @@ pub struct SimulationConfig {
     /// Requires enable_friendships to be true for imitation learning to work
     /// (agents imitate strategies of their friends who are more successful).
     ///
-    /// **Note on Checkpoints:** When resuming from a checkpoint, strategy evolution
-    /// history (snapshots and counters) is reset. Only the current strategy distribution
-    /// is preserved. This is by design to keep checkpoint files compact.
-    ///
     /// Set to false to disable strategy evolution (default).
     #[serde(default)]
     pub enable_strategy_evolution: bool,
@@ -1700,6 +1987,378 @@ pub struct SimulationConfig {
     #[serde(default)]
     pub initial_sick_persons: usize,
 
+    /// Number of steps between catching the disease and becoming sick.
+    ///
+    /// Exposed persons are not infectious and trade normally until the incubation
+    /// period has passed. A value of 0 skips the exposed state (SIR model); larger
+    /// values give an SEIR model.
+    ///
+    /// Only used when enable_health is true.
+    /// Default: 0 (infection makes persons sick immediately)
+    #[serde(default)]
+    pub disease_incubation_period: usize,
+
+    /// Number of steps recovered persons stay immune.
+    ///
+    /// After this many steps a recovered or vaccinated person becomes susceptible
+    /// again (SEIRS model). A value of 0 gives permanent immunity.
+    ///
+    /// Only used when enable_health is true.
+    /// Default: 0 (permanent immunity)
+    #[serde(default)]
+    pub immunity_duration: usize,
+
+    /// Probability per step that a sick person infects each susceptible friend (0.0-1.0).
+    ///
+    /// Spreads the disease along friendship edges in addition to trades. Has no
+    /// effect unless enable_friendships is true.
+    ///
+    /// Only used when enable_health is true.
+    /// Default: 0.02 (2% chance per sick friend per step)
+    #[serde(default = "default_contact_transmission_rate")]
+    pub contact_transmission_rate: f64,
+
+    /// Share of the population that must be sick for a lockdown to be in force (0.0-1.0).
+    ///
+    /// The lockdown is checked every step and lifts once the sick share falls below
+    /// the threshold again. Set to 0.0 to never lock down.
+    ///
+    /// Only used when enable_health is true.
+    /// Default: 0.0 (no lockdowns)
+    #[serde(default)]
+    pub lockdown_threshold: f64,
+
+    /// Share of trades that do not take place during a lockdown (0.0-1.0).
+    ///
+    /// Only used when enable_health is true and lockdown_threshold is above 0.0.
+    /// Default: 0.5 (half of all trades are cancelled)
+    #[serde(default = "default_lockdown_trade_reduction")]
+    pub lockdown_trade_reduction: f64,
+
+    /// Probability per step that a susceptible person is vaccinated (0.0-1.0).
+    ///
+    /// Vaccinated persons become immune as if they had recovered.
+    ///
+    /// Only used when enable_health is true.
+    /// Default: 0.0 (no vaccination campaign)
+    #[serde(default)]
+    pub vaccination_rate: f64,
+
+    /// Step at which the vaccination campaign starts.
+    ///
+    /// Only used when enable_health is true and vaccination_rate is above 0.0.
+    /// Default: 0 (vaccinate from the start)
+    #[serde(default)]
+    pub vaccination_start_step: usize,
+
+    /// Sick pay per sick person per step, paid out of the tax pool.
+    ///
+    /// When the tax pool cannot cover everyone, the available money is shared
+    /// equally among the sick. Set to 0.0 to disable sick pay.
+    ///
+    /// Only used when enable_health is true.
+    /// Default: 0.0 (no sick pay)
+    #[serde(default)]
+    pub sick_pay: f64,
+
+    /// Enable demographics: ageing, retirement, death, births, immigration and market exit.
+    ///
+    /// When enabled, every person has an age in steps. Persons retire at
+    /// `retirement_age` and stop selling their skills, and retirees die with
+    /// probability `mortality_rate` per step. Estates pass to the person's children,
+    /// or to the tax pool when they have none. Working-age persons have children with
+    /// probability `birth_rate` per step, immigrants arrive at `immigration_rate` per
+    /// step, and persons who are broke for `exit_after_broke_steps` steps leave the
+    /// market. The population therefore changes over the run.
+    ///
+    /// Set to false to keep the population fixed at entity_count (default).
+    #[serde(default)]
+    pub enable_demographics: bool,
+
+    /// Age in steps at which a person retires and stops selling skills.
+    ///
+    /// Initial persons start with an age drawn uniformly below this value.
+    /// Only used when enable_demographics is true.
+    /// Default: 60 steps
+    #[serde(default = "default_retirement_age")]
+    pub retirement_age: usize,
+
+    /// Probability that a retired person dies in a step (0.0-1.0).
+    ///
+    /// Only used when enable_demographics is true.
+    /// Default: 0.05 (5% per step, an expected retirement of 20 steps)
+    #[serde(default = "default_mortality_rate")]
+    pub mortality_rate: f64,
+
+    /// Probability that a working-age person has a child in a step (0.0-1.0).
+    ///
+    /// The child inherits the parent's skills and strategy and receives up to
+    /// initial_money_per_person from the parent, at most half of their money.
+    /// Only used when enable_demographics is true.
+    /// Default: 0.01 (1% per step)
+    #[serde(default = "default_birth_rate")]
+    pub birth_rate: f64,
+
+    /// Expected number of immigrants arriving per step.
+    ///
+    /// The integer part arrives every step and the fractional part is the
+    /// probability of one more. Immigrants draw skills_per_person random skills from
+    /// the market and bring initial_money_per_person of new money.
+    /// Only used when enable_demographics is true.
+    /// Default: 0.1 (one immigrant every 10 steps on average)
+    #[serde(default = "default_immigration_rate")]
+    pub immigration_rate: f64,
+
+    /// Consecutive broke steps after which a person exits the market.
+    ///
+    /// A person is broke when their money and savings together cannot pay
+    /// min_skill_price. Set to 0 to disable market exit.
+    /// Only used when enable_demographics is true.
+    /// Default: 10 steps
+    #[serde(default = "default_exit_after_broke_steps")]
+    pub exit_after_broke_steps: usize,
+
+    /// Enable firms: agents that employ persons at a wage and sell a produced skill.
+    ///
+    /// When enabled, `firm_count` firms are founded before the first step by persons
+    /// who put up `firm_initial_capital`. Each firm combines two skills offered on the
+    /// market through a production recipe, hires persons who provide them and pays
+    /// each employee `firm_wage` per step. Employees stop selling their own skills.
+    /// Firms sell their output to persons who need it, pay part of their profit to
+    /// their owner and go bankrupt when they cannot pay their wage bill.
+    ///
+    /// Set to false to have only self-employed persons (default).
+    #[serde(default)]
+    pub enable_firms: bool,
+
+    /// Number of firms founded before the first step.
+    ///
+    /// Only used when enable_firms is true.
+    /// Default: 5
+    #[serde(default = "default_firm_count")]
+    pub firm_count: usize,
+
+    /// Probability that a new firm is founded in a step (0.0-1.0).
+    ///
+    /// Only used when enable_firms is true.
+    /// Default: 0.05 (one new firm every 20 steps on average)
+    #[serde(default = "default_firm_founding_rate")]
+    pub firm_founding_rate: f64,
+
+    /// Capital the founder puts into a new firm, in the base currency.
+    ///
+    /// Only persons who hold at least this much money can found a firm.
+    /// Only used when enable_firms is true.
+    /// Default: 50.0
+    #[serde(default = "default_firm_initial_capital")]
+    pub firm_initial_capital: f64,
+
+    /// Wage a firm pays each employee per step, in the base currency.
+    ///
+    /// Only used when enable_firms is true.
+    /// Default: 5.0
+    #[serde(default = "default_firm_wage")]
+    pub firm_wage: f64,
+
+    /// Maximum number of employees per firm.
+    ///
+    /// Only used when enable_firms is true.
+    /// Default: 10
+    #[serde(default = "default_firm_max_employees")]
+    pub firm_max_employees: usize,
+
+    /// Units of output produced per step by each pair of employees providing the
+    /// two recipe inputs.
+    ///
+    /// Only used when enable_firms is true.
+    /// Default: 2.0
+    #[serde(default = "default_firm_productivity")]
+    pub firm_productivity: f64,
+
+    /// Share of a firm's profit paid to its owner each step (0.0-1.0).
+    ///
+    /// The rest is retained as cash. Only used when enable_firms is true.
+    /// Default: 0.5
+    #[serde(default = "default_firm_dividend_share")]
+    pub firm_dividend_share: f64,
+
+    /// Enable the central bank and monetary policy.
+    ///
+    /// When enabled, a central bank sets a policy rate each step, which replaces
+    /// `loan_interest_rate` as the base rate that credit scores scale. It follows
+    /// `monetary_policy_rule` and can create or withdraw money through
+    /// `money_operation`. The money conservation invariant accounts for the money it
+    /// issues.
+    ///
+    /// Set to false to keep the loan interest rate and money supply fixed (default).
+    #[serde(default)]
+    pub enable_central_bank: bool,
+
+    /// How the central bank sets its policy.
+    ///
+    /// - TaylorRule: the rate follows inflation and the output gap (default)
+    /// - Scripted: the rate and money operations follow `monetary_policy_path`
+    ///
+    /// Only used when enable_central_bank is true.
+    #[serde(default)]
+    pub monetary_policy_rule: MonetaryPolicyRule,
+
+    /// Inflation the Taylor rule aims for, as the change of the average market price
+    /// per step.
+    ///
+    /// Only used when enable_central_bank is true.
+    /// Default: 0.0 (stable prices)
+    #[serde(default)]
+    pub inflation_target: f64,
+
+    /// Weight of the inflation gap in the Taylor rule.
+    ///
+    /// Only used when enable_central_bank is true.
+    /// Default: 1.5
+    #[serde(default = "default_taylor_inflation_weight")]
+    pub taylor_inflation_weight: f64,
+
+    /// Weight of the output gap (trades relative to their recent average) in the
+    /// Taylor rule.
+    ///
+    /// Only used when enable_central_bank is true.
+    /// Default: 0.5
+    #[serde(default = "default_taylor_output_weight")]
+    pub taylor_output_weight: f64,
+
+    /// How the central bank creates and withdraws money.
+    ///
+    /// - None: only set the policy rate (default)
+    /// - OpenMarket: buy or sell bonds, in proportion to each person's wealth
+    /// - Helicopter: give or levy equal amounts to or from every active person
+    ///
+    /// Only used when enable_central_bank is true.
+    #[serde(default)]
+    pub money_operation: MoneyOperation,
+
+    /// Money the Taylor rule creates per unit of inflation below target, as a share
+    /// of the money supply. Inflation above target withdraws money. Each operation is
+    /// limited to 5% of the money supply.
+    ///
+    /// Only used when enable_central_bank is true and money_operation is not None.
+    /// Default: 1.0
+    #[serde(default = "default_money_growth_response")]
+    pub money_growth_response: f64,
+
+    /// Policy path followed by the Scripted rule.
+    ///
+    /// Each entry sets the policy rate and the money created per step (negative values
+    /// withdraw money) from its step until the step of the next entry. Before the
+    /// first entry the rate stays at `loan_interest_rate` and no money is created.
+    ///
+    /// Example (YAML):
+    /// ```yaml
+    /// monetary_policy_path:
+    ///   - { step: 0, policy_rate: 0.01 }
+    ///   - { step: 50, policy_rate: 0.0, money_injection: 20.0 }
+    /// ```
+    ///
+    /// Only used when monetary_policy_rule is Scripted.
+    #[serde(default)]
+    pub monetary_policy_path: Vec<PolicyPathEntry>,
+
+    /// Enable the government budget and fiscal policy.
+    ///
+    /// When enabled, every tax collected goes to a government budget, which also
+    /// levies `income_tax_brackets`, a wealth tax and VAT, and spends on public goods,
+    /// unemployment benefits and `class_transfers`. The government borrows to cover
+    /// deficits, up to `government_debt_limit`, and pays interest on its debt at the
+    /// loan interest rate. Cannot be combined with enable_tax_redistribution.
+    ///
+    /// Set to false to keep the flat tax model (default).
+    #[serde(default)]
+    pub enable_government: bool,
+
+    /// Marginal income tax brackets levied on each person's sales in a step.
+    ///
+    /// Income above a bracket's threshold is taxed at its rate up to the next
+    /// threshold. The brackets apply in addition to the flat `tax_rate`.
+    ///
+    /// Example (YAML):
+    /// ```yaml
+    /// income_tax_brackets:
+    ///   - { threshold: 0.0, rate: 0.05 }
+    ///   - { threshold: 50.0, rate: 0.2 }
+    ///   - { threshold: 150.0, rate: 0.4 }
+    /// ```
+    ///
+    /// Only used when enable_government is true.
+    #[serde(default)]
+    pub income_tax_brackets: Vec<TaxBracket>,
+
+    /// Tax rate per step on each person's money and savings above
+    /// `wealth_tax_threshold` (0.0-1.0).
+    ///
+    /// Only used when enable_government is true.
+    /// Default: 0.0 (no wealth tax)
+    #[serde(default)]
+    pub wealth_tax_rate: f64,
+
+    /// Wealth exempt from the wealth tax.
+    ///
+    /// Only used when enable_government is true.
+    /// Default: 500.0
+    #[serde(default = "default_wealth_tax_threshold")]
+    pub wealth_tax_threshold: f64,
+
+    /// Value added tax the buyer pays on top of the price of every trade (0.0-1.0).
+    ///
+    /// Only used when enable_government is true.
+    /// Default: 0.0 (no VAT)
+    #[serde(default)]
+    pub vat_rate: f64,
+
+    /// Government spending on public goods per step.
+    ///
+    /// The money is paid equally to working-age persons for public works, and it
+    /// raises the efficiency of every skill (see `public_goods_efficiency`).
+    /// Only used when enable_government is true.
+    /// Default: 0.0
+    #[serde(default)]
+    pub public_goods_spending: f64,
+
+    /// Efficiency gain of every skill per step when public goods spending equals one
+    /// base skill price per active person.
+    ///
+    /// Only used when enable_government is true.
+    /// Default: 0.01 (1%)
+    #[serde(default = "default_public_goods_efficiency")]
+    pub public_goods_efficiency: f64,
+
+    /// Benefit paid per step to each working-age person who neither works for a firm
+    /// nor sold a skill in the step.
+    ///
+    /// Only used when enable_government is true.
+    /// Default: 0.0
+    #[serde(default)]
+    pub unemployment_benefit: f64,
+
+    /// Transfer paid per step to each member of a social class.
+    ///
+    /// Example (YAML):
+    /// ```yaml
+    /// class_transfers:
+    ///   Lower: 5.0
+    ///   Middle: 1.0
+    /// ```
+    ///
+    /// Only used when enable_government is true.
+    #[serde(default)]
+    pub class_transfers: HashMap<SocialClass, f64>,
+
+    /// Maximum government debt. Spending beyond the treasury and the remaining
+    /// borrowing capacity is scaled down.
+    ///
+    /// Only used when enable_government is true.
+    /// Default: None (no limit); 0.0 requires a balanced budget
+    #[serde(default)]
+    pub government_debt_limit: Option<f64>,
+
     /// Enable invariant checking during simulation.
     ///
     /// When enabled, the simulation will check configured invariants at each step
@@ -1828,6 +2487,49 @@ pub struct SimulationConfig {
     /// Valid range: 1.0-100.0
     #[serde(default = "default_asset_price_multiplier")]
     pub asset_price_multiplier: f64,
+
+    /// Order of the phases that make up each simulation step.
+    ///
+    /// Empty runs every built-in phase in its default order, followed by any custom
+    /// phases registered with `SimulationEngine::register_phase`. A non-empty list runs
+    /// exactly the named phases in the given order; built-in phases left out are skipped.
+    /// See `BuiltinPhase` for the available names.
+    ///
+    /// Example (redistribute taxes before savings are taken):
+    /// ```yaml
+    /// step_phases: [trade_agreements, demand, pricing, crises, insurance, trades,
+    ///               tax_redistribution, savings, wealth_statistics]
+    /// ```
+    /// Default: empty (default order)
+    #[serde(default)]
+    pub step_phases: Vec<String>,
+
+    /// Phases to skip, whether built in or custom.
+    ///
+    /// Applied on top of `step_phases`, so a single phase can be switched off without
+    /// spelling out the whole order.
+    /// Default: empty
+    #[serde(default)]
+    pub disabled_step_phases: Vec<String>,
+
+    /// Interventions applied at given steps of the run, in the order listed.
+    ///
+    /// Parameter changes follow the rules of `SimulationEngine::set_parameter`, so
+    /// parameters the engine is built from cannot be changed. A crisis without a
+    /// severity uses `crisis_severity`. A price cap lowers the skill's maximum price
+    /// until `until_step`, when its `per_skill_price_limits` apply again.
+    ///
+    /// Example (YAML):
+    /// ```yaml
+    /// timeline:
+    ///   - { step: 100, action: price_cap, skill: Skill3, max_price: 40.0, until_step: 300 }
+    ///   - { step: 200, action: set_parameter, parameter: tax_rate, value: 0.3 }
+    ///   - { step: 350, action: crisis, crisis: DemandShock, severity: 0.6 }
+    ///   - { step: 500, action: set_parameter, parameter: enable_education, value: true }
+    /// ```
+    /// Default: empty (every parameter is constant for the whole run)
+    #[serde(default)]
+    pub timeline: Vec<TimelineEvent>,
 }
 
 fn default_disease_transmission_rate() -> f64 {
@@ -1838,31 +2540,127 @@ fn default_disease_recovery_duration() -> usize {
     10 // Recover after 10 steps
 }
 
-fn default_max_steps() -> usize {
-    500 // Default to 500 steps
+fn default_contact_transmission_rate() -> f64 {
+    0.02 // 2% chance per sick friend per step
 }
 
-fn default_entity_count() -> usize {
-    100 // Default to 100 persons
+fn default_lockdown_trade_reduction() -> f64 {
+    0.5 // Half of all trades are cancelled during a lockdown
 }
 
-fn default_seed() -> u64 {
-    42 // Default seed for reproducibility
+fn default_retirement_age() -> usize {
+    60 // Retire after 60 steps
 }
 
-fn default_initial_money_per_person() -> f64 {
-    100.0 // 100 units of initial money
+fn default_mortality_rate() -> f64 {
+    0.05 // 5% of retirees die per step
 }
 
-fn default_base_skill_price() -> f64 {
-    10.0 // 10 units base price
+fn default_birth_rate() -> f64 {
+    0.01 // 1% of working-age persons have a child per step
 }
 
-fn default_time_step() -> f64 {
-    1.0 // One discrete step
+fn default_immigration_rate() -> f64 {
+    0.1 // One immigrant every 10 steps on average
 }
 
-fn default_scenario() -> Scenario {
+fn default_exit_after_broke_steps() -> usize {
+    10 // Leave the market after 10 broke steps
+}
+
+fn default_firm_count() -> usize {
+    5 // Five firms at the start
+}
+
+fn default_firm_founding_rate() -> f64 {
+    0.05 // One new firm every 20 steps on average
+}
+
+fn default_firm_initial_capital() -> f64 {
+    50.0 // Half of the default initial money
+}
+
+fn default_firm_wage() -> f64 {
+    5.0 // Half of the default base skill price per step
+}
+
+fn default_firm_max_employees() -> usize {
+    10
+}
+
+fn default_firm_productivity() -> f64 {
+    2.0 // Two units per pair of workers per step
+}
+
+fn default_firm_dividend_share() -> f64 {
+    0.5 // Half of the profit goes to the owner
+}
+
+fn default_taylor_inflation_weight() -> f64 {
+    1.5 // Taylor's original coefficient
+}
+
+fn default_taylor_output_weight() -> f64 {
+    0.5 // Taylor's original coefficient
+}
+
+fn default_money_growth_response() -> f64 {
+    1.0
+}
+
+fn default_transfer_amount() -> f64 {
+    5.0
+}
+
+fn default_negative_income_tax_threshold() -> f64 {
+    50.0
+}
+
+fn default_negative_income_tax_rate() -> f64 {
+    0.5
+}
+
+fn default_means_test_percentile() -> f64 {
+    0.25
+}
+
+fn default_wealth_tax_threshold() -> f64 {
+    500.0 // Five times the default initial money
+}
+
+fn default_public_goods_efficiency() -> f64 {
+    0.01
+}
+
+fn default_migration_threshold() -> f64 {
+    0.2
+}
+
+fn default_max_steps() -> usize {
+    500 // Default to 500 steps
+}
+
+fn default_entity_count() -> usize {
+    100 // Default to 100 persons
+}
+
+fn default_seed() -> u64 {
+    42 // Default seed for reproducibility
+}
+
+fn default_initial_money_per_person() -> f64 {
+    100.0 // 100 units of initial money
+}
+
+fn default_base_skill_price() -> f64 {
+    10.0 // 10 units base price
+}
+
+fn default_time_step() -> f64 {
+    1.0 // One discrete step
+}
+
+fn default_scenario() -> Scenario {
     Scenario::Original
 }
 
@@ -1918,6 +2716,10 @@ fn default_resource_cost_per_transaction() -> f64 {
     1.0 // Resource consumption matches transaction value
 }
 
+fn default_resource_scarcity_price_factor() -> f64 {
+    1.0 // Average skill costs twice as much once all reserves are exhausted
+}
+
 fn default_seasonal_period() -> usize {
     100
 }
@@ -1950,6 +2752,14 @@ fn default_min_money_to_lend() -> f64 {
     50.0 // Must have at least 50 money to lend
 }
 
+fn default_loan_default_threshold() -> usize {
+    3 // Default after 3 missed payments
+}
+
+fn default_bankruptcy_haircut() -> f64 {
+    0.3 // Lenders forgive 30% when debt is restructured
+}
+
 fn default_p2p_platform_fee() -> f64 {
     0.01 // 1% platform fee
 }
@@ -2003,7 +2813,11 @@ fn default_black_market_participation_rate() -> f64 {
 }
 
 fn default_auction_participation_rate() -> f64 {
-    0.2 // 20% of trades use auctions
+    0.2 // 20% of buyers of contested skills bid in auctions
+}
+
+fn default_auction_types() -> Vec<crate::auction::AuctionType> {
+    vec![crate::auction::AuctionType::English]
 }
 
 fn default_price_elasticity_factor() -> f64 {
@@ -2058,6 +2872,10 @@ fn default_crisis_severity() -> f64 {
     0.5 // Moderate severity (50% of maximum impact)
 }
 
+fn default_fx_adjustment_rate() -> f64 {
+    0.05 // Rates move at most 5% per step on a fully one-sided trade balance
+}
+
 fn default_insurance_premium_rate() -> f64 {
     0.05 // 5% of coverage amount
 }
@@ -2205,129 +3023,197 @@ impl Default for SimulationConfig {
             loan_interest_rate: 0.01,
             loan_repayment_period: 20,
             min_money_to_lend: 50.0,
-            enable_p2p_lending: false,            // Disabled by default
-            p2p_platform_fee_rate: 0.01,          // 1% platform fee
-            enable_investments: false,            // Disabled by default
-            investment_return_rate: 0.02,         // 2% return per step
-            investment_duration: 20,              // 20 steps duration
-            investment_probability: 0.05,         // 5% chance per step
-            min_money_to_invest: 100.0,           // Require 100 money to invest
-            checkpoint_interval: 0,               // Disabled by default
-            checkpoint_file: None,                // No default checkpoint file
-            resume_from_checkpoint: false,        // Don't resume by default
-            tax_rate: 0.0,                        // Disabled by default
-            enable_tax_redistribution: false,     // Disabled by default
-            skills_per_person: 1,                 // One skill per person by default
-            stream_output_path: None,             // Disabled by default
-            priority_urgency_weight: 0.5,         // Balanced urgency consideration
-            priority_affordability_weight: 0.3,   // Moderate affordability consideration
-            priority_efficiency_weight: 0.1,      // Minor efficiency consideration
-            priority_reputation_weight: 0.1,      // Minor reputation consideration
-            enable_black_market: false,           // Disabled by default
-            black_market_price_multiplier: 0.8,   // 20% cheaper
+            loan_default_threshold: 3,    // Default after 3 missed payments
+            enable_bankruptcy: false,     // Disabled by default
+            bankruptcy_haircut: 0.3,      // 30% forgiven on restructuring
+            enable_p2p_lending: false,    // Disabled by default
+            p2p_platform_fee_rate: 0.01,  // 1% platform fee
+            enable_investments: false,    // Disabled by default
+            investment_return_rate: 0.02, // 2% return per step
+            investment_duration: 20,      // 20 steps duration
+            investment_probability: 0.05, // 5% chance per step
+            min_money_to_invest: 100.0,   // Require 100 money to invest
+            checkpoint_interval: 0,       // Disabled by default
+            checkpoint_file: None,        // No default checkpoint file
+            resume_from_checkpoint: false, // Don't resume by default
+            incremental_checkpoints: false, // Full snapshots by default
+            checkpoint_rebase_interval: 0, // Never rebase by default
+            tax_rate: 0.0,                // Disabled by default
+            enable_tax_redistribution: false, // Disabled by default
+            transfer_scheme: TransferScheme::default(),
+            transfer_funding: TransferFunding::default(),
+            transfer_amount: default_transfer_amount(),
+            negative_income_tax_threshold: default_negative_income_tax_threshold(),
+            negative_income_tax_rate: default_negative_income_tax_rate(),
+            means_test_percentile: default_means_test_percentile(),
+            transfer_condition: TransferCondition::default(),
+            skills_per_person: 1,         // One skill per person by default
+            stream_output_path: None,     // Disabled by default
+            priority_urgency_weight: 0.5, // Balanced urgency consideration
+            priority_affordability_weight: 0.3, // Moderate affordability consideration
+            priority_efficiency_weight: 0.1, // Minor efficiency consideration
+            priority_reputation_weight: 0.1, // Minor reputation consideration
+            enable_black_market: false,   // Disabled by default
+            black_market_price_multiplier: 0.8, // 20% cheaper
             black_market_participation_rate: 0.2, // 20% of trades
-            enable_auctions: false,               // Disabled by default
-            auction_participation_rate: 0.2,      // 20% of trades use auctions
-            enable_contracts: false,              // Disabled by default
-            max_contract_duration: 50,            // Maximum 50 steps
-            min_contract_duration: 10,            // Minimum 10 steps
-            contract_price_discount: 0.05,        // 5% discount
-            enable_education: false,              // Disabled by default
-            learning_cost_multiplier: 3.0,        // Learning costs 3x market price
-            learning_probability: 0.1,            // 10% chance per step
-            enable_mentorship: false,             // Disabled by default
-            mentorship_cost_reduction: 0.5,       // 50% cost reduction for mentees
-            min_mentor_quality: 3.5,              // Above average quality required
-            mentor_reputation_bonus: 0.05,        // Small reputation boost
-            enable_automation: false,             // Disabled by default
+            enable_auctions: false,       // Disabled by default
+            auction_participation_rate: 0.2, // 20% of buyers bid in auctions
+            auction_types: default_auction_types(), // English auctions
+            enable_contracts: false,      // Disabled by default
+            max_contract_duration: 50,    // Maximum 50 steps
+            min_contract_duration: 10,    // Minimum 10 steps
+            contract_price_discount: 0.05, // 5% discount
+            enable_education: false,      // Disabled by default
+            learning_cost_multiplier: 3.0, // Learning costs 3x market price
+            learning_probability: 0.1,    // 10% chance per step
+            enable_mentorship: false,     // Disabled by default
+            mentorship_cost_reduction: 0.5, // 50% cost reduction for mentees
+            min_mentor_quality: 3.5,      // Above average quality required
+            mentor_reputation_bonus: 0.05, // Small reputation boost
+            enable_automation: false,     // Disabled by default
             automation_rate: default_automation_rate(), // 0.1% demand reduction per step for fully automatable skills
             automation_risks_per_skill: HashMap::new(), // No per-skill automation risks by default
             enable_crisis_events: false,                // Disabled by default
             crisis_probability: 0.02,                   // 2% chance per step
             crisis_severity: 0.5,                       // Moderate severity
+            custom_crises: Vec::new(),
             currency_system: crate::currency::CurrencySystem::default(), // Single BASE currency by default
             enable_multi_currency: false,                                // Disabled by default
-            enable_insurance: false,                                     // Disabled by default
-            insurance_premium_rate: 0.05,                                // 5% of coverage amount
-            insurance_duration: 100,                                     // Policies last 100 steps
-            insurance_purchase_probability: 0.05,                        // 5% chance per step
-            insurance_coverage_amount: 50.0,                             // Default coverage of 50
-            enable_reinforcement_learning: false,                        // Disabled by default
-            rl_learning_rate: default_rl_learning_rate(),                // 0.1
-            rl_discount_factor: default_rl_discount_factor(),            // 0.9
-            rl_epsilon: default_rl_epsilon(),                            // 0.1
-            rl_epsilon_decay: default_rl_epsilon_decay(),                // 0.995
+            fx_adjustment_rate: default_fx_adjustment_rate(),
+            enable_insurance: false,              // Disabled by default
+            insurance_premium_rate: 0.05,         // 5% of coverage amount
+            insurance_duration: 100,              // Policies last 100 steps
+            insurance_purchase_probability: 0.05, // 5% chance per step
+            insurance_coverage_amount: 50.0,      // Default coverage of 50
+            enable_reinforcement_learning: false, // Disabled by default
+            rl_learning_rate: default_rl_learning_rate(), // 0.1
+            rl_discount_factor: default_rl_discount_factor(), // 0.9
+            rl_epsilon: default_rl_epsilon(),     // 0.1
+            rl_epsilon_decay: default_rl_epsilon_decay(), // 0.995
             rl_reward_success_multiplier: default_rl_reward_success_multiplier(), // 1.0
             rl_reward_failure_multiplier: default_rl_reward_failure_multiplier(), // 0.5
-            enable_time_preferences: false,                              // Disabled by default
-            time_preference_mean: default_time_preference_mean(),        // 0.90
-            time_preference_std_dev: default_time_preference_std_dev(),  // 0.10
-            enable_friendships: false,                                   // Disabled by default
-            friendship_probability: 0.1,                                 // 10% chance per trade
-            friendship_discount: 0.1,                                    // 10% discount for friends
-            enable_trade_agreements: false,                              // Disabled by default
-            trade_agreement_probability: 0.05,                           // 5% chance per step
-            trade_agreement_discount: 0.15, // 15% discount for agreement partners
-            trade_agreement_duration: 100,  // Agreements last 100 steps
-            enable_trust_networks: false,   // Disabled by default
-            enable_influence: false,        // Disabled by default
-            num_groups: None,               // No groups by default
-            distance_cost_factor: 0.0,      // Disabled by default
-            price_elasticity_factor: 0.1,   // 10% price adjustment per unit imbalance
-            volatility_percentage: 0.02,    // ±2% random price variation
-            enable_events: false,           // Disabled by default
-            enable_production: false,       // Disabled by default
-            production_probability: 0.05,   // 5% chance per step
-            enable_satisficing: false,      // Disabled by default
-            satisficing_threshold: 0.5,     // Accept "good enough" options (0.0-1.0)
-            enable_environment: false,      // Disabled by default
+            enable_time_preferences: false,       // Disabled by default
+            time_preference_mean: default_time_preference_mean(), // 0.90
+            time_preference_std_dev: default_time_preference_std_dev(), // 0.10
+            enable_friendships: false,            // Disabled by default
+            friendship_probability: 0.1,          // 10% chance per trade
+            friendship_discount: 0.1,             // 10% discount for friends
+            enable_trade_agreements: false,       // Disabled by default
+            trade_agreement_probability: 0.05,    // 5% chance per step
+            trade_agreement_discount: 0.15,       // 15% discount for agreement partners
+            trade_agreement_duration: 100,        // Agreements last 100 steps
+            enable_trust_networks: false,         // Disabled by default
+            enable_influence: false,              // Disabled by default
+            num_groups: None,                     // No groups by default
+            distance_cost_factor: 0.0,            // Disabled by default
+            regions: Vec::new(),                  // No regions by default
+            transport_links: Vec::new(),          // Every region connected for free
+            migration_rate: 0.0,                  // No migration by default
+            migration_threshold: default_migration_threshold(),
+            price_elasticity_factor: 0.1, // 10% price adjustment per unit imbalance
+            volatility_percentage: 0.02,  // ±2% random price variation
+            enable_events: false,         // Disabled by default
+            enable_profiling: false,      // Disabled by default
+            enable_production: false,     // Disabled by default
+            production_probability: 0.05, // 5% chance per step
+            enable_satisficing: false,    // Disabled by default
+            satisficing_threshold: 0.5,   // Accept "good enough" options (0.0-1.0)
+            enable_environment: false,    // Disabled by default
             resource_cost_per_transaction: 1.0, // Resource consumption matches transaction value
             custom_resource_reserves: None, // Use default reserves
-            enable_voting: false,           // Disabled by default
+            resource_regeneration_rates: HashMap::new(), // Resources do not regenerate
+            resource_carrying_capacities: HashMap::new(), // Capacity equals initial reserves
+            resource_quotas: HashMap::new(), // No consumption quotas
+            enforce_resource_reserves: false, // Reserves can be overconsumed
+            resource_scarcity_price_factor: 1.0, // Scarcity doubles average costs at depletion
+            enable_voting: false,         // Disabled by default
             voting_method: crate::voting::VotingMethod::SimpleMajority, // One person, one vote
-            proposal_duration: 20,          // 20 steps voting period
-            proposal_probability: 0.05,     // 5% chance per step to create proposal
+            proposal_duration: 20,        // 20 steps voting period
+            proposal_probability: 0.05,   // 5% chance per step to create proposal
             voting_participation_rate: 0.3, // 30% chance per person per step to vote
-            enable_quality: false,          // Disabled by default
-            quality_improvement_rate: 0.1,  // Quality increases by 0.1 per trade
-            quality_decay_rate: 0.05,       // Quality decreases by 0.05 per step
-            initial_quality: 3.0,           // Average quality (0.0-5.0 scale)
-            enable_certification: false,    // Disabled by default
+            enable_quality: false,        // Disabled by default
+            quality_improvement_rate: 0.1, // Quality increases by 0.1 per trade
+            quality_decay_rate: 0.05,     // Quality decreases by 0.05 per step
+            initial_quality: 3.0,         // Average quality (0.0-5.0 scale)
+            enable_certification: false,  // Disabled by default
             certification_cost_multiplier: 2.0, // 2x base price per level
             certification_duration: Some(200), // Certifications last 200 steps
             certification_probability: 0.05, // 5% chance per step to attempt certification
-            enable_market_segments: false,  // Disabled by default
-            enable_resource_pools: false,   // Disabled by default
-            pool_contribution_rate: 0.02,   // 2% contribution per step
+            enable_market_segments: false, // Disabled by default
+            enable_resource_pools: false, // Disabled by default
+            pool_contribution_rate: 0.02, // 2% contribution per step
             pool_withdrawal_threshold: 30.0, // Support for members below $30
             enable_adaptive_strategies: false, // Disabled by default
-            adaptation_rate: 0.1,           // 10% adaptation rate
-            exploration_rate: 0.05,         // 5% exploration (ε-greedy)
+            adaptation_rate: 0.1,         // 10% adaptation rate
+            exploration_rate: 0.05,       // 5% exploration (ε-greedy)
             enable_strategy_evolution: false, // Disabled by default
             evolution_update_frequency: 50, // Evolution every 50 steps
-            imitation_probability: 0.3,     // 30% imitation chance
-            mutation_rate: 0.05,            // 5% mutation rate
-            enable_specialization: false,   // Disabled by default
-            enable_parallel_trades: false,  // Disabled by default
-            enable_externalities: false,    // Disabled by default
-            externality_rate: 0.0,          // No externalities by default
+            imitation_probability: 0.3,   // 30% imitation chance
+            mutation_rate: 0.05,          // 5% mutation rate
+            enable_specialization: false, // Disabled by default
+            enable_parallel_trades: false, // Disabled by default
+            enable_externalities: false,  // Disabled by default
+            externality_rate: 0.0,        // No externalities by default
             externality_rates_per_skill: HashMap::new(), // No per-skill rates by default
-            enable_health: false,           // Disabled by default
+            enable_health: false,         // Disabled by default
             disease_transmission_rate: default_disease_transmission_rate(),
             disease_recovery_duration: default_disease_recovery_duration(),
-            initial_sick_persons: 0,             // No initial infections
-            enable_invariant_checking: false,    // Disabled by default
-            strict_invariant_mode: false,        // Lenient mode by default
-            check_money_conservation: true,      // Check money conservation by default
-            check_non_negative_wealth: true,     // Check non-negative wealth by default
-            enable_assets: false,                // Disabled by default
-            asset_purchase_probability: 0.02,    // 2% chance per step
+            initial_sick_persons: 0,      // No initial infections
+            disease_incubation_period: 0, // Infection makes persons sick immediately
+            immunity_duration: 0,         // Permanent immunity
+            contact_transmission_rate: default_contact_transmission_rate(),
+            lockdown_threshold: 0.0, // No lockdowns
+            lockdown_trade_reduction: default_lockdown_trade_reduction(),
+            vaccination_rate: 0.0, // No vaccination campaign
+            vaccination_start_step: 0,
+            sick_pay: 0.0,              // No sick pay
+            enable_demographics: false, // Disabled by default
+            retirement_age: default_retirement_age(),
+            mortality_rate: default_mortality_rate(),
+            birth_rate: default_birth_rate(),
+            immigration_rate: default_immigration_rate(),
+            exit_after_broke_steps: default_exit_after_broke_steps(),
+            enable_firms: false, // Disabled by default
+            firm_count: default_firm_count(),
+            firm_founding_rate: default_firm_founding_rate(),
+            firm_initial_capital: default_firm_initial_capital(),
+            firm_wage: default_firm_wage(),
+            firm_max_employees: default_firm_max_employees(),
+            firm_productivity: default_firm_productivity(),
+            firm_dividend_share: default_firm_dividend_share(),
+            enable_central_bank: false, // Disabled by default
+            monetary_policy_rule: MonetaryPolicyRule::default(),
+            inflation_target: 0.0,
+            taylor_inflation_weight: default_taylor_inflation_weight(),
+            taylor_output_weight: default_taylor_output_weight(),
+            money_operation: MoneyOperation::default(),
+            money_growth_response: default_money_growth_response(),
+            monetary_policy_path: Vec::new(),
+            enable_government: false, // Disabled by default
+            income_tax_brackets: Vec::new(),
+            wealth_tax_rate: 0.0,
+            wealth_tax_threshold: default_wealth_tax_threshold(),
+            vat_rate: 0.0,
+            public_goods_spending: 0.0,
+            public_goods_efficiency: default_public_goods_efficiency(),
+            unemployment_benefit: 0.0,
+            class_transfers: HashMap::new(),
+            government_debt_limit: None,
+            enable_invariant_checking: false, // Disabled by default
+            strict_invariant_mode: false,     // Lenient mode by default
+            check_money_conservation: true,   // Check money conservation by default
+            check_non_negative_wealth: true,  // Check non-negative wealth by default
+            enable_assets: false,             // Disabled by default
+            asset_purchase_probability: 0.02, // 2% chance per step
             min_money_for_asset_purchase: 200.0, // Require 200 money
-            property_appreciation_rate: 0.002,   // 0.2% appreciation per step
-            equipment_depreciation_rate: 0.01,   // 1% depreciation per step
-            rental_income_rate: 0.001,           // 0.1% rental income per step
-            stock_return_rate: 0.003,            // 0.3% expected return per step
-            asset_price_multiplier: 10.0,        // Assets cost 10x base skill price
+            property_appreciation_rate: 0.002, // 0.2% appreciation per step
+            equipment_depreciation_rate: 0.01, // 1% depreciation per step
+            rental_income_rate: 0.001,        // 0.1% rental income per step
+            stock_return_rate: 0.003,         // 0.3% expected return per step
+            asset_price_multiplier: 10.0,     // Assets cost 10x base skill price
+            step_phases: Vec::new(),
+            disabled_step_phases: Vec::new(),
+            timeline: Vec::new(),
         }
     }
 }
@@ -2556,6 +3442,25 @@ impl SimulationConfig {
             )));
         }
 
+        if self.loan_default_threshold == 0 {
+            return Err(SimulationError::ValidationError(
+                "Configuration Error: loan_default_threshold must be greater than 0. \
+                 This sets how many missed payments turn a loan into a default. \
+                 Recommended: 2-6 missed payments. \
+                 Current value: 0"
+                    .to_string(),
+            ));
+        }
+
+        if !(0.0..=1.0).contains(&self.bankruptcy_haircut) {
+            return Err(SimulationError::ValidationError(format!(
+                "Configuration Error: bankruptcy_haircut must be between 0.0 and 1.0 (0% to 100%). \
+                 This sets the share of debt forgiven when a bankruptcy restructures it. \
+                 Current value: {}",
+                self.bankruptcy_haircut
+            )));
+        }
+
         if !(0.0..=0.1).contains(&self.p2p_platform_fee_rate) {
             return Err(SimulationError::ValidationError(format!(
                 "Configuration Error: p2p_platform_fee_rate must be between 0.0 and 0.1 (0% to 10%). \
@@ -2587,6 +3492,250 @@ impl SimulationConfig {
             ));
         }
 
+        // Epidemic validation
+        for (name, rate) in [
+            ("contact_transmission_rate", self.contact_transmission_rate),
+            ("lockdown_threshold", self.lockdown_threshold),
+            ("lockdown_trade_reduction", self.lockdown_trade_reduction),
+            ("vaccination_rate", self.vaccination_rate),
+        ] {
+            if !(0.0..=1.0).contains(&rate) {
+                return Err(SimulationError::ValidationError(format!(
+                    "Configuration Error: {} must be between 0.0 and 1.0. Current value: {}",
+                    name, rate
+                )));
+            }
+        }
+
+        if self.sick_pay < 0.0 {
+            return Err(SimulationError::ValidationError(format!(
+                "Configuration Error: sick_pay must be non-negative. \
+                 This sets the amount paid to each sick person per step from the tax pool. \
+                 Current value: {}",
+                self.sick_pay
+            )));
+        }
+
+        // Demographics validation
+        if self.retirement_age == 0 {
+            return Err(SimulationError::ValidationError(
+                "Configuration Error: retirement_age must be greater than 0. \
+                 This sets the age in steps at which persons retire. \
+                 Recommended: 40-200 steps. \
+                 Current value: 0"
+                    .to_string(),
+            ));
+        }
+
+        for (name, rate) in
+            [("mortality_rate", self.mortality_rate), ("birth_rate", self.birth_rate)]
+        {
+            if !(0.0..=1.0).contains(&rate) {
+                return Err(SimulationError::ValidationError(format!(
+                    "Configuration Error: {} must be between 0.0 and 1.0 (0% to 100% per step). \
+                     Current value: {}",
+                    name, rate
+                )));
+            }
+        }
+
+        if !(0.0..=100.0).contains(&self.immigration_rate) {
+            return Err(SimulationError::ValidationError(format!(
+                "Configuration Error: immigration_rate must be between 0.0 and 100.0. \
+                 This sets the expected number of immigrants per step. \
+                 Current value: {}",
+                self.immigration_rate
+            )));
+        }
+
+        // Firm validation
+        for (name, rate) in [
+            ("firm_founding_rate", self.firm_founding_rate),
+            ("firm_dividend_share", self.firm_dividend_share),
+        ] {
+            if !(0.0..=1.0).contains(&rate) {
+                return Err(SimulationError::ValidationError(format!(
+                    "Configuration Error: {} must be between 0.0 and 1.0. Current value: {}",
+                    name, rate
+                )));
+            }
+        }
+
+        for (name, amount) in [
+            ("firm_initial_capital", self.firm_initial_capital),
+            ("firm_wage", self.firm_wage),
+            ("firm_productivity", self.firm_productivity),
+        ] {
+            if amount < 0.0 {
+                return Err(SimulationError::ValidationError(format!(
+                    "Configuration Error: {} must be non-negative. Current value: {}",
+                    name, amount
+                )));
+            }
+        }
+
+        if self.firm_max_employees < 2 {
+            return Err(SimulationError::ValidationError(format!(
+                "Configuration Error: firm_max_employees must be at least 2. \
+                 A firm needs one employee for each of its two recipe inputs. \
+                 Current value: {}",
+                self.firm_max_employees
+            )));
+        }
+
+        // Central bank validation
+        for (name, amount) in [
+            ("taylor_inflation_weight", self.taylor_inflation_weight),
+            ("taylor_output_weight", self.taylor_output_weight),
+            ("money_growth_response", self.money_growth_response),
+        ] {
+            if amount < 0.0 {
+                return Err(SimulationError::ValidationError(format!(
+                    "Configuration Error: {} must be non-negative. Current value: {}",
+                    name, amount
+                )));
+            }
+        }
+
+        if !(-1.0..=1.0).contains(&self.inflation_target) {
+            return Err(SimulationError::ValidationError(format!(
+                "Configuration Error: inflation_target must be between -1.0 and 1.0. \
+                 Current value: {}",
+                self.inflation_target
+            )));
+        }
+
+        for entry in &self.monetary_policy_path {
+            if !(0.0..=1.0).contains(&entry.policy_rate) {
+                return Err(SimulationError::ValidationError(format!(
+                    "Configuration Error: monetary_policy_path rate at step {} must be between \
+                     0.0 and 1.0. Current value: {}",
+                    entry.step, entry.policy_rate
+                )));
+            }
+        }
+
+        if self.enable_central_bank
+            && self.monetary_policy_rule == MonetaryPolicyRule::Scripted
+            && self.monetary_policy_path.is_empty()
+        {
+            return Err(SimulationError::ValidationError(
+                "Configuration Error: monetary_policy_path must list at least one entry \
+                 when monetary_policy_rule is Scripted."
+                    .to_string(),
+            ));
+        }
+
+        if self.money_operation == MoneyOperation::None
+            && self.monetary_policy_path.iter().any(|entry| entry.money_injection != 0.0)
+        {
+            return Err(SimulationError::ValidationError(
+                "Configuration Error: monetary_policy_path creates money but money_operation \
+                 is None. Solution: Set money_operation to OpenMarket or Helicopter."
+                    .to_string(),
+            ));
+        }
+
+        // Transfer scheme validation
+        if self.transfer_scheme == TransferScheme::EqualShare
+            && self.transfer_funding == TransferFunding::MoneyCreation
+        {
+            return Err(SimulationError::ValidationError(
+                "Configuration Error: EqualShare shares out the taxes collected and cannot be \
+                 funded by money creation. \
+                 Solution: Use UniversalBasicIncome or another transfer_scheme."
+                    .to_string(),
+            ));
+        }
+
+        for (name, value) in [
+            ("negative_income_tax_rate", self.negative_income_tax_rate),
+            ("means_test_percentile", self.means_test_percentile),
+        ] {
+            if !(0.0..=1.0).contains(&value) {
+                return Err(SimulationError::ValidationError(format!(
+                    "Configuration Error: {} must be between 0.0 and 1.0. Current value: {}",
+                    name, value
+                )));
+            }
+        }
+
+        for (name, amount) in [
+            ("transfer_amount", self.transfer_amount),
+            ("negative_income_tax_threshold", self.negative_income_tax_threshold),
+        ] {
+            if amount < 0.0 {
+                return Err(SimulationError::ValidationError(format!(
+                    "Configuration Error: {} must be non-negative. Current value: {}",
+                    name, amount
+                )));
+            }
+        }
+
+        // Government validation
+        if self.enable_government && self.enable_tax_redistribution {
+            return Err(SimulationError::ValidationError(
+                "Configuration Error: enable_government cannot be combined with \
+                 enable_tax_redistribution. The government budget spends the taxes. \
+                 Solution: Use class_transfers to return taxes to persons."
+                    .to_string(),
+            ));
+        }
+
+        for (name, rate) in [("wealth_tax_rate", self.wealth_tax_rate), ("vat_rate", self.vat_rate)]
+        {
+            if !(0.0..=1.0).contains(&rate) {
+                return Err(SimulationError::ValidationError(format!(
+                    "Configuration Error: {} must be between 0.0 and 1.0. Current value: {}",
+                    name, rate
+                )));
+            }
+        }
+
+        for bracket in &self.income_tax_brackets {
+            if !(0.0..=1.0).contains(&bracket.rate) || bracket.threshold < 0.0 {
+                return Err(SimulationError::ValidationError(format!(
+                    "Configuration Error: income_tax_brackets need a non-negative threshold \
+                     and a rate between 0.0 and 1.0. Current bracket: threshold {}, rate {}",
+                    bracket.threshold, bracket.rate
+                )));
+            }
+        }
+
+        for (name, amount) in [
+            ("wealth_tax_threshold", self.wealth_tax_threshold),
+            ("public_goods_spending", self.public_goods_spending),
+            ("public_goods_efficiency", self.public_goods_efficiency),
+            ("unemployment_benefit", self.unemployment_benefit),
+            ("government_debt_limit", self.government_debt_limit.unwrap_or(0.0)),
+        ] {
+            if amount < 0.0 {
+                return Err(SimulationError::ValidationError(format!(
+                    "Configuration Error: {} must be non-negative. Current value: {}",
+                    name, amount
+                )));
+            }
+        }
+
+        if let Some((class, amount)) = self.class_transfers.iter().find(|(_, &amount)| amount < 0.0)
+        {
+            return Err(SimulationError::ValidationError(format!(
+                "Configuration Error: class_transfers must be non-negative. \
+                 Current value for {:?}: {}",
+                class, amount
+            )));
+        }
+
+        // Bankruptcy validation
+        if self.enable_bankruptcy && !self.enable_loans {
+            return Err(SimulationError::ValidationError(
+                "Feature Dependency Error: enable_bankruptcy requires enable_loans to be true. \
+                 Bankruptcy settles defaulted loans, so the loan system must be enabled. \
+                 Solution: Set enable_loans=true or disable bankruptcy."
+                    .to_string(),
+            ));
+        }
+
         // Loans work better with savings
         if self.enable_loans && self.savings_rate == 0.0 {
             log::warn!(
@@ -2653,6 +3802,20 @@ impl SimulationConfig {
             )));
         }
 
+        if !(0.0..=1.0).contains(&self.auction_participation_rate) {
+            return Err(SimulationError::ValidationError(format!(
+                "auction_participation_rate must be between 0.0 and 1.0 (0% to 100%), got: {}",
+                self.auction_participation_rate
+            )));
+        }
+
+        if self.enable_auctions && self.auction_types.is_empty() {
+            return Err(SimulationError::ValidationError(
+                "auction_types must list at least one auction type when auctions are enabled"
+                    .to_string(),
+            ));
+        }
+
         if self.skills_per_person == 0 {
             return Err(SimulationError::ValidationError(
                 "skills_per_person must be at least 1".to_string(),
@@ -2732,6 +3895,65 @@ impl SimulationConfig {
             }
         }
 
+        if self.enable_investments {
+            if self.investment_duration == 0 {
+                return Err(SimulationError::ValidationError(
+                    "investment_duration must be at least 1 step when investments are enabled"
+                        .to_string(),
+                ));
+            }
+
+            if !(0.0..=1.0).contains(&self.investment_return_rate) {
+                return Err(SimulationError::ValidationError(format!(
+                    "investment_return_rate must be between 0.0 and 1.0 (0% to 100%), got: {}",
+                    self.investment_return_rate
+                )));
+            }
+
+            if !(0.0..=1.0).contains(&self.investment_probability) {
+                return Err(SimulationError::ValidationError(format!(
+                    "investment_probability must be between 0.0 and 1.0 (0% to 100%), got: {}",
+                    self.investment_probability
+                )));
+            }
+
+            if self.min_money_to_invest < 0.0 {
+                return Err(SimulationError::ValidationError(format!(
+                    "min_money_to_invest must be non-negative, got: {}",
+                    self.min_money_to_invest
+                )));
+            }
+        }
+
+        if self.enable_multi_currency && !(0.0..=1.0).contains(&self.fx_adjustment_rate) {
+            return Err(SimulationError::ValidationError(format!(
+                "fx_adjustment_rate must be between 0.0 and 1.0 (0% to 100%), got: {}",
+                self.fx_adjustment_rate
+            )));
+        }
+
+        if self.enable_voting {
+            if self.proposal_duration == 0 {
+                return Err(SimulationError::ValidationError(
+                    "proposal_duration must be at least 1 step when voting is enabled".to_string(),
+                ));
+            }
+
+            if !(0.0..=1.0).contains(&self.proposal_probability) {
+                return Err(SimulationError::ValidationError(format!(
+                    "proposal_probability must be between 0.0 and 1.0 (0% to 100%), got: {}",
+                    self.proposal_probability
+                )));
+            }
+
+            if !(0.0..=1.0).contains(&self.voting_participation_rate) {
+                return Err(SimulationError::ValidationError(format!(
+                    "voting_participation_rate must be between 0.0 and 1.0 (0% to 100%), got: {}",
+                    self.voting_participation_rate
+                )));
+            }
+        }
+
         if self.enable_education {
             if self.learning_cost_multiplier < 0.0 {
                 return Err(SimulationError::ValidationError(format!(
@@ -2805,6 +4027,16 @@ impl SimulationConfig {
             }
         }
 
+        for (index, crisis) in self.custom_crises.iter().enumerate() {
+            self.validate_custom_crisis(crisis)?;
+            if self.custom_crises[..index].iter().any(|c| c.name == crisis.name) {
+                return Err(SimulationError::ValidationError(format!(
+                    "Configuration Error: custom_crises defines '{}' more than once",
+                    crisis.name
+                )));
+            }
+        }
+
         // Insurance system validation
         if self.enable_insurance {
             if !(0.0..=1.0).contains(&self.insurance_premium_rate) {
@@ -2943,6 +4175,9 @@ impl SimulationConfig {
             )));
         }
 
+        // Regions, transport links and migration validation
+        self.validate_geography()?;
+
         // Market dynamics parameter validation
         if self.price_elasticity_factor.is_sign_negative() {
             return Err(SimulationError::ValidationError(format!(
@@ -3003,6 +4238,58 @@ impl SimulationConfig {
             }
         }
 
+        for (name, &rate) in &self.resource_regeneration_rates {
+            if Resource::from_name(name).is_none() {
+                return Err(SimulationError::ValidationError(format!(
+                    "resource_regeneration_rates has unknown resource '{}'",
+                    name
+                )));
+            }
+            if !(0.0..=1.0).contains(&rate) {
+                return Err(SimulationError::ValidationError(format!(
+                    "resource_regeneration_rates for '{}' must be between 0.0 and 1.0, got: {}",
+                    name, rate
+                )));
+            }
+        }
+
+        for (name, &capacity) in &self.resource_carrying_capacities {
+            if !Resource::from_name(name).is_some_and(|resource| resource.has_carrying_capacity()) {
+                return Err(SimulationError::ValidationError(format!(
+                    "resource_carrying_capacities only applies to water and land, got: '{}'",
+                    name
+                )));
+            }
+            if capacity < 0.0 {
+                return Err(SimulationError::ValidationError(format!(
+                    "resource_carrying_capacities for '{}' must be non-negative, got: {}",
+                    name, capacity
+                )));
+            }
+        }
+
+        for (name, &quota) in &self.resource_quotas {
+            if Resource::from_name(name).is_none() {
+                return Err(SimulationError::ValidationError(format!(
+                    "resource_quotas has unknown resource '{}'",
+                    name
+                )));
+            }
+            if quota < 0.0 {
+                return Err(SimulationError::ValidationError(format!(
+                    "resource_quotas for '{}' must be non-negative, got: {}",
+                    name, quota
+                )));
+            }
+        }
+
+        if !(0.0..=10.0).contains(&self.resource_scarcity_price_factor) {
+            return Err(SimulationError::ValidationError(format!(
+                "resource_scarcity_price_factor must be between 0.0 and 10.0, got: {}",
+                self.resource_scarcity_price_factor
+            )));
+        }
+
         // Quality system validation
         // Validate quality parameters even when disabled to prevent configuration errors
         if !(0.0..=1.0).contains(&self.quality_improvement_rate) {
@@ -3147,21 +4434,254 @@ impl SimulationConfig {
                 )));
             }
 
-            // Validate per-skill externality rates
-            for (skill, rate) in &self.externality_rates_per_skill {
-                if !(-1.0..=1.0).contains(rate) {
+            // Validate per-skill externality rates
+            for (skill, rate) in &self.externality_rates_per_skill {
+                if !(-1.0..=1.0).contains(rate) {
+                    return Err(SimulationError::ValidationError(format!(
+                        "Configuration Error: externality rate for skill '{}' must be between -1.0 and 1.0. \
+                         Current value: {}",
+                        skill, rate
+                    )));
+                }
+            }
+        }
+
+        // Step phase pipeline validation
+        for (index, name) in self.step_phases.iter().enumerate() {
+            if name.trim().is_empty() {
+                return Err(SimulationError::ValidationError(
+                    "step_phases must not contain empty phase names".to_string(),
+                ));
+            }
+            if self.step_phases[..index].contains(name) {
+                return Err(SimulationError::ValidationError(format!(
+                    "step_phases lists phase '{}' more than once",
+                    name
+                )));
+            }
+        }
+        if self.disabled_step_phases.iter().any(|name| name.trim().is_empty()) {
+            return Err(SimulationError::ValidationError(
+                "disabled_step_phases must not contain empty phase names".to_string(),
+            ));
+        }
+
+        self.validate_timeline()
+    }
+
+    /// Checks the regions, the transport links between them and the migration settings.
+    fn validate_geography(&self) -> Result<()> {
+        let invalid = |problem: String| {
+            Err(SimulationError::ValidationError(format!("Configuration Error: {}", problem)))
+        };
+        for (index, region) in self.regions.iter().enumerate() {
+            if region.name.trim().is_empty() {
+                return invalid("regions need a name".to_string());
+            }
+            if self.regions[..index].iter().any(|r| r.name == region.name) {
+                return invalid(format!("regions define '{}' more than once", region.name));
+            }
+            if !(0.0..=100.0).contains(&region.x) || !(0.0..=100.0).contains(&region.y) {
+                return invalid(format!(
+                    "region '{}' needs a center within 0.0-100.0 on both axes, got: ({}, {})",
+                    region.name, region.x, region.y
+                ));
+            }
+        }
+        for link in &self.transport_links {
+            for end in [&link.from, &link.to] {
+                if !self.regions.iter().any(|r| &r.name == end) {
+                    return invalid(format!(
+                        "transport link {} - {} names unknown region '{}'",
+                        link.from, link.to, end
+                    ));
+                }
+            }
+            if link.from == link.to {
+                return invalid(format!(
+                    "transport link {} - {} connects a region to itself",
+                    link.from, link.to
+                ));
+            }
+            if !link.cost.is_finite() || link.cost < 0.0 {
+                return invalid(format!(
+                    "transport link {} - {} needs a non-negative cost, got: {}",
+                    link.from, link.to, link.cost
+                ));
+            }
+        }
+        if !(0.0..=1.0).contains(&self.migration_rate) {
+            return invalid(format!(
+                "migration_rate must be between 0.0 and 1.0, got: {}",
+                self.migration_rate
+            ));
+        }
+        if self.migration_rate > 0.0 && self.regions.len() < 2 {
+            return invalid("migration_rate requires at least two regions".to_string());
+        }
+        if !self.migration_threshold.is_finite() || self.migration_threshold < 0.0 {
+            return invalid(format!(
+                "migration_threshold must be non-negative, got: {}",
+                self.migration_threshold
+            ));
+        }
+        Ok(())
+    }
+
+    /// Checks the ranges and targets of a custom crisis.
+    fn validate_custom_crisis(&self, crisis: &CrisisDefinition) -> Result<()> {
+        let invalid = |problem: String| {
+            Err(SimulationError::ValidationError(format!(
+                "Configuration Error: custom crisis '{}' {}",
+                crisis.name, problem
+            )))
+        };
+        if crisis.name.trim().is_empty() {
+            return Err(SimulationError::ValidationError(
+                "Configuration Error: custom_crises need a name".to_string(),
+            ));
+        }
+        if crisis.duration == 0 {
+            return invalid("needs a duration of at least one step".to_string());
+        }
+        if crisis.half_life <= 0.0 {
+            return invalid(format!("needs a positive half_life, got: {}", crisis.half_life));
+        }
+        let effects = &crisis.effects;
+        for (name, value) in [
+            ("probability", crisis.probability),
+            ("contagion", crisis.contagion),
+            ("contagion_intensity", crisis.contagion_intensity),
+            ("demand effect", effects.demand),
+            ("health effect", effects.health),
+        ] {
+            if !(0.0..=1.0).contains(&value) {
+                return invalid(format!("needs a {} between 0.0 and 1.0, got: {}", name, value));
+            }
+        }
+        if !(-1.0..=1.0).contains(&effects.money) {
+            return invalid(format!(
+                "needs a money effect between -1.0 and 1.0, got: {}",
+                effects.money
+            ));
+        }
+        if !effects.prices.is_finite() || effects.prices <= -1.0 {
+            return invalid(format!("needs a prices effect above -1.0, got: {}", effects.prices));
+        }
+        if effects.health > 0.0 && !self.enable_health {
+            return invalid("has a health effect, which requires enable_health".to_string());
+        }
+
+        let targets = &crisis.targets;
+        if !targets.groups.is_empty() || crisis.contagion > 0.0 {
+            let Some(num_groups) = self.num_groups else {
+                return invalid("targets groups, which requires num_groups".to_string());
+            };
+            if let Some(group) = targets.groups.iter().find(|&&g| g >= num_groups) {
+                return invalid(format!(
+                    "targets group {}, but there are only {} groups",
+                    group, num_groups
+                ));
+            }
+        }
+        if crisis.contagion > 0.0 && targets.groups.is_empty() {
+            return invalid("spreads by contagion, which requires target groups".to_string());
+        }
+        let currencies = self.currency_system.sorted_ids();
+        if let Some(currency) = targets.currencies.iter().find(|c| !currencies.contains(c)) {
+            return invalid(format!("targets unknown currency '{}'", currency));
+        }
+        Ok(())
+    }
+
+    /// Checks every timeline event, applying the parameter changes in step order so
+    /// that each changed configuration is validated as the engine will see it.
+    fn validate_timeline(&self) -> Result<()> {
+        if self.timeline.is_empty() {
+            return Ok(());
+        }
+        let mut events: Vec<&TimelineEvent> = self.timeline.iter().collect();
+        events.sort_by_key(|event| event.step);
+        let mut config = SimulationConfig { timeline: Vec::new(), ..self.clone() };
+        for event in events {
+            match &event.action {
+                TimelineAction::SetParameter { parameter, value } => {
+                    config = config.with_parameter(parameter, value.clone()).map_err(|e| {
+                        SimulationError::ValidationError(format!(
+                            "Configuration Error: timeline event '{}' is invalid: {}",
+                            event, e
+                        ))
+                    })?;
+                },
+                TimelineAction::Crisis { severity: Some(severity), .. }
+                    if !(0.0..=1.0).contains(severity) =>
+                {
                     return Err(SimulationError::ValidationError(format!(
-                        "Configuration Error: externality rate for skill '{}' must be between -1.0 and 1.0. \
-                         Current value: {}",
-                        skill, rate
+                        "Configuration Error: timeline event '{}' needs a crisis severity \
+                         between 0.0 and 1.0",
+                        event
                     )));
-                }
+                },
+                TimelineAction::PriceCap { max_price, until_step, .. }
+                    if *max_price <= 0.0 || *until_step <= event.step =>
+                {
+                    return Err(SimulationError::ValidationError(format!(
+                        "Configuration Error: timeline event '{}' needs a positive max_price \
+                         and an until_step after its step",
+                        event
+                    )));
+                },
+                _ => {},
             }
         }
-
         Ok(())
     }
 
+    /// Returns a copy of the configuration with one parameter set to a JSON value.
+    ///
+    /// The parameter is matched by its configuration field name. Parameters in
+    /// [`crate::engine::FIXED_PARAMETERS`] are rejected, and the changed configuration
+    /// must pass [`SimulationConfig::validate`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SimulationError::ValidationError`] for an unknown or fixed parameter,
+    /// a value of the wrong type or a configuration that fails validation.
+    ///
+    /// # Examples
+    /// ```
+    /// use community_simulation::SimulationConfig;
+    ///
+    /// let config = SimulationConfig::default();
+    /// let taxed = config.with_parameter("tax_rate", serde_json::json!(0.2)).unwrap();
+    /// assert_eq!(taxed.tax_rate, 0.2);
+    /// assert!(config.with_parameter("seed", serde_json::json!(7)).is_err());
+    /// ```
+    pub fn with_parameter(&self, name: &str, value: serde_json::Value) -> Result<SimulationConfig> {
+        let mut state = serde_json::to_value(self)
+            .map_err(|e| SimulationError::JsonSerialize(e.to_string()))?;
+        let fields = state.as_object_mut().expect("configuration serializes to an object");
+        if !fields.contains_key(name) {
+            return Err(SimulationError::ValidationError(format!("Unknown parameter: '{}'", name)));
+        }
+        if crate::engine::FIXED_PARAMETERS.contains(&name) {
+            return Err(SimulationError::ValidationError(format!(
+                "Parameter '{}' is fixed when the simulation is created and cannot be changed mid-run",
+                name
+            )));
+        }
+
+        fields.insert(name.to_string(), value.clone());
+        let config: SimulationConfig = serde_json::from_value(state).map_err(|e| {
+            SimulationError::ValidationError(format!(
+                "Invalid value {} for parameter '{}': {}",
+                value, name, e
+            ))
+        })?;
+        config.validate()?;
+        Ok(config)
+    }
+
     /// Create a configuration from a preset.
     ///
     /// # Arguments
@@ -3911,6 +5431,322 @@ scenario: Original
         assert!(err.to_string().contains("loan_repayment_period must be greater than 0"));
     }
 
+    #[test]
+    fn test_validate_loan_defaults_and_bankruptcy() {
+        let config = SimulationConfig { loan_default_threshold: 0, ..Default::default() };
+        let err = config.validate().unwrap_err();
+        assert!(err.to_string().contains("loan_default_threshold must be greater than 0"));
+
+        let config = SimulationConfig { bankruptcy_haircut: 1.5, ..Default::default() };
+        let err = config.validate().unwrap_err();
+        assert!(err.to_string().contains("bankruptcy_haircut must be between 0.0 and 1.0"));
+
+        let config = SimulationConfig { enable_bankruptcy: true, ..Default::default() };
+        let err = config.validate().unwrap_err();
+        assert!(err.to_string().contains("enable_bankruptcy requires enable_loans"));
+
+        let config = SimulationConfig {
+            enable_loans: true,
+            enable_bankruptcy: true,
+            bankruptcy_haircut: 1.0,
+            ..Default::default()
+        };
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_epidemic() {
+        let config = SimulationConfig { contact_transmission_rate: 1.5, ..Default::default() };
+        let err = config.validate().unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("contact_transmission_rate must be between 0.0 and 1.0"));
+
+        let config = SimulationConfig { lockdown_threshold: -0.1, ..Default::default() };
+        let err = config.validate().unwrap_err();
+        assert!(err.to_string().contains("lockdown_threshold must be between 0.0 and 1.0"));
+
+        let config = SimulationConfig { vaccination_rate: 2.0, ..Default::default() };
+        assert!(config.validate().is_err());
+
+        let config = SimulationConfig { sick_pay: -1.0, ..Default::default() };
+        let err = config.validate().unwrap_err();
+        assert!(err.to_string().contains("sick_pay must be non-negative"));
+
+        let config = SimulationConfig {
+            enable_health: true,
+            disease_incubation_period: 3,
+            immunity_duration: 50,
+            lockdown_threshold: 0.2,
+            vaccination_rate: 0.05,
+            sick_pay: 2.0,
+            ..Default::default()
+        };
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_demographics() {
+        let config = SimulationConfig { retirement_age: 0, ..Default::default() };
+        let err = config.validate().unwrap_err();
+        assert!(err.to_string().contains("retirement_age must be greater than 0"));
+
+        let config = SimulationConfig { mortality_rate: 1.5, ..Default::default() };
+        let err = config.validate().unwrap_err();
+        assert!(err.to_string().contains("mortality_rate must be between 0.0 and 1.0"));
+
+        let config = SimulationConfig { birth_rate: -0.1, ..Default::default() };
+        let err = config.validate().unwrap_err();
+        assert!(err.to_string().contains("birth_rate must be between 0.0 and 1.0"));
+
+        let config = SimulationConfig { immigration_rate: -1.0, ..Default::default() };
+        let err = config.validate().unwrap_err();
+        assert!(err.to_string().contains("immigration_rate must be between 0.0 and 100.0"));
+
+        let config = SimulationConfig {
+            enable_demographics: true,
+            immigration_rate: 2.5,
+            exit_after_broke_steps: 0,
+            ..Default::default()
+        };
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_firms() {
+        let config = SimulationConfig { firm_founding_rate: 1.5, ..Default::default() };
+        let err = config.validate().unwrap_err();
+        assert!(err.to_string().contains("firm_founding_rate must be between 0.0 and 1.0"));
+
+        let config = SimulationConfig { firm_wage: -1.0, ..Default::default() };
+        let err = config.validate().unwrap_err();
+        assert!(err.to_string().contains("firm_wage must be non-negative"));
+
+        let config = SimulationConfig { firm_max_employees: 1, ..Default::default() };
+        let err = config.validate().unwrap_err();
+        assert!(err.to_string().contains("firm_max_employees must be at least 2"));
+
+        let config = SimulationConfig { enable_firms: true, firm_count: 0, ..Default::default() };
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_central_bank() {
+        let config = SimulationConfig { taylor_inflation_weight: -1.0, ..Default::default() };
+        let err = config.validate().unwrap_err();
+        assert!(err.to_string().contains("taylor_inflation_weight must be non-negative"));
+
+        let config = SimulationConfig {
+            enable_central_bank: true,
+            monetary_policy_rule: MonetaryPolicyRule::Scripted,
+            ..Default::default()
+        };
+        let err = config.validate().unwrap_err();
+        assert!(err.to_string().contains("monetary_policy_path must list at least one entry"));
+
+        let yaml = "\
+enable_central_bank: true
+monetary_policy_rule: Scripted
+monetary_policy_path:
+  - { step: 0, policy_rate: 0.02 }
+  - { step: 10, policy_rate: 0.0, money_injection: 25.0 }
+";
+        let config: SimulationConfig = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(config.monetary_policy_path.len(), 2);
+        assert_eq!(config.monetary_policy_path[0].money_injection, 0.0);
+        let err = config.validate().unwrap_err();
+        assert!(err.to_string().contains("money_operation is None"));
+
+        let config = SimulationConfig { money_operation: MoneyOperation::Helicopter, ..config };
+        assert!(config.validate().is_ok());
+
+        let mut config = config;
+        config.monetary_policy_path[1].policy_rate = 1.5;
+        let err = config.validate().unwrap_err();
+        assert!(err.to_string().contains("monetary_policy_path rate at step 10"));
+    }
+
+    #[test]
+    fn test_validate_transfer_schemes() {
+        let config = SimulationConfig {
+            transfer_funding: TransferFunding::MoneyCreation,
+            ..Default::default()
+        };
+        let err = config.validate().unwrap_err();
+        assert!(err.to_string().contains("EqualShare shares out the taxes collected"));
+
+        let config = SimulationConfig { means_test_percentile: 1.5, ..Default::default() };
+        let err = config.validate().unwrap_err();
+        assert!(err.to_string().contains("means_test_percentile must be between 0.0 and 1.0"));
+
+        let config = SimulationConfig { transfer_amount: -1.0, ..Default::default() };
+        let err = config.validate().unwrap_err();
+        assert!(err.to_string().contains("transfer_amount must be non-negative"));
+
+        let yaml = "\
+enable_tax_redistribution: true
+transfer_scheme: Conditional
+transfer_funding: MoneyCreation
+transfer_condition: Health
+";
+        let config: SimulationConfig = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(config.transfer_scheme, TransferScheme::Conditional);
+        assert_eq!(config.transfer_condition, TransferCondition::Health);
+        assert_eq!(config.transfer_amount, 5.0);
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_government() {
+        let config = SimulationConfig {
+            enable_government: true,
+            enable_tax_redistribution: true,
+            ..Default::default()
+        };
+        let err = config.validate().unwrap_err();
+        assert!(err.to_string().contains("cannot be combined with enable_tax_redistribution"));
+
+        let config = SimulationConfig { vat_rate: 1.5, ..Default::default() };
+        let err = config.validate().unwrap_err();
+        assert!(err.to_string().contains("vat_rate must be between 0.0 and 1.0"));
+
+        let config = SimulationConfig { government_debt_limit: Some(-1.0), ..Default::default() };
+        let err = config.validate().unwrap_err();
+        assert!(err.to_string().contains("government_debt_limit must be non-negative"));
+
+        let yaml = "\
+enable_government: true
+income_tax_brackets:
+  - { threshold: 0.0, rate: 0.1 }
+  - { threshold: 50.0, rate: 1.2 }
+class_transfers:
+  Lower: 5.0
+";
+        let config: SimulationConfig = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(config.class_transfers[&SocialClass::Lower], 5.0);
+        let err = config.validate().unwrap_err();
+        assert!(err.to_string().contains("income_tax_brackets"));
+
+        let mut config = config;
+        config.income_tax_brackets[1].rate = 0.3;
+        assert!(config.validate().is_ok());
+        config.class_transfers.insert(SocialClass::Elite, -1.0);
+        let err = config.validate().unwrap_err();
+        assert!(err.to_string().contains("class_transfers must be non-negative"));
+    }
+
+    #[test]
+    fn test_validate_custom_crises() {
+        let yaml = "\
+num_groups: 4
+custom_crises:
+  - name: Harvest Failure
+    start_step: 100
+    duration: 30
+    decay: Linear
+    targets: { skills: [Skill1], groups: [0] }
+    effects: { demand: 0.3, prices: 0.5 }
+    contagion: 0.2
+  - name: Bank Run
+    probability: 0.01
+    duration: 10
+    decay: Exponential
+    effects: { money: -0.02 }
+";
+        let config: SimulationConfig = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(config.custom_crises[0].decay, crate::crisis::CrisisDecay::Linear);
+        assert_eq!(config.custom_crises[1].half_life, 5.0);
+        assert_eq!(config.custom_crises[1].contagion_intensity, 0.5);
+        assert!(config.validate().is_ok());
+
+        let mut invalid = config.clone();
+        invalid.custom_crises[1].name = "Harvest Failure".to_string();
+        let err = invalid.validate().unwrap_err();
+        assert!(err.to_string().contains("defines 'Harvest Failure' more than once"));
+
+        type Breakage = fn(&mut SimulationConfig);
+        let cases: [(Breakage, &str); 7] = [
+            (|c| c.custom_crises[0].duration = 0, "duration of at least one step"),
+            (|c| c.custom_crises[0].effects.demand = 1.5, "demand effect between 0.0 and 1.0"),
+            (|c| c.custom_crises[0].effects.prices = -1.0, "prices effect above -1.0"),
+            (|c| c.custom_crises[0].effects.health = 0.5, "requires enable_health"),
+            (|c| c.custom_crises[0].targets.groups = vec![4], "only 4 groups"),
+            (|c| c.custom_crises[0].targets.groups.clear(), "requires target groups"),
+            (|c| c.custom_crises[1].targets.currencies = vec!["XYZ".to_string()], "'XYZ'"),
+        ];
+        for (breakage, message) in cases {
+            let mut invalid = config.clone();
+            breakage(&mut invalid);
+            let err = invalid.validate().unwrap_err();
+            assert!(err.to_string().contains(message), "{}", err);
+        }
+        let invalid = SimulationConfig { num_groups: None, ..config };
+        assert!(invalid.validate().unwrap_err().to_string().contains("requires num_groups"));
+    }
+
+    #[test]
+    fn test_validate_timeline() {
+        let yaml = "\
+enable_government: true
+timeline:
+  - { step: 100, action: price_cap, skill: Skill3, max_price: 40.0, until_step: 300 }
+  - { step: 200, action: set_parameter, parameter: vat_rate, value: 0.1 }
+  - { step: 350, action: crisis, crisis: DemandShock }
+  - { step: 500, action: set_parameter, parameter: transfer_scheme, value: MeansTested }
+";
+        let config: SimulationConfig = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(config.timeline.len(), 4);
+        assert!(config.validate().is_ok());
+
+        // Changes are checked in step order against the configuration at that step
+        let mut invalid = config.clone();
+        invalid.timeline[1].action = TimelineAction::SetParameter {
+            parameter: "enable_tax_redistribution".to_string(),
+            value: serde_json::json!(true),
+        };
+        let err = invalid.validate().unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("timeline event 'step 200: set enable_tax_redistribution"));
+
+        for (action, message) in [
+            (
+                TimelineAction::SetParameter {
+                    parameter: "entity_count".to_string(),
+                    value: serde_json::json!(5),
+                },
+                "is fixed",
+            ),
+            (
+                TimelineAction::SetParameter {
+                    parameter: "tax_rate".to_string(),
+                    value: serde_json::json!("high"),
+                },
+                "Invalid value",
+            ),
+            (
+                TimelineAction::Crisis {
+                    crisis: crate::crisis::CrisisEvent::MarketCrash,
+                    severity: Some(1.5),
+                },
+                "crisis severity",
+            ),
+            (
+                TimelineAction::PriceCap {
+                    skill: "Skill3".to_string(),
+                    max_price: 10.0,
+                    until_step: 50,
+                },
+                "until_step after its step",
+            ),
+        ] {
+            let mut invalid = config.clone();
+            invalid.timeline[1].action = action;
+            let err = invalid.validate().unwrap_err();
+            assert!(err.to_string().contains(message), "{}", err);
+        }
+    }
+
     #[test]
     fn test_validate_min_money_to_lend_negative() {
         let config = SimulationConfig { min_money_to_lend: -50.0, ..Default::default() };
@@ -4060,6 +5896,140 @@ scenario: Original
         assert!(config2.validate().is_err());
     }
 
+    #[test]
+    fn test_validate_investments_when_enabled() {
+        let config = SimulationConfig {
+            enable_investments: true,
+            investment_duration: 0,
+            ..Default::default()
+        };
+        assert!(config.validate().is_err());
+
+        let config2 = SimulationConfig {
+            enable_investments: true,
+            investment_return_rate: 1.5,
+            ..Default::default()
+        };
+        assert!(config2.validate().is_err());
+
+        let config3 = SimulationConfig {
+            enable_investments: true,
+            investment_probability: -0.1,
+            ..Default::default()
+        };
+        assert!(config3.validate().is_err());
+
+        let config4 = SimulationConfig {
+            enable_investments: true,
+            min_money_to_invest: -1.0,
+            ..Default::default()
+        };
+        assert!(config4.validate().is_err());
+
+        let config5 = SimulationConfig { enable_investments: true, ..Default::default() };
+        assert!(config5.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_fx_adjustment_rate_when_multi_currency() {
+        let config = SimulationConfig {
+            enable_multi_currency: true,
+            fx_adjustment_rate: 1.5,
+            ..Default::default()
+        };
+        assert!(config.validate().is_err());
+
+        // Ignored while multi-currency is disabled
+        let config2 = SimulationConfig { fx_adjustment_rate: 1.5, ..Default::default() };
+        assert!(config2.validate().is_ok());
+
+        let config3 = SimulationConfig { enable_multi_currency: true, ..Default::default() };
+        assert!(config3.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_step_phases() {
+        let duplicate = SimulationConfig {
+            step_phases: vec!["trades".to_string(), "trades".to_string()],
+            ..Default::default()
+        };
+        assert!(duplicate.validate().is_err());
+
+        let empty =
+            SimulationConfig { disabled_step_phases: vec![" ".to_string()], ..Default::default() };
+        assert!(empty.validate().is_err());
+
+        // Unknown names are allowed, they may belong to custom phases
+        let custom = SimulationConfig {
+            step_phases: vec!["trades".to_string(), "my_phase".to_string()],
+            ..Default::default()
+        };
+        assert!(custom.validate().is_ok());
+    }
+
+    #[test]
+    fn test_step_phases_from_yaml() {
+        let yaml = "step_phases: [demand, pricing, trades]\ndisabled_step_phases: [pricing]\n";
+        let config: SimulationConfig = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(config.step_phases, vec!["demand", "pricing", "trades"]);
+        assert_eq!(config.disabled_step_phases, vec!["pricing"]);
+    }
+
+    #[test]
+    fn test_validate_auctions() {
+        let config = SimulationConfig { auction_participation_rate: 1.5, ..Default::default() };
+        assert!(config.validate().is_err());
+
+        let config =
+            SimulationConfig { enable_auctions: true, auction_types: vec![], ..Default::default() };
+        assert!(config.validate().is_err());
+
+        let yaml = "enable_auctions: true\nauction_types: [Dutch, Vickrey]\n";
+        let config: SimulationConfig = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(
+            config.auction_types,
+            vec![crate::auction::AuctionType::Dutch, crate::auction::AuctionType::Vickrey]
+        );
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_incremental_checkpoints_from_yaml() {
+        let config: SimulationConfig = serde_yaml::from_str("max_steps: 10\n").unwrap();
+        assert!(!config.incremental_checkpoints);
+        assert_eq!(config.checkpoint_rebase_interval, 0);
+
+        let yaml = "incremental_checkpoints: true\ncheckpoint_rebase_interval: 50\n";
+        let config: SimulationConfig = serde_yaml::from_str(yaml).unwrap();
+        assert!(config.incremental_checkpoints);
+        assert_eq!(config.checkpoint_rebase_interval, 50);
+    }
+
+    #[test]
+    fn test_validate_voting_when_enabled() {
+        let config =
+            SimulationConfig { enable_voting: true, proposal_duration: 0, ..Default::default() };
+        assert!(config.validate().is_err());
+
+        let config2 = SimulationConfig {
+            enable_voting: true,
+            proposal_probability: 1.5,
+            ..Default::default()
+        };
+        assert!(config2.validate().is_err());
+
+        let config3 = SimulationConfig {
+            enable_voting: true,
+            voting_participation_rate: -0.1,
+            ..Default::default()
+        };
+        assert!(config3.validate().is_err());
+
+        // Out-of-range values are ignored while voting is disabled
+        let config4 = SimulationConfig { proposal_probability: 1.5, ..Default::default() };
+        assert!(config4.validate().is_ok());
+    }
+
     #[test]
     fn test_validate_mentorship_requires_education() {
         let config = SimulationConfig {
@@ -4241,6 +6211,41 @@ scenario: Original
         assert!(config2.validate().is_err());
     }
 
+    #[test]
+    fn test_validate_geography() {
+        let yaml = "\
+regions:
+  - { name: Port, x: 20.0, y: 50.0 }
+  - { name: Hills, x: 80.0, y: 50.0 }
+transport_links:
+  - { from: Port, to: Hills, cost: 0.1 }
+migration_rate: 0.05
+";
+        let config: SimulationConfig = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(config.regions[1].name, "Hills");
+        assert_eq!(config.migration_threshold, 0.2);
+        assert!(config.validate().is_ok());
+
+        type Breakage = fn(&mut SimulationConfig);
+        let cases: [(Breakage, &str); 7] = [
+            (|c| c.regions[1].name = "Port".to_string(), "define 'Port' more than once"),
+            (|c| c.regions[0].x = 120.0, "center within 0.0-100.0"),
+            (|c| c.transport_links[0].to = "Coast".to_string(), "unknown region 'Coast'"),
+            (|c| c.transport_links[0].to = "Port".to_string(), "connects a region to itself"),
+            (|c| c.transport_links[0].cost = -0.1, "non-negative cost"),
+            (|c| c.migration_rate = 1.5, "migration_rate must be between"),
+            (|c| c.migration_threshold = -1.0, "migration_threshold must be non-negative"),
+        ];
+        for (breakage, message) in cases {
+            let mut invalid = config.clone();
+            breakage(&mut invalid);
+            let err = invalid.validate().unwrap_err();
+            assert!(err.to_string().contains(message), "{}", err);
+        }
+        let invalid = SimulationConfig { regions: vec![], transport_links: vec![], ..config };
+        assert!(invalid.validate().unwrap_err().to_string().contains("at least two regions"));
+    }
+
     #[test]
     fn test_validate_production_probability() {
         let config = SimulationConfig { production_probability: 1.5, ..Default::default() };
@@ -4274,6 +6279,49 @@ scenario: Original
             .contains("custom_resource_reserves for 'gold' must be non-negative"));
     }
 
+    #[test]
+    fn test_validate_resource_regeneration() {
+        let valid = SimulationConfig {
+            resource_regeneration_rates: HashMap::from([("Water".to_string(), 0.05)]),
+            resource_carrying_capacities: HashMap::from([("land".to_string(), 5_000.0)]),
+            resource_quotas: HashMap::from([("energy".to_string(), 100.0)]),
+            ..Default::default()
+        };
+        assert!(valid.validate().is_ok());
+
+        let config = SimulationConfig {
+            resource_regeneration_rates: HashMap::from([("water".to_string(), 1.5)]),
+            ..Default::default()
+        };
+        assert!(config.validate().unwrap_err().to_string().contains("between 0.0 and 1.0"));
+
+        let config = SimulationConfig {
+            resource_regeneration_rates: HashMap::from([("gold".to_string(), 0.1)]),
+            ..Default::default()
+        };
+        assert!(config.validate().unwrap_err().to_string().contains("unknown resource 'gold'"));
+
+        let config = SimulationConfig {
+            resource_carrying_capacities: HashMap::from([("energy".to_string(), 10.0)]),
+            ..Default::default()
+        };
+        assert!(config
+            .validate()
+            .unwrap_err()
+            .to_string()
+            .contains("only applies to water and land"));
+
+        let config = SimulationConfig {
+            resource_quotas: HashMap::from([("materials".to_string(), -1.0)]),
+            ..Default::default()
+        };
+        assert!(config.validate().unwrap_err().to_string().contains("must be non-negative"));
+
+        let config =
+            SimulationConfig { resource_scarcity_price_factor: 11.0, ..Default::default() };
+        assert!(config.validate().is_err());
+    }
+
     #[test]
     fn test_validate_quality_parameters() {
         // quality_improvement_rate out of range
@@ -303,14 +303,23 @@ impl CreditScore {
 
     /// Returns a human-readable rating category based on the credit score.
     pub fn rating_category(&self) -> &str {
-        match self.score {
-            800..=850 => "Excellent",
-            740..=799 => "Very Good",
-            670..=739 => "Good",
-            580..=669 => "Fair",
-            300..=579 => "Poor",
-            _ => "No Rating",
-        }
+        rating_category_for(self.score)
+    }
+}
+
+/// All rating categories, from the best to the worst credit.
+pub const RATING_CATEGORIES: [&str; 6] =
+    ["Excellent", "Very Good", "Good", "Fair", "Poor", "No Rating"];
+
+/// Returns the human-readable rating category of a credit score.
+pub fn rating_category_for(score: u16) -> &'static str {
+    match score {
+        800..=850 => "Excellent",
+        740..=799 => "Very Good",
+        670..=739 => "Good",
+        580..=669 => "Fair",
+        300..=579 => "Poor",
+        _ => "No Rating",
     }
 }
 
@@ -1,12 +1,13 @@
 use rand::{Rng, RngExt};
 use serde::{Deserialize, Serialize};
+use strum_macros::EnumString;
 
 /// Types of economic crises that can occur during the simulation.
 ///
 /// Crisis events simulate unexpected economic shocks that test the resilience
 /// of the simulated economy. Each crisis type has different effects on market
 /// prices, demand, supply, or individual wealth.
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, EnumString, PartialEq)]
 pub enum CrisisEvent {
     /// Market crash - sudden price drop across all skills
     ///
@@ -132,6 +133,246 @@ impl CrisisEvent {
     }
 }
 
+/// Number of steps before a custom crisis whose average trade volume is its
+/// pre-crisis GDP.
+pub const PRE_CRISIS_WINDOW: usize = 10;
+
+/// Number of steps averaged when checking whether GDP is back to its pre-crisis level.
+pub const RECOVERY_SMOOTHING: usize = 5;
+
+fn default_half_life() -> f64 {
+    5.0
+}
+
+fn default_contagion_intensity() -> f64 {
+    0.5
+}
+
+/// How the intensity of a custom crisis changes over its duration.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, EnumString, PartialEq, Eq, Default)]
+pub enum CrisisDecay {
+    /// Full intensity until the crisis ends (default)
+    #[default]
+    Constant,
+    /// Intensity falls linearly from full to zero over the duration
+    Linear,
+    /// Intensity halves every `half_life` steps
+    Exponential,
+}
+
+impl CrisisDecay {
+    /// Returns the intensity (0.0-1.0) of a crisis `elapsed` steps after its onset;
+    /// 0.0 once its `duration` has passed.
+    pub fn intensity(&self, elapsed: usize, duration: usize, half_life: f64) -> f64 {
+        if elapsed >= duration {
+            return 0.0;
+        }
+        match self {
+            CrisisDecay::Constant => 1.0,
+            CrisisDecay::Linear => 1.0 - elapsed as f64 / duration as f64,
+            CrisisDecay::Exponential => 0.5f64.powf(elapsed as f64 / half_life),
+        }
+    }
+}
+
+/// Who and what a custom crisis hits. An empty list targets everything of its kind.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct CrisisTargets {
+    /// Skills whose demand and prices are affected
+    #[serde(default)]
+    pub skills: Vec<String>,
+    /// Groups whose members are affected (requires `num_groups`)
+    #[serde(default)]
+    pub groups: Vec<usize>,
+    /// Currencies whose holders are affected
+    #[serde(default)]
+    pub currencies: Vec<String>,
+}
+
+/// Effects of a custom crisis at full intensity.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct CrisisEffects {
+    /// Share of targeted persons' needs for targeted skills dropped each step (0.0-1.0)
+    #[serde(default)]
+    pub demand: f64,
+    /// Relative change of targeted skills' prices (-0.3 lowers them by 30%), undone
+    /// as the crisis decays
+    #[serde(default)]
+    pub prices: f64,
+    /// Relative change of targeted persons' money each step (-1.0 to 1.0)
+    #[serde(default)]
+    pub money: f64,
+    /// Chance each step that a targeted healthy person falls sick (0.0-1.0, requires
+    /// `enable_health`)
+    #[serde(default)]
+    pub health: f64,
+}
+
+/// A user-defined crisis.
+///
+/// Example (YAML):
+/// ```yaml
+/// custom_crises:
+///   - name: Harvest Failure
+///     start_step: 100
+///     duration: 30
+///     decay: Linear
+///     targets: { skills: [Skill1, Skill2], groups: [0] }
+///     effects: { demand: 0.3, prices: 0.5, money: -0.01 }
+///     contagion: 0.2
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CrisisDefinition {
+    /// Name shown in logs and results; must be unique
+    pub name: String,
+    /// Step at which the crisis starts, if any
+    #[serde(default)]
+    pub start_step: Option<usize>,
+    /// Chance per step (0.0-1.0) that the crisis starts while it is not under way
+    #[serde(default)]
+    pub probability: f64,
+    /// Number of steps the crisis lasts
+    pub duration: usize,
+    /// How the intensity changes over the duration
+    #[serde(default)]
+    pub decay: CrisisDecay,
+    /// Steps in which the intensity halves with `Exponential` decay (default: 5.0)
+    #[serde(default = "default_half_life")]
+    pub half_life: f64,
+    /// Who and what the crisis hits
+    #[serde(default)]
+    pub targets: CrisisTargets,
+    /// What the crisis does at full intensity
+    #[serde(default)]
+    pub effects: CrisisEffects,
+    /// Chance per step, scaled by intensity, that the crisis spreads from each
+    /// targeted group to each neighboring group (group ids one apart, wrapping around)
+    #[serde(default)]
+    pub contagion: f64,
+    /// Intensity of a spread crisis relative to the crisis it spread from (default: 0.5)
+    #[serde(default = "default_contagion_intensity")]
+    pub contagion_intensity: f64,
+}
+
+impl CrisisDefinition {
+    /// Returns true if the definition targets the given skill.
+    pub fn targets_skill(&self, skill_id: &str) -> bool {
+        self.targets.skills.is_empty() || self.targets.skills.iter().any(|s| s == skill_id)
+    }
+}
+
+/// A custom crisis under way or over, started at onset or spread by contagion.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CrisisInstance {
+    /// Index of the definition in `custom_crises`
+    pub definition: usize,
+    /// Step at which the crisis started
+    pub start_step: usize,
+    /// Groups hit (empty hits every group)
+    pub groups: Vec<usize>,
+    /// Intensity at onset: 1.0, or lower for a spread crisis
+    pub scale: f64,
+    /// Index of the crisis this one spread from
+    pub source: Option<usize>,
+}
+
+impl CrisisInstance {
+    /// Returns the intensity of the crisis at `step` (0.0 outside its duration).
+    pub fn intensity(&self, definition: &CrisisDefinition, step: usize) -> f64 {
+        if step < self.start_step {
+            return 0.0;
+        }
+        self.scale
+            * definition.decay.intensity(
+                step - self.start_step,
+                definition.duration,
+                definition.half_life,
+            )
+    }
+}
+
+/// Custom crises started so far.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CrisisLedger {
+    /// Every crisis started, in order
+    pub instances: Vec<CrisisInstance>,
+    /// Net money created (positive) or destroyed (negative) by money effects, in the
+    /// base currency
+    pub money_changed: f64,
+}
+
+/// How the economy fared during and after a custom crisis, with trade volume per
+/// step as GDP.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CrisisRecovery {
+    /// Name of the crisis definition
+    pub name: String,
+    /// Step at which the crisis started
+    pub start_step: usize,
+    /// First step after the crisis
+    pub end_step: usize,
+    /// Groups hit (empty hits every group)
+    pub groups: Vec<usize>,
+    /// Index of the crisis this one spread from
+    pub source: Option<usize>,
+    /// Average GDP over the [`PRE_CRISIS_WINDOW`] steps before the crisis
+    pub pre_crisis_gdp: f64,
+    /// Lowest GDP from the start of the crisis until recovery
+    pub trough_gdp: f64,
+    /// GDP lost below the pre-crisis level until recovery
+    pub output_loss: f64,
+    /// Steps from the start of the crisis until GDP, averaged over
+    /// [`RECOVERY_SMOOTHING`] steps, was back to its pre-crisis level (None if it
+    /// has not recovered yet)
+    pub recovery_steps: Option<usize>,
+}
+
+/// Measures the recovery from a crisis that started at `start_step` from the GDP
+/// (trade volume) of each step run so far.
+pub fn crisis_recovery(
+    name: &str,
+    instance: &CrisisInstance,
+    duration: usize,
+    gdp_per_step: &[f64],
+) -> CrisisRecovery {
+    let start = instance.start_step.min(gdp_per_step.len());
+    let before = &gdp_per_step[start.saturating_sub(PRE_CRISIS_WINDOW)..start];
+    let pre_crisis_gdp = if before.is_empty() {
+        0.0
+    } else {
+        before.iter().sum::<f64>() / before.len() as f64
+    };
+
+    let mut recovery_steps = None;
+    let mut trough_gdp = f64::INFINITY;
+    let mut output_loss = 0.0;
+    for step in start..gdp_per_step.len() {
+        trough_gdp = trough_gdp.min(gdp_per_step[step]);
+        output_loss += (pre_crisis_gdp - gdp_per_step[step]).max(0.0);
+        let window = &gdp_per_step[(step + 1).saturating_sub(RECOVERY_SMOOTHING).max(start)..=step];
+        if window.iter().sum::<f64>() / window.len() as f64 >= pre_crisis_gdp {
+            recovery_steps = Some(step - start);
+            break;
+        }
+    }
+
+    CrisisRecovery {
+        name: name.to_string(),
+        start_step: instance.start_step,
+        end_step: instance.start_step + duration,
+        groups: instance.groups.clone(),
+        source: instance.source,
+        pre_crisis_gdp,
+        trough_gdp: if trough_gdp.is_finite() {
+            trough_gdp
+        } else {
+            0.0
+        },
+        output_loss,
+        recovery_steps,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -306,4 +547,52 @@ mod tests {
             assert!(result > 0.0, "Crisis {:?} should not eliminate value completely", crisis);
         }
     }
+
+    #[test]
+    fn test_crisis_decay_profiles() {
+        assert_eq!(CrisisDecay::Constant.intensity(9, 10, 5.0), 1.0);
+        assert_eq!(CrisisDecay::Constant.intensity(10, 10, 5.0), 0.0);
+        assert_eq!(CrisisDecay::Linear.intensity(0, 10, 5.0), 1.0);
+        assert!((CrisisDecay::Linear.intensity(5, 10, 5.0) - 0.5).abs() < 1e-12);
+        assert!((CrisisDecay::Exponential.intensity(4, 10, 2.0) - 0.25).abs() < 1e-12);
+
+        let instance = CrisisInstance {
+            definition: 0,
+            start_step: 20,
+            groups: vec![],
+            scale: 0.5,
+            source: None,
+        };
+        let definition: CrisisDefinition =
+            serde_yaml::from_str("{ name: Drought, duration: 4, decay: Linear }").unwrap();
+        assert_eq!(instance.intensity(&definition, 19), 0.0);
+        assert_eq!(instance.intensity(&definition, 20), 0.5);
+        assert!((instance.intensity(&definition, 22) - 0.25).abs() < 1e-12);
+        assert_eq!(instance.intensity(&definition, 24), 0.0);
+        assert!(definition.targets_skill("Skill1"));
+    }
+
+    #[test]
+    fn test_crisis_recovery() {
+        let instance = CrisisInstance {
+            definition: 0,
+            start_step: 3,
+            groups: vec![1],
+            scale: 1.0,
+            source: None,
+        };
+        // Pre-crisis GDP is 100; it dips to 40 and is back on average at step 8
+        let gdp = [100.0, 100.0, 100.0, 40.0, 60.0, 80.0, 120.0, 150.0, 150.0, 90.0];
+        let recovery = crisis_recovery("Drought", &instance, 4, &gdp);
+        assert_eq!(recovery.pre_crisis_gdp, 100.0);
+        assert_eq!(recovery.trough_gdp, 40.0);
+        assert_eq!(recovery.end_step, 7);
+        assert_eq!(recovery.output_loss, 60.0 + 40.0 + 20.0);
+        assert_eq!(recovery.recovery_steps, Some(5));
+
+        // Not recovered by the last step run
+        let recovery = crisis_recovery("Drought", &instance, 4, &gdp[..7]);
+        assert_eq!(recovery.recovery_steps, None);
+        assert_eq!(recovery.trough_gdp, 40.0);
+    }
 }
@@ -7,8 +7,10 @@ pub type CurrencyId = String;
 /// Represents a currency with its exchange rate relative to the base currency.
 ///
 /// The base currency has an exchange rate of 1.0. Other currencies are valued
-/// relative to this base. For example, if currency "USD" is the base with rate 1.0,
-/// and "EUR" has rate 1.2, then 1 EUR = 1.2 USD.
+/// relative to this base: the rate is the number of units of the currency that one
+/// unit of the base currency buys. For example, if currency "USD" is the base with
+/// rate 1.0 and "EUR" has rate 1.2, then 1 USD = 1.2 EUR. A rising rate therefore
+/// means the currency is losing value (depreciating).
 ///
 /// # Examples
 ///
@@ -28,7 +30,7 @@ pub struct Currency {
     /// Unique identifier for this currency (e.g., "USD", "EUR", "JPY")
     pub id: CurrencyId,
 
-    /// Exchange rate relative to the base currency.
+    /// Exchange rate relative to the base currency (units of this currency per base unit).
     /// Base currency = 1.0, other currencies valued relative to base.
     /// Must be positive.
     pub exchange_rate: f64,
@@ -276,6 +278,132 @@ impl CurrencySystem {
             .get(&self.base_currency_id)
             .expect("Base currency must exist in the system")
     }
+
+    /// Returns all currency IDs in sorted order.
+    ///
+    /// Useful wherever currencies must be visited deterministically (the underlying
+    /// map has no stable iteration order).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use community_simulation::currency::{CurrencySystem, Currency};
+    ///
+    /// let mut system = CurrencySystem::default();
+    /// system.add_currency(Currency::new("EUR".to_string(), 1.2));
+    /// assert_eq!(system.sorted_ids(), vec!["BASE".to_string(), "EUR".to_string()]);
+    /// ```
+    pub fn sorted_ids(&self) -> Vec<CurrencyId> {
+        let mut ids: Vec<CurrencyId> = self.currencies.keys().cloned().collect();
+        ids.sort();
+        ids
+    }
+}
+
+/// Smallest exchange rate a floating currency may reach.
+const MIN_EXCHANGE_RATE: f64 = 1e-6;
+
+/// Tracks cross-currency trade flows and floats exchange rates on them.
+///
+/// Exports and imports of each currency zone are accumulated in base currency units.
+/// Once per step, [`ForeignExchange::update_rates`] moves each non-base rate in
+/// proportion to the zone's normalized trade balance: a surplus (more exports than
+/// imports) appreciates the currency, a deficit depreciates it. The base currency is
+/// the numeraire and keeps its rate of 1.0.
+///
+/// # Examples
+///
+/// ```
+/// use community_simulation::currency::{Currency, CurrencySystem, ForeignExchange};
+///
+/// let mut system = CurrencySystem::default();
+/// system.add_currency(Currency::new("EUR".to_string(), 1.0));
+/// let mut fx = ForeignExchange::default();
+///
+/// // A BASE buyer imports from an EUR seller: EUR runs a surplus and appreciates
+/// fx.record_trade("BASE", "EUR", 50.0);
+/// fx.update_rates(&mut system, 0.1);
+/// assert!(system.get_currency("EUR").unwrap().exchange_rate < 1.0);
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ForeignExchange {
+    /// Exports and imports of each currency during the current step (base units)
+    step_flows: HashMap<CurrencyId, (f64, f64)>,
+    /// Cumulative exports per currency (base units)
+    pub total_exports: HashMap<CurrencyId, f64>,
+    /// Cumulative imports per currency (base units)
+    pub total_imports: HashMap<CurrencyId, f64>,
+    /// Exchange rate of every currency at the end of each step
+    pub rate_history: HashMap<CurrencyId, Vec<f64>>,
+    /// Number of trades between persons holding different currencies
+    pub cross_currency_trades: usize,
+    /// Value of cross-currency trades (base units)
+    pub cross_currency_volume: f64,
+    /// Number of devaluation crises suffered per currency
+    pub devaluations: HashMap<CurrencyId, usize>,
+    /// Base value gained (positive) or lost (negative) by persons' holdings as
+    /// exchange rates moved
+    #[serde(default)]
+    pub revaluation: f64,
+}
+
+impl ForeignExchange {
+    /// Records a trade between zones. Same-currency trades are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `buyer_currency` - Currency of the buyer (importing zone)
+    /// * `seller_currency` - Currency of the seller (exporting zone)
+    /// * `base_value` - Value of the trade in base currency units
+    pub fn record_trade(&mut self, buyer_currency: &str, seller_currency: &str, base_value: f64) {
+        if buyer_currency == seller_currency {
+            return;
+        }
+        self.cross_currency_trades += 1;
+        self.cross_currency_volume += base_value;
+        self.step_flows.entry(seller_currency.to_string()).or_default().0 += base_value;
+        self.step_flows.entry(buyer_currency.to_string()).or_default().1 += base_value;
+        *self.total_exports.entry(seller_currency.to_string()).or_default() += base_value;
+        *self.total_imports.entry(buyer_currency.to_string()).or_default() += base_value;
+    }
+
+    /// Floats exchange rates on this step's trade balances and records the new rates.
+    ///
+    /// Each non-base rate is multiplied by `1 - adjustment_rate * balance`, where
+    /// `balance = (exports - imports) / (exports + imports)` lies in [-1, 1].
+    /// An `adjustment_rate` of 0.0 keeps rates fixed.
+    pub fn update_rates(&mut self, system: &mut CurrencySystem, adjustment_rate: f64) {
+        for id in system.sorted_ids() {
+            let (exports, imports) = self.step_flows.get(&id).copied().unwrap_or((0.0, 0.0));
+            let Some(currency) = system.currencies.get_mut(&id) else {
+                continue;
+            };
+            if id != system.base_currency_id && exports + imports > 0.0 {
+                let balance = (exports - imports) / (exports + imports);
+                currency.exchange_rate = (currency.exchange_rate
+                    * (1.0 - adjustment_rate * balance))
+                    .max(MIN_EXCHANGE_RATE);
+            }
+            self.rate_history.entry(id).or_default().push(currency.exchange_rate);
+        }
+        self.step_flows.clear();
+    }
+
+    /// Devalues a currency: its rate is divided by `value_factor` (0.0-1.0 exclusive of 0),
+    /// so each unit buys proportionally less abroad.
+    ///
+    /// Returns the new exchange rate, or `None` if the currency does not exist.
+    pub fn devalue(
+        &mut self,
+        system: &mut CurrencySystem,
+        currency_id: &str,
+        value_factor: f64,
+    ) -> Option<f64> {
+        let currency = system.currencies.get_mut(currency_id)?;
+        currency.exchange_rate /= value_factor.max(MIN_EXCHANGE_RATE);
+        *self.devaluations.entry(currency_id.to_string()).or_default() += 1;
+        Some(currency.exchange_rate)
+    }
 }
 
 #[cfg(test)]
@@ -392,6 +520,75 @@ mod tests {
         assert_eq!(base.exchange_rate, 1.0);
     }
 
+    #[test]
+    fn test_sorted_ids() {
+        let mut system = CurrencySystem::default();
+        system.add_currency(Currency::new("JPY".to_string(), 150.0));
+        system.add_currency(Currency::new("EUR".to_string(), 1.2));
+        assert_eq!(system.sorted_ids(), vec!["BASE", "EUR", "JPY"]);
+    }
+
+    #[test]
+    fn test_fx_record_trade_ignores_same_currency() {
+        let mut fx = ForeignExchange::default();
+        fx.record_trade("EUR", "EUR", 10.0);
+        assert_eq!(fx.cross_currency_trades, 0);
+        assert!(fx.total_exports.is_empty());
+
+        fx.record_trade("BASE", "EUR", 10.0);
+        assert_eq!(fx.cross_currency_trades, 1);
+        assert_eq!(fx.cross_currency_volume, 10.0);
+        assert_eq!(fx.total_exports["EUR"], 10.0);
+        assert_eq!(fx.total_imports["BASE"], 10.0);
+    }
+
+    #[test]
+    fn test_fx_rates_float_on_trade_balance() {
+        let mut system = CurrencySystem::default();
+        system.add_currency(Currency::new("EUR".to_string(), 1.0));
+        system.add_currency(Currency::new("JPY".to_string(), 100.0));
+        let mut fx = ForeignExchange::default();
+
+        // EUR exports to JPY only: EUR appreciates, JPY depreciates
+        fx.record_trade("JPY", "EUR", 20.0);
+        fx.update_rates(&mut system, 0.1);
+
+        assert!((system.get_currency("EUR").unwrap().exchange_rate - 0.9).abs() < 1e-12);
+        assert!((system.get_currency("JPY").unwrap().exchange_rate - 110.0).abs() < 1e-9);
+        assert_eq!(system.get_base_currency().exchange_rate, 1.0);
+        assert_eq!(fx.rate_history["EUR"], vec![0.9]);
+        assert_eq!(fx.rate_history["BASE"], vec![1.0]);
+
+        // No trade flows: rates stay put but history still grows
+        fx.update_rates(&mut system, 0.1);
+        assert_eq!(fx.rate_history["EUR"].len(), 2);
+        assert!((system.get_currency("EUR").unwrap().exchange_rate - 0.9).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_fx_base_currency_is_numeraire() {
+        let mut system = CurrencySystem::default();
+        system.add_currency(Currency::new("EUR".to_string(), 1.0));
+        let mut fx = ForeignExchange::default();
+
+        fx.record_trade("EUR", "BASE", 50.0);
+        fx.update_rates(&mut system, 0.5);
+        assert_eq!(system.get_base_currency().exchange_rate, 1.0);
+        assert!((system.get_currency("EUR").unwrap().exchange_rate - 1.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_fx_devalue() {
+        let mut system = CurrencySystem::default();
+        system.add_currency(Currency::new("EUR".to_string(), 1.0));
+        let mut fx = ForeignExchange::default();
+
+        let new_rate = fx.devalue(&mut system, "EUR", 0.8).unwrap();
+        assert!((new_rate - 1.25).abs() < 1e-12);
+        assert_eq!(fx.devaluations["EUR"], 1);
+        assert!(fx.devalue(&mut system, "XXX", 0.8).is_none());
+    }
+
     #[test]
     fn test_currency_serialization() {
         let currency = Currency::new("USD".to_string(), 1.5);
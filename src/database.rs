@@ -30,6 +30,7 @@ use rusqlite::{Connection, Result};
 /// - `money_distribution`: Final money distribution per person
 /// - `reputation_distribution`: Final reputation distribution per person  
 /// - `skill_prices`: Final skill prices
+/// - `investment_statistics`: Investment system totals (one row, only if investments were enabled)
 ///
 /// # Arguments
 ///
@@ -62,6 +63,7 @@ pub fn export_to_sqlite(result: &SimulationResult, db_path: &str) -> Result<()>
     insert_money_distribution(&conn, result)?;
     insert_reputation_distribution(&conn, result)?;
     insert_skill_prices(&conn, result)?;
+    insert_investment_statistics(&conn, result)?;
 
     Ok(())
 }
@@ -116,6 +118,21 @@ fn create_tables(conn: &Connection) -> Result<()> {
         [],
     )?;
 
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS investment_statistics (
+            id INTEGER PRIMARY KEY,
+            total_investments_created INTEGER NOT NULL,
+            total_investments_completed INTEGER NOT NULL,
+            total_investments_defaulted INTEGER NOT NULL,
+            active_investments INTEGER NOT NULL,
+            total_invested REAL NOT NULL,
+            total_returns_paid REAL NOT NULL,
+            total_losses REAL NOT NULL,
+            avg_roi_percentage REAL NOT NULL
+        )",
+        [],
+    )?;
+
     Ok(())
 }
 
@@ -186,6 +203,33 @@ fn insert_skill_prices(conn: &Connection, result: &SimulationResult) -> Result<(
     Ok(())
 }
 
+/// Inserts investment statistics into the database (if investments were enabled).
+fn insert_investment_statistics(conn: &Connection, result: &SimulationResult) -> Result<()> {
+    let Some(stats) = &result.investment_statistics else {
+        return Ok(());
+    };
+
+    conn.execute(
+        "INSERT INTO investment_statistics (
+            total_investments_created, total_investments_completed,
+            total_investments_defaulted, active_investments,
+            total_invested, total_returns_paid, total_losses, avg_roi_percentage
+        ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+        rusqlite::params![
+            stats.total_investments_created as i64,
+            stats.total_investments_completed as i64,
+            stats.total_investments_defaulted as i64,
+            stats.active_investments as i64,
+            stats.total_invested,
+            stats.total_returns_paid,
+            stats.total_losses,
+            stats.avg_roi_percentage,
+        ],
+    )?;
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -224,7 +268,7 @@ mod tests {
                 row.get(0)
             })
             .unwrap();
-        assert_eq!(table_count, 4);
+        assert_eq!(table_count, 5);
     }
 
     #[test]
@@ -280,6 +324,45 @@ mod tests {
         assert_eq!(count, 3);
     }
 
+    #[test]
+    fn test_export_to_sqlite_investment_statistics() {
+        let temp_file = NamedTempFile::new().unwrap();
+        let db_path = temp_file.path().to_str().unwrap();
+
+        // No row without investment statistics
+        let (conn, _temp) = setup_test_database();
+        let count: i64 = conn
+            .query_row("SELECT COUNT(*) FROM investment_statistics", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(count, 0);
+
+        let mut result = create_test_result();
+        result.investment_statistics = Some(crate::result::InvestmentStats {
+            total_investments_created: 12,
+            total_investments_completed: 7,
+            active_investments: 3,
+            total_invested: 600.0,
+            total_returns_paid: 540.0,
+            avg_roi_percentage: 40.0,
+            total_investments_defaulted: 2,
+            total_losses: 45.5,
+        });
+        export_to_sqlite(&result, db_path).unwrap();
+
+        let conn = Connection::open(db_path).unwrap();
+        let (created, defaulted, losses): (i64, i64, f64) = conn
+            .query_row(
+                "SELECT total_investments_created, total_investments_defaulted, total_losses
+                 FROM investment_statistics WHERE id = 1",
+                [],
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+            )
+            .unwrap();
+        assert_eq!(created, 12);
+        assert_eq!(defaulted, 2);
+        assert_eq!(losses, 45.5);
+    }
+
     fn create_test_result() -> SimulationResult {
         // Capture metadata for this test result
         let metadata = crate::result::SimulationMetadata::capture(42, 10, 100);
@@ -289,6 +372,8 @@ mod tests {
             total_steps: 100,
             total_duration: 1.5,
             step_times: vec![],
+            phase_timings: vec![],
+            profile: None,
             active_persons: 10,
             failed_steps: 0,
             final_money_distribution: vec![
@@ -369,6 +454,15 @@ mod tests {
             },
             centrality_analysis: None,
             mobility_statistics: None,
+            demographics_statistics: None,
+            epidemic_statistics: None,
+            firm_statistics: None,
+            monetary_policy_statistics: None,
+            government_statistics: None,
+            transfer_statistics: None,
+            timeline_statistics: None,
+            custom_crisis_statistics: None,
+            geography_statistics: None,
             social_class_statistics: crate::result::SocialClassStats::default(),
             failed_trade_statistics: crate::result::FailedTradeStats {
                 total_failed_attempts: 0,
@@ -382,7 +476,10 @@ mod tests {
             total_taxes_collected: None,
             total_taxes_redistributed: None,
             loan_statistics: None,
+            lending_marketplace_statistics: None,
             investment_statistics: None,
+            currency_statistics: None,
+            auction_statistics: None,
             contract_statistics: None,
             education_statistics: None,
             mentorship_statistics: None,
@@ -393,6 +490,7 @@ mod tests {
             influence_statistics: None,
             trust_network_statistics: None,
             trade_agreement_statistics: None,
+            voting_statistics: None,
             insurance_statistics: None,
             asset_statistics: None,
             technology_breakthrough_statistics: None,
@@ -0,0 +1,419 @@
+//! # Debugger Module
+//!
+//! Time-travel debugging for interactive mode. A [`Debugger`] steps the engine,
+//! keeps in-memory [`EngineSnapshot`]s at a fixed interval so the simulation can be
+//! rewound to any earlier step, evaluates watch expressions and stops at breakpoints.
+//!
+//! Watch expressions are [`Metric`]s, such as `gini`, `avg_money` or
+//! `person 12 savings`. Breakpoints are [`Condition`]s comparing a metric with a
+//! threshold, such as `gini > 0.6` or `person 12 < 0` (a person's money when no
+//! field is given). A breakpoint stops the simulation when its condition becomes
+//! true, so a condition that already holds does not stop every step.
+//!
+//! Rewinding restores the latest snapshot at or before the target step and replays
+//! the remaining steps. Every step reseeds the engine's random number generator from
+//! the seed and the step number (see [`SimulationEngine::step`]), so a debugged run
+//! draws the same numbers as a batch run and the replayed steps repeat it, except for
+//! state that checkpoints do not persist either.
+//!
+//! # Examples
+//!
+//! ```
+//! use community_simulation::debugger::{Condition, Debugger};
+//! use community_simulation::{SimulationConfig, SimulationEngine};
+//!
+//! let config = SimulationConfig { entity_count: 10, max_steps: 50, ..Default::default() };
+//! let mut engine = SimulationEngine::new(config);
+//! let mut debugger = Debugger::new(&engine, 5);
+//!
+//! let condition: Condition = "total_money < 0".parse().unwrap();
+//! debugger.add_breakpoint(condition, &engine);
+//! for _ in 0..20 {
+//!     debugger.step(&mut engine);
+//! }
+//!
+//! debugger.rewind(&mut engine, 12).unwrap();
+//! assert_eq!(engine.get_current_step(), 12);
+//! ```
+
+use crate::engine::EngineSnapshot;
+use crate::person::PersonId;
+use crate::result::calculate_gini_coefficient;
+use crate::SimulationEngine;
+use std::collections::VecDeque;
+use std::fmt;
+use std::str::FromStr;
+
+/// Default number of steps between two snapshots.
+pub const DEFAULT_SNAPSHOT_INTERVAL: usize = 10;
+
+/// Number of snapshots kept in memory; the oldest is dropped beyond it.
+pub const MAX_SNAPSHOTS: usize = 100;
+
+/// A field of a person that can be watched.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PersonField {
+    /// Money held (default)
+    Money,
+    /// Savings
+    Savings,
+    /// Reputation score
+    Reputation,
+}
+
+/// A value of the simulation that can be watched or compared in a breakpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Metric {
+    /// Gini coefficient of the money of active persons
+    Gini,
+    /// Money held by all active persons
+    TotalMoney,
+    /// Average money of active persons
+    AverageMoney,
+    /// Number of active persons
+    ActivePersons,
+    /// Current simulation step
+    Step,
+    /// A field of one person
+    Person(PersonId, PersonField),
+}
+
+impl Metric {
+    /// Returns the metric's value, or None if it refers to a person that does not exist.
+    pub fn evaluate(&self, engine: &SimulationEngine) -> Option<f64> {
+        let active_money =
+            || engine.get_entities().iter().filter(|e| e.active).map(|e| e.person_data.money);
+        match self {
+            Metric::Gini => {
+                let mut money: Vec<f64> = active_money().collect();
+                money.sort_by(|a, b| a.total_cmp(b));
+                Some(calculate_gini_coefficient(&money, money.iter().sum()))
+            },
+            Metric::TotalMoney => Some(active_money().sum()),
+            Metric::AverageMoney => {
+                let active = engine.get_active_persons();
+                Some(if active == 0 {
+                    0.0
+                } else {
+                    active_money().sum::<f64>() / active as f64
+                })
+            },
+            Metric::ActivePersons => Some(engine.get_active_persons() as f64),
+            Metric::Step => Some(engine.get_current_step() as f64),
+            Metric::Person(id, field) => {
+                let entity = engine.get_entities().iter().find(|e| e.id == *id)?;
+                let person = &entity.person_data;
+                Some(match field {
+                    PersonField::Money => person.money,
+                    PersonField::Savings => person.savings,
+                    PersonField::Reputation => person.reputation,
+                })
+            },
+        }
+    }
+}
+
+impl FromStr for Metric {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let words: Vec<String> = s.split_whitespace().map(str::to_lowercase).collect();
+        let words: Vec<&str> = words.iter().map(String::as_str).collect();
+        match words.as_slice() {
+            ["gini"] => Ok(Metric::Gini),
+            ["total_money"] => Ok(Metric::TotalMoney),
+            ["avg_money"] => Ok(Metric::AverageMoney),
+            ["active"] => Ok(Metric::ActivePersons),
+            ["step"] => Ok(Metric::Step),
+            ["person", id, rest @ ..] => {
+                let id: PersonId = id.parse().map_err(|_| format!("Invalid person ID: '{}'", id))?;
+                let field = match rest {
+                    [] | ["money"] => PersonField::Money,
+                    ["savings"] => PersonField::Savings,
+                    ["reputation"] => PersonField::Reputation,
+                    _ => return Err(format!("Unknown person field: '{}'", rest.join(" "))),
+                };
+                Ok(Metric::Person(id, field))
+            },
+            _ => Err(format!(
+                "Unknown metric: '{}' (expected gini, total_money, avg_money, active, step or person <id> [money|savings|reputation])",
+                s.trim()
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Metric {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Metric::Gini => write!(f, "gini"),
+            Metric::TotalMoney => write!(f, "total_money"),
+            Metric::AverageMoney => write!(f, "avg_money"),
+            Metric::ActivePersons => write!(f, "active"),
+            Metric::Step => write!(f, "step"),
+            Metric::Person(id, PersonField::Money) => write!(f, "person {} money", id),
+            Metric::Person(id, PersonField::Savings) => write!(f, "person {} savings", id),
+            Metric::Person(id, PersonField::Reputation) => write!(f, "person {} reputation", id),
+        }
+    }
+}
+
+/// How a breakpoint compares a metric with its threshold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Comparison {
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+}
+
+impl Comparison {
+    const OPERATORS: [(&'static str, Comparison); 4] = [
+        ("<=", Comparison::LessOrEqual),
+        (">=", Comparison::GreaterOrEqual),
+        ("<", Comparison::Less),
+        (">", Comparison::Greater),
+    ];
+
+    fn symbol(self) -> &'static str {
+        match self {
+            Comparison::Less => "<",
+            Comparison::LessOrEqual => "<=",
+            Comparison::Greater => ">",
+            Comparison::GreaterOrEqual => ">=",
+        }
+    }
+
+    fn holds(self, value: f64, threshold: f64) -> bool {
+        match self {
+            Comparison::Less => value < threshold,
+            Comparison::LessOrEqual => value <= threshold,
+            Comparison::Greater => value > threshold,
+            Comparison::GreaterOrEqual => value >= threshold,
+        }
+    }
+}
+
+/// A comparison of a metric with a threshold, such as `gini > 0.6`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Condition {
+    pub metric: Metric,
+    pub comparison: Comparison,
+    pub threshold: f64,
+}
+
+impl Condition {
+    /// Returns whether the condition holds (false for a person that does not exist).
+    pub fn holds(&self, engine: &SimulationEngine) -> bool {
+        self.metric
+            .evaluate(engine)
+            .is_some_and(|value| self.comparison.holds(value, self.threshold))
+    }
+}
+
+impl FromStr for Condition {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (index, symbol, comparison) = Comparison::OPERATORS
+            .iter()
+            .find_map(|&(symbol, comparison)| s.find(symbol).map(|i| (i, symbol, comparison)))
+            .ok_or_else(|| format!("Missing comparison in '{}' (use <, <=, > or >=)", s.trim()))?;
+        let metric = s[..index].parse()?;
+        let threshold = s[index + symbol.len()..].trim();
+        let threshold =
+            threshold.parse().map_err(|_| format!("Invalid threshold: '{}'", threshold))?;
+        Ok(Condition { metric, comparison, threshold })
+    }
+}
+
+impl fmt::Display for Condition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} {}", self.metric, self.comparison.symbol(), self.threshold)
+    }
+}
+
+/// A condition that stops the simulation when it becomes true.
+#[derive(Debug, Clone)]
+pub struct Breakpoint {
+    pub condition: Condition,
+    /// Whether the condition held after the last step
+    held: bool,
+}
+
+/// Steps an engine while keeping snapshots, watches and breakpoints.
+#[derive(Debug)]
+pub struct Debugger {
+    snapshot_interval: usize,
+    snapshots: VecDeque<EngineSnapshot>,
+    watches: Vec<Metric>,
+    breakpoints: Vec<Breakpoint>,
+}
+
+impl Debugger {
+    /// Creates a debugger that snapshots the engine every `snapshot_interval` steps
+    /// (at least 1), starting with its current state.
+    pub fn new(engine: &SimulationEngine, snapshot_interval: usize) -> Self {
+        Self {
+            snapshot_interval: snapshot_interval.max(1),
+            snapshots: VecDeque::from([engine.snapshot()]),
+            watches: Vec::new(),
+            breakpoints: Vec::new(),
+        }
+    }
+
+    /// Executes one step and returns the indices of the breakpoints it hit.
+    pub fn step(&mut self, engine: &mut SimulationEngine) -> Vec<usize> {
+        engine.step();
+        self.record_snapshot(engine);
+
+        let mut hits = Vec::new();
+        for (index, breakpoint) in self.breakpoints.iter_mut().enumerate() {
+            let holds = breakpoint.condition.holds(engine);
+            if holds && !breakpoint.held {
+                hits.push(index);
+            }
+            breakpoint.held = holds;
+        }
+        hits
+    }
+
+    /// Takes a snapshot if the engine is at a snapshot step and not snapshotted yet.
+    fn record_snapshot(&mut self, engine: &SimulationEngine) {
+        let step = engine.get_current_step();
+        let is_new = self.snapshots.back().is_none_or(|latest| latest.step() < step);
+        if is_new && step.is_multiple_of(self.snapshot_interval) {
+            if self.snapshots.len() == MAX_SNAPSHOTS {
+                self.snapshots.pop_front();
+            }
+            self.snapshots.push_back(engine.snapshot());
+        }
+    }
+
+    /// Rewinds the engine to an earlier `step`: restores the latest snapshot at or
+    /// before it and replays the steps in between. Breakpoints are not checked while
+    /// replaying. Returns the step of the restored snapshot.
+    pub fn rewind(&mut self, engine: &mut SimulationEngine, step: usize) -> Result<usize, String> {
+        let current = engine.get_current_step();
+        if step > current {
+            return Err(format!(
+                "Cannot rewind forward to step {} (current step: {})",
+                step, current
+            ));
+        }
+        let snapshot = self
+            .snapshots
+            .iter()
+            .rev()
+            .find(|snapshot| snapshot.step() <= step)
+            .ok_or_else(|| match self.snapshots.front() {
+                Some(oldest) => format!(
+                    "No snapshot at or before step {} (oldest snapshot: step {})",
+                    step,
+                    oldest.step()
+                ),
+                None => format!("No snapshot at or before step {}", step),
+            })?;
+        let restored_step = snapshot.step();
+        engine.restore_snapshot(snapshot);
+        self.snapshots.retain(|snapshot| snapshot.step() <= restored_step);
+
+        while engine.get_current_step() < step {
+            engine.step();
+            self.record_snapshot(engine);
+        }
+        for breakpoint in &mut self.breakpoints {
+            breakpoint.held = breakpoint.condition.holds(engine);
+        }
+        Ok(restored_step)
+    }
+
+    /// Returns the steps of the snapshots kept in memory, oldest first.
+    pub fn snapshot_steps(&self) -> Vec<usize> {
+        self.snapshots.iter().map(EngineSnapshot::step).collect()
+    }
+
+    /// Adds a watch expression.
+    pub fn add_watch(&mut self, metric: Metric) {
+        self.watches.push(metric);
+    }
+
+    /// Removes the watch expression at `index`, returning it.
+    pub fn remove_watch(&mut self, index: usize) -> Option<Metric> {
+        (index < self.watches.len()).then(|| self.watches.remove(index))
+    }
+
+    /// Removes all watch expressions.
+    pub fn clear_watches(&mut self) {
+        self.watches.clear();
+    }
+
+    /// Returns the watch expressions.
+    pub fn watches(&self) -> &[Metric] {
+        &self.watches
+    }
+
+    /// Adds a breakpoint. A condition that already holds only stops the simulation
+    /// after it has stopped holding and becomes true again.
+    pub fn add_breakpoint(&mut self, condition: Condition, engine: &SimulationEngine) {
+        let held = condition.holds(engine);
+        self.breakpoints.push(Breakpoint { condition, held });
+    }
+
+    /// Removes the breakpoint at `index`, returning its condition.
+    pub fn remove_breakpoint(&mut self, index: usize) -> Option<Condition> {
+        (index < self.breakpoints.len()).then(|| self.breakpoints.remove(index).condition)
+    }
+
+    /// Removes all breakpoints.
+    pub fn clear_breakpoints(&mut self) {
+        self.breakpoints.clear();
+    }
+
+    /// Returns the breakpoints.
+    pub fn breakpoints(&self) -> &[Breakpoint] {
+        &self.breakpoints
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_metrics() {
+        assert_eq!("gini".parse(), Ok(Metric::Gini));
+        assert_eq!(" AVG_MONEY ".parse(), Ok(Metric::AverageMoney));
+        assert_eq!("person 12".parse(), Ok(Metric::Person(12, PersonField::Money)));
+        assert_eq!("person 3 savings".parse(), Ok(Metric::Person(3, PersonField::Savings)));
+        assert!("person x".parse::<Metric>().is_err());
+        assert!("person 3 height".parse::<Metric>().is_err());
+        assert!("inflation".parse::<Metric>().is_err());
+    }
+
+    #[test]
+    fn test_parse_conditions() {
+        let condition: Condition = "gini > 0.6".parse().unwrap();
+        assert_eq!(condition.metric, Metric::Gini);
+        assert_eq!(condition.comparison, Comparison::Greater);
+        assert_eq!(condition.threshold, 0.6);
+
+        let condition: Condition = "person 12 <= -5".parse().unwrap();
+        assert_eq!(condition.metric, Metric::Person(12, PersonField::Money));
+        assert_eq!(condition.comparison, Comparison::LessOrEqual);
+        assert_eq!(condition.threshold, -5.0);
+        assert_eq!(condition.to_string(), "person 12 money <= -5");
+
+        assert!("gini 0.6".parse::<Condition>().is_err());
+        assert!("gini > high".parse::<Condition>().is_err());
+    }
+
+    #[test]
+    fn test_comparisons() {
+        assert!(Comparison::Less.holds(-0.1, 0.0));
+        assert!(!Comparison::Less.holds(0.0, 0.0));
+        assert!(Comparison::LessOrEqual.holds(0.0, 0.0));
+        assert!(Comparison::Greater.holds(0.7, 0.6));
+        assert!(Comparison::GreaterOrEqual.holds(0.6, 0.6));
+    }
+}
@@ -0,0 +1,87 @@
+//! # Demographics Module
+//!
+//! Lets the population change over a run. When demographics are enabled, persons
+//! age every step, retire at the configured age and die with a per-step mortality
+//! rate. Working-age persons have children who inherit their skills, immigrants
+//! arrive with randomly drawn skills, and persons who stay broke leave the market.
+//! The estate of a person who leaves passes to their children, or to the tax
+//! pool when they have none.
+//!
+//! The engine applies these events in the `demographics` phase. This module holds the
+//! running totals kept across checkpoints and the small rules the phase relies on.
+
+use serde::{Deserialize, Serialize};
+
+/// Why a person left the simulation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Departure {
+    /// A retiree died
+    Death,
+    /// A broke person exited the market
+    MarketExit,
+}
+
+/// Running totals of population changes.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DemographicsLedger {
+    /// Children born during the simulation
+    pub births: usize,
+    /// Immigrants who arrived during the simulation
+    pub immigrants: usize,
+    /// Persons who reached retirement age
+    pub retirements: usize,
+    /// Retirees who died
+    pub deaths: usize,
+    /// Broke persons who left the market
+    pub market_exits: usize,
+    /// Estates split among the deceased's children
+    pub estates_to_heirs: usize,
+    /// Estates paid into the tax pool because there were no heirs
+    pub estates_to_tax_pool: usize,
+    /// Money passed to heirs, in the base currency
+    pub inherited_money: f64,
+    /// Estate money paid into the tax pool, in the base currency
+    pub estate_tax_pool_money: f64,
+    /// New money brought in by immigrants, in the base currency
+    pub immigrant_money: f64,
+    /// Active population at the end of each step
+    pub population_history: Vec<usize>,
+}
+
+/// Returns how many immigrants arrive this step.
+///
+/// `rate` is the expected number per step: its integer part always arrives and its
+/// fractional part is the chance of one more, decided by `draw` (uniform in 0.0-1.0).
+pub fn arrivals(rate: f64, draw: f64) -> usize {
+    let whole = rate.max(0.0).floor();
+    whole as usize + usize::from(draw < rate - whole)
+}
+
+/// Returns whether a person with the given money and savings is broke, i.e. cannot
+/// pay even the minimum skill price.
+pub fn is_broke(money: f64, savings: f64, min_skill_price: f64) -> bool {
+    money + savings.max(0.0) < min_skill_price
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arrivals() {
+        assert_eq!(arrivals(0.0, 0.0), 0);
+        assert_eq!(arrivals(0.3, 0.2), 1);
+        assert_eq!(arrivals(0.3, 0.5), 0);
+        assert_eq!(arrivals(2.0, 0.0), 2);
+        assert_eq!(arrivals(2.5, 0.1), 3);
+        assert_eq!(arrivals(2.5, 0.9), 2);
+    }
+
+    #[test]
+    fn test_is_broke() {
+        assert!(is_broke(0.5, 0.0, 1.0));
+        assert!(!is_broke(0.5, 0.5, 1.0));
+        assert!(is_broke(-10.0, 5.0, 1.0));
+        assert!(!is_broke(1.0, -3.0, 1.0));
+    }
+}
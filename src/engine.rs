@@ -1,14 +1,29 @@
 use crate::{
+    auction::{Auction, AuctionType},
+    central_bank::{self, CentralBank, MonetaryPolicyRule, MonetarySnapshot, MoneyOperation},
+    checkpoint::IncrementalCheckpointer,
     contract::{Contract, ContractId},
-    credit_rating::DEFAULT_CREDIT_SCORE,
-    crisis::CrisisEvent,
+    credit_rating::{CreditScore, DEFAULT_CREDIT_SCORE, RATING_CATEGORIES},
+    crisis::{CrisisDefinition, CrisisEvent, CrisisInstance, CrisisLedger},
+    currency::ForeignExchange,
+    demographics::{self, DemographicsLedger, Departure},
     environment::Environment,
+    epidemic::{CompartmentCounts, EpidemicLedger, EpidemicSnapshot},
     event::EventBus,
-    loan::{Loan, LoanId},
+    firm::{self, Employee, Firm, FirmLedger},
+    geography::{self, RegionLedger, TransportNetwork},
+    government::{self, BudgetSnapshot, Government},
+    investment::{Investment, InvestmentId, InvestmentType},
+    loan::{DefaultLedger, Loan, LoanId},
     person::{Person, PersonId, Strategy},
-    plugin::{PluginContext, PluginRegistry},
-    result::{write_step_to_stream, StepData},
-    scenario::{DemandGenerator, PriceUpdater},
+    phase::{resolve_pipeline, BuiltinPhase, PhaseRef, StepPhase},
+    plugin::{EngineView, PluginContext, PluginRegistry, ProposedTrade},
+    profiler::{ProfileMark, ProfileReport, Profiler},
+    result::{write_step_to_stream, PhaseTiming, StepData},
+    scenario::{DemandGenerator, PriceUpdater, Scenario},
+    timeline::{self, TimelineAction},
+    transfers::{self, TransferCondition, TransferFunding, TransferLedger, TransferScheme},
+    voting::{ProposalId, ProposalType},
     Entity, Market, SimulationConfig, SimulationResult, Skill, SkillId,
 };
 use indicatif::{ProgressBar, ProgressStyle};
@@ -20,7 +35,7 @@ use rand::{RngExt, SeedableRng};
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 use std::fs::File;
-use std::io::{BufReader, BufWriter};
+use std::io::BufWriter;
 use std::panic;
 use std::path::Path;
 use std::time::Instant;
@@ -49,6 +64,100 @@ const ASSUMED_PROFIT_MARGIN: f64 = 0.70;
 /// market frictions (insufficient funds, price controls, etc.) prevented it.
 const DEADWEIGHT_LOSS_RATIO: f64 = 0.20;
 
+// Investment constants
+/// Share of an investor's money committed when backing another person's skill.
+const INVESTMENT_CAPITAL_SHARE: f64 = 0.2;
+
+// P2P lending constants
+/// Share of a lender's money above `min_money_to_lend` offered on the P2P marketplace.
+const P2P_LENDABLE_SHARE: f64 = 0.5;
+
+// Governance constants
+/// Upper bound for tax rates proposed through voting.
+const MAX_VOTED_TAX_RATE: f64 = 0.5;
+
+/// Upper bound for transaction fees proposed through voting.
+const MAX_VOTED_TRANSACTION_FEE: f64 = 0.2;
+
+/// Fraction of votes cast against a person's economic self-interest, modelling
+/// ideology and imperfect information so that outcomes are not fully predetermined.
+const VOTE_NOISE: f64 = 0.2;
+
+// Environment constants
+/// Share of the Water and Land carrying capacity lost each step in the ClimateChange scenario.
+const CLIMATE_CAPACITY_LOSS: f64 = 0.001;
+
+// Auction constants
+/// Valuation premium over the market price per urgency level (1-3) of a buyer's need.
+const AUCTION_URGENCY_PREMIUM: f64 = 0.25;
+
+/// Bid increment and Dutch clock decrement as a share of the reserve price.
+const AUCTION_TICK_SHARE: f64 = 0.05;
+
+// Runtime mutation constants
+/// Parameters the engine builds state from when it is created (the market, optional
+/// subsystems, the initial population), which [`SimulationEngine::set_parameter`]
+/// refuses to change mid-run.
+pub const FIXED_PARAMETERS: &[&str] = &[
+    "seed",
+    "entity_count",
+    "max_steps",
+    "scenario",
+    "demand_strategy",
+    "base_skill_price",
+    "min_skill_price",
+    "price_elasticity_factor",
+    "volatility_percentage",
+    "per_skill_price_limits",
+    "enable_black_market",
+    "stream_output_path",
+    "enable_production",
+    "enable_environment",
+    "custom_resource_reserves",
+    "resource_regeneration_rates",
+    "resource_carrying_capacities",
+    "resource_quotas",
+    "enforce_resource_reserves",
+    "enable_voting",
+    "voting_method",
+    "enable_events",
+    "enable_profiling",
+    "enable_resource_pools",
+    "num_groups",
+    "enable_trust_networks",
+    "enable_p2p_lending",
+    "p2p_platform_fee_rate",
+    "enable_multi_currency",
+    "currency_system",
+    "enable_reinforcement_learning",
+    "enable_demographics",
+    "enable_firms",
+    "firm_count",
+    "timeline",
+    "custom_crises",
+    "regions",
+    "transport_links",
+    // The invariant checker and its baseline are set up when the run starts
+    "enable_invariant_checking",
+    "strict_invariant_mode",
+    "check_money_conservation",
+    "check_non_negative_wealth",
+];
+
+/// Trade statistics of the current step, written by the trades phase and recorded
+/// into the per-step histories once all phases have run.
+#[derive(Debug, Clone, Copy, Default)]
+struct StepTally {
+    trades: usize,
+    volume: f64,
+    black_market_trades: usize,
+    black_market_volume: f64,
+    failed_attempts: usize,
+    taxes_collected_start: f64,
+    sick_pay: f64,
+    vat: f64,
+}
+
 /// Represents a positive technology breakthrough event.
 ///
 /// Breakthroughs are sudden innovations that boost the efficiency of specific skills,
@@ -69,8 +178,13 @@ pub struct TechnologyBreakthrough {
 /// This structure captures all the stateful information needed to resume
 /// a simulation from a specific point. The random number generator state
 /// is not included; instead, the RNG is reseeded based on the current step.
+///
+/// Older checkpoint files are upgraded by [`crate::checkpoint::migrate_checkpoint`]
+/// before they are deserialized into this structure.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SimulationCheckpoint {
+    /// Checkpoint format version (see [`crate::checkpoint::CHECKPOINT_SCHEMA_VERSION`])
+    pub schema_version: u32,
     /// Configuration used for this simulation
     pub config: SimulationConfig,
     /// All entities in the simulation
@@ -93,6 +207,12 @@ pub struct SimulationCheckpoint {
     pub black_market_volume_per_step: Vec<f64>,
     /// Total transaction fees collected
     pub total_fees_collected: f64,
+    /// Net money injected by plugins through mutating hooks
+    #[serde(default)]
+    pub total_plugin_money_injected: f64,
+    /// Auction outcomes per auction type
+    #[serde(default)]
+    pub auction_stats: Vec<crate::result::AuctionTypeStats>,
     /// Number of failed steps (recovered from panics)
     pub failed_steps: usize,
     /// Total failed trade attempts (due to insufficient funds)
@@ -105,6 +225,32 @@ pub struct SimulationCheckpoint {
     pub total_loans_issued: usize,
     /// Total loans repaid counter
     pub total_loans_repaid: usize,
+    /// Loan defaults and how their losses were absorbed
+    #[serde(default)]
+    pub loan_defaults: DefaultLedger,
+    /// All active investments in the system
+    #[serde(default)]
+    pub investments: HashMap<InvestmentId, Investment>,
+    /// Investment system tracking
+    #[serde(default)]
+    pub total_investments_created: usize,
+    #[serde(default)]
+    pub total_investments_completed: usize,
+    #[serde(default)]
+    pub total_investments_defaulted: usize,
+    #[serde(default)]
+    pub total_invested: f64,
+    #[serde(default)]
+    pub total_investment_returns: f64,
+    #[serde(default)]
+    pub total_investment_losses: f64,
+    #[serde(default)]
+    pub total_investment_education_spending: f64,
+    #[serde(default)]
+    pub completed_investment_roi_sum: f64,
+    /// Cross-currency trade flows and exchange rate history (if multi-currency is enabled)
+    #[serde(default)]
+    pub foreign_exchange: Option<ForeignExchange>,
     /// P2P Lending marketplace (if enabled)
     pub lending_marketplace: Option<crate::lending_marketplace::LendingMarketplace>,
     /// Total taxes collected during the simulation
@@ -130,6 +276,64 @@ pub struct SimulationCheckpoint {
     pub max_money: f64,
     /// Social mobility tracking: person_id -> Vec of quintile assignments at each step
     pub mobility_quintiles: HashMap<usize, Vec<usize>>,
+    /// Births, deaths, immigration and market exits (if demographics are enabled)
+    #[serde(default)]
+    pub demographics: DemographicsLedger,
+    /// Epidemic totals and SEIR history (if the health system is enabled)
+    #[serde(default)]
+    pub epidemic: EpidemicLedger,
+    /// Firms founded during the simulation (if firms are enabled)
+    #[serde(default)]
+    pub firms: Vec<Firm>,
+    /// Hiring, layoffs and employment history (if firms are enabled)
+    #[serde(default)]
+    pub firm_ledger: FirmLedger,
+    /// Monetary policy state and history (if the central bank is enabled)
+    #[serde(default)]
+    pub central_bank: CentralBank,
+    /// Government budget, debt and fiscal history (if the government is enabled)
+    #[serde(default)]
+    pub government: Government,
+    /// Transfers paid by the transfer scheme (if tax redistribution is enabled)
+    #[serde(default)]
+    pub transfer_ledger: TransferLedger,
+    /// Custom crises started so far (if any are defined)
+    #[serde(default)]
+    pub crisis_ledger: CrisisLedger,
+    /// Trades and migration of each region (if regions are configured)
+    #[serde(default)]
+    pub region_ledgers: Vec<RegionLedger>,
+    /// Active mentorships and mentorship statistics (if mentorship is enabled)
+    #[serde(default)]
+    pub mentorships: Vec<crate::person::Mentorship>,
+    #[serde(default)]
+    pub total_mentorships_formed: usize,
+    #[serde(default)]
+    pub successful_mentored_learnings: usize,
+    #[serde(default)]
+    pub total_mentorship_cost_savings: f64,
+    #[serde(default)]
+    pub unique_mentors: HashSet<usize>,
+    #[serde(default)]
+    pub unique_mentees: HashSet<usize>,
+    /// Strategy evolution history (if strategy evolution is enabled)
+    #[serde(default)]
+    pub strategy_distribution_history: Vec<crate::result::StrategyDistributionSnapshot>,
+    #[serde(default)]
+    pub total_strategy_changes: usize,
+    #[serde(default)]
+    pub total_strategy_mutations: usize,
+    #[serde(default)]
+    pub total_strategy_imitations: usize,
+    /// Assets owned by persons (if assets are enabled)
+    #[serde(default)]
+    pub assets: HashMap<crate::asset::AssetId, crate::asset::Asset>,
+    #[serde(default)]
+    pub asset_counter: usize,
+    #[serde(default)]
+    pub total_assets_purchased: usize,
+    #[serde(default)]
+    pub total_assets_sold: usize,
     /// Environmental resource tracking (if enabled)
     pub environment: Option<Environment>,
     /// Voting system state (if enabled)
@@ -163,6 +367,23 @@ pub struct SimulationCheckpoint {
     pub externality_stats: crate::externality::ExternalityStats,
 }
 
+/// An in-memory copy of the simulation state, taken with [`SimulationEngine::snapshot`].
+///
+/// A snapshot holds the same state as a checkpoint file without the cost of writing
+/// it. An engine restored from it with [`SimulationEngine::restore_snapshot`] continues
+/// exactly like the run it was taken from.
+#[derive(Debug, Clone)]
+pub struct EngineSnapshot {
+    state: SimulationCheckpoint,
+}
+
+impl EngineSnapshot {
+    /// Returns the step the snapshot was taken at.
+    pub fn step(&self) -> usize {
+        self.state.current_step
+    }
+}
+
 pub struct SimulationEngine {
     config: SimulationConfig,
     entities: Vec<Entity>,
@@ -182,6 +403,10 @@ pub struct SimulationEngine {
     black_market_volume_per_step: Vec<f64>,
     // Transaction fees tracking
     total_fees_collected: f64,
+    // Net money injected by plugins through mutating hooks
+    total_plugin_money_injected: f64,
+    // Auction outcomes per auction type (if auctions are enabled)
+    auction_stats: Vec<crate::result::AuctionTypeStats>,
     // Panic recovery tracking
     failed_steps: usize,
     // Failed trade attempts tracking
@@ -191,6 +416,20 @@ pub struct SimulationEngine {
     loans: HashMap<LoanId, Loan>,
     total_loans_issued: usize,
     total_loans_repaid: usize,
+    loan_defaults: DefaultLedger,
+    // Investment system tracking (IDs are assigned from total_investments_created)
+    investments: HashMap<InvestmentId, Investment>,
+    total_investments_created: usize,
+    total_investments_completed: usize,
+    total_investments_defaulted: usize,
+    total_invested: f64,
+    total_investment_returns: f64,
+    total_investment_losses: f64,
+    // Learning costs paid by investors for education investments (a money sink)
+    total_investment_education_spending: f64,
+    completed_investment_roi_sum: f64,
+    // Cross-currency trade flows and exchange rate history (if multi-currency is enabled)
+    foreign_exchange: Option<ForeignExchange>,
     // P2P Lending marketplace (if enabled)
     lending_marketplace: Option<crate::lending_marketplace::LendingMarketplace>,
     // Tax system tracking
@@ -226,8 +465,41 @@ pub struct SimulationEngine {
     max_money: f64,
     // Social mobility tracking: person_id -> Vec of quintile assignments (0-4) at each step
     mobility_quintiles: HashMap<usize, Vec<usize>>,
+    // Population changes (births, deaths, immigration, market exits)
+    demographics: DemographicsLedger,
+    // Epidemic totals, policy responses and SEIR history
+    epidemic: EpidemicLedger,
+    // Firms employing persons, and the labor market totals
+    firms: Vec<Firm>,
+    firm_ledger: FirmLedger,
+    // Central bank policy rate, money issued and policy history
+    central_bank: CentralBank,
+    // Government budget, debt and fiscal history
+    government: Government,
+    // Transfers paid by the transfer scheme
+    transfer_ledger: TransferLedger,
+    // Custom crises started so far
+    crisis_ledger: CrisisLedger,
+    // Trades and migration of each region, parallel to config.regions
+    region_ledgers: Vec<RegionLedger>,
+    // Cheapest routes between regions, derived from config.transport_links
+    transport_network: TransportNetwork,
     // Plugin system for extending simulation
     plugin_registry: PluginRegistry,
+    // Step phase pipeline, resolved lazily from the configured order
+    phase_pipeline: Vec<PhaseRef>,
+    phase_pipeline_resolved: bool,
+    // Custom phases; a slot is empty while its phase is running
+    custom_phases: Vec<Option<Box<dyn StepPhase>>>,
+    // Accumulated time per pipeline entry, parallel to phase_pipeline
+    phase_timings: Vec<PhaseTiming>,
+    // Per-step trade statistics shared between phases
+    step_tally: StepTally,
+    // Opt-in per-phase profiler (if enabled)
+    profiler: Option<Profiler>,
+    profiled_steps: usize,
+    // Incremental checkpoint writer of this run (if delta checkpoints were saved)
+    incremental_checkpointer: Option<IncrementalCheckpointer>,
     // Resource pool tracking: group_id -> (balance, total_contributions, total_withdrawals)
     resource_pools: HashMap<usize, (f64, f64, f64)>,
     // Production system recipes (cached for performance)
@@ -292,7 +564,14 @@ impl SimulationEngine {
         );
 
         // This is the version from feat/economic-simulation-model
-        let entities = Self::initialize_entities(&config, &mut rng, &mut market);
+        let mut entities = Self::initialize_entities(&config, &mut rng, &mut market);
+
+        // Spread initial ages so that the population does not retire all at once
+        if config.enable_demographics {
+            for entity in entities.iter_mut() {
+                entity.person_data.age = rng.random_range(0..config.retirement_age);
+            }
+        }
 
         // Apply per-skill price limits from configuration
         if !config.per_skill_price_limits.is_empty() {
@@ -312,7 +591,9 @@ impl SimulationEngine {
             }
         }
 
-        let all_skill_ids = market.skills.keys().cloned().collect::<Vec<SkillId>>();
+        // Sorted so that demand generation and breakthroughs draw from a stable order
+        let mut all_skill_ids = market.skills.keys().cloned().collect::<Vec<SkillId>>();
+        all_skill_ids.sort();
 
         // Initialize black market if enabled
         let black_market = if config.enable_black_market {
@@ -370,25 +651,38 @@ impl SimulationEngine {
             use crate::environment::Resource;
             use std::collections::HashMap as StdHashMap;
 
-            let env = if let Some(custom_reserves) = &config.custom_resource_reserves {
-                // Parse custom reserves from string keys to Resource enum
-                let mut reserves = StdHashMap::new();
-                for (resource_name, amount) in custom_reserves {
-                    match resource_name.to_lowercase().as_str() {
-                        "energy" => reserves.insert(Resource::Energy, *amount),
-                        "water" => reserves.insert(Resource::Water, *amount),
-                        "materials" => reserves.insert(Resource::Materials, *amount),
-                        "land" => reserves.insert(Resource::Land, *amount),
-                        _ => {
-                            warn!("Unknown resource type: {}, ignoring", resource_name);
-                            continue;
+            // Parse resource settings from string keys to the Resource enum
+            let parse = |settings: &StdHashMap<String, f64>| -> StdHashMap<Resource, f64> {
+                let mut parsed = StdHashMap::new();
+                for (resource_name, &amount) in settings {
+                    match Resource::from_name(resource_name) {
+                        Some(resource) => {
+                            parsed.insert(resource, amount);
                         },
-                    };
+                        None => warn!("Unknown resource type: {}, ignoring", resource_name),
+                    }
                 }
-                Environment::new(reserves)
+                parsed
+            };
+
+            let mut env = if let Some(custom_reserves) = &config.custom_resource_reserves {
+                Environment::new(parse(custom_reserves))
             } else {
                 Environment::with_default_reserves()
             };
+            env.regeneration_rates = parse(&config.resource_regeneration_rates);
+            env.carrying_capacities = parse(&config.resource_carrying_capacities);
+            env.step_quotas = parse(&config.resource_quotas);
+            env.enforce_reserves = config.enforce_resource_reserves;
+
+            // Intensities average 1.0, so total consumption matches an even split
+            for skill_id in &all_skill_ids {
+                let intensities = Resource::all()
+                    .into_iter()
+                    .map(|resource| (resource, rng.random_range(0.0..2.0)))
+                    .collect();
+                env.skill_intensities.insert(skill_id.clone(), intensities);
+            }
             debug!("Environment tracking initialized");
             Some(env)
         } else {
@@ -408,6 +702,10 @@ impl SimulationEngine {
         if config.enable_events {
             debug!("Event tracking system enabled");
         }
+        let enable_profiling = config.enable_profiling;
+
+        // The central bank starts from the configured loan interest rate
+        let central_bank = CentralBank::new(config.loan_interest_rate);
 
         // Initialize resource pools before moving config
         let resource_pools = {
@@ -458,10 +756,13 @@ impl SimulationEngine {
 
         // Capture P2P lending config values before moving config
         let enable_p2p = config.enable_p2p_lending;
+        let enable_multi_currency = config.enable_multi_currency;
         let p2p_fee = config.p2p_platform_fee_rate;
         // Capture RL config before moving config
         let enable_rl = config.enable_reinforcement_learning;
         let rl_epsilon = config.rl_epsilon;
+        let region_ledgers = vec![RegionLedger::default(); config.regions.len()];
+        let transport_network = TransportNetwork::new(&config.regions, &config.transport_links);
 
         let mut engine = Self {
             config,
@@ -477,12 +778,25 @@ impl SimulationEngine {
             black_market_trades_per_step: Vec::new(),
             black_market_volume_per_step: Vec::new(),
             total_fees_collected: 0.0,
+            total_plugin_money_injected: 0.0,
+            auction_stats: Vec::new(),
             failed_steps: 0,
             failed_trade_attempts: 0,
             failed_attempts_per_step: Vec::new(),
             loans: HashMap::new(),
             total_loans_issued: 0,
             total_loans_repaid: 0,
+            loan_defaults: DefaultLedger::default(),
+            investments: HashMap::new(),
+            total_investments_created: 0,
+            total_investments_completed: 0,
+            total_investments_defaulted: 0,
+            total_invested: 0.0,
+            total_investment_returns: 0.0,
+            total_investment_losses: 0.0,
+            total_investment_education_spending: 0.0,
+            completed_investment_roi_sum: 0.0,
+            foreign_exchange: enable_multi_currency.then(ForeignExchange::default),
             lending_marketplace: if enable_p2p {
                 Some(crate::lending_marketplace::LendingMarketplace::new(p2p_fee))
             } else {
@@ -510,7 +824,25 @@ impl SimulationEngine {
             min_money: f64::INFINITY,
             max_money: f64::NEG_INFINITY,
             mobility_quintiles: HashMap::new(),
+            demographics: DemographicsLedger::default(),
+            epidemic: EpidemicLedger::default(),
+            firms: Vec::new(),
+            firm_ledger: FirmLedger::default(),
+            central_bank,
+            government: Government::default(),
+            transfer_ledger: TransferLedger::default(),
+            crisis_ledger: CrisisLedger::default(),
+            region_ledgers,
+            transport_network,
             plugin_registry: PluginRegistry::new(),
+            phase_pipeline: Vec::new(),
+            phase_pipeline_resolved: false,
+            custom_phases: Vec::new(),
+            phase_timings: Vec::new(),
+            step_tally: StepTally::default(),
+            profiler: enable_profiling.then(Profiler::new),
+            profiled_steps: 0,
+            incremental_checkpointer: None,
             resource_pools,
             production_recipes,
             environment,
@@ -550,6 +882,14 @@ impl SimulationEngine {
             debug!("Reinforcement learning enabled with initial epsilon: {}", rl_epsilon);
         }
 
+        // Found the initial firms
+        if engine.config.enable_firms {
+            for _ in 0..engine.config.firm_count {
+                engine.found_firm();
+            }
+            info!("{} firms founded", engine.firms.len());
+        }
+
         engine
     }
 
@@ -616,6 +956,97 @@ impl SimulationEngine {
         debug!("Action recording enabled for replay and debugging");
     }
 
+    /// Changes a configuration parameter mid-run.
+    ///
+    /// `value` is parsed as JSON (`0.2`, `true`, `null`, `[1, 2]`), falling back to a
+    /// plain string for enum variants such as `MeansTested`. The changed configuration
+    /// must pass [`SimulationConfig::validate`], and [`FIXED_PARAMETERS`] cannot be
+    /// changed, so a rejected change leaves the engine untouched. Accepted changes take
+    /// effect from the next step and are recorded in the action log (if enabled) so
+    /// that a replay applies them at the same step.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`crate::SimulationError::ValidationError`] for an unknown or fixed
+    /// parameter, a value of the wrong type or a configuration that fails validation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use community_simulation::{SimulationConfig, SimulationEngine};
+    ///
+    /// let mut engine = SimulationEngine::new(SimulationConfig::default());
+    /// engine.step();
+    /// engine.set_parameter("tax_rate", "0.2").unwrap();
+    /// assert_eq!(engine.get_config().tax_rate, 0.2);
+    ///
+    /// assert!(engine.set_parameter("tax_rate", "1.5").is_err());
+    /// assert!(engine.set_parameter("entity_count", "50").is_err());
+    /// ```
+    pub fn set_parameter(&mut self, name: &str, value: &str) -> crate::Result<()> {
+        let value = serde_json::from_str(value)
+            .unwrap_or_else(|_| serde_json::Value::String(value.to_string()));
+        let config = self.config.with_parameter(name, value.clone())?;
+
+        info!("Parameter {} set to {} at step {}", name, value, self.current_step);
+        self.replace_config(config);
+        if let Some(ref mut action_log) = self.action_log {
+            action_log.record(crate::replay::SimulationAction::ParameterChange {
+                step: self.current_step,
+                parameter: name.to_string(),
+                value: value.to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Switches to a changed configuration mid-run.
+    ///
+    /// The phase pipeline is resolved again before the next phases run if the changed
+    /// configuration reorders or disables phases.
+    fn replace_config(&mut self, config: SimulationConfig) {
+        if config.step_phases != self.config.step_phases
+            || config.disabled_step_phases != self.config.disabled_step_phases
+        {
+            self.phase_pipeline_resolved = false;
+        }
+        self.config = config;
+    }
+
+    /// Triggers a crisis of the given severity (0.0-1.0) before the next step.
+    ///
+    /// The crisis is applied like a random crisis event, whether or not random crises
+    /// are enabled, and recorded in the action log (if enabled).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`crate::SimulationError::ValidationError`] if the severity is outside
+    /// 0.0-1.0.
+    pub fn trigger_crisis(&mut self, crisis: CrisisEvent, severity: f64) -> crate::Result<()> {
+        if !(0.0..=1.0).contains(&severity) {
+            return Err(crate::SimulationError::ValidationError(format!(
+                "Crisis severity must be between 0.0 and 1.0, got {}",
+                severity
+            )));
+        }
+
+        info!(
+            "🚨 CRISIS TRIGGERED at step {}: {} (severity {:.2})",
+            self.current_step,
+            crisis.name(),
+            severity
+        );
+        if let Some(ref mut action_log) = self.action_log {
+            action_log.record(crate::replay::SimulationAction::TriggeredCrisis {
+                step: self.current_step,
+                crisis,
+                severity,
+            });
+        }
+        self.apply_crisis(crisis, severity);
+        Ok(())
+    }
+
     /// Initialize invariant checking based on configuration.
     ///
     /// This should be called after engine creation to set up invariant checks
@@ -637,8 +1068,8 @@ impl SimulationEngine {
 
         // Add money conservation invariant if enabled
         if self.config.check_money_conservation {
-            let initial_total_money: f64 =
-                self.entities.iter().map(|e| e.get_money() + e.person_data.savings).sum();
+            let initial_total_money =
+                self.total_person_money_in_base_currency() + self.get_total_firm_cash();
             checker.add_invariant(Box::new(MoneyConservationInvariant::new(initial_total_money)));
             debug!(
                 "Money conservation invariant enabled (initial total: {:.2})",
@@ -700,6 +1131,13 @@ impl SimulationEngine {
         }
     }
 
+    /// Get read-only access to the recorded action log.
+    ///
+    /// Returns None if action recording has not been enabled.
+    pub fn get_action_log(&self) -> Option<&crate::replay::ActionLog> {
+        self.action_log.as_ref()
+    }
+
     // This is the version from feat/economic-simulation-model
     fn initialize_entities(
         config: &SimulationConfig,
@@ -748,21 +1186,7 @@ impl SimulationEngine {
             );
 
             // Generate discount factor (time preference) for this person
-            let discount_factor = if config.enable_time_preferences {
-                // Sample from normal distribution with configured mean and std_dev
-                use rand_distr::{Distribution, Normal};
-                let normal =
-                    Normal::new(config.time_preference_mean, config.time_preference_std_dev)
-                        .unwrap_or_else(|_| {
-                            // Fallback if distribution parameters are invalid
-                            Normal::new(0.90, 0.10).unwrap()
-                        });
-                // Clamp to valid range [0.3, 0.99] to avoid extreme values
-                normal.sample(rng).clamp(0.3, 0.99)
-            } else {
-                // Default: moderately patient (0.95)
-                0.95
-            };
+            let discount_factor = Self::sample_discount_factor(config, rng);
 
             let mut entity = Entity::new(
                 i,
@@ -798,6 +1222,20 @@ impl SimulationEngine {
             info!("{} persons start with illness (seed infections)", num_to_infect);
         }
 
+        // Assign currencies round-robin if multi-currency is enabled.
+        // initial_money_per_person is denominated in the base currency.
+        if config.enable_multi_currency {
+            let system = &config.currency_system;
+            let currency_ids = system.sorted_ids();
+            for (i, entity) in entities.iter_mut().enumerate() {
+                let currency_id = &currency_ids[i % currency_ids.len()];
+                entity.person_data.money = system
+                    .convert(entity.person_data.money, &system.base_currency_id, currency_id)
+                    .unwrap_or(entity.person_data.money);
+                entity.person_data.currency_id = currency_id.clone();
+            }
+        }
+
         // Assign groups if configured
         if let Some(num_groups) = config.num_groups {
             for (i, entity) in entities.iter_mut().enumerate() {
@@ -806,6 +1244,12 @@ impl SimulationEngine {
             }
         }
 
+        // Everyone lives in the region nearest to them
+        for entity in entities.iter_mut() {
+            entity.person_data.region =
+                geography::nearest_region(&config.regions, &entity.person_data.location);
+        }
+
         // Initialize skill qualities if quality system is enabled
         if config.enable_quality {
             for entity in entities.iter_mut() {
@@ -827,6 +1271,24 @@ impl SimulationEngine {
         entities
     }
 
+    /// Draws a person's discount factor (time preference).
+    fn sample_discount_factor(config: &SimulationConfig, rng: &mut StdRng) -> f64 {
+        if config.enable_time_preferences {
+            // Sample from normal distribution with configured mean and std_dev
+            use rand_distr::{Distribution, Normal};
+            let normal = Normal::new(config.time_preference_mean, config.time_preference_std_dev)
+                .unwrap_or_else(|_| {
+                    // Fallback if distribution parameters are invalid
+                    Normal::new(0.90, 0.10).unwrap()
+                });
+            // Clamp to valid range [0.3, 0.99] to avoid extreme values
+            normal.sample(rng).clamp(0.3, 0.99)
+        } else {
+            // Default: moderately patient (0.95)
+            0.95
+        }
+    }
+
     /// Calculate seasonal demand factor for a specific skill at the current step.
     ///
     /// This function creates cyclical demand variations using sine waves,
@@ -867,6 +1329,88 @@ impl SimulationEngine {
         1.0 + sine_value * self.config.seasonal_amplitude
     }
 
+    /// Applies the timeline events due at the current step, in the order listed.
+    ///
+    /// Parameter changes are not recorded in the action log, since a replay from the
+    /// same configuration applies the timeline again; forced crises are recorded like
+    /// random ones.
+    fn apply_timeline(&mut self) {
+        if self.config.timeline.is_empty() {
+            return;
+        }
+        let step = self.current_step;
+        // Changed configurations are built without the timeline, which was validated
+        // with the rest of the configuration
+        let timeline = std::mem::take(&mut self.config.timeline);
+        for event in &timeline {
+            match &event.action {
+                TimelineAction::SetParameter { parameter, value } if event.step == step => {
+                    match self.config.with_parameter(parameter, value.clone()) {
+                        Ok(config) => {
+                            info!("📅 Timeline at step {}: {} set to {}", step, parameter, value);
+                            self.replace_config(config);
+                        },
+                        Err(e) => warn!("Timeline event '{}' skipped: {}", event, e),
+                    }
+                },
+                TimelineAction::Crisis { crisis, severity } if event.step == step => {
+                    let severity = severity.unwrap_or(self.config.crisis_severity);
+                    info!(
+                        "📅 Timeline at step {}: {} (severity {:.2})",
+                        step,
+                        crisis.name(),
+                        severity
+                    );
+                    if let Some(ref mut action_log) = self.action_log {
+                        action_log.record(crate::replay::SimulationAction::CrisisEvent {
+                            step,
+                            event_type: crisis.name().to_string(),
+                            severity,
+                        });
+                    }
+                    self.apply_crisis(*crisis, severity);
+                },
+                TimelineAction::PriceCap { skill, max_price, until_step } => {
+                    if event.step == step {
+                        self.set_timeline_price_cap(skill, Some(*max_price));
+                    } else if *until_step == step {
+                        self.set_timeline_price_cap(skill, None);
+                    }
+                },
+                _ => {},
+            }
+        }
+        self.config.timeline = timeline;
+    }
+
+    /// Caps the maximum price of a skill, keeping its configured minimum, or restores
+    /// its configured limits when `max_price` is None.
+    fn set_timeline_price_cap(&mut self, skill_id: &str, max_price: Option<f64>) {
+        let Some(skill) = self.market.skills.get_mut(skill_id) else {
+            warn!("Timeline price cap skipped: unknown skill '{}'", skill_id);
+            return;
+        };
+        let (min, configured_max) = self
+            .config
+            .per_skill_price_limits
+            .get(skill_id)
+            .copied()
+            .unwrap_or((None, None));
+        match max_price {
+            Some(cap) => {
+                skill.current_price = skill.current_price.min(cap);
+                info!(
+                    "📅 Price of {} capped at {:.2} at step {}",
+                    skill_id, cap, self.current_step
+                );
+            },
+            None => info!("📅 Price cap on {} lifted at step {}", skill_id, self.current_step),
+        }
+        let skill_id = skill.id.clone();
+        self.market
+            .set_per_skill_price_limits(&skill_id, min, max_price.or(configured_max));
+    }
+
     /// Check for and potentially trigger a crisis event.
     ///
     /// This method is called once per simulation step. It randomly determines
@@ -885,7 +1429,7 @@ impl SimulationEngine {
 
         // Select a random crisis type
         let crisis_types = CrisisEvent::all_types();
-        let crisis = crisis_types.choose(&mut self.rng).unwrap();
+        let crisis = *crisis_types.choose(&mut self.rng).unwrap();
 
         info!(
             "🚨 CRISIS EVENT at step {}: {} - {}",
@@ -903,18 +1447,21 @@ impl SimulationEngine {
             });
         }
 
-        // Apply crisis effects based on type
+        self.apply_crisis(crisis, self.config.crisis_severity);
+    }
+
+    /// Applies the effects of a crisis of the given severity to the economy.
+    fn apply_crisis(&mut self, crisis: CrisisEvent, severity: f64) {
         match crisis {
             CrisisEvent::MarketCrash => {
                 // Reduce all skill prices
                 debug!("Applying market crash: reducing all skill prices");
-                for (_skill_id, skill) in self.market.skills.iter_mut() {
+                let mut skills: Vec<_> = self.market.skills.iter_mut().collect();
+                skills.sort_unstable_by(|a, b| a.0.cmp(b.0));
+                for (_skill_id, skill) in skills {
                     let old_price = skill.current_price;
-                    skill.current_price = crisis.apply_effect(
-                        skill.current_price,
-                        self.config.crisis_severity,
-                        &mut self.rng,
-                    );
+                    skill.current_price =
+                        crisis.apply_effect(skill.current_price, severity, &mut self.rng);
                     // Respect minimum price floor
                     skill.current_price = skill.current_price.max(self.config.min_skill_price);
                     debug!(
@@ -924,12 +1471,11 @@ impl SimulationEngine {
                 }
                 // Also apply to black market if enabled
                 if let Some(ref mut bm) = self.black_market {
-                    for (_skill_id, skill) in bm.skills.iter_mut() {
-                        skill.current_price = crisis.apply_effect(
-                            skill.current_price,
-                            self.config.crisis_severity,
-                            &mut self.rng,
-                        );
+                    let mut skills: Vec<_> = bm.skills.iter_mut().collect();
+                    skills.sort_unstable_by(|a, b| a.0.cmp(b.0));
+                    for (_skill_id, skill) in skills {
+                        skill.current_price =
+                            crisis.apply_effect(skill.current_price, severity, &mut self.rng);
                         skill.current_price = skill.current_price.max(self.config.min_skill_price);
                     }
                 }
@@ -944,8 +1490,7 @@ impl SimulationEngine {
                     let original_count = entity.person_data.needed_skills.len();
                     if original_count > 0 {
                         // Apply crisis effect to determine how many needs to keep
-                        let reduction_factor =
-                            crisis.apply_effect(1.0, self.config.crisis_severity, &mut self.rng);
+                        let reduction_factor = crisis.apply_effect(1.0, severity, &mut self.rng);
                         let keep_ratio = 1.0 - reduction_factor;
                         let keep_count = ((original_count as f64) * keep_ratio).ceil() as usize;
                         entity.person_data.needed_skills.truncate(keep_count);
@@ -958,37 +1503,58 @@ impl SimulationEngine {
                 // we could track "supply reduction" and reduce effective supply counts
                 debug!("Applying supply shock: supply chain disruptions");
                 // Apply effect to supply counts in the market
-                for (_skill_id, count) in self.market.supply_counts.iter_mut() {
+                let mut supply_counts: Vec<_> = self.market.supply_counts.iter_mut().collect();
+                supply_counts.sort_unstable_by(|a, b| a.0.cmp(b.0));
+                for (_skill_id, count) in supply_counts {
                     let old_supply = *count;
-                    let reduction_factor =
-                        crisis.apply_effect(1.0, self.config.crisis_severity, &mut self.rng);
+                    let reduction_factor = crisis.apply_effect(1.0, severity, &mut self.rng);
                     *count = ((old_supply as f64) * reduction_factor) as usize;
                     debug!("  Supply reduced from {} to {}", old_supply, *count);
                 }
             },
             CrisisEvent::CurrencyDevaluation => {
-                // Reduce everyone's money holdings
-                debug!("Applying currency devaluation: reducing all money holdings");
-                for entity in self.entities.iter_mut() {
-                    if !entity.active {
-                        continue;
+                // With several currencies, the crisis hits one non-base currency:
+                // its exchange rate jumps, so its holders' money buys less
+                let system = &self.config.currency_system;
+                let targets: Vec<_> = system
+                    .sorted_ids()
+                    .into_iter()
+                    .filter(|id| *id != system.base_currency_id)
+                    .collect();
+                let before = self.total_person_money_in_base_currency();
+                if let (Some(fx), false) = (self.foreign_exchange.as_mut(), targets.is_empty()) {
+                    let target = &targets[self.rng.random_range(0..targets.len())];
+                    let value_factor = crisis.apply_effect(1.0, severity, &mut self.rng);
+                    if let Some(new_rate) =
+                        fx.devalue(&mut self.config.currency_system, target, value_factor)
+                    {
+                        debug!(
+                            "Applying currency devaluation to {}: exchange rate now {:.4}",
+                            target, new_rate
+                        );
+                    }
+                    self.record_exchange_revaluation(before);
+                } else {
+                    // Reduce everyone's money holdings
+                    debug!("Applying currency devaluation: reducing all money holdings");
+                    for entity in self.entities.iter_mut() {
+                        if !entity.active {
+                            continue;
+                        }
+                        let old_money = entity.person_data.money;
+                        entity.person_data.money =
+                            crisis.apply_effect(entity.person_data.money, severity, &mut self.rng);
+                        // Also affect savings
+                        entity.person_data.savings = crisis.apply_effect(
+                            entity.person_data.savings,
+                            severity,
+                            &mut self.rng,
+                        );
+                        debug!(
+                            "  Person {}: ${:.2} -> ${:.2}",
+                            entity.id, old_money, entity.person_data.money
+                        );
                     }
-                    let old_money = entity.person_data.money;
-                    entity.person_data.money = crisis.apply_effect(
-                        entity.person_data.money,
-                        self.config.crisis_severity,
-                        &mut self.rng,
-                    );
-                    // Also affect savings
-                    entity.person_data.savings = crisis.apply_effect(
-                        entity.person_data.savings,
-                        self.config.crisis_severity,
-                        &mut self.rng,
-                    );
-                    debug!(
-                        "  Person {}: ${:.2} -> ${:.2}",
-                        entity.id, old_money, entity.person_data.money
-                    );
                 }
             },
             CrisisEvent::TechnologyShock => {
@@ -997,12 +1563,13 @@ impl SimulationEngine {
 
                 // Randomly select 20-40% of skills to be affected (scaled by severity)
                 let total_skills = self.market.skills.len();
-                let affected_percentage = TECH_SHOCK_MIN_AFFECTED_PERCENTAGE
-                    + (self.config.crisis_severity * TECH_SHOCK_SEVERITY_RANGE);
+                let affected_percentage =
+                    TECH_SHOCK_MIN_AFFECTED_PERCENTAGE + (severity * TECH_SHOCK_SEVERITY_RANGE);
                 let num_affected = ((total_skills as f64) * affected_percentage).ceil() as usize;
 
                 // Collect skill IDs and shuffle them to randomly select affected skills
                 let mut skill_ids: Vec<_> = self.market.skills.keys().cloned().collect();
+                skill_ids.sort();
                 skill_ids.shuffle(&mut self.rng);
 
                 // Take the first N skills as the affected ones
@@ -1013,11 +1580,8 @@ impl SimulationEngine {
                 for skill_id in &affected_skills {
                     if let Some(skill) = self.market.skills.get_mut(skill_id) {
                         let old_price = skill.current_price;
-                        skill.current_price = crisis.apply_effect(
-                            skill.current_price,
-                            self.config.crisis_severity,
-                            &mut self.rng,
-                        );
+                        skill.current_price =
+                            crisis.apply_effect(skill.current_price, severity, &mut self.rng);
                         // Respect minimum price floor
                         skill.current_price = skill.current_price.max(self.config.min_skill_price);
 
@@ -1039,11 +1603,8 @@ impl SimulationEngine {
                 if let Some(ref mut bm) = self.black_market {
                     for skill_id in &affected_skills {
                         if let Some(skill) = bm.skills.get_mut(skill_id) {
-                            skill.current_price = crisis.apply_effect(
-                                skill.current_price,
-                                self.config.crisis_severity,
-                                &mut self.rng,
-                            );
+                            skill.current_price =
+                                crisis.apply_effect(skill.current_price, severity, &mut self.rng);
                             skill.current_price =
                                 skill.current_price.max(self.config.min_skill_price);
                         }
@@ -1059,7 +1620,170 @@ impl SimulationEngine {
         }
 
         // Process insurance payouts for crisis events
-        self.process_crisis_insurance_payouts(self.config.crisis_severity);
+        self.process_crisis_insurance_payouts(severity);
+    }
+
+    /// Starts, applies and spreads the custom crises defined in the configuration.
+    fn process_custom_crises(&mut self) {
+        if self.config.custom_crises.is_empty() {
+            return;
+        }
+        let step = self.current_step;
+
+        // Onsets: a crisis does not start again while it is under way
+        for index in 0..self.config.custom_crises.len() {
+            let definition = &self.config.custom_crises[index];
+            let under_way = self.crisis_ledger.instances.iter().any(|instance| {
+                instance.definition == index
+                    && instance.source.is_none()
+                    && step < instance.start_step + definition.duration
+            });
+            if under_way {
+                continue;
+            }
+            let starts = definition.start_step == Some(step)
+                || (definition.probability > 0.0
+                    && self.rng.random::<f64>() < definition.probability);
+            if starts {
+                let definition = &self.config.custom_crises[index];
+                info!("🚨 CUSTOM CRISIS at step {}: {}", step, definition.name);
+                if let Some(ref mut action_log) = self.action_log {
+                    action_log.record(crate::replay::SimulationAction::CrisisEvent {
+                        step,
+                        event_type: definition.name.clone(),
+                        severity: 1.0,
+                    });
+                }
+                self.crisis_ledger.instances.push(CrisisInstance {
+                    definition: index,
+                    start_step: step,
+                    groups: definition.targets.groups.clone(),
+                    scale: 1.0,
+                    source: None,
+                });
+            }
+        }
+
+        // Effects, including the price reversal in the step after a crisis ends
+        let mut spread = Vec::new();
+        for index in 0..self.crisis_ledger.instances.len() {
+            let instance = self.crisis_ledger.instances[index].clone();
+            let definition = self.config.custom_crises[instance.definition].clone();
+            if step < instance.start_step || step > instance.start_step + definition.duration {
+                continue;
+            }
+            let intensity = instance.intensity(&definition, step);
+            let previous = if step == instance.start_step {
+                0.0
+            } else {
+                instance.intensity(&definition, step - 1)
+            };
+            self.apply_custom_crisis_effects(&definition, &instance, intensity, previous);
+
+            // Contagion to the groups next to each group hit
+            if let (Some(num_groups), true) =
+                (self.config.num_groups, definition.contagion > 0.0 && intensity > 0.0)
+            {
+                for &group in &instance.groups {
+                    for neighbor in
+                        [(group + num_groups - 1) % num_groups, (group + 1) % num_groups]
+                    {
+                        let affected = self.crisis_ledger.instances.iter().chain(&spread).any(
+                            |other: &CrisisInstance| {
+                                other.definition == instance.definition
+                                    && other.groups.contains(&neighbor)
+                                    && step < other.start_step + definition.duration
+                            },
+                        );
+                        if !affected && self.rng.random::<f64>() < definition.contagion * intensity
+                        {
+                            info!(
+                                "🚨 {} spreads from group {} to group {} at step {}",
+                                definition.name, group, neighbor, step
+                            );
+                            spread.push(CrisisInstance {
+                                definition: instance.definition,
+                                start_step: step + 1,
+                                groups: vec![neighbor],
+                                scale: instance.scale * definition.contagion_intensity,
+                                source: Some(index),
+                            });
+                        }
+                    }
+                }
+            }
+        }
+        self.crisis_ledger.instances.extend(spread);
+    }
+
+    /// Applies one step of a custom crisis at the given intensity. Prices move by the
+    /// change in intensity since the previous step, so they recover as it decays.
+    fn apply_custom_crisis_effects(
+        &mut self,
+        definition: &CrisisDefinition,
+        instance: &CrisisInstance,
+        intensity: f64,
+        previous: f64,
+    ) {
+        let effects = &definition.effects;
+        if effects.prices != 0.0 && intensity != previous {
+            let factor = (1.0 + effects.prices * intensity) / (1.0 + effects.prices * previous);
+            let mut skill_ids: Vec<SkillId> = self
+                .market
+                .skills
+                .keys()
+                .filter(|id| definition.targets_skill(id))
+                .cloned()
+                .collect();
+            skill_ids.sort();
+            for skill_id in skill_ids {
+                let min = self.market.get_effective_min_price(&skill_id);
+                let max = self.market.get_effective_max_price(&skill_id);
+                if let Some(skill) = self.market.skills.get_mut(&skill_id) {
+                    skill.current_price = (skill.current_price * factor).clamp(min, max);
+                }
+            }
+        }
+        if intensity <= 0.0 {
+            return;
+        }
+
+        let targets = &definition.targets;
+        let step = self.current_step;
+        let mut money_changes = Vec::new();
+        for (idx, entity) in self.entities.iter_mut().enumerate() {
+            let person = &mut entity.person_data;
+            let hit = entity.active
+                && (instance.groups.is_empty()
+                    || person.group_id.is_some_and(|g| instance.groups.contains(&g)))
+                && (targets.currencies.is_empty()
+                    || targets.currencies.contains(&person.currency_id));
+            if !hit {
+                continue;
+            }
+            if effects.demand > 0.0 {
+                let drop = effects.demand * intensity;
+                let rng = &mut self.rng;
+                person.needed_skills.retain(|need| {
+                    !definition.targets_skill(&need.id) || rng.random::<f64>() >= drop
+                });
+            }
+            if effects.money != 0.0 {
+                let change = person.money * effects.money * intensity;
+                person.money += change;
+                money_changes.push((idx, change));
+            }
+            if effects.health > 0.0
+                && person.is_susceptible()
+                && self.rng.random::<f64>() < effects.health * intensity
+            {
+                person.infect(step);
+            }
+        }
+        // Persons lose or gain money in their own currency; the ledger is in base units
+        for (idx, change) in money_changes {
+            self.crisis_ledger.money_changed += self.entity_to_base_currency(change, idx);
+        }
     }
 
     pub fn run(&mut self) -> SimulationResult {
@@ -1211,7 +1935,12 @@ impl SimulationEngine {
                     .clone()
                     .unwrap_or_else(|| "checkpoint.json".to_string());
 
-                if let Err(e) = self.save_checkpoint(&checkpoint_path) {
+                let saved = if self.config.incremental_checkpoints {
+                    self.save_checkpoint_incremental(&checkpoint_path)
+                } else {
+                    self.save_checkpoint(&checkpoint_path)
+                };
+                if let Err(e) = saved {
                     warn!("Failed to save checkpoint at step {}: {}", self.current_step, e);
                 } else {
                     debug!("Auto-checkpoint saved at step {}", self.current_step);
@@ -1241,12 +1970,7 @@ impl SimulationEngine {
 
                     // Calculate Gini coefficient for wealth inequality
                     // Collect and sort money values
-                    let mut money_values: Vec<f64> = self
-                        .entities
-                        .iter()
-                        .filter(|e| e.active)
-                        .map(|e| e.person_data.money)
-                        .collect();
+                    let mut money_values = self.active_money_in_base_currency();
                     money_values
                         .sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
                     // Calculate sum once and pass to gini coefficient function
@@ -1299,8 +2023,7 @@ impl SimulationEngine {
 
         let total_duration = start_time.elapsed();
 
-        let mut final_money_distribution: Vec<f64> =
-            self.entities.iter().filter(|e| e.active).map(|e| e.person_data.money).collect();
+        let mut final_money_distribution = self.active_money_in_base_currency();
         final_money_distribution
             .sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
 
@@ -1478,16 +2201,10 @@ impl SimulationEngine {
             }
         };
 
-        // Calculate loan statistics if loans are enabled
-        let loan_statistics = if self.config.enable_loans {
-            Some(crate::result::LoanStats {
-                total_loans_issued: self.total_loans_issued,
-                total_loans_repaid: self.total_loans_repaid,
-                active_loans: self.loans.len(),
-            })
-        } else {
-            None
-        };
+        let investment_statistics = self.calculate_investment_statistics();
+        let lending_marketplace_statistics = self.calculate_lending_marketplace_statistics();
+
+        let loan_statistics = self.calculate_loan_statistics();
 
         // Calculate insurance statistics if insurance is enabled
         let insurance_statistics = if self.config.enable_insurance {
@@ -1741,6 +2458,8 @@ impl SimulationEngine {
             total_steps: self.config.max_steps,
             total_duration: total_duration.as_secs_f64(),
             step_times,
+            phase_timings: self.phase_timings.clone(),
+            profile: self.get_profile_report(),
             active_persons: self.entities.iter().filter(|e| e.active).count(),
             failed_steps: self.failed_steps,
             final_money_distribution,
@@ -1803,20 +2522,25 @@ impl SimulationEngine {
             } else {
                 None
             },
-            total_taxes_collected: if self.config.tax_rate > 0.0 {
+            // Taxes may have been collected before a vote lowered the rate to zero
+            total_taxes_collected: if self.config.tax_rate > 0.0 || self.total_taxes_collected > 0.0
+            {
                 Some(self.total_taxes_collected)
             } else {
                 None
             },
             total_taxes_redistributed: if self.config.enable_tax_redistribution
-                && self.config.tax_rate > 0.0
+                && (self.config.tax_rate > 0.0 || self.total_taxes_collected > 0.0)
             {
                 Some(self.total_taxes_redistributed)
             } else {
                 None
             },
             loan_statistics,
-            investment_statistics: None, // Investment system not yet fully implemented
+            lending_marketplace_statistics,
+            investment_statistics,
+            currency_statistics: self.calculate_currency_statistics(),
+            auction_statistics: self.calculate_auction_statistics(),
             contract_statistics: if self.config.enable_contracts {
                 let active_contracts = self.contracts.values().filter(|c| c.is_active()).count();
 
@@ -1960,6 +2684,33 @@ impl SimulationEngine {
                     let overall_sustainability_score = environment.overall_sustainability_score();
                     let is_sustainable = environment.is_sustainable();
 
+                    let total_regeneration: HashMap<String, f64> = environment
+                        .total_regeneration
+                        .iter()
+                        .map(|(resource, &amount)| (resource.name().to_string(), amount))
+                        .collect();
+
+                    let carrying_capacities: HashMap<String, f64> = Resource::all()
+                        .into_iter()
+                        .filter(Resource::has_carrying_capacity)
+                        .map(|resource| {
+                            (resource.name().to_string(), environment.carrying_capacity(resource))
+                        })
+                        .collect();
+
+                    let multipliers: Vec<f64> = self
+                        .all_skill_ids
+                        .iter()
+                        .map(|skill_id| self.market.get_resource_cost_multiplier(skill_id))
+                        .collect();
+                    let avg_resource_cost_multiplier = if multipliers.is_empty() {
+                        1.0
+                    } else {
+                        multipliers.iter().sum::<f64>() / multipliers.len() as f64
+                    };
+                    let max_resource_cost_multiplier =
+                        multipliers.iter().copied().fold(1.0, f64::max);
+
                     Some(crate::result::EnvironmentStats {
                         total_consumption,
                         initial_reserves,
@@ -1967,6 +2718,11 @@ impl SimulationEngine {
                         sustainability_scores,
                         overall_sustainability_score,
                         is_sustainable,
+                        total_regeneration,
+                        carrying_capacities,
+                        blocked_transactions: environment.blocked_consumptions,
+                        avg_resource_cost_multiplier,
+                        max_resource_cost_multiplier,
                     })
                 } else {
                     None
@@ -2160,6 +2916,7 @@ impl SimulationEngine {
             } else {
                 None
             },
+            voting_statistics: self.voting_system.as_ref().map(|v| v.statistics()),
             insurance_statistics,
             asset_statistics,
             technology_breakthrough_statistics,
@@ -2179,7 +2936,10 @@ impl SimulationEngine {
                 for group_id in 0..num_groups {
                     if let Some(members) = group_data.get(&group_id) {
                         let member_count = members.len();
-                        let total_money: f64 = members.iter().map(|e| e.person_data.money).sum();
+                        let total_money: f64 = members
+                            .iter()
+                            .map(|e| self.entity_to_base_currency(e.person_data.money, e.id))
+                            .sum();
                         let avg_money = if member_count > 0 {
                             total_money / member_count as f64
                         } else {
@@ -2352,6 +3112,15 @@ impl SimulationEngine {
             mobility_statistics: crate::result::calculate_mobility_statistics(
                 &self.mobility_quintiles,
             ),
+            demographics_statistics: self.calculate_demographics_statistics(),
+            epidemic_statistics: self.calculate_epidemic_statistics(),
+            firm_statistics: self.calculate_firm_statistics(),
+            monetary_policy_statistics: self.calculate_monetary_policy_statistics(),
+            government_statistics: self.calculate_government_statistics(),
+            transfer_statistics: self.calculate_transfer_statistics(),
+            timeline_statistics: self.calculate_timeline_statistics(),
+            custom_crisis_statistics: self.calculate_custom_crisis_statistics(),
+            geography_statistics: self.calculate_geography_statistics(),
             social_class_statistics: self.calculate_social_class_statistics(),
             quality_statistics: if self.config.enable_quality {
                 // Collect all quality ratings from all persons
@@ -2552,11 +3321,14 @@ impl SimulationEngine {
 
                 // Update skill_providers cache with the newly learned skill
                 // Note: No duplicate check needed because has_skill() above ensures
-                // the person doesn't already have this skill (line 2152)
-                self.skill_providers
-                    .entry(new_skill.id.clone())
-                    .or_default()
-                    .push(self.entities[idx].id);
+                // the person doesn't already have this skill (line 2152).
+                // Retired persons and employees do not sell, so they are left out.
+                if self.entities[idx].person_data.sells_skills() {
+                    self.skill_providers
+                        .entry(new_skill.id.clone())
+                        .or_default()
+                        .push(self.entities[idx].id);
+                }
 
                 // Add skill to market if it doesn't exist yet
                 if !self.market.skills.contains_key(&new_skill.id) {
@@ -2589,38 +3361,264 @@ impl SimulationEngine {
         productions_count
     }
 
+    /// Advances the simulation by one step, running every enabled phase in order.
+    ///
+    /// See [`crate::phase`] for the built-in phases and how to reorder, disable or
+    /// extend them.
+    ///
+    /// The random number generator is reseeded from the seed and the step number, so
+    /// a step draws the same numbers whether the run was started in batch mode,
+    /// stepped interactively, snapshotted or resumed from a checkpoint.
     pub fn step(&mut self) {
-        self.market.reset_demand_counts();
-        for entity in self.entities.iter_mut() {
-            if entity.active {
-                entity.person_data.needed_skills.clear();
-                entity.person_data.satisfied_needs_current_step.clear();
+        self.rng = Self::step_rng(self.config.seed, self.current_step);
+
+        let setup = self.profile_mark();
+        self.apply_timeline();
+        // After the timeline, which may reorder or disable phases from this step on
+        if !self.phase_pipeline_resolved {
+            self.resolve_phase_pipeline();
+        }
+        self.begin_step();
+        self.profile_record("step_setup", setup);
+
+        for index in 0..self.phase_pipeline.len() {
+            let mark = self.profile_mark();
+            let started = Instant::now();
+            match self.phase_pipeline[index] {
+                PhaseRef::Builtin(phase) => self.run_builtin_phase(phase),
+                PhaseRef::Custom(custom) => self.run_custom_phase(custom),
+            }
+            self.phase_timings[index].record(started.elapsed());
+            if let (Some(profiler), Some(mark)) = (self.profiler.as_mut(), mark) {
+                profiler.record(&self.phase_timings[index].name, mark);
             }
         }
 
-        // Try to form new trade agreements and remove expired ones
-        self.try_form_trade_agreements();
+        let bookkeeping = self.profile_mark();
+        self.finish_step();
+        self.profile_record("step_bookkeeping", bookkeeping);
+        if self.profiler.is_some() {
+            self.profiled_steps += 1;
+        }
+    }
 
-        // Pre-calculate seasonal factors only for skills owned by active entities
-        // Performance optimization: Instead of calculating factors for ALL skills in the market,
-        // we only calculate for skills that are actually owned by active entities.
-        // This reduces unnecessary HashMap allocations and cloning operations.
-        // Measured improvements: ~2.5% faster for small simulations (10 entities).
-        // Expected larger gains (~8-12%) when seasonal features are enabled (seasonal_amplitude > 0).
-        let seasonal_enabled = self.config.seasonal_amplitude > 0.0;
-        let seasonal_factors: HashMap<SkillId, f64> = if seasonal_enabled {
-            let mut factors = HashMap::new();
-            for entity in self.entities.iter() {
-                if entity.active {
-                    for skill in &entity.person_data.own_skills {
-                        // Only calculate if not already computed (avoids duplicate calculations
-                        // when multiple entities own the same skill)
-                        if !factors.contains_key(&skill.id) {
-                            let factor = self.calculate_seasonal_factor(&skill.id);
-                            factors.insert(skill.id.clone(), factor);
-                        }
-                    }
-                }
+    /// Starts a profiled span if profiling is enabled.
+    fn profile_mark(&self) -> Option<ProfileMark> {
+        self.profiler.as_ref().map(Profiler::start)
+    }
+
+    /// Records a span started with [`Self::profile_mark`].
+    fn profile_record(&mut self, name: &str, mark: Option<ProfileMark>) {
+        if let (Some(profiler), Some(mark)) = (self.profiler.as_mut(), mark) {
+            profiler.record(name, mark);
+        }
+    }
+
+    /// The profile recorded so far, if profiling is enabled.
+    pub fn get_profile_report(&self) -> Option<ProfileReport> {
+        self.profiler.as_ref().map(|profiler| profiler.report(self.profiled_steps))
+    }
+
+    /// Register a custom step phase.
+    ///
+    /// The phase runs at the position its name has in `step_phases`, or after the
+    /// built-in phases when no explicit order is configured. Registering a phase under
+    /// the name of an already registered custom phase replaces it.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if the name is empty or belongs to a built-in phase.
+    pub fn register_phase(&mut self, phase: Box<dyn StepPhase>) -> crate::Result<()> {
+        let name = phase.name().to_string();
+        if name.trim().is_empty() {
+            return Err(crate::SimulationError::ValidationError(
+                "Step phase name must not be empty".to_string(),
+            ));
+        }
+        if BuiltinPhase::from_name(&name).is_some() {
+            return Err(crate::SimulationError::ValidationError(format!(
+                "Step phase '{}' is a built-in phase and cannot be registered",
+                name
+            )));
+        }
+        if !self.config.step_phases.is_empty() && !self.config.step_phases.contains(&name) {
+            warn!("Step phase '{}' is not listed in step_phases and will not run", name);
+        }
+
+        let existing = self
+            .custom_phases
+            .iter()
+            .position(|slot| slot.as_ref().is_some_and(|p| p.name() == name));
+        match existing {
+            Some(index) => self.custom_phases[index] = Some(phase),
+            None => self.custom_phases.push(Some(phase)),
+        }
+        info!("Registered step phase: {}", name);
+        self.phase_pipeline_resolved = false;
+        Ok(())
+    }
+
+    /// Names of the phases each step runs, in order.
+    pub fn phase_names(&mut self) -> Vec<String> {
+        if !self.phase_pipeline_resolved {
+            self.resolve_phase_pipeline();
+        }
+        self.phase_timings.iter().map(|timing| timing.name.clone()).collect()
+    }
+
+    /// Time spent in each phase so far, in pipeline order.
+    pub fn get_phase_timings(&self) -> &[PhaseTiming] {
+        &self.phase_timings
+    }
+
+    /// Runs `f` with an [`EngineView`] over the engine state.
+    ///
+    /// This is how custom step phases change prices, move money or add skills with
+    /// the same guarantees plugins get; injected money is tracked for the money
+    /// conservation invariant.
+    pub fn with_engine_view<R>(&mut self, f: impl FnOnce(&mut EngineView) -> R) -> R {
+        let mut view = EngineView {
+            current_step: self.current_step,
+            config: &self.config,
+            market: &mut self.market,
+            entities: &mut self.entities,
+            loans: &self.loans,
+            contracts: &self.contracts,
+            all_skill_ids: &mut self.all_skill_ids,
+            skill_providers: &mut self.skill_providers,
+            proposed_trades: &[],
+            vetoed: Vec::new(),
+            money_injected: 0.0,
+        };
+        let output = f(&mut view);
+        self.total_plugin_money_injected += view.money_injected;
+        output
+    }
+
+    /// Builds the phase pipeline from the configuration and the registered phases.
+    fn resolve_phase_pipeline(&mut self) {
+        let custom_names: Vec<&str> =
+            self.custom_phases.iter().flatten().map(|phase| phase.name()).collect();
+        let (pipeline, unknown) = resolve_pipeline(
+            &self.config.step_phases,
+            &self.config.disabled_step_phases,
+            &custom_names,
+        );
+        for name in unknown {
+            warn!("Step phase '{}' is neither built in nor registered and will be skipped", name);
+        }
+
+        // Keep the time already accumulated by phases that stay in the pipeline
+        let mut previous = std::mem::take(&mut self.phase_timings);
+        self.phase_timings = pipeline
+            .iter()
+            .map(|phase| {
+                let name = match *phase {
+                    PhaseRef::Builtin(builtin) => builtin.as_str(),
+                    PhaseRef::Custom(index) => custom_names[index],
+                };
+                match previous.iter().position(|timing| timing.name == name) {
+                    Some(pos) => previous.swap_remove(pos),
+                    None => PhaseTiming::new(name),
+                }
+            })
+            .collect();
+        self.phase_pipeline = pipeline;
+        self.phase_pipeline_resolved = true;
+    }
+
+    /// Runs a built-in phase.
+    pub(crate) fn run_builtin_phase(&mut self, phase: BuiltinPhase) {
+        match phase {
+            BuiltinPhase::TradeAgreements => self.phase_trade_agreements(),
+            BuiltinPhase::Demand => self.phase_demand(),
+            BuiltinPhase::Pricing => self.phase_pricing(),
+            BuiltinPhase::Crises => self.phase_crises(),
+            BuiltinPhase::Insurance => self.phase_insurance(),
+            BuiltinPhase::Trades => self.phase_trades(),
+            BuiltinPhase::ReputationDecay => self.phase_reputation_decay(),
+            BuiltinPhase::Health => self.phase_health(),
+            BuiltinPhase::StrategyAdaptation => self.phase_strategy_adaptation(),
+            BuiltinPhase::CreditRating => self.phase_credit_rating(),
+            BuiltinPhase::Savings => self.phase_savings(),
+            BuiltinPhase::Assets => self.phase_assets(),
+            BuiltinPhase::ResourcePools => self.phase_resource_pools(),
+            BuiltinPhase::Education => self.phase_education(),
+            BuiltinPhase::Certification => self.phase_certification(),
+            BuiltinPhase::CentralBank => self.phase_central_bank(),
+            BuiltinPhase::Loans => self.phase_loans(),
+            BuiltinPhase::Investments => self.phase_investments(),
+            BuiltinPhase::Technology => self.phase_technology(),
+            BuiltinPhase::Production => self.phase_production(),
+            BuiltinPhase::Firms => self.phase_firms(),
+            BuiltinPhase::Demographics => self.phase_demographics(),
+            BuiltinPhase::Migration => self.phase_migration(),
+            BuiltinPhase::TaxRedistribution => self.phase_tax_redistribution(),
+            BuiltinPhase::Government => self.phase_government(),
+            BuiltinPhase::Voting => self.phase_voting(),
+            BuiltinPhase::Streaming => self.phase_streaming(),
+            BuiltinPhase::WealthStatistics => self.phase_wealth_statistics(),
+            BuiltinPhase::Environment => self.phase_environment(),
+            BuiltinPhase::QualityDecay => self.phase_quality_decay(),
+            BuiltinPhase::ReinforcementLearning => self.phase_reinforcement_learning(),
+        }
+    }
+
+    /// Runs a custom phase, taking it out of its slot for the duration of the call.
+    ///
+    /// The phase is put back even if it panics, so a recovered step does not lose it.
+    fn run_custom_phase(&mut self, index: usize) {
+        let Some(mut phase) = self.custom_phases[index].take() else {
+            return;
+        };
+        let outcome = panic::catch_unwind(panic::AssertUnwindSafe(|| phase.run(self)));
+        self.custom_phases[index] = Some(phase);
+        if let Err(payload) = outcome {
+            panic::resume_unwind(payload);
+        }
+    }
+
+    /// Resets the per-step state before the first phase runs.
+    fn begin_step(&mut self) {
+        self.step_tally =
+            StepTally { taxes_collected_start: self.total_taxes_collected, ..StepTally::default() };
+        self.market.reset_demand_counts();
+        for entity in self.entities.iter_mut() {
+            if entity.active {
+                entity.person_data.needed_skills.clear();
+                entity.person_data.satisfied_needs_current_step.clear();
+            }
+        }
+    }
+
+    /// Forms new trade agreements and removes expired ones.
+    fn phase_trade_agreements(&mut self) {
+        // Try to form new trade agreements and remove expired ones
+        self.try_form_trade_agreements();
+    }
+
+    /// Generates this step's skill needs, modulated by seasonality and automation.
+    fn phase_demand(&mut self) {
+        // Pre-calculate seasonal factors only for skills owned by active entities
+        // Performance optimization: Instead of calculating factors for ALL skills in the market,
+        // we only calculate for skills that are actually owned by active entities.
+        // This reduces unnecessary HashMap allocations and cloning operations.
+        // Measured improvements: ~2.5% faster for small simulations (10 entities).
+        // Expected larger gains (~8-12%) when seasonal features are enabled (seasonal_amplitude > 0).
+        let seasonal_enabled = self.config.seasonal_amplitude > 0.0;
+        let seasonal_factors: HashMap<SkillId, f64> = if seasonal_enabled {
+            let mut factors = HashMap::new();
+            for entity in self.entities.iter() {
+                if entity.active {
+                    for skill in &entity.person_data.own_skills {
+                        // Only calculate if not already computed (avoids duplicate calculations
+                        // when multiple entities own the same skill)
+                        if !factors.contains_key(&skill.id) {
+                            let factor = self.calculate_seasonal_factor(&skill.id);
+                            factors.insert(skill.id.clone(), factor);
+                        }
+                    }
+                }
             }
             factors
         } else {
@@ -2729,20 +3727,25 @@ impl SimulationEngine {
                 }
             }
         }
+    }
 
+    /// Updates market prices and lets plugins regulate the result.
+    fn phase_pricing(&mut self) {
         // Capture prices before update for event emission and action recording
         // Performance optimization: Use Option<Vec> to completely avoid allocation when
         // tracking is disabled, eliminating 500-1000+ unnecessary allocations per simulation.
         // This provides 2-5% speedup for simulations without event tracking (the default case).
         let should_track_prices = self.event_bus.is_enabled() || self.action_log.is_some();
         let prices_before: Option<Vec<(SkillId, f64)>> = if should_track_prices {
-            Some(
-                self.market
-                    .skills
-                    .iter()
-                    .map(|(id, skill)| (id.clone(), skill.current_price))
-                    .collect(),
-            )
+            let mut prices: Vec<(SkillId, f64)> = self
+                .market
+                .skills
+                .iter()
+                .map(|(id, skill)| (id.clone(), skill.current_price))
+                .collect();
+            // Stable order keeps recorded price updates comparable between runs
+            prices.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+            Some(prices)
         } else {
             None
         };
@@ -2783,9 +3786,21 @@ impl SimulationEngine {
             }
         }
 
+        // Let plugins regulate the freshly updated prices
+        if !self.plugin_registry.is_empty() {
+            self.run_plugin_view_hook(PluginRegistry::on_after_price_update, &[]);
+        }
+    }
+
+    /// Triggers random crisis events.
+    fn phase_crises(&mut self) {
         // Check for and trigger crisis events (if enabled)
         self.check_and_trigger_crisis();
+        self.process_custom_crises();
+    }
 
+    /// Sells insurance policies and pays out income and credit claims.
+    fn phase_insurance(&mut self) {
         // Try to purchase insurance policies
         self.try_purchase_insurance();
 
@@ -2794,7 +3809,10 @@ impl SimulationEngine {
 
         // Process insurance claims for credit defaults
         self.process_credit_insurance_payouts();
+    }
 
+    /// Matches buyers with sellers and executes the resulting trades.
+    fn phase_trades(&mut self) {
         /// Helper struct to hold priority information for purchase decisions.
         /// Combines multiple factors (urgency, affordability, efficiency, reputation)
         /// into a single priority score for sorting purchase options.
@@ -2818,21 +3836,33 @@ impl SimulationEngine {
         const REPUTATION_OFFSET: f64 = 0.5; // Offset to center reputation (neutral = 1.0) at 0.5
         const REPUTATION_SCALE_FACTOR: f64 = 1.5; // Scales reputation (0.0-2.0) to 0.0-1.0 range
 
+        // Sell contested skills by auction first; winners skip bilateral matching
+        let auction_trades = if self.config.enable_auctions {
+            let auctions = self.profile_mark();
+            let trades = self.run_auctions();
+            self.profile_record("trades/auctions", auctions);
+            trades
+        } else {
+            Vec::new()
+        };
+
         // Build a map of skill providers
         // Since multiple persons can now provide the same skill, we use Vec<usize>
         // Performance optimization: Use cached skill_providers instead of rebuilding every step
-        // The cache is updated when skills are learned (see production skill learning)
-        // Note: Entities never become inactive during simulation, so no cache invalidation needed
+        // The cache is updated when skills are learned (see production skill learning) and
+        // when persons retire, leave or join (see demographics)
         let skill_providers = &self.skill_providers;
 
         // Performance optimization: Pre-allocate trades_to_execute with capacity based on entity count.
         // Typical simulations have ~0.5-1.0 successful trades per entity per step.
-        // Since entities never become inactive (as noted in the skill_providers comment above),
-        // we use entities.len() directly without filtering, avoiding the O(n) filter operation.
-        // Starting with capacity = entity_count avoids most reallocations while not over-allocating.
+        // We use entities.len() directly without filtering out inactive persons, avoiding the
+        // O(n) filter operation; only demographics makes persons inactive, so this rarely
+        // over-allocates.
         let mut trades_to_execute: Vec<(usize, usize, SkillId, f64)> =
             Vec::with_capacity(self.entities.len());
+        trades_to_execute.extend(auction_trades);
         let mut failed_attempts_this_step = 0usize;
+        let matching = self.profile_mark();
 
         for buyer_idx in 0..self.entities.len() {
             if !self.entities[buyer_idx].active {
@@ -3195,7 +4225,43 @@ impl SimulationEngine {
                     }
                 }
 
-                if self.entities[buyer_idx].person_data.can_afford_with_strategy(final_price) {
+                // Apply the transport cost between the buyer's and the seller's regions
+                if let (Some(seller_entity_idx), Some(buyer_region)) =
+                    (seller_id, self.entities[buyer_idx].person_data.region)
+                {
+                    let seller_region = self.entities[seller_entity_idx].person_data.region;
+                    match seller_region
+                        .and_then(|region| self.transport_network.route_cost(buyer_region, region))
+                    {
+                        Some(cost) => final_price *= 1.0 + cost,
+                        None => {
+                            trace!(
+                                "Person {} cannot reach the region of Person {} for skill {:?}",
+                                self.entities[buyer_idx].id,
+                                self.entities[seller_entity_idx].id,
+                                needed_skill_id
+                            );
+                            continue;
+                        },
+                    }
+                }
+
+                // Apply resource scarcity cost if the environment is tracked
+                let resource_multiplier =
+                    self.market.get_resource_cost_multiplier(&needed_skill_id);
+                if resource_multiplier != 1.0 {
+                    final_price *= resource_multiplier;
+                    trace!(
+                        "Resource scarcity applied: Skill '{}' price increased by {:.1}% to ${:.2}",
+                        needed_skill_id,
+                        (resource_multiplier - 1.0) * 100.0,
+                        final_price
+                    );
+                }
+
+                // Prices are denominated in the base currency; buyers pay in their own
+                let buyer_price = self.base_to_entity_currency(final_price, buyer_idx);
+                if self.entities[buyer_idx].person_data.can_afford_with_strategy(buyer_price) {
                     if let Some(seller_entity_idx) = seller_id {
                         if buyer_idx == seller_entity_idx {
                             trace!(
@@ -3265,13 +4331,38 @@ impl SimulationEngine {
             }
         }
 
-        // Track trade volume for this step
-        let trades_count = trades_to_execute.len();
-        let total_volume: f64 = trades_to_execute.iter().map(|(_, _, _, price)| price).sum();
-        let step_taxes_collected_start = self.total_taxes_collected;
+        self.profile_record("trades/matching", matching);
+
+        // Let plugins inspect and veto the matched trades before execution
+        if !self.plugin_registry.is_empty() && !trades_to_execute.is_empty() {
+            let proposed: Vec<ProposedTrade> = trades_to_execute
+                .iter()
+                .map(|(buyer_idx, seller_idx, skill_id, price)| ProposedTrade {
+                    buyer_id: self.entities[*buyer_idx].id,
+                    seller_id: self.entities[*seller_idx].id,
+                    skill_id: skill_id.clone(),
+                    price: *price,
+                })
+                .collect();
+            let vetoed = self.run_plugin_view_hook(PluginRegistry::on_before_trades, &proposed);
+            let mut vetoed = vetoed.into_iter();
+            trades_to_execute.retain(|(buyer_idx, _, skill_id, _)| {
+                let keep = !vetoed.next().unwrap_or(false);
+                if !keep {
+                    self.entities[*buyer_idx]
+                        .person_data
+                        .satisfied_needs_current_step
+                        .remove(skill_id);
+                }
+                keep
+            });
+        }
 
-        // Track failed trade attempts for this step
-        self.failed_attempts_per_step.push(failed_attempts_this_step);
+        // Track failed trade attempts for this step; trades and volume are counted as
+        // they settle
+        self.step_tally.trades = 0;
+        self.step_tally.volume = 0.0;
+        self.step_tally.failed_attempts = failed_attempts_this_step;
 
         // Determine which trades go to black market (if enabled)
         let mut black_market_trade_indices: Vec<usize> = Vec::new();
@@ -3318,43 +4409,165 @@ impl SimulationEngine {
         // Execute all trades (prices already adjusted for black market trades)
         // If parallel trades are enabled, use the parallel execution path.
         // Otherwise, execute sequentially.
+        let execution = self.profile_mark();
         if self.config.enable_parallel_trades {
             self.execute_trades_parallel(trades_to_execute);
         } else {
             // Sequential execution (original logic)
             self.execute_trades_sequential(trades_to_execute);
         }
+        self.profile_record("trades/execution", execution);
+
+        // Float exchange rates on this step's cross-currency trade balances; holdings
+        // change value in the base currency, but no money is created or destroyed
+        if self.foreign_exchange.is_some() {
+            let before = self.total_person_money_in_base_currency();
+            if let Some(ref mut fx) = self.foreign_exchange {
+                fx.update_rates(&mut self.config.currency_system, self.config.fx_adjustment_rate);
+            }
+            self.record_exchange_revaluation(before);
+        }
+
+        self.step_tally.black_market_trades = black_market_trade_indices.len();
+        self.step_tally.black_market_volume = black_market_volume;
+    }
+
+    /// Sells contested skills by auction and returns the resulting trades.
+    ///
+    /// A skill is contested when more persons need it this step than provide it. Each
+    /// active provider auctions one unit, with its bilateral price as reserve, to the
+    /// buyers taking part (each with probability `auction_participation_rate`). Buyers
+    /// value the skill at the market price plus a premium for the urgency of their need,
+    /// capped by what their strategy lets them spend. Winners have their need satisfied
+    /// for this step.
+    fn run_auctions(&mut self) -> Vec<(usize, usize, SkillId, f64)> {
+        let mut trades = Vec::new();
+        let mut contested: Vec<SkillId> = self
+            .market
+            .demand_counts
+            .iter()
+            .filter(|(id, &demand)| {
+                demand > self.market.supply_counts.get(*id).copied().unwrap_or(0)
+            })
+            .map(|(id, _)| id.clone())
+            .collect();
+        contested.sort();
+
+        for skill_id in contested {
+            let Some((price, efficiency)) = self.market.get_price_and_efficiency(&skill_id) else {
+                continue;
+            };
+            let market_price =
+                price / efficiency * self.market.get_resource_cost_multiplier(&skill_id);
+
+            let mut bidders: Vec<(usize, f64)> = Vec::new();
+            for idx in 0..self.entities.len() {
+                let entity = &self.entities[idx];
+                if !entity.active
+                    || entity.person_data.satisfied_needs_current_step.contains(&skill_id)
+                {
+                    continue;
+                }
+                let Some(urgency) = entity
+                    .person_data
+                    .needed_skills
+                    .iter()
+                    .find(|need| need.id == skill_id)
+                    .map(|need| need.urgency)
+                else {
+                    continue;
+                };
+                if self.rng.random_range(0.0..1.0) >= self.config.auction_participation_rate {
+                    continue;
+                }
+                let budget = self
+                    .entity_to_base_currency(self.entities[idx].person_data.spending_budget(), idx);
+                let valuation = market_price * (1.0 + AUCTION_URGENCY_PREMIUM * urgency as f64);
+                bidders.push((idx, valuation.min(budget)));
+            }
+
+            let providers = self.skill_providers.get(&skill_id).cloned().unwrap_or_default();
+            for seller_idx in providers {
+                if !self.entities[seller_idx].active {
+                    continue;
+                }
+                let candidates: Vec<(usize, f64)> =
+                    bidders.iter().copied().filter(|(idx, _)| *idx != seller_idx).collect();
+                if candidates.is_empty() {
+                    break;
+                }
+                let Some(&auction_type) = self.config.auction_types.choose(&mut self.rng) else {
+                    return trades;
+                };
+                let reserve_price = market_price
+                    * self.entities[seller_idx].person_data.reputation_price_multiplier();
+                let mut auction = Auction::new(skill_id.clone(), auction_type)
+                    .with_reserve_price(reserve_price)
+                    .with_tick_size(reserve_price * AUCTION_TICK_SHARE);
+                auction.bid_with_valuations(&candidates);
+
+                let outcome = auction.resolve();
+                self.record_auction(
+                    auction_type,
+                    auction.bid_count(),
+                    outcome.map(|(_, price)| (price, reserve_price)),
+                );
+                let Some((winner_idx, price)) = outcome else {
+                    continue;
+                };
+                debug!(
+                    "{:?} auction: Person {} buys skill {:?} from Person {} for ${:.2} (reserve ${:.2}, {} bids)",
+                    auction_type,
+                    self.entities[winner_idx].id,
+                    skill_id,
+                    self.entities[seller_idx].id,
+                    price,
+                    reserve_price,
+                    auction.bid_count()
+                );
+                bidders.retain(|(idx, _)| *idx != winner_idx);
+                self.entities[winner_idx]
+                    .person_data
+                    .satisfied_needs_current_step
+                    .insert(skill_id.clone());
+                trades.push((winner_idx, seller_idx, skill_id.clone(), price));
+            }
+        }
+        trades
+    }
 
-        // Common post-trade processing continues below...
-        self.trades_per_step.push(trades_count);
-        self.volume_per_step.push(total_volume);
-        self.black_market_trades_per_step.push(black_market_trade_indices.len());
-        self.black_market_volume_per_step.push(black_market_volume);
+    /// Adds one auction to the statistics of its type.
+    fn record_auction(&mut self, auction_type: AuctionType, bids: usize, sale: Option<(f64, f64)>) {
+        match self.auction_stats.iter_mut().find(|stats| stats.auction_type == auction_type) {
+            Some(stats) => stats.record(bids, sale),
+            None => {
+                let mut stats = crate::result::AuctionTypeStats::new(auction_type);
+                stats.record(bids, sale);
+                self.auction_stats.push(stats);
+            },
+        }
+    }
 
+    /// Decays every active person's reputation towards neutral.
+    fn phase_reputation_decay(&mut self) {
         // Apply reputation decay for all active entities
         for entity in &mut self.entities {
             if entity.active {
                 entity.person_data.apply_reputation_decay();
             }
         }
+    }
 
-        // Health system: Apply recovery for sick persons
+    /// Advances the epidemic and applies the health policies.
+    fn phase_health(&mut self) {
+        // Health system: disease progression, contact transmission and policy responses
         if self.config.enable_health {
-            for entity in &mut self.entities {
-                if entity.active && entity.person_data.is_sick() {
-                    let recovered = entity
-                        .person_data
-                        .try_recover(self.current_step, self.config.disease_recovery_duration);
-                    if recovered {
-                        debug!(
-                            "Person {} recovered from illness at step {}",
-                            entity.id, self.current_step
-                        );
-                    }
-                }
-            }
+            self.process_epidemic();
         }
+    }
 
+    /// Adapts and evolves trading strategies.
+    fn phase_strategy_adaptation(&mut self) {
         // Adapt strategies based on performance (if enabled)
         if self.config.enable_adaptive_strategies {
             for entity in &mut self.entities {
@@ -3383,7 +4596,10 @@ impl SimulationEngine {
         {
             self.evolve_strategies();
         }
+    }
 
+    /// Updates credit scores from current debt and wealth.
+    fn phase_credit_rating(&mut self) {
         // Update credit scores and history if credit rating is enabled
         if self.config.enable_credit_rating {
             for entity in &mut self.entities {
@@ -3409,7 +4625,10 @@ impl SimulationEngine {
                 }
             }
         }
+    }
 
+    /// Moves a share of every person's money into savings.
+    fn phase_savings(&mut self) {
         // Apply savings - persons save a portion of their money
         if self.config.savings_rate > 0.0 {
             for entity in &mut self.entities {
@@ -3430,11 +4649,15 @@ impl SimulationEngine {
                 }
             }
         }
+    }
 
+    /// Revalues assets, pays asset income and buys new assets.
+    fn phase_assets(&mut self) {
         // Asset system: Update asset values and attempt purchases (if enabled)
         if self.config.enable_assets {
             // Step 1: Update all existing asset values and generate income
-            let asset_ids: Vec<crate::asset::AssetId> = self.assets.keys().copied().collect();
+            let mut asset_ids: Vec<crate::asset::AssetId> = self.assets.keys().copied().collect();
+            asset_ids.sort_unstable();
             for asset_id in asset_ids {
                 if let Some(asset) = self.assets.get_mut(&asset_id) {
                     // Generate random volatility factor for stocks
@@ -3518,7 +4741,10 @@ impl SimulationEngine {
                 }
             }
         }
+    }
 
+    /// Collects group pool contributions and supports needy members.
+    fn phase_resource_pools(&mut self) {
         // Resource pool contributions and withdrawals (if enabled)
         if self.config.enable_resource_pools && self.config.num_groups.is_some() {
             // Step 1: Collect contributions from all group members
@@ -3596,7 +4822,10 @@ impl SimulationEngine {
                 }
             }
         }
+    }
 
+    /// Lets persons learn new skills, optionally from a mentor.
+    fn phase_education(&mut self) {
         // Education system - persons can learn new skills (with optional mentorship support)
         if self.config.enable_education && self.config.learning_probability > 0.0 {
             for i in 0..self.entities.len() {
@@ -3613,13 +4842,14 @@ impl SimulationEngine {
                 let learner_id = self.entities[i].id;
 
                 // Find skills this person doesn't have yet
-                let potential_skills: Vec<Skill> = self
+                let mut potential_skills: Vec<Skill> = self
                     .market
                     .skills
                     .values()
                     .filter(|skill| !self.entities[i].person_data.has_skill(&skill.id))
                     .cloned()
                     .collect();
+                potential_skills.sort_unstable_by(|a, b| a.id.cmp(&b.id));
 
                 if potential_skills.is_empty() {
                     continue;
@@ -3699,7 +4929,10 @@ impl SimulationEngine {
                 }
             }
         }
+    }
 
+    /// Certifies skills and expires old certifications.
+    fn phase_certification(&mut self) {
         // Certification system - persons can get their skills certified
         if self.config.enable_certification && self.config.certification_probability > 0.0 {
             for i in 0..self.entities.len() {
@@ -3811,12 +5044,38 @@ impl SimulationEngine {
                 }
             }
         }
+    }
+
+    /// Sets the policy rate and creates or withdraws money.
+    fn phase_central_bank(&mut self) {
+        // Central bank - monetary policy and money operations
+        if self.config.enable_central_bank {
+            self.process_central_bank();
+        }
+    }
 
+    /// Collects loan repayments.
+    fn phase_loans(&mut self) {
         // Process loan payments - borrowers pay back loans
         if self.config.enable_loans {
             self.process_loan_payments();
+            if self.lending_marketplace.is_some() {
+                self.run_p2p_lending();
+            }
+        }
+    }
+
+    /// Collects investment returns and makes new investments.
+    fn phase_investments(&mut self) {
+        // Investment system - collect returns on existing investments, then make new ones
+        if self.config.enable_investments {
+            self.collect_investment_returns();
+            self.try_create_investments();
         }
+    }
 
+    /// Applies gradual technological progress and random breakthroughs.
+    fn phase_technology(&mut self) {
         // Apply technological progress - increase skill efficiency
         if self.config.tech_growth_rate > 0.0 {
             for skill in self.market.skills.values_mut() {
@@ -3874,46 +5133,74 @@ impl SimulationEngine {
                 }
             }
         }
+    }
 
+    /// Lets persons combine skills into new ones.
+    fn phase_production(&mut self) {
         // Attempt production - persons combine skills to create new ones
         if self.config.enable_production {
             let _productions_count = self.attempt_production();
             debug!("Production: {} persons successfully produced new skills", _productions_count);
         }
+    }
 
-        // Tax redistribution - distribute collected taxes equally among all persons
-        if self.config.enable_tax_redistribution && self.config.tax_rate > 0.0 {
-            let active_count = self.entities.iter().filter(|e| e.active).count();
-            if active_count > 0 {
-                // Calculate actual taxes collected this step
-                let step_taxes = self.total_taxes_collected - step_taxes_collected_start;
+    /// Lets firms sell their output, pay wages and dividends, and hire or lay off.
+    fn phase_firms(&mut self) {
+        // Firms - founding, sales, wages, dividends and workforce changes
+        if self.config.enable_firms {
+            self.process_firms();
+        }
+    }
 
-                if step_taxes > 0.0 {
-                    let redistribution_per_person = step_taxes / active_count as f64;
+    /// Ages the population and admits and removes persons.
+    fn phase_demographics(&mut self) {
+        // Demographics - retirement, deaths, market exits, births and immigration
+        if self.config.enable_demographics {
+            self.process_demographics();
+        }
+    }
 
-                    debug!(
-                        "Redistributing ${:.2} in taxes (${:.2} per person) to {} active persons",
-                        step_taxes, redistribution_per_person, active_count
-                    );
+    /// Moves persons to regions with better prospects.
+    fn phase_migration(&mut self) {
+        // Migration - persons move where residents earn more, net of transport costs
+        if self.config.migration_rate > 0.0 && self.config.regions.len() > 1 {
+            self.process_migration();
+        }
+    }
 
-                    for entity in &mut self.entities {
-                        if entity.active {
-                            entity.person_data.money += redistribution_per_person;
-                        }
-                    }
+    /// Redistributes the taxes collected this step.
+    fn phase_tax_redistribution(&mut self) {
+        // Tax redistribution - pay out transfers according to the transfer scheme
+        if self.config.enable_tax_redistribution {
+            self.process_transfers();
+        }
+    }
 
-                    self.total_taxes_redistributed += step_taxes;
-                }
-            }
+    /// Collects the government's taxes, pays for its programs and services its debt.
+    fn phase_government(&mut self) {
+        // Government budget - taxes, public spending and debt
+        if self.config.enable_government {
+            self.process_government();
+        }
+    }
+
+    /// Runs governance proposals and votes.
+    fn phase_voting(&mut self) {
+        // Governance: enact passed proposals, open new ones and collect votes
+        if self.config.enable_voting {
+            self.process_voting();
         }
+    }
 
+    /// Writes this step's summary to the streaming output.
+    fn phase_streaming(&mut self) {
         // Write step data to streaming output if enabled
-        if let Some(writer) = &mut self.stream_writer {
+        if self.stream_writer.is_some() {
             use crate::result::SkillPriceInfo;
 
             // Calculate current step statistics
-            let money_values: Vec<f64> =
-                self.entities.iter().filter(|e| e.active).map(|e| e.person_data.money).collect();
+            let mut money_values = self.active_money_in_base_currency();
+            money_values.sort_by(|a, b| a.total_cmp(b));
 
             let avg_money = if !money_values.is_empty() {
                 money_values.iter().sum::<f64>() / money_values.len() as f64
@@ -3952,24 +5239,33 @@ impl SimulationEngine {
 
             let step_data = StepData {
                 step: self.current_step,
-                trades: trades_count,
-                volume: total_volume,
+                trades: self.step_tally.trades,
+                volume: self.step_tally.volume,
                 avg_money,
                 gini_coefficient: gini,
                 avg_reputation,
                 top_skill_prices: skill_prices,
+                epidemic: self.config.enable_health.then(|| {
+                    CompartmentCounts::count(
+                        self.entities.iter().filter(|e| e.active).map(|e| &e.person_data),
+                    )
+                }),
             };
 
             // Write to stream, but don't fail the simulation if streaming fails
-            if let Err(e) = write_step_to_stream(writer, &step_data) {
-                warn!("Failed to write step {} to streaming output: {}", self.current_step, e);
+            if let Some(writer) = &mut self.stream_writer {
+                if let Err(e) = write_step_to_stream(writer, &step_data) {
+                    warn!("Failed to write step {} to streaming output: {}", self.current_step, e);
+                }
             }
         }
+    }
 
+    /// Records wealth distribution and mobility statistics.
+    fn phase_wealth_statistics(&mut self) {
         // Collect wealth distribution statistics for this step
         // This enables time-series analysis of how wealth inequality evolves
-        let money_values: Vec<f64> =
-            self.entities.iter().filter(|e| e.active).map(|e| e.person_data.money).collect();
+        let money_values = self.active_money_in_base_currency();
 
         if !money_values.is_empty() {
             let mut sorted_money = money_values.clone();
@@ -4023,6 +5319,7 @@ impl SimulationEngine {
                 .entities
                 .iter()
                 .enumerate()
+                .filter(|(_, entity)| entity.active)
                 .map(|(idx, entity)| (entity.get_money(), idx))
                 .collect();
             money_with_indices
@@ -4038,34 +5335,93 @@ impl SimulationEngine {
                 self.mobility_quintiles.entry(*entity_idx).or_default().push(quintile);
             }
         }
+    }
 
-        // Update environment step counter (if enabled)
+    /// Advances the environment and passes resource scarcity on to skill costs.
+    fn phase_environment(&mut self) {
+        // Regenerate resources and update the step counter (if enabled)
         if let Some(ref mut environment) = self.environment {
+            if self.config.scenario == Scenario::ClimateChange {
+                environment.degrade_carrying_capacity(CLIMATE_CAPACITY_LOSS);
+            }
             environment.step();
+
+            if self.config.resource_scarcity_price_factor > 0.0 {
+                for skill_id in &self.all_skill_ids {
+                    let multiplier = environment
+                        .scarcity_multiplier(skill_id, self.config.resource_scarcity_price_factor);
+                    self.market.set_resource_cost_multiplier(skill_id, multiplier);
+                }
+            }
         }
+    }
 
+    /// Decays the quality of unused skills.
+    fn phase_quality_decay(&mut self) {
         // Apply quality decay for unused skills (if quality system enabled)
         if self.config.enable_quality {
             self.apply_quality_decay();
         }
+    }
+
+    /// Applies reinforcement learning updates to strategies.
+    fn phase_reinforcement_learning(&mut self) {
+        // Apply reinforcement learning updates (if RL enabled)
+        if self.config.enable_reinforcement_learning {
+            self.apply_rl_updates();
+        }
+    }
+
+    /// Books the step's statistics once every phase has run.
+    fn finish_step(&mut self) {
+        let tally = self.step_tally;
+        self.trades_per_step.push(tally.trades);
+        self.volume_per_step.push(tally.volume);
+        self.black_market_trades_per_step.push(tally.black_market_trades);
+        self.black_market_volume_per_step.push(tally.black_market_volume);
+        self.failed_attempts_per_step.push(tally.failed_attempts);
 
         // Emit step completed event
-        let trades_this_step = *self.trades_per_step.last().unwrap_or(&0);
-        let volume_this_step = *self.volume_per_step.last().unwrap_or(&0.0);
         self.event_bus
-            .emit_step_completed(self.current_step, trades_this_step, volume_this_step);
+            .emit_step_completed(self.current_step, tally.trades, tally.volume);
 
         // Update incremental money statistics
         self.update_money_statistics();
 
-        // Apply reinforcement learning updates (if RL enabled)
-        if self.config.enable_reinforcement_learning {
-            self.apply_rl_updates();
-        }
-
         self.current_step += 1;
     }
 
+    /// Runs a mutating plugin hook with an [`EngineView`] over the engine state.
+    ///
+    /// Returns the veto flag of each proposed trade and books any money the plugins
+    /// injected, so money conservation checks can account for it.
+    fn run_plugin_view_hook(
+        &mut self,
+        hook: fn(&mut PluginRegistry, &mut EngineView),
+        proposed_trades: &[ProposedTrade],
+    ) -> Vec<bool> {
+        let mut view = EngineView {
+            current_step: self.current_step,
+            config: &self.config,
+            market: &mut self.market,
+            entities: &mut self.entities,
+            loans: &self.loans,
+            contracts: &self.contracts,
+            all_skill_ids: &mut self.all_skill_ids,
+            skill_providers: &mut self.skill_providers,
+            proposed_trades,
+            vetoed: vec![false; proposed_trades.len()],
+            money_injected: 0.0,
+        };
+        hook(&mut self.plugin_registry, &mut view);
+
+        if view.money_injected != 0.0 {
+            debug!("Plugins injected ${:.2} at step {}", view.money_injected, self.current_step);
+        }
+        self.total_plugin_money_injected += view.money_injected;
+        view.vetoed
+    }
+
     /// Execute trades sequentially (original logic).
     ///
     /// This method executes all trades one by one in the order they were collected.
@@ -4123,28 +5479,94 @@ impl SimulationEngine {
         let seller_entity_id = self.entities[seller_idx].id;
         let buyer_entity_id = self.entities[buyer_idx].id;
 
-        // Calculate transaction fee (deducted from seller's proceeds)
-        let fee = price * self.config.transaction_fee;
-        let seller_proceeds = price - fee;
+        // Trades that would exceed a resource quota or the remaining reserves do not happen
+        if let Some(ref mut environment) = self.environment {
+            if environment.limits_consumption() {
+                let base_consumption = price * self.config.resource_cost_per_transaction;
+                let resource_costs = environment.resource_costs(&skill_id, base_consumption);
+                if !environment.can_consume(&resource_costs) {
+                    environment.record_blocked_consumption();
+                    self.entities[buyer_idx]
+                        .person_data
+                        .satisfied_needs_current_step
+                        .remove(&skill_id);
+                    debug!(
+                        "Trade blocked: Person {} cannot buy skill {:?} from Person {}, resources exhausted",
+                        buyer_entity_id, skill_id, seller_entity_id
+                    );
+                    return;
+                }
+            }
+        }
 
-        debug!(
-            "Executing trade: Buyer {} pays ${:.2}, Seller {} receives ${:.2} (fee: ${:.2})",
-            buyer_entity_id, price, seller_entity_id, seller_proceeds, fee
-        );
+        // A lockdown cancels part of the trades
+        if self.epidemic.lockdown_active && self.config.lockdown_trade_reduction > 0.0 {
+            let cancel_roll: f64 = self.rng.random();
+            if cancel_roll < self.config.lockdown_trade_reduction {
+                self.epidemic.lockdown_cancelled_trades += 1;
+                self.entities[buyer_idx]
+                    .person_data
+                    .satisfied_needs_current_step
+                    .remove(&skill_id);
+                debug!(
+                    "Trade cancelled by lockdown: Person {} cannot buy skill {:?} from Person {}",
+                    buyer_entity_id, skill_id, seller_entity_id
+                );
+                return;
+            }
+        }
+
+        self.step_tally.trades += 1;
+        self.step_tally.volume += price;
+        if let Some(region) = self.entities[buyer_idx].person_data.region {
+            let market_value = self.market.skills.get(&skill_id).map_or(price, |s| s.current_price);
+            let ledger = &mut self.region_ledgers[region];
+            ledger.trades += 1;
+            ledger.paid += price;
+            ledger.market_value += market_value;
+        }
+
+        // Calculate transaction fee (deducted from seller's proceeds)
+        let fee = price * self.config.transaction_fee;
+        let seller_proceeds = price - fee;
+
+        debug!(
+            "Executing trade: Buyer {} pays ${:.2}, Seller {} receives ${:.2} (fee: ${:.2})",
+            buyer_entity_id, price, seller_entity_id, seller_proceeds, fee
+        );
+
+        // Cross-currency trades settle at the live exchange rate: the price (and the fee
+        // and tax derived from it) is in base units, converted into each party's currency
+        if let Some(ref mut fx) = self.foreign_exchange {
+            fx.record_trade(
+                &self.entities[buyer_idx].person_data.currency_id,
+                &self.entities[seller_idx].person_data.currency_id,
+                price,
+            );
+        }
+        let buyer_cost = self.base_to_entity_currency(price, buyer_idx);
 
         // Buyer pays full price
         // Note: This may result in negative balance (debt) for Aggressive strategy agents,
         // which is intentional behavior to simulate risk-taking. The simulation supports
         // negative money as reflected in Gini coefficient calculations.
         let buyer_balance_before = self.entities[buyer_idx].person_data.money;
-        self.entities[buyer_idx].person_data.money -= price;
+        self.entities[buyer_idx].person_data.money -= buyer_cost;
         trace!(
             "Person {} balance: ${:.2} -> ${:.2} (spent ${:.2})",
             buyer_entity_id,
             buyer_balance_before,
             self.entities[buyer_idx].person_data.money,
-            price
+            buyer_cost
         );
+        // Value added tax on top of the price, paid by the buyer to the government
+        if self.config.enable_government && self.config.vat_rate > 0.0 {
+            let vat = price * self.config.vat_rate;
+            self.entities[buyer_idx].person_data.money -=
+                self.base_to_entity_currency(vat, buyer_idx);
+            self.total_taxes_collected += vat;
+            self.step_tally.vat += vat;
+        }
         self.entities[buyer_idx].person_data.record_transaction(
             self.current_step,
             skill_id.clone(),
@@ -4183,7 +5605,8 @@ impl SimulationEngine {
 
         // Reduce seller proceeds if they're sick (lower productivity)
         let health_adjusted_proceeds = seller_proceeds * health_multiplier;
-        self.entities[seller_idx].person_data.money += health_adjusted_proceeds;
+        self.entities[seller_idx].person_data.money +=
+            self.base_to_entity_currency(health_adjusted_proceeds, seller_idx);
 
         // Log productivity penalty if seller was sick
         if health_multiplier < 1.0 {
@@ -4198,7 +5621,8 @@ impl SimulationEngine {
 
         // Calculate and collect tax on seller's proceeds (after transaction fee and health adjustment)
         let tax = health_adjusted_proceeds * self.config.tax_rate;
-        self.entities[seller_idx].person_data.money -= tax;
+        self.entities[seller_idx].person_data.money -=
+            self.base_to_entity_currency(tax, seller_idx);
 
         if tax > 0.0 {
             trace!(
@@ -4350,31 +5774,26 @@ impl SimulationEngine {
         }
 
         // Disease transmission (if health system enabled)
-        // After a successful trade, disease may transmit between sick and healthy persons
+        // After a successful trade, a sick party may pass the disease to a susceptible one
         if self.config.enable_health {
             let buyer_sick = self.entities[buyer_idx].person_data.is_sick();
             let seller_sick = self.entities[seller_idx].person_data.is_sick();
-
-            // Transmission can occur if exactly one party is sick
-            if buyer_sick && !seller_sick {
-                // Buyer is sick, may transmit to seller
-                let transmission_roll: f64 = self.rng.random();
-                if transmission_roll < self.config.disease_transmission_rate {
-                    self.entities[seller_idx].person_data.infect(self.current_step);
-                    debug!(
-                        "Disease transmitted: Sick buyer {} infected seller {} during trade",
-                        self.entities[buyer_idx].id, self.entities[seller_idx].id
-                    );
-                }
-            } else if seller_sick && !buyer_sick {
-                // Seller is sick, may transmit to buyer
-                let transmission_roll: f64 = self.rng.random();
-                if transmission_roll < self.config.disease_transmission_rate {
-                    self.entities[buyer_idx].person_data.infect(self.current_step);
-                    debug!(
-                        "Disease transmitted: Sick seller {} infected buyer {} during trade",
-                        self.entities[seller_idx].id, self.entities[buyer_idx].id
-                    );
+            let contact = match (buyer_sick, seller_sick) {
+                (true, false) => Some((buyer_idx, seller_idx)),
+                (false, true) => Some((seller_idx, buyer_idx)),
+                _ => None,
+            };
+            if let Some((source_idx, target_idx)) = contact {
+                if self.entities[target_idx].person_data.is_susceptible() {
+                    let transmission_roll: f64 = self.rng.random();
+                    if transmission_roll < self.config.disease_transmission_rate {
+                        self.transmit_disease(target_idx);
+                        self.epidemic.trade_exposures += 1;
+                        debug!(
+                            "Disease transmitted: Sick person {} infected person {} during trade",
+                            self.entities[source_idx].id, self.entities[target_idx].id
+                        );
+                    }
                 }
             }
         }
@@ -4386,19 +5805,10 @@ impl SimulationEngine {
 
         // Track environmental resource consumption (if enabled)
         if let Some(ref mut environment) = self.environment {
-            use crate::environment::Resource;
-            use std::collections::HashMap as StdHashMap;
-
-            // Calculate resource consumption based on transaction value
+            // Calculate resource consumption based on transaction value, split across
+            // resource types by the skill's intensities
             let base_consumption = price * self.config.resource_cost_per_transaction;
-
-            // Distribute consumption evenly across resource types
-            let mut resource_costs = StdHashMap::new();
-            let num_resources = Resource::all().len() as f64;
-            for resource in Resource::all() {
-                resource_costs.insert(resource, base_consumption / num_resources);
-            }
-
+            let resource_costs = environment.resource_costs(&skill_id, base_consumption);
             environment.consume_resources(&resource_costs);
 
             trace!(
@@ -4451,8 +5861,7 @@ impl SimulationEngine {
         self.max_money = f64::NEG_INFINITY;
 
         // Update with current money values
-        for entity in self.entities.iter().filter(|e| e.active) {
-            let money = entity.person_data.money;
+        for money in self.active_money_in_base_currency() {
             self.money_incremental_stats.update(money);
             self.min_money = self.min_money.min(money);
             self.max_money = self.max_money.max(money);
@@ -4658,8 +6067,9 @@ impl SimulationEngine {
             }
 
             // Step 2: Imitation - copy strategy from most successful friend
-            let friends: Vec<usize> =
+            let mut friends: Vec<usize> =
                 self.entities[entity_idx].person_data.friends.iter().copied().collect();
+            friends.sort_unstable();
 
             if friends.is_empty() {
                 continue; // No friends to imitate
@@ -4720,84 +6130,2358 @@ impl SimulationEngine {
         self.strategy_distribution_history.push(snapshot);
     }
 
-    /// Processes loan payments for the current step.
-    /// Borrowers make scheduled payments to lenders.
-    fn process_loan_payments(&mut self) {
-        let mut completed_loans = Vec::new();
+    /// Pays out one return on every active investment.
+    ///
+    /// Investees pay their investor from their own money. When an investee cannot
+    /// cover the payment they are considered broke: the investment defaults and the
+    /// investor absorbs the unrecovered principal as a loss. Investments without a
+    /// target (production capacity) pay out of the investor's own production.
+    fn collect_investment_returns(&mut self) {
+        let mut investment_ids: Vec<InvestmentId> = self.investments.keys().copied().collect();
+        investment_ids.sort_unstable();
+
+        for investment_id in investment_ids {
+            let Some(investment) = self.investments.get(&investment_id) else {
+                continue;
+            };
+            let investor_idx = investment.investor_id;
+            let target_id = investment.target_id;
+            let payment = investment.return_per_step;
+            let outstanding_principal = investment.outstanding_principal();
+
+            // Returns are denominated in the base currency; each side pays or
+            // receives them in their own currency
+            if let Some(target_idx) = target_id {
+                let payment_due = self.base_to_entity_currency(payment, target_idx);
+                if self.entities[target_idx].person_data.money < payment_due {
+                    self.total_investments_defaulted += 1;
+                    self.total_investment_losses += outstanding_principal;
+                    debug!(
+                        "Investment {} defaulted: person {} cannot pay ${:.2}, person {} loses ${:.2}",
+                        investment_id, target_idx, payment, investor_idx, outstanding_principal
+                    );
+                    self.remove_investment(investment_id);
+                    continue;
+                }
+                self.entities[target_idx].person_data.money -= payment_due;
+            }
 
-        for (loan_id, loan) in self.loans.iter_mut() {
-            if loan.is_repaid {
+            let Some(investment) = self.investments.get_mut(&investment_id) else {
                 continue;
+            };
+            let amount = investment.collect_return();
+            let completed_roi = investment
+                .is_completed
+                .then(|| investment.net_profit() / investment.principal * 100.0);
+
+            self.entities[investor_idx].person_data.money +=
+                self.base_to_entity_currency(amount, investor_idx);
+            self.total_investment_returns += amount;
+
+            if let Some(roi) = completed_roi {
+                self.total_investments_completed += 1;
+                self.completed_investment_roi_sum += roi;
+                trace!("Investment {} completed", investment_id);
+                self.remove_investment(investment_id);
             }
+        }
+    }
 
-            let borrower_idx = loan.borrower_id;
-            let lender_idx = loan.lender_id;
+    /// Removes a finished or defaulted investment from the system and its investor.
+    fn remove_investment(&mut self, investment_id: InvestmentId) {
+        if let Some(investment) = self.investments.remove(&investment_id) {
+            self.entities[investment.investor_id]
+                .person_data
+                .active_investments
+                .retain(|&id| id != investment_id);
+        }
+    }
 
-            // Check if borrower can afford the payment
-            if self.entities[borrower_idx].person_data.money >= loan.payment_per_step {
-                // Make the payment
-                let payment_amount = loan.make_payment();
+    /// Lets persons with enough money invest in another person.
+    ///
+    /// An investor either funds the target's education in a skill they lack (the
+    /// target learns it immediately) or provides working capital for one of the
+    /// target's existing skills. The target repays principal plus returns over
+    /// `investment_duration` steps.
+    fn try_create_investments(&mut self) {
+        for i in 0..self.entities.len() {
+            // Principals, thresholds and learning costs are in the base currency
+            let investor_money =
+                self.entity_to_base_currency(self.entities[i].person_data.money, i);
+            if !self.entities[i].active || investor_money < self.config.min_money_to_invest {
+                continue;
+            }
+            if self.rng.random_range(0.0..1.0) >= self.config.investment_probability {
+                continue;
+            }
 
-                // Transfer money
-                self.entities[borrower_idx].person_data.money -= payment_amount;
-                self.entities[lender_idx].person_data.money += payment_amount;
+            let candidates: Vec<usize> = (0..self.entities.len())
+                .filter(|&j| j != i && self.entities[j].active)
+                .collect();
+            let Some(&target_idx) = candidates.as_slice().choose(&mut self.rng) else {
+                continue;
+            };
 
-                // Update credit score if credit rating is enabled
-                if self.config.enable_credit_rating {
-                    self.entities[borrower_idx]
-                        .person_data
-                        .credit_score
-                        .record_successful_payment();
-                }
+            let Some((investment_type, principal)) =
+                self.choose_investment(target_idx, investor_money)
+            else {
+                continue;
+            };
+            if principal <= 0.0 || principal > investor_money {
+                continue;
+            }
 
-                debug!(
-                    "Loan payment: Person {} paid ${:.2} to Person {} (remaining: ${:.2})",
-                    self.entities[borrower_idx].id,
-                    payment_amount,
-                    self.entities[lender_idx].id,
-                    loan.remaining_principal
-                );
+            let investment_id = self.total_investments_created;
+            let investment = Investment::new(
+                investment_id,
+                self.entities[i].id,
+                Some(self.entities[target_idx].id),
+                investment_type,
+                principal,
+                self.config.investment_return_rate,
+                self.config.investment_duration,
+                self.current_step,
+            );
 
-                // Check if loan is now fully repaid
-                if loan.is_repaid {
-                    completed_loans.push(*loan_id);
-                    debug!(
-                        "Loan {} fully repaid: Person {} to Person {}",
-                        loan_id, self.entities[borrower_idx].id, self.entities[lender_idx].id
-                    );
-                }
-            } else {
-                // Borrower cannot afford the payment - record as missed payment
-                if self.config.enable_credit_rating {
-                    self.entities[borrower_idx].person_data.credit_score.record_missed_payment();
-                    debug!(
-                        "Person {} missed loan payment, credit score affected",
-                        self.entities[borrower_idx].id
-                    );
+            self.entities[i].person_data.money -= self.base_to_entity_currency(principal, i);
+            match &investment.investment_type {
+                InvestmentType::Education { skill_id } => {
+                    // The principal pays for the course and leaves the economy, like the
+                    // learning cost of regular education; the target acquires the skill
+                    self.total_investment_education_spending += principal;
+                    if let Some(skill) = self.market.skills.get(skill_id).cloned() {
+                        self.entities[target_idx].person_data.learned_skills.push(skill);
+                        self.add_skill_provider(skill_id, target_idx);
+                    }
+                },
+                _ => {
+                    // Working capital goes straight to the target
+                    self.entities[target_idx].person_data.money +=
+                        self.base_to_entity_currency(principal, target_idx);
+                },
+            }
+
+            debug!(
+                "Person {} invested ${:.2} in person {} ({:?})",
+                self.entities[i].id,
+                principal,
+                self.entities[target_idx].id,
+                investment.investment_type
+            );
+
+            self.entities[i].person_data.active_investments.push(investment_id);
+            self.investments.insert(investment_id, investment);
+            self.total_investments_created += 1;
+            self.total_invested += principal;
+        }
+    }
+
+    /// Picks an investment opportunity in the target person and its principal.
+    ///
+    /// Education is chosen half of the time when the target lacks an affordable skill;
+    /// it costs the usual learning price (`learning_cost_multiplier` times the market
+    /// price). Otherwise the investor backs the target's most valuable skill with a
+    /// fixed share of their own money. `investor_money` and the returned principal are
+    /// in the base currency.
+    fn choose_investment(
+        &mut self,
+        target_idx: usize,
+        investor_money: f64,
+    ) -> Option<(InvestmentType, f64)> {
+        let target = &self.entities[target_idx].person_data;
+
+        if self.rng.random_bool(0.5) {
+            let mut learnable: Vec<(SkillId, f64)> = self
+                .market
+                .skills
+                .values()
+                .filter(|skill| !target.has_skill(&skill.id))
+                .map(|skill| {
+                    (skill.id.clone(), skill.current_price * self.config.learning_cost_multiplier)
+                })
+                .filter(|(_, cost)| *cost <= investor_money)
+                .collect();
+            learnable.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+            if let Some((skill_id, cost)) = learnable.as_slice().choose(&mut self.rng).cloned() {
+                return Some((InvestmentType::Education { skill_id }, cost));
+            }
+        }
+
+        let target = &self.entities[target_idx].person_data;
+        let skill_id = target
+            .own_skills
+            .iter()
+            .filter_map(|s| self.market.skills.get(&s.id))
+            .max_by(|a, b| {
+                a.current_price
+                    .partial_cmp(&b.current_price)
+                    .unwrap_or(std::cmp::Ordering::Equal)
+                    .then_with(|| b.id.cmp(&a.id))
+            })
+            .map(|s| s.id.clone())?;
+        Some((InvestmentType::Skill { skill_id }, investor_money * INVESTMENT_CAPITAL_SHARE))
+    }
+
+    /// Converts a base-currency amount into the currency held by the entity at `idx`.
+    ///
+    /// Market prices, fees and taxes are denominated in the base currency. Returns the
+    /// amount unchanged when multi-currency is disabled.
+    fn base_to_entity_currency(&self, amount: f64, idx: usize) -> f64 {
+        if !self.config.enable_multi_currency {
+            return amount;
+        }
+        let system = &self.config.currency_system;
+        system
+            .convert(amount, &system.base_currency_id, &self.entities[idx].person_data.currency_id)
+            .unwrap_or(amount)
+    }
+
+    /// Converts an amount held by the entity at `idx` into the base currency.
+    fn entity_to_base_currency(&self, amount: f64, idx: usize) -> f64 {
+        if !self.config.enable_multi_currency {
+            return amount;
+        }
+        let system = &self.config.currency_system;
+        system
+            .convert(amount, &self.entities[idx].person_data.currency_id, &system.base_currency_id)
+            .unwrap_or(amount)
+    }
+
+    /// Books the change in the base value of persons' holdings since `before` that
+    /// exchange rate movements caused.
+    fn record_exchange_revaluation(&mut self, before: f64) {
+        let after = self.total_person_money_in_base_currency();
+        if let Some(ref mut fx) = self.foreign_exchange {
+            fx.revaluation += after - before;
+        }
+    }
+
+    /// Money held by each active person, converted into the base currency so that
+    /// balances in different currencies can be compared and added up.
+    fn active_money_in_base_currency(&self) -> Vec<f64> {
+        self.entities
+            .iter()
+            .enumerate()
+            .filter(|(_, e)| e.active)
+            .map(|(idx, e)| self.entity_to_base_currency(e.person_data.money, idx))
+            .collect()
+    }
+
+    /// Money and savings held by all persons, in the base currency.
+    fn total_person_money_in_base_currency(&self) -> f64 {
+        self.entities
+            .iter()
+            .enumerate()
+            .map(|(idx, e)| {
+                self.entity_to_base_currency(e.get_money() + e.person_data.savings, idx)
+            })
+            .sum()
+    }
+
+    /// Builds per-currency statistics (only when multi-currency is enabled).
+    fn calculate_currency_statistics(&self) -> Option<crate::result::CurrencyStats> {
+        let fx = self.foreign_exchange.as_ref()?;
+        let system = &self.config.currency_system;
+        let currencies = system
+            .sorted_ids()
+            .into_iter()
+            .map(|currency_id| {
+                let holders = self
+                    .entities
+                    .iter()
+                    .filter(|e| e.active && e.person_data.currency_id == currency_id);
+                let (persons, money_supply) =
+                    holders.fold((0, 0.0), |(n, sum), e| (n + 1, sum + e.person_data.money));
+                crate::result::CurrencyZoneStats {
+                    persons,
+                    money_supply,
+                    money_supply_in_base: system
+                        .convert(money_supply, &currency_id, &system.base_currency_id)
+                        .unwrap_or(money_supply),
+                    final_exchange_rate: system.currencies[&currency_id].exchange_rate,
+                    total_exports: fx.total_exports.get(&currency_id).copied().unwrap_or(0.0),
+                    total_imports: fx.total_imports.get(&currency_id).copied().unwrap_or(0.0),
+                    devaluations: fx.devaluations.get(&currency_id).copied().unwrap_or(0),
+                    currency_id,
                 }
+            })
+            .collect();
+        Some(crate::result::CurrencyStats {
+            base_currency_id: system.base_currency_id.clone(),
+            cross_currency_trades: fx.cross_currency_trades,
+            cross_currency_volume: fx.cross_currency_volume,
+            currencies,
+            exchange_rate_history: fx.rate_history.clone(),
+        })
+    }
 
-                trace!(
-                    "Person {} cannot afford loan payment of ${:.2} (has ${:.2})",
-                    self.entities[borrower_idx].id,
-                    loan.payment_per_step,
-                    self.entities[borrower_idx].person_data.money
-                );
+    /// Builds auction statistics (only when auctions are enabled).
+    fn calculate_auction_statistics(&self) -> Option<crate::result::AuctionStats> {
+        if !self.config.enable_auctions {
+            return None;
+        }
+        let mut by_type = self.auction_stats.clone();
+        by_type.sort_by_key(|stats| {
+            AuctionType::ALL
+                .iter()
+                .position(|t| *t == stats.auction_type)
+                .unwrap_or(usize::MAX)
+        });
+        Some(crate::result::AuctionStats {
+            total_auctions: by_type.iter().map(|stats| stats.auctions).sum(),
+            total_sold: by_type.iter().map(|stats| stats.sold).sum(),
+            total_volume: by_type.iter().map(|stats| stats.total_volume).sum(),
+            total_seller_surplus: by_type.iter().map(|stats| stats.total_seller_surplus).sum(),
+            by_type,
+        })
+    }
+
+    /// Builds investment statistics (only when the investment system is enabled).
+    fn calculate_investment_statistics(&self) -> Option<crate::result::InvestmentStats> {
+        if !self.config.enable_investments {
+            return None;
+        }
+        let avg_roi_percentage = if self.total_investments_completed > 0 {
+            self.completed_investment_roi_sum / self.total_investments_completed as f64
+        } else {
+            0.0
+        };
+        Some(crate::result::InvestmentStats {
+            total_investments_created: self.total_investments_created,
+            total_investments_completed: self.total_investments_completed,
+            active_investments: self.investments.len(),
+            total_invested: self.total_invested,
+            total_returns_paid: self.total_investment_returns,
+            avg_roi_percentage,
+            total_investments_defaulted: self.total_investments_defaulted,
+            total_losses: self.total_investment_losses,
+        })
+    }
+
+    /// Runs one round of the P2P lending marketplace.
+    ///
+    /// Persons whose unmet needs this step cost more than they own request the
+    /// shortfall as a loan, as long as they have no loan outstanding. Everyone else
+    /// with more than `min_money_to_lend` posts an offer for a share of the excess;
+    /// the lender's strategy sets the minimum credit score it accepts, and the rate
+    /// is the credit-rated rate of the riskiest borrower it accepts. Each request is
+    /// matched with the cheapest offer the borrower qualifies for, and the borrower
+    /// pays the platform fee out of the principal. The offer book is rebuilt every
+    /// step so offers never exceed the lender's current money. Requests, offers and
+    /// loans are in the base currency; each side's money is converted at matching.
+    fn run_p2p_lending(&mut self) {
+        let Some(mut marketplace) = self.lending_marketplace.take() else {
+            return;
+        };
+        marketplace.clear_offers();
+
+        let mut requests: Vec<(usize, f64)> = Vec::new();
+        for (idx, entity) in self.entities.iter().enumerate() {
+            let person = &entity.person_data;
+            if !entity.active || !person.borrowed_loans.is_empty() {
+                continue;
+            }
+            let unmet_cost: f64 = person
+                .needed_skills
+                .iter()
+                .filter(|need| !person.satisfied_needs_current_step.contains(&need.id))
+                .filter_map(|need| self.market.skills.get(&need.id))
+                .map(|skill| skill.current_price)
+                .sum();
+            let shortfall = unmet_cost - self.entity_to_base_currency(person.money, idx);
+            if shortfall > 0.0 {
+                requests.push((idx, shortfall));
+            }
+        }
+
+        let borrowers: HashSet<usize> = requests.iter().map(|&(idx, _)| idx).collect();
+        for (idx, entity) in self.entities.iter().enumerate() {
+            let person = &entity.person_data;
+            if !entity.active
+                || borrowers.contains(&idx)
+                || person.money <= self.config.min_money_to_lend
+            {
+                continue;
+            }
+            let min_credit_score = person.strategy.lending_min_credit_score();
+            let risk_floor =
+                CreditScore { score: min_credit_score.unwrap_or(300), ..CreditScore::default() };
+            let lendable = (person.money - self.config.min_money_to_lend) * P2P_LENDABLE_SHARE;
+            marketplace.add_offer(
+                entity.id,
+                self.entity_to_base_currency(lendable, idx),
+                risk_floor.calculate_interest_rate(self.config.loan_interest_rate),
+                min_credit_score,
+                self.current_step,
+            );
+        }
+        marketplace.record_offer_book_depth();
+
+        for (borrower_idx, amount) in requests {
+            let credit_score = self.entities[borrower_idx].person_data.credit_score.score;
+            let Some(offer) = marketplace.find_best_offer(credit_score, amount).cloned() else {
+                marketplace.record_unmatched_request();
+                continue;
+            };
+            marketplace.fill_offer(offer.id, amount);
+            let fee = marketplace.charge_platform_fee(amount);
+            marketplace.record_match(credit_score, amount, offer.interest_rate);
+
+            let lender_idx = offer.lender_id;
+            let loan_id = self.total_loans_issued;
+            let loan = Loan::new(
+                loan_id,
+                lender_idx,
+                borrower_idx,
+                amount,
+                offer.interest_rate,
+                self.config.loan_repayment_period,
+                self.current_step,
+            );
+
+            let lent = self.base_to_entity_currency(amount, lender_idx);
+            let received = self.base_to_entity_currency(amount - fee, borrower_idx);
+            self.entities[lender_idx].person_data.money -= lent;
+            self.entities[lender_idx].person_data.lent_loans.push(loan_id);
+            let borrower = &mut self.entities[borrower_idx].person_data;
+            borrower.money += received;
+            borrower.borrowed_loans.push(loan_id);
+            borrower.credit_score.start_credit_history(self.current_step);
+            borrower.credit_score.record_new_loan(self.current_step);
+
+            debug!(
+                "Person {} borrowed ${:.2} from person {} at {:.2}% per step (fee ${:.2})",
+                self.entities[borrower_idx].id,
+                amount,
+                self.entities[lender_idx].id,
+                offer.interest_rate * 100.0,
+                fee
+            );
+
+            self.loans.insert(loan_id, loan);
+            self.total_loans_issued += 1;
+        }
+
+        self.lending_marketplace = Some(marketplace);
+    }
+
+    /// Builds loan statistics, including defaults and bankruptcies (only when loans are enabled).
+    fn calculate_loan_statistics(&self) -> Option<crate::result::LoanStats> {
+        if !self.config.enable_loans {
+            return None;
+        }
+        Some(crate::result::LoanStats {
+            total_loans_issued: self.total_loans_issued,
+            total_loans_repaid: self.total_loans_repaid,
+            active_loans: self.loans.len(),
+            total_loans_defaulted: self.loan_defaults.defaults,
+            cascade_defaults: self.loan_defaults.cascade_defaults,
+            total_written_off: self.loan_defaults.written_off,
+            collateral_seized_value: self.loan_defaults.collateral_seized,
+            assets_seized: self.loan_defaults.assets_seized,
+            credit_insurance_recovered: self.loan_defaults.insurance_recovered,
+            bankruptcies_restructured: self.loan_defaults.bankruptcies_restructured,
+            bankruptcies_discharged: self.loan_defaults.bankruptcies_discharged,
+        })
+    }
+
+    /// Builds population change statistics (only when demographics are enabled).
+    fn calculate_demographics_statistics(&self) -> Option<crate::result::DemographicsStats> {
+        if !self.config.enable_demographics {
+            return None;
+        }
+        let active: Vec<&Person> =
+            self.entities.iter().filter(|e| e.active).map(|e| &e.person_data).collect();
+        let average_age = if active.is_empty() {
+            0.0
+        } else {
+            active.iter().map(|person| person.age as f64).sum::<f64>() / active.len() as f64
+        };
+        let ledger = &self.demographics;
+        Some(crate::result::DemographicsStats {
+            total_births: ledger.births,
+            total_immigrants: ledger.immigrants,
+            total_retirements: ledger.retirements,
+            total_deaths: ledger.deaths,
+            total_market_exits: ledger.market_exits,
+            estates_to_heirs: ledger.estates_to_heirs,
+            estates_to_tax_pool: ledger.estates_to_tax_pool,
+            total_inherited: ledger.inherited_money,
+            total_estate_tax_pool: ledger.estate_tax_pool_money,
+            total_immigrant_money: ledger.immigrant_money,
+            final_population: active.len(),
+            retired_population: active.iter().filter(|person| person.retired).count(),
+            average_age,
+            population_history: ledger.population_history.clone(),
+        })
+    }
+
+    /// Builds epidemic statistics (only when the health system is enabled).
+    fn calculate_epidemic_statistics(&self) -> Option<crate::result::EpidemicStats> {
+        if !self.config.enable_health {
+            return None;
+        }
+        let ledger = &self.epidemic;
+        let (peak_infected, peak_step) =
+            ledger.peak().map_or((0, 0), |peak| (peak.counts.infected, peak.step));
+        Some(crate::result::EpidemicStats {
+            final_counts: CompartmentCounts::count(
+                self.entities.iter().filter(|e| e.active).map(|e| &e.person_data),
+            ),
+            peak_infected,
+            peak_step,
+            trade_exposures: ledger.trade_exposures,
+            contact_exposures: ledger.contact_exposures,
+            recoveries: ledger.recoveries,
+            vaccinations: ledger.vaccinations,
+            immunity_losses: ledger.immunity_losses,
+            lockdown_steps: ledger.lockdown_steps,
+            lockdown_cancelled_trades: ledger.lockdown_cancelled_trades,
+            total_sick_pay: ledger.sick_pay_paid,
+            history: ledger.history.clone(),
+        })
+    }
+
+    /// Builds monetary policy statistics (only when the central bank is enabled).
+    fn calculate_monetary_policy_statistics(&self) -> Option<crate::result::MonetaryPolicyStats> {
+        if !self.config.enable_central_bank {
+            return None;
+        }
+        let bank = &self.central_bank;
+        let steps = bank.history.len().max(1) as f64;
+        let rates = bank.history.iter().map(|snapshot| snapshot.policy_rate);
+        Some(crate::result::MonetaryPolicyStats {
+            rule: self.config.monetary_policy_rule,
+            money_operation: self.config.money_operation,
+            neutral_rate: bank.neutral_rate,
+            policy_rate: bank.policy_rate,
+            avg_policy_rate: rates.clone().sum::<f64>() / steps,
+            min_policy_rate: rates.clone().reduce(f64::min).unwrap_or(bank.policy_rate),
+            max_policy_rate: rates.reduce(f64::max).unwrap_or(bank.policy_rate),
+            avg_inflation: bank.history.iter().map(|snapshot| snapshot.inflation).sum::<f64>()
+                / steps,
+            total_money_created: bank.money_created,
+            total_money_withdrawn: bank.money_withdrawn,
+            net_money_issued: bank.net_money_issued(),
+            bond_holdings: bank.bond_holdings,
+            money_supply: self.get_money_supply(),
+            history: bank.history.clone(),
+        })
+    }
+
+    /// Builds transfer statistics (only when tax redistribution is enabled).
+    ///
+    /// Wealth before transfers is each active person's money in the base currency less
+    /// the transfers they received; the poverty line is half the median of that wealth
+    /// and applies to both distributions.
+    fn calculate_transfer_statistics(&self) -> Option<crate::result::TransferStats> {
+        if !self.config.enable_tax_redistribution {
+            return None;
+        }
+        let (before, after): (Vec<f64>, Vec<f64>) = self
+            .entities
+            .iter()
+            .enumerate()
+            .filter(|(_, e)| e.active)
+            .map(|(idx, e)| {
+                let money = self.entity_to_base_currency(e.person_data.money, idx);
+                (money - e.person_data.transfers_received, money)
+            })
+            .unzip();
+        let ledger = &self.transfer_ledger;
+        let total_paid = ledger.total_paid();
+        let poverty_line = transfers::poverty_line(&before);
+        Some(crate::result::TransferStats {
+            scheme: self.config.transfer_scheme,
+            funding: self.config.transfer_funding,
+            total_paid,
+            paid_from_taxes: ledger.paid_from_taxes,
+            money_created: ledger.money_created,
+            cost_per_step: total_paid / ledger.history.len().max(1) as f64,
+            payments: ledger.payments,
+            avg_payment: total_paid / ledger.payments.max(1) as f64,
+            underfunded_steps: ledger.underfunded_steps,
+            poverty_line,
+            poverty_rate_before: transfers::poverty_rate(&before, poverty_line),
+            poverty_rate_after: transfers::poverty_rate(&after, poverty_line),
+            gini_before: transfers::gini(&before),
+            gini_after: transfers::gini(&after),
+            history: ledger.history.clone(),
+        })
+    }
+
+    /// Builds the recovery metrics of every custom crisis started so far (only when
+    /// custom crises are defined), with trade volume per step as GDP.
+    fn calculate_custom_crisis_statistics(&self) -> Option<crate::result::CustomCrisisStats> {
+        if self.config.custom_crises.is_empty() {
+            return None;
+        }
+        let ledger = &self.crisis_ledger;
+        let crises = ledger
+            .instances
+            .iter()
+            .map(|instance| {
+                let definition = &self.config.custom_crises[instance.definition];
+                crate::crisis::crisis_recovery(
+                    &definition.name,
+                    instance,
+                    definition.duration,
+                    &self.volume_per_step,
+                )
+            })
+            .collect();
+        Some(crate::result::CustomCrisisStats {
+            crises,
+            contagions: ledger.instances.iter().filter(|i| i.source.is_some()).count(),
+            money_changed: ledger.money_changed,
+        })
+    }
+
+    /// Summarizes the population, wealth and prices of each region (only when regions
+    /// are configured).
+    fn calculate_geography_statistics(&self) -> Option<crate::result::GeographyStats> {
+        if self.config.regions.is_empty() {
+            return None;
+        }
+        let mut money = vec![Vec::new(); self.config.regions.len()];
+        for (idx, entity) in self.entities.iter().enumerate().filter(|(_, e)| e.active) {
+            if let Some(region) = entity.person_data.region {
+                money[region].push(self.entity_to_base_currency(entity.person_data.money, idx));
+            }
+        }
+        let regions = self
+            .config
+            .regions
+            .iter()
+            .zip(&money)
+            .zip(&self.region_ledgers)
+            .map(|((region, money), ledger)| geography::region_stats(region, money, ledger))
+            .collect();
+        Some(crate::result::GeographyStats {
+            migrations: self.region_ledgers.iter().map(|ledger| ledger.immigrants).sum(),
+            regions,
+        })
+    }
+
+    /// Builds the before/after comparison of the timeline events applied so far (only
+    /// when the configuration has a timeline).
+    fn calculate_timeline_statistics(&self) -> Option<crate::result::TimelineStats> {
+        if self.config.timeline.is_empty() {
+            return None;
+        }
+        let mut events: Vec<_> =
+            self.config.timeline.iter().filter(|e| e.step < self.current_step).collect();
+        events.sort_by_key(|e| e.step);
+        let interventions = events
+            .into_iter()
+            .map(|event| {
+                timeline::intervention_impact(
+                    event,
+                    &self.trades_per_step,
+                    &self.volume_per_step,
+                    &self.wealth_stats_history,
+                    timeline::IMPACT_WINDOW,
+                )
+            })
+            .collect();
+        Some(crate::result::TimelineStats { window: timeline::IMPACT_WINDOW, interventions })
+    }
+
+    /// Builds government budget statistics (only when the government is enabled).
+    fn calculate_government_statistics(&self) -> Option<crate::result::GovernmentStats> {
+        if !self.config.enable_government {
+            return None;
+        }
+        let government = &self.government;
+        Some(crate::result::GovernmentStats {
+            trade_tax_revenue: government.trade_tax_revenue,
+            vat_revenue: government.vat_revenue,
+            income_tax_revenue: government.income_tax_revenue,
+            wealth_tax_revenue: government.wealth_tax_revenue,
+            total_revenue: government.tax_revenue(),
+            public_goods_spending: government.public_goods_spending,
+            unemployment_benefits_paid: government.unemployment_benefits_paid,
+            class_transfers_paid: government.class_transfers_paid.clone(),
+            total_spending: government.total_spending(),
+            interest_due: government.interest_due,
+            treasury: government.treasury,
+            debt: government.debt,
+            peak_debt: government.history.iter().map(|snapshot| snapshot.debt).fold(0.0, f64::max),
+            debt_issued: government.debt_issued,
+            debt_repaid: government.debt_repaid,
+            deficit_steps: government
+                .history
+                .iter()
+                .filter(|snapshot| snapshot.balance < 0.0)
+                .count(),
+            history: government.history.clone(),
+        })
+    }
+
+    /// Builds firm and labor market statistics (only when firms are enabled).
+    fn calculate_firm_statistics(&self) -> Option<crate::result::FirmStats> {
+        if !self.config.enable_firms {
+            return None;
+        }
+        let ledger = &self.firm_ledger;
+        let mut firm_size_distribution: Vec<usize> = self
+            .firms
+            .iter()
+            .filter(|firm| firm.is_active())
+            .map(|firm| firm.employees.len())
+            .collect();
+        firm_size_distribution.sort_unstable_by(|a, b| b.cmp(a));
+        let active_firms = firm_size_distribution.len();
+        let employed_persons: usize = firm_size_distribution.iter().sum();
+        let avg_unemployment_rate = if ledger.unemployment_history.is_empty() {
+            0.0
+        } else {
+            ledger.unemployment_history.iter().sum::<f64>()
+                / ledger.unemployment_history.len() as f64
+        };
+        Some(crate::result::FirmStats {
+            total_firms_founded: self.firms.len(),
+            active_firms,
+            bankruptcies: self.firms.len() - active_firms,
+            total_hires: ledger.hires,
+            total_layoffs: ledger.layoffs,
+            bankruptcy_job_losses: ledger.bankruptcy_job_losses,
+            employed_persons,
+            unemployment_rate: ledger.unemployment_history.last().copied().unwrap_or(0.0),
+            avg_unemployment_rate,
+            total_units_sold: self.firms.iter().map(|firm| firm.units_sold).sum(),
+            total_revenue: self.firms.iter().map(|firm| firm.revenue).sum(),
+            total_wages_paid: self.firms.iter().map(|firm| firm.wages_paid).sum(),
+            total_dividends_paid: self.firms.iter().map(|firm| firm.dividends_paid).sum(),
+            firm_cash: self.get_total_firm_cash(),
+            avg_firm_size: if active_firms > 0 {
+                employed_persons as f64 / active_firms as f64
+            } else {
+                0.0
+            },
+            largest_firm_size: firm_size_distribution.first().copied().unwrap_or(0),
+            firm_size_distribution,
+            employment_history: ledger.employment_history.clone(),
+            unemployment_history: ledger.unemployment_history.clone(),
+            firms: self
+                .firms
+                .iter()
+                .map(|firm| crate::result::FirmSummary {
+                    id: firm.id,
+                    owner_id: firm.owner_id,
+                    product: firm.recipe.output_skill.clone(),
+                    employees: firm.employees.len(),
+                    units_sold: firm.units_sold,
+                    revenue: firm.revenue,
+                    wages_paid: firm.wages_paid,
+                    dividends_paid: firm.dividends_paid,
+                    balance_sheet: firm.balance_sheet(),
+                    founded_at_step: firm.founded_at_step,
+                    bankrupt_at_step: firm.bankrupt_at_step,
+                })
+                .collect(),
+        })
+    }
+
+    /// Builds P2P lending marketplace statistics (only when the marketplace is active).
+    fn calculate_lending_marketplace_statistics(
+        &self,
+    ) -> Option<crate::result::LendingMarketplaceStats> {
+        let marketplace = self.lending_marketplace.as_ref()?;
+        let mut by_credit_band: Vec<crate::result::CreditBandLendingStats> = marketplace
+            .credit_band_totals
+            .iter()
+            .map(|(band, totals)| crate::result::CreditBandLendingStats {
+                credit_band: band.clone(),
+                loans: totals.loans,
+                volume: totals.volume,
+                avg_interest_rate: totals.avg_interest_rate(),
+            })
+            .collect();
+        by_credit_band.sort_by_key(|stats| {
+            RATING_CATEGORIES
+                .iter()
+                .position(|category| *category == stats.credit_band)
+                .unwrap_or(usize::MAX)
+        });
+        let rate_sum: f64 = marketplace
+            .credit_band_totals
+            .values()
+            .map(|totals| totals.interest_rate_sum)
+            .sum();
+        let avg_interest_rate = if marketplace.total_matched_loans > 0 {
+            rate_sum / marketplace.total_matched_loans as f64
+        } else {
+            0.0
+        };
+        Some(crate::result::LendingMarketplaceStats {
+            total_offers_posted: marketplace.total_offers_posted,
+            avg_offer_book_depth: marketplace.avg_offer_book_depth(),
+            open_offers: marketplace.offers.len(),
+            open_offer_volume: marketplace.offer_book_volume(),
+            total_matched_loans: marketplace.total_matched_loans,
+            total_matched_volume: marketplace.total_matched_volume,
+            unmatched_requests: marketplace.unmatched_requests,
+            avg_interest_rate,
+            total_platform_fees: marketplace.total_fees_collected,
+            by_credit_band,
+        })
+    }
+
+    /// Processes loan payments for the current step.
+    /// Borrowers make scheduled payments to lenders, converted from the base currency
+    /// the loan is held in.
+    fn process_loan_payments(&mut self) {
+        let mut completed_loans = Vec::new();
+        let mut defaulted_loans = Vec::new();
+
+        // Pay in loan order so that who goes broke first does not depend on hashing
+        let mut loan_ids: Vec<LoanId> = self.loans.keys().copied().collect();
+        loan_ids.sort_unstable();
+        for loan_id in &loan_ids {
+            let Some(loan) = self.loans.get(loan_id) else {
+                continue;
+            };
+            if loan.is_repaid {
+                continue;
+            }
+
+            let borrower_idx = loan.borrower_id;
+            let lender_idx = loan.lender_id;
+            let payment_due = self.base_to_entity_currency(loan.payment_per_step, borrower_idx);
+            let Some(loan) = self.loans.get_mut(loan_id) else {
+                continue;
+            };
+
+            // Check if borrower can afford the payment
+            if self.entities[borrower_idx].person_data.money >= payment_due {
+                // Make the payment
+                let payment_amount = loan.make_payment();
+                let remaining_principal = loan.remaining_principal;
+                let is_repaid = loan.is_repaid;
+
+                // Transfer money
+                let paid = self.base_to_entity_currency(payment_amount, borrower_idx);
+                let received = self.base_to_entity_currency(payment_amount, lender_idx);
+                self.entities[borrower_idx].person_data.money -= paid;
+                self.entities[lender_idx].person_data.money += received;
+
+                // Update credit score if credit rating is enabled
+                if self.config.enable_credit_rating {
+                    self.entities[borrower_idx]
+                        .person_data
+                        .credit_score
+                        .record_successful_payment();
+                }
+
+                debug!(
+                    "Loan payment: Person {} paid ${:.2} to Person {} (remaining: ${:.2})",
+                    self.entities[borrower_idx].id,
+                    payment_amount,
+                    self.entities[lender_idx].id,
+                    remaining_principal
+                );
+
+                // Check if loan is now fully repaid
+                if is_repaid {
+                    completed_loans.push(*loan_id);
+                    debug!(
+                        "Loan {} fully repaid: Person {} to Person {}",
+                        loan_id, self.entities[borrower_idx].id, self.entities[lender_idx].id
+                    );
+                }
+            } else {
+                // Borrower cannot afford the payment - record as missed payment
+                if loan.record_missed_payment() >= self.config.loan_default_threshold {
+                    defaulted_loans.push(*loan_id);
+                }
+                if self.config.enable_credit_rating {
+                    self.entities[borrower_idx].person_data.credit_score.record_missed_payment();
+                    debug!(
+                        "Person {} missed loan payment, credit score affected",
+                        self.entities[borrower_idx].id
+                    );
+                }
+
+                trace!(
+                    "Person {} cannot afford loan payment of ${:.2} (has ${:.2})",
+                    self.entities[borrower_idx].id,
+                    loan.payment_per_step,
+                    self.entities[borrower_idx].person_data.money
+                );
+            }
+        }
+
+        // Remove completed loans and update statistics
+        for loan_id in completed_loans {
+            let loan = self.loans.remove(&loan_id).unwrap();
+
+            // Remove loan from person tracking
+            self.entities[loan.borrower_id]
+                .person_data
+                .borrowed_loans
+                .retain(|&id| id != loan_id);
+            self.entities[loan.lender_id].person_data.lent_loans.retain(|&id| id != loan_id);
+
+            self.total_loans_repaid += 1;
+        }
+
+        for loan_id in defaulted_loans {
+            // An earlier bankruptcy in this step may already have settled the loan
+            if self
+                .loans
+                .get(&loan_id)
+                .is_some_and(|loan| loan.is_in_default(self.config.loan_default_threshold))
+            {
+                self.default_loan(loan_id);
+            }
+        }
+    }
+
+    /// Handles a loan that reached the default threshold.
+    ///
+    /// The lender first seizes the borrower's assets as collateral, then the
+    /// borrower's credit insurance covers what it can. The remaining balance is
+    /// written off, or settled through bankruptcy when it is enabled.
+    fn default_loan(&mut self, loan_id: LoanId) {
+        let Some(loan) = self.loans.get(&loan_id) else {
+            return;
+        };
+        let borrower_idx = loan.borrower_id;
+        let lender_idx = loan.lender_id;
+        let outstanding = loan.outstanding_balance();
+
+        self.loan_defaults.defaults += 1;
+        if self.entities[borrower_idx].person_data.loan_losses > 0.0 {
+            self.loan_defaults.cascade_defaults += 1;
+        }
+
+        let mut remaining =
+            outstanding - self.seize_collateral(borrower_idx, lender_idx, outstanding);
+        if remaining > 0.0 {
+            let payout = self.claim_credit_insurance_at_default(borrower_idx, remaining);
+            self.entities[lender_idx].person_data.money +=
+                self.base_to_entity_currency(payout, lender_idx);
+            self.loan_defaults.insurance_recovered += payout;
+            remaining -= payout;
+        }
+        let remaining = remaining.max(0.0);
+
+        debug!(
+            "Loan {} defaulted: Person {} owed ${:.2} to Person {} (${:.2} unrecovered)",
+            loan_id,
+            self.entities[borrower_idx].id,
+            outstanding,
+            self.entities[lender_idx].id,
+            remaining
+        );
+
+        if self.config.enable_bankruptcy {
+            self.declare_bankruptcy(borrower_idx, loan_id, remaining);
+        } else {
+            self.write_off_loan(loan_id, remaining);
+        }
+    }
+
+    /// Transfers the borrower's most valuable assets to the lender until they cover
+    /// `debt`. Returns the value recovered, at most `debt`.
+    ///
+    /// When the last asset is worth more than what is left of the debt, the lender
+    /// refunds the surplus to the borrower in money, as far as the lender's money
+    /// covers it. The lender's money never goes negative because of a refund.
+    fn seize_collateral(&mut self, borrower_idx: usize, lender_idx: usize, debt: f64) -> f64 {
+        let mut collateral: Vec<(crate::asset::AssetId, f64)> = self.entities[borrower_idx]
+            .person_data
+            .owned_assets
+            .iter()
+            .filter_map(|id| self.assets.get(id).map(|asset| (*id, asset.current_value)))
+            .collect();
+        collateral.sort_by(|a, b| {
+            b.1.partial_cmp(&a.1)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| a.0.cmp(&b.0))
+        });
+
+        let mut recovered = 0.0;
+        for (asset_id, value) in collateral {
+            if recovered >= debt {
+                break;
+            }
+            if let Some(asset) = self.assets.get_mut(&asset_id) {
+                asset.owner_id = self.entities[lender_idx].id;
+            }
+            self.entities[borrower_idx]
+                .person_data
+                .owned_assets
+                .retain(|&id| id != asset_id);
+            self.entities[lender_idx].person_data.owned_assets.push(asset_id);
+            let covered = value.min(debt - recovered);
+            let surplus = value - covered;
+            if surplus > 0.0 {
+                // The lender refunds no more than they hold; the rest of the surplus
+                // stays with the asset
+                let lender_money = self.entities[lender_idx].person_data.money.max(0.0);
+                let refund = surplus.min(self.entity_to_base_currency(lender_money, lender_idx));
+                self.entities[lender_idx].person_data.money -=
+                    self.base_to_entity_currency(refund, lender_idx);
+                self.entities[borrower_idx].person_data.money +=
+                    self.base_to_entity_currency(refund, borrower_idx);
+                if refund < surplus {
+                    debug!(
+                        "Person {} could only refund ${:.2} of a ${:.2} collateral surplus",
+                        self.entities[lender_idx].id, refund, surplus
+                    );
+                }
+            }
+            recovered += covered;
+            self.loan_defaults.assets_seized += 1;
+            self.loan_defaults.collateral_seized += covered;
+        }
+        recovered
+    }
+
+    /// Claims the borrower's credit insurance for an unrecovered loan balance.
+    ///
+    /// Returns the payout, which goes to the lender; 0.0 without a valid policy.
+    fn claim_credit_insurance_at_default(&mut self, borrower_idx: usize, claim_amount: f64) -> f64 {
+        if !self.config.enable_insurance {
+            return 0.0;
+        }
+        for &policy_id in &self.entities[borrower_idx].person_data.insurance_policies {
+            let Some(policy) = self.insurances.get_mut(&policy_id) else {
+                continue;
+            };
+            if policy.insurance_type != crate::insurance::InsuranceType::Credit {
+                continue;
+            }
+            let payout = policy.file_claim(claim_amount, self.current_step);
+            if payout > 0.0 {
+                self.total_insurance_claims_paid += 1;
+                self.total_payouts_made += payout;
+                debug!(
+                    "💰 Credit insurance covered ${:.2} of Person {}'s defaulted loan (Policy ID: {})",
+                    payout, self.entities[borrower_idx].id, policy_id
+                );
+                return payout;
+            }
+        }
+        0.0
+    }
+
+    /// Settles all of a defaulting borrower's loans through bankruptcy.
+    ///
+    /// The first bankruptcy restructures each loan: the lender forgives
+    /// `bankruptcy_haircut` of the balance and the rest is repaid interest-free over
+    /// `loan_repayment_period` steps. Later bankruptcies discharge all debt.
+    /// `defaulted_balance` is what is left of the defaulted loan after collateral
+    /// and insurance.
+    fn declare_bankruptcy(
+        &mut self,
+        borrower_idx: usize,
+        defaulted_loan_id: LoanId,
+        defaulted_balance: f64,
+    ) {
+        let person = &mut self.entities[borrower_idx].person_data;
+        let restructure = person.bankruptcies == 0;
+        person.bankruptcies += 1;
+        let mut loan_ids = person.borrowed_loans.clone();
+        loan_ids.sort_unstable();
+
+        for loan_id in loan_ids {
+            let Some(loan) = self.loans.get(&loan_id) else {
+                continue;
+            };
+            let balance = if loan_id == defaulted_loan_id {
+                defaulted_balance
+            } else {
+                loan.outstanding_balance()
+            };
+            if !restructure {
+                self.write_off_loan(loan_id, balance);
+                continue;
+            }
+
+            let forgiven = balance * self.config.bankruptcy_haircut;
+            let new_balance = balance - forgiven;
+            if new_balance < 0.01 {
+                self.write_off_loan(loan_id, balance);
+                continue;
+            }
+            let lender_idx = loan.lender_id;
+            self.record_loan_loss(lender_idx, forgiven);
+            if let Some(loan) = self.loans.get_mut(&loan_id) {
+                loan.restructure(new_balance, self.config.loan_repayment_period);
+            }
+        }
+
+        if restructure {
+            self.loan_defaults.bankruptcies_restructured += 1;
+        } else {
+            self.loan_defaults.bankruptcies_discharged += 1;
+        }
+        debug!(
+            "Person {} declared bankruptcy ({})",
+            self.entities[borrower_idx].id,
+            if restructure {
+                "debt restructured"
+            } else {
+                "debt discharged"
+            }
+        );
+    }
+
+    /// Closes a loan, with the lender writing off `amount`.
+    fn write_off_loan(&mut self, loan_id: LoanId, amount: f64) {
+        let Some(loan) = self.loans.remove(&loan_id) else {
+            return;
+        };
+        self.entities[loan.borrower_id]
+            .person_data
+            .borrowed_loans
+            .retain(|&id| id != loan_id);
+        self.entities[loan.lender_id].person_data.lent_loans.retain(|&id| id != loan_id);
+        self.record_loan_loss(loan.lender_id, amount);
+    }
+
+    /// Books a loss a lender takes on a loan.
+    fn record_loan_loss(&mut self, lender_idx: usize, amount: f64) {
+        if amount > 0.0 {
+            self.entities[lender_idx].person_data.loan_losses += amount;
+            self.loan_defaults.written_off += amount;
+        }
+    }
+
+    /// Applies one step of the epidemic and the health policies.
+    ///
+    /// Exposed persons fall sick after the incubation period, sick persons recover
+    /// with immunity and immunity wanes after `immunity_duration` steps. Sick persons
+    /// then infect susceptible friends, the vaccination campaign immunises part of the
+    /// susceptible population, the lockdown is switched on or off for the next step's
+    /// trades and sick persons receive sick pay from the tax pool. Persons are visited
+    /// in index order so that runs stay reproducible.
+    fn process_epidemic(&mut self) {
+        let step = self.current_step;
+        for entity in self.entities.iter_mut().filter(|e| e.active) {
+            let person = &mut entity.person_data;
+            if person.try_become_sick(step, self.config.disease_incubation_period) {
+                debug!("Person {} fell sick at step {}", entity.id, step);
+            } else if person.try_recover(step, self.config.disease_recovery_duration) {
+                self.epidemic.recoveries += 1;
+                debug!("Person {} recovered from illness at step {}", entity.id, step);
+            } else if person.try_lose_immunity(step, self.config.immunity_duration) {
+                self.epidemic.immunity_losses += 1;
+            }
+        }
+
+        // Contact transmission along friendship edges
+        if self.config.enable_friendships && self.config.contact_transmission_rate > 0.0 {
+            let sick: Vec<usize> = (0..self.entities.len())
+                .filter(|&idx| {
+                    self.entities[idx].active && self.entities[idx].person_data.is_sick()
+                })
+                .collect();
+            for idx in sick {
+                let mut friends: Vec<usize> =
+                    self.entities[idx].person_data.friends.iter().copied().collect();
+                friends.sort_unstable();
+                for friend_idx in friends {
+                    let friend = &self.entities[friend_idx];
+                    if !friend.active || !friend.person_data.is_susceptible() {
+                        continue;
+                    }
+                    if self.rng.random_range(0.0..1.0) < self.config.contact_transmission_rate {
+                        self.transmit_disease(friend_idx);
+                        self.epidemic.contact_exposures += 1;
+                        debug!(
+                            "Disease transmitted: Sick person {} infected friend {}",
+                            self.entities[idx].id, friend_idx
+                        );
+                    }
+                }
+            }
+        }
+
+        // Vaccination campaign
+        if self.config.vaccination_rate > 0.0 && step >= self.config.vaccination_start_step {
+            for idx in 0..self.entities.len() {
+                if !self.entities[idx].active || !self.entities[idx].person_data.is_susceptible() {
+                    continue;
+                }
+                if self.rng.random_range(0.0..1.0) < self.config.vaccination_rate
+                    && self.entities[idx].person_data.vaccinate(step)
+                {
+                    self.epidemic.vaccinations += 1;
+                }
+            }
+        }
+
+        // Lockdown while the sick share is at or above the threshold
+        let counts = CompartmentCounts::count(
+            self.entities.iter().filter(|e| e.active).map(|e| &e.person_data),
+        );
+        let lockdown = self.config.lockdown_threshold > 0.0
+            && counts.infected_share() >= self.config.lockdown_threshold;
+        if lockdown != self.epidemic.lockdown_active {
+            info!(
+                "Lockdown {} at step {} ({:.1}% of persons sick)",
+                if lockdown { "imposed" } else { "lifted" },
+                step,
+                counts.infected_share() * 100.0
+            );
+        }
+        self.epidemic.lockdown_active = lockdown;
+        if lockdown {
+            self.epidemic.lockdown_steps += 1;
+        }
+
+        // Sick pay, shared out equally when the tax pool cannot cover it in full
+        if self.config.sick_pay > 0.0 && counts.infected > 0 {
+            let pool = (self.total_taxes_collected
+                - self.total_taxes_redistributed
+                - self.epidemic.sick_pay_paid
+                - self.government.tax_revenue())
+            .max(0.0);
+            let per_person = self.config.sick_pay.min(pool / counts.infected as f64);
+            if per_person > 0.0 {
+                for idx in 0..self.entities.len() {
+                    if self.entities[idx].active && self.entities[idx].person_data.is_sick() {
+                        let amount = self.base_to_entity_currency(per_person, idx);
+                        self.entities[idx].person_data.money += amount;
+                    }
+                }
+                let paid = per_person * counts.infected as f64;
+                self.epidemic.sick_pay_paid += paid;
+                self.step_tally.sick_pay += paid;
+            }
+        }
+
+        self.epidemic.history.push(EpidemicSnapshot { step, counts });
+    }
+
+    /// Passes the disease to a susceptible person: they are exposed when the disease
+    /// has an incubation period and fall sick at once otherwise.
+    fn transmit_disease(&mut self, idx: usize) {
+        let person = &mut self.entities[idx].person_data;
+        if self.config.disease_incubation_period > 0 {
+            person.expose(self.current_step);
+        } else {
+            person.infect(self.current_step);
+        }
+    }
+
+    /// Applies one step of the labor market.
+    ///
+    /// Departed and retired employees leave their firm and a new firm may be
+    /// founded. Every trading firm then sells its output to persons who still need
+    /// it, pays its wage bill (going bankrupt when it cannot), pays its owner a share
+    /// of the profit and adjusts its workforce: a firm that sold out hires when its
+    /// cash covers two more wage bills, and one that sold less than half of its
+    /// output lays off its newest worker. Firms and persons are visited in index
+    /// order so that runs stay reproducible.
+    fn process_firms(&mut self) {
+        self.release_departed_employees();
+        if self.rng.random_range(0.0..1.0) < self.config.firm_founding_rate {
+            self.found_firm();
+        }
+
+        for firm_idx in 0..self.firms.len() {
+            if !self.firms[firm_idx].is_active() {
+                continue;
+            }
+            let capacity = self.firms[firm_idx].capacity(self.config.firm_productivity);
+            let (units, revenue) = self.sell_firm_output(firm_idx, capacity);
+
+            let wage_bill = self.firms[firm_idx].wage_bill();
+            let wage = self.firms[firm_idx].pay_wages();
+            let employees: Vec<usize> =
+                self.firms[firm_idx].employees.iter().map(|e| e.person_id).collect();
+            for &idx in &employees {
+                let amount = self.base_to_entity_currency(wage, idx);
+                self.entities[idx].person_data.money += amount;
+            }
+            if self.firms[firm_idx].is_insolvent() {
+                self.bankrupt_firm(firm_idx);
+                continue;
+            }
+
+            let owner_idx = self.firms[firm_idx].owner_id;
+            if self.entities[owner_idx].active {
+                let dividend = self.firms[firm_idx]
+                    .pay_dividend(revenue - wage_bill, self.config.firm_dividend_share);
+                let amount = self.base_to_entity_currency(dividend, owner_idx);
+                self.entities[owner_idx].person_data.money += amount;
+            }
+
+            let firm = &self.firms[firm_idx];
+            if units == capacity
+                && firm.employees.len() < self.config.firm_max_employees
+                && firm.cash >= 2.0 * firm.wage * (firm.employees.len() + 1) as f64
+            {
+                self.hire(firm_idx);
+            } else if units * 2 < capacity {
+                self.lay_off_newest(firm_idx);
+            }
+        }
+
+        // Labor force: active working-age persons; the unemployed neither work for a
+        // firm nor sold anything this step
+        let step = self.current_step;
+        let mut labor_force = 0;
+        let mut employed = 0;
+        let mut unemployed = 0;
+        for entity in self.entities.iter().filter(|e| e.active && !e.person_data.retired) {
+            labor_force += 1;
+            if entity.person_data.is_employed() {
+                employed += 1;
+            } else if entity.person_data.is_unemployed(step) {
+                unemployed += 1;
+            }
+        }
+        self.firm_ledger.employment_history.push(employed);
+        self.firm_ledger
+            .unemployment_history
+            .push(firm::unemployment_rate(labor_force, unemployed));
+    }
+
+    /// Founds a firm owned by a random person who can put up the capital.
+    ///
+    /// The firm uses a default production recipe whose inputs are both offered on
+    /// the market, or else a recipe combining two random offered skills. Its output
+    /// skill joins the market if it is new. Returns false if no person can afford the
+    /// capital or fewer than two skills are offered.
+    fn found_firm(&mut self) -> bool {
+        let capital = self.config.firm_initial_capital;
+        let founders: Vec<usize> = (0..self.entities.len())
+            .filter(|&idx| {
+                let entity = &self.entities[idx];
+                entity.active
+                    && !entity.person_data.retired
+                    && self.entity_to_base_currency(entity.person_data.money, idx) >= capital
+            })
+            .collect();
+        let Some(&owner_idx) = founders.choose(&mut self.rng) else {
+            return false;
+        };
+
+        let mut offered: Vec<SkillId> = self
+            .skill_providers
+            .iter()
+            .filter(|(_, providers)| !providers.is_empty())
+            .map(|(skill_id, _)| skill_id.clone())
+            .collect();
+        offered.sort();
+        if offered.len() < 2 {
+            return false;
+        }
+        let known: Vec<crate::production::Recipe> = crate::production::generate_default_recipes()
+            .into_iter()
+            .filter(|recipe| recipe.can_craft(&offered))
+            .collect();
+        let recipe = match known.choose(&mut self.rng) {
+            Some(recipe) => recipe.clone(),
+            None => {
+                offered.shuffle(&mut self.rng);
+                let mut inputs = [offered[0].clone(), offered[1].clone()];
+                inputs.sort();
+                let output = format!("{}+{}", inputs[0], inputs[1]);
+                let [input_1, input_2] = inputs;
+                crate::production::Recipe::new(
+                    input_1,
+                    input_2,
+                    output,
+                    self.rng.random_range(1.2..1.5),
+                )
+            },
+        };
+
+        if !self.market.skills.contains_key(&recipe.output_skill) {
+            let price_of = |skill_id: &SkillId| {
+                self.market.get_price(skill_id).unwrap_or(self.config.base_skill_price)
+            };
+            let price = recipe
+                .calculate_cost(price_of(&recipe.input_skill_1), price_of(&recipe.input_skill_2));
+            let product = Skill::new(recipe.output_skill.clone(), price);
+            self.market.skills.insert(product.id.clone(), product.clone());
+            self.all_skill_ids.push(product.id.clone());
+            if let Some(ref mut bm) = self.black_market {
+                let bm_skill = Skill::new(
+                    product.id.clone(),
+                    product.current_price * self.config.black_market_price_multiplier,
+                );
+                bm.skills.insert(bm_skill.id.clone(), bm_skill);
+            }
+        }
+        self.market.increment_skill_supply(&recipe.output_skill);
+
+        let amount = self.base_to_entity_currency(capital, owner_idx);
+        self.entities[owner_idx].person_data.money -= amount;
+        let id = self.firms.len();
+        debug!(
+            "Person {} founded firm {} producing {} from {} + {}",
+            owner_idx, id, recipe.output_skill, recipe.input_skill_1, recipe.input_skill_2
+        );
+        self.firms.push(Firm::new(
+            id,
+            owner_idx,
+            recipe,
+            self.config.firm_wage,
+            capital,
+            self.current_step,
+        ));
+        true
+    }
+
+    /// Sells up to `capacity` units of a firm's output to persons who still need it
+    /// this step and can afford it. Returns the units sold and the revenue.
+    fn sell_firm_output(&mut self, firm_idx: usize, capacity: usize) -> (usize, f64) {
+        let product = self.firms[firm_idx].recipe.output_skill.clone();
+        let Some(price) = self.market.get_price(&product) else {
+            return (0, 0.0);
+        };
+        let mut units = 0;
+        for idx in 0..self.entities.len() {
+            if units == capacity {
+                break;
+            }
+            let person = &self.entities[idx].person_data;
+            if !self.entities[idx].active
+                || !person.needed_skills.iter().any(|need| need.id == product)
+                || person.satisfied_needs_current_step.contains(&product)
+            {
+                continue;
+            }
+            let cost = self.base_to_entity_currency(price, idx);
+            if !person.can_afford_with_strategy(cost) {
+                continue;
+            }
+            let person = &mut self.entities[idx].person_data;
+            person.money -= cost;
+            person.satisfied_needs_current_step.insert(product.clone());
+            person.record_transaction(
+                self.current_step,
+                product.clone(),
+                crate::person::TransactionType::Buy,
+                price,
+                None,
+            );
+            self.firms[firm_idx].record_sale(price);
+            units += 1;
+        }
+        (units, price * units as f64)
+    }
+
+    /// Hires a job seeker providing the firm's scarcer recipe input.
+    ///
+    /// Job seekers are active working-age persons without a job, other than the
+    /// firm's owner; those who sold nothing this step are hired first. The new
+    /// employee stops selling their own skills.
+    fn hire(&mut self, firm_idx: usize) {
+        let firm = &self.firms[firm_idx];
+        let skill_id = firm.scarcer_input().clone();
+        let step = self.current_step;
+        let candidate = (0..self.entities.len())
+            .filter(|&idx| {
+                let entity = &self.entities[idx];
+                entity.active
+                    && entity.person_data.sells_skills()
+                    && idx != firm.owner_id
+                    && entity.person_data.has_skill(&skill_id)
+            })
+            .min_by_key(|&idx| self.entities[idx].person_data.sold_in_step(step));
+        let Some(idx) = candidate else {
+            return;
+        };
+        self.withdraw_skills(idx);
+        self.entities[idx].person_data.employer = Some(firm_idx);
+        self.firms[firm_idx].employees.push(Employee {
+            person_id: idx,
+            skill_id,
+            hired_at_step: step,
+        });
+        self.firm_ledger.hires += 1;
+        debug!("Firm {} hired person {}", firm_idx, idx);
+    }
+
+    /// Lays off the most recently hired employee providing the firm's more abundant
+    /// recipe input.
+    fn lay_off_newest(&mut self, firm_idx: usize) {
+        let firm = &self.firms[firm_idx];
+        let scarcer = firm.scarcer_input().clone();
+        let abundant = if scarcer == firm.recipe.input_skill_1 {
+            &firm.recipe.input_skill_2
+        } else {
+            &firm.recipe.input_skill_1
+        };
+        let Some(position) = firm.employees.iter().rposition(|e| &e.skill_id == abundant) else {
+            return;
+        };
+        let employee = self.firms[firm_idx].employees.remove(position);
+        self.release_employee(employee.person_id);
+        self.firm_ledger.layoffs += 1;
+        debug!("Firm {} laid off person {}", firm_idx, employee.person_id);
+    }
+
+    /// Closes a firm that could not pay its wages and lays off all its employees.
+    fn bankrupt_firm(&mut self, firm_idx: usize) {
+        let firm = &mut self.firms[firm_idx];
+        firm.bankrupt_at_step = Some(self.current_step);
+        let employees = std::mem::take(&mut firm.employees);
+        let product = firm.recipe.output_skill.clone();
+        self.market.decrement_skill_supply(&product);
+        self.firm_ledger.bankruptcy_job_losses += employees.len();
+        for employee in employees {
+            self.release_employee(employee.person_id);
+        }
+        info!("Firm {} went bankrupt at step {}", firm_idx, self.current_step);
+    }
+
+    /// Ends a person's employment; working-age persons sell their skills again.
+    fn release_employee(&mut self, idx: usize) {
+        self.entities[idx].person_data.employer = None;
+        if !self.entities[idx].active || self.entities[idx].person_data.retired {
+            return;
+        }
+        let person = &self.entities[idx].person_data;
+        let skill_ids: Vec<SkillId> = person
+            .own_skills
+            .iter()
+            .chain(&person.learned_skills)
+            .map(|s| s.id.clone())
+            .collect();
+        for skill_id in &skill_ids {
+            self.skill_providers.entry(skill_id.clone()).or_default().push(idx);
+        }
+        // Supply is only counted for the skills persons start with
+        for skill in &self.entities[idx].person_data.own_skills {
+            self.market.increment_skill_supply(&skill.id);
+        }
+    }
+
+    /// Removes employees who retired or left the simulation from their firms.
+    fn release_departed_employees(&mut self) {
+        for firm_idx in 0..self.firms.len() {
+            let departed: Vec<usize> = self.firms[firm_idx]
+                .employees
+                .iter()
+                .map(|e| e.person_id)
+                .filter(|&idx| !self.entities[idx].active || self.entities[idx].person_data.retired)
+                .collect();
+            if departed.is_empty() {
+                continue;
+            }
+            self.firms[firm_idx].employees.retain(|e| !departed.contains(&e.person_id));
+            for idx in departed {
+                self.release_employee(idx);
+            }
+        }
+    }
+
+    /// Runs one step of monetary policy.
+    ///
+    /// The central bank measures inflation as the change of the average market price
+    /// and the output gap from the step's trades, sets the policy rate by its rule and
+    /// writes it to `loan_interest_rate`, so loans offered from now on price off it.
+    /// It then creates or withdraws money through the configured operation.
+    fn process_central_bank(&mut self) {
+        let inflation = self.central_bank.observe_price_level(self.market.get_average_price());
+        let output_gap = central_bank::output_gap(self.step_tally.trades, &self.trades_per_step);
+        let money_supply = self.get_money_supply();
+
+        let (policy_rate, target_injection) = match self.config.monetary_policy_rule {
+            MonetaryPolicyRule::TaylorRule => {
+                let rate = central_bank::taylor_rate(
+                    self.central_bank.neutral_rate,
+                    inflation,
+                    self.config.inflation_target,
+                    self.config.taylor_inflation_weight,
+                    self.config.taylor_output_weight,
+                    output_gap,
+                );
+                // Create money when inflation runs below target, withdraw it above
+                let limit = money_supply.max(0.0) * central_bank::MAX_OPERATION_SHARE;
+                let injection = money_supply
+                    * self.config.money_growth_response
+                    * (self.config.inflation_target - inflation);
+                (rate.min(1.0), injection.clamp(-limit, limit))
+            },
+            MonetaryPolicyRule::Scripted => {
+                central_bank::scripted_entry(&self.config.monetary_policy_path, self.current_step)
+                    .map_or((self.central_bank.neutral_rate, 0.0), |entry| {
+                        (entry.policy_rate, entry.money_injection)
+                    })
+            },
+        };
+        self.central_bank.policy_rate = policy_rate;
+        self.config.loan_interest_rate = policy_rate;
+
+        let operation = self.config.money_operation;
+        let money_created = if operation == MoneyOperation::None {
+            0.0
+        } else if target_injection > 0.0 {
+            self.create_money(operation, target_injection)
+        } else if target_injection < 0.0 {
+            -self.withdraw_money(operation, -target_injection)
+        } else {
+            0.0
+        };
+        if money_created > 0.0 {
+            self.central_bank.money_created += money_created;
+        } else {
+            self.central_bank.money_withdrawn -= money_created;
+        }
+        if operation == MoneyOperation::OpenMarket {
+            self.central_bank.bond_holdings += money_created;
+        }
+        if money_created != 0.0 {
+            debug!(
+                "Central bank moved ${:.2} at step {} (policy rate {:.4})",
+                money_created, self.current_step, policy_rate
+            );
+        }
+
+        self.central_bank.history.push(MonetarySnapshot {
+            step: self.current_step,
+            policy_rate,
+            inflation,
+            output_gap,
+            money_created,
+            money_supply: self.get_money_supply(),
+        });
+    }
+
+    /// Creates money and hands it to active persons, returning the amount created.
+    ///
+    /// Open-market purchases pay persons in proportion to their money and savings;
+    /// helicopter money gives everyone the same amount.
+    fn create_money(&mut self, operation: MoneyOperation, amount: f64) -> f64 {
+        let weights: Vec<(usize, f64)> = self
+            .entities
+            .iter()
+            .enumerate()
+            .filter(|(_, e)| e.active)
+            .map(|(idx, e)| {
+                let weight = match operation {
+                    MoneyOperation::OpenMarket => {
+                        (e.person_data.money + e.person_data.savings).max(0.0)
+                    },
+                    _ => 1.0,
+                };
+                (idx, weight)
+            })
+            .collect();
+        let total_weight: f64 = weights.iter().map(|&(_, weight)| weight).sum();
+        if total_weight <= 0.0 {
+            return 0.0;
+        }
+        for (idx, weight) in weights {
+            let share = self.base_to_entity_currency(amount * weight / total_weight, idx);
+            self.entities[idx].person_data.money += share;
+        }
+        amount
+    }
+
+    /// Withdraws money from active persons, returning the amount withdrawn.
+    ///
+    /// Open-market sales take money in proportion to each person's cash; the helicopter
+    /// levy takes the same amount from everyone. Nobody pays more than the cash they
+    /// hold, so less than `amount` may be withdrawn.
+    fn withdraw_money(&mut self, operation: MoneyOperation, amount: f64) -> f64 {
+        let holdings: Vec<(usize, f64)> = self
+            .entities
+            .iter()
+            .enumerate()
+            .filter(|(_, e)| e.active)
+            .map(|(idx, e)| (idx, self.entity_to_base_currency(e.person_data.money, idx).max(0.0)))
+            .collect();
+        let total_holdings: f64 = holdings.iter().map(|&(_, held)| held).sum();
+        if holdings.is_empty() || total_holdings <= 0.0 {
+            return 0.0;
+        }
+        let levy = amount / holdings.len() as f64;
+        let mut withdrawn = 0.0;
+        for (idx, held) in holdings {
+            let payment = match operation {
+                MoneyOperation::OpenMarket => amount.min(total_holdings) * held / total_holdings,
+                _ => levy.min(held),
+            };
+            self.entities[idx].person_data.money -= self.base_to_entity_currency(payment, idx);
+            withdrawn += payment;
+        }
+        withdrawn
+    }
+
+    /// Pays this step's transfers according to the transfer scheme.
+    ///
+    /// EqualShare shares the taxes collected this step equally among active persons.
+    /// The other schemes work out what each person they target is owed. Funded by
+    /// taxes, every payment is scaled down by the same share when the step's taxes
+    /// fall short; funded by money creation, the payments are made in full.
+    fn process_transfers(&mut self) {
+        let step = self.current_step;
+        // Sick pay already drew on this step's revenue
+        let step_taxes = (self.total_taxes_collected
+            - self.step_tally.taxes_collected_start
+            - self.step_tally.sick_pay)
+            .max(0.0);
+        let active: Vec<usize> =
+            (0..self.entities.len()).filter(|&idx| self.entities[idx].active).collect();
+
+        let amount = self.config.transfer_amount;
+        let mut owed: Vec<(usize, f64)> = match self.config.transfer_scheme {
+            TransferScheme::EqualShare if self.config.tax_rate > 0.0 && !active.is_empty() => {
+                let per_person = step_taxes / active.len() as f64;
+                active.iter().map(|&idx| (idx, per_person)).collect()
+            },
+            TransferScheme::EqualShare => Vec::new(),
+            TransferScheme::UniversalBasicIncome => {
+                active.iter().map(|&idx| (idx, amount)).collect()
+            },
+            TransferScheme::NegativeIncomeTax => active
+                .iter()
+                .map(|&idx| {
+                    let income = self.entities[idx].person_data.sales_in_step(step);
+                    let transfer = transfers::negative_income_tax(
+                        income,
+                        self.config.negative_income_tax_threshold,
+                        self.config.negative_income_tax_rate,
+                    );
+                    (idx, transfer)
+                })
+                .collect(),
+            TransferScheme::MeansTested | TransferScheme::Conditional => self
+                .means_tested_persons(&active)
+                .into_iter()
+                .filter(|&idx| {
+                    let person = &self.entities[idx].person_data;
+                    match self.config.transfer_scheme {
+                        TransferScheme::Conditional => match self.config.transfer_condition {
+                            TransferCondition::Education => !person.learned_skills.is_empty(),
+                            TransferCondition::Health => person.is_healthy(),
+                        },
+                        _ => true,
+                    }
+                })
+                .map(|idx| (idx, amount))
+                .collect(),
+        };
+        owed.retain(|&(_, transfer)| transfer > 0.0);
+
+        let wanted: f64 = owed.iter().map(|&(_, transfer)| transfer).sum();
+        let funding = self.config.transfer_funding;
+        // EqualShare already pays out exactly the step's taxes
+        let scale = if funding == TransferFunding::Taxes
+            && self.config.transfer_scheme != TransferScheme::EqualShare
+            && wanted > step_taxes
+        {
+            self.transfer_ledger.underfunded_steps += 1;
+            step_taxes / wanted
+        } else {
+            1.0
+        };
+
+        let mut paid = 0.0;
+        for (idx, transfer) in owed {
+            let transfer = transfer * scale;
+            if transfer <= 0.0 {
+                continue;
+            }
+            let amount = self.base_to_entity_currency(transfer, idx);
+            let person = &mut self.entities[idx].person_data;
+            person.money += amount;
+            person.transfers_received += transfer;
+            self.transfer_ledger.payments += 1;
+            paid += transfer;
+        }
+
+        if paid > 0.0 {
+            debug!(
+                "Paid ${:.2} in {:?} transfers at step {} ({:?} funded)",
+                paid, self.config.transfer_scheme, step, funding
+            );
+        }
+        match funding {
+            TransferFunding::Taxes => {
+                self.transfer_ledger.paid_from_taxes += paid;
+                self.total_taxes_redistributed += paid;
+            },
+            TransferFunding::MoneyCreation => self.transfer_ledger.money_created += paid,
+        }
+        self.transfer_ledger.history.push(paid);
+    }
+
+    /// Returns the given persons whose wealth percentile is below the means test.
+    ///
+    /// Percentiles rank persons by money in the base currency as social classes do, so
+    /// the poorest person is at 0.0 and the richest at 1.0.
+    fn means_tested_persons(&self, persons: &[usize]) -> Vec<usize> {
+        let mut ranked: Vec<(usize, f64)> = persons
+            .iter()
+            .map(|&idx| {
+                (idx, self.entity_to_base_currency(self.entities[idx].person_data.money, idx))
+            })
+            .collect();
+        ranked.sort_by(|a, b| a.1.total_cmp(&b.1).then(a.0.cmp(&b.0)));
+        let count = ranked.len();
+        ranked
+            .into_iter()
+            .enumerate()
+            .filter(|&(rank, _)| {
+                let percentile = if count > 1 {
+                    rank as f64 / (count - 1) as f64
+                } else {
+                    0.5
+                };
+                percentile < self.config.means_test_percentile
+            })
+            .map(|(_, (idx, _))| idx)
+            .collect()
+    }
+
+    /// Runs one step of the government budget.
+    ///
+    /// The government receives the taxes collected during the step, levies income
+    /// tax on each person's sales and wealth tax on their money and savings, and
+    /// then pays for public goods, unemployment benefits and class transfers. When
+    /// the treasury and the remaining borrowing capacity cannot cover every program,
+    /// all programs are scaled down by the same share. Finally it pays interest on
+    /// its debt at the loan interest rate and repays debt out of any surplus.
+    fn process_government(&mut self) {
+        let step = self.current_step;
+
+        // Taxes collected by the other phases; sick pay already drew on them
+        let step_taxes = (self.total_taxes_collected
+            - self.step_tally.taxes_collected_start
+            - self.step_tally.sick_pay)
+            .max(0.0);
+        let vat = self.step_tally.vat.min(step_taxes);
+        self.government.vat_revenue += vat;
+        self.government.trade_tax_revenue += step_taxes - vat;
+        let mut revenue = step_taxes;
+
+        // Income and wealth taxes, taken from each person's cash
+        let mut income_tax = 0.0;
+        let mut wealth_tax = 0.0;
+        for idx in 0..self.entities.len() {
+            if !self.entities[idx].active {
+                continue;
+            }
+            let person = &self.entities[idx].person_data;
+            let income = person.sales_in_step(step);
+            let wealth = self.entity_to_base_currency(person.money + person.savings, idx);
+            let on_income = government::progressive_tax(income, &self.config.income_tax_brackets);
+            let on_wealth = government::wealth_tax(
+                wealth,
+                self.config.wealth_tax_threshold,
+                self.config.wealth_tax_rate,
+            );
+            if on_income + on_wealth > 0.0 {
+                self.entities[idx].person_data.money -=
+                    self.base_to_entity_currency(on_income + on_wealth, idx);
+                income_tax += on_income;
+                wealth_tax += on_wealth;
+            }
+        }
+        self.total_taxes_collected += income_tax + wealth_tax;
+        self.government.income_tax_revenue += income_tax;
+        self.government.wealth_tax_revenue += wealth_tax;
+        revenue += income_tax + wealth_tax;
+        self.government.treasury += revenue;
+
+        // What each program would cost in full
+        if !self.config.class_transfers.is_empty() {
+            self.update_social_classes(step);
+        }
+        let workers: Vec<usize> = (0..self.entities.len())
+            .filter(|&idx| self.entities[idx].active && !self.entities[idx].person_data.retired)
+            .collect();
+        let unemployed: Vec<usize> = workers
+            .iter()
+            .copied()
+            .filter(|&idx| self.entities[idx].person_data.is_unemployed(step))
+            .collect();
+        let transfers: Vec<(usize, crate::person::SocialClass, f64)> = (0..self.entities.len())
+            .filter(|&idx| self.entities[idx].active)
+            .filter_map(|idx| {
+                let class = self.entities[idx].person_data.social_class;
+                self.config.class_transfers.get(&class).map(|&amount| (idx, class, amount))
+            })
+            .filter(|&(_, _, amount)| amount > 0.0)
+            .collect();
+        let public_goods = if workers.is_empty() {
+            0.0
+        } else {
+            self.config.public_goods_spending
+        };
+        let benefits = self.config.unemployment_benefit * unemployed.len() as f64;
+        let transfer_total: f64 = transfers.iter().map(|&(_, _, amount)| amount).sum();
+        let wanted = public_goods + benefits + transfer_total;
+
+        // Scale every program down when the budget cannot cover them all
+        let capacity = self.government.spending_capacity(self.config.government_debt_limit);
+        let scale = if wanted > capacity {
+            capacity / wanted
+        } else {
+            1.0
+        };
+        let public_goods = public_goods * scale;
+        if public_goods > 0.0 {
+            // Public works pay working-age persons and public goods raise productivity
+            let per_worker = public_goods / workers.len() as f64;
+            for &idx in &workers {
+                let amount = self.base_to_entity_currency(per_worker, idx);
+                self.entities[idx].person_data.money += amount;
+            }
+            let boost = 1.0
+                + self.config.public_goods_efficiency * public_goods
+                    / (workers.len() as f64 * self.config.base_skill_price);
+            for skill in self.market.skills.values_mut() {
+                skill.efficiency_multiplier *= boost;
+            }
+            if let Some(ref mut bm) = self.black_market {
+                for skill in bm.skills.values_mut() {
+                    skill.efficiency_multiplier *= boost;
+                }
+            }
+            self.government.public_goods_spending += public_goods;
+        }
+        let benefit = self.config.unemployment_benefit * scale;
+        if benefit > 0.0 {
+            for &idx in &unemployed {
+                let amount = self.base_to_entity_currency(benefit, idx);
+                self.entities[idx].person_data.money += amount;
+            }
+            self.government.unemployment_benefits_paid += benefit * unemployed.len() as f64;
+        }
+        for (idx, class, amount) in transfers {
+            let paid = amount * scale;
+            if paid > 0.0 {
+                let converted = self.base_to_entity_currency(paid, idx);
+                self.entities[idx].person_data.money += converted;
+                *self.government.class_transfers_paid.entry(class).or_insert(0.0) += paid;
+            }
+        }
+        let spending = wanted * scale;
+        self.government.spend(spending);
+
+        let interest = self.government.service_debt(self.config.loan_interest_rate);
+        if scale < 1.0 {
+            debug!(
+                "Government spending cut to {:.0}% at step {} (debt ${:.2})",
+                scale * 100.0,
+                step,
+                self.government.debt
+            );
+        }
+
+        self.government.history.push(BudgetSnapshot {
+            step,
+            revenue,
+            spending,
+            interest,
+            balance: revenue - spending - interest,
+            debt: self.government.debt,
+        });
+    }
+
+    /// Applies one step of population change.
+    ///
+    /// Persons age and retire, retirees may die and persons who have been broke for
+    /// `exit_after_broke_steps` steps leave the market. Working-age persons may then
+    /// have children and immigrants arrive. Persons are visited in index order so
+    /// that runs stay reproducible.
+    fn process_demographics(&mut self) {
+        let mut departures = Vec::new();
+        for idx in 0..self.entities.len() {
+            if !self.entities[idx].active {
+                continue;
+            }
+            let money = self.entity_to_base_currency(self.entities[idx].person_data.money, idx);
+            let savings = self.entity_to_base_currency(self.entities[idx].person_data.savings, idx);
+            let person = &mut self.entities[idx].person_data;
+            person.age += 1;
+            if demographics::is_broke(money, savings, self.config.min_skill_price) {
+                person.broke_steps += 1;
+            } else {
+                person.broke_steps = 0;
+            }
+            let broke_steps = person.broke_steps;
+
+            if !person.retired && person.age >= self.config.retirement_age {
+                self.retire(idx);
+            }
+            if self.entities[idx].person_data.retired
+                && self.rng.random_range(0.0..1.0) < self.config.mortality_rate
+            {
+                departures.push((idx, Departure::Death));
+            } else if self.config.exit_after_broke_steps > 0
+                && broke_steps >= self.config.exit_after_broke_steps
+            {
+                departures.push((idx, Departure::MarketExit));
+            }
+        }
+        for (idx, departure) in departures {
+            self.remove_person(idx, departure);
+        }
+
+        let parents: Vec<usize> = (0..self.entities.len())
+            .filter(|&idx| self.entities[idx].active && !self.entities[idx].person_data.retired)
+            .collect();
+        for parent_idx in parents {
+            if self.rng.random_range(0.0..1.0) < self.config.birth_rate {
+                self.add_child(parent_idx);
+            }
+        }
+
+        let draw = self.rng.random_range(0.0..1.0);
+        for _ in 0..demographics::arrivals(self.config.immigration_rate, draw) {
+            self.add_immigrant();
+        }
+
+        let population = self.get_active_entity_count();
+        self.demographics.population_history.push(population);
+    }
+
+    /// Retires a person: they keep buying but stop selling their skills.
+    fn retire(&mut self, idx: usize) {
+        self.entities[idx].person_data.retired = true;
+        // Employees already stopped selling when they were hired
+        if !self.entities[idx].person_data.is_employed() {
+            self.withdraw_skills(idx);
+        }
+        self.demographics.retirements += 1;
+        debug!(
+            "Person {} retired at age {}",
+            self.entities[idx].id, self.entities[idx].person_data.age
+        );
+    }
+
+    /// Removes a person from the providers of every skill they sell.
+    fn withdraw_skills(&mut self, idx: usize) {
+        let person = &self.entities[idx].person_data;
+        for skill in person.own_skills.iter().chain(&person.learned_skills) {
+            if let Some(providers) = self.skill_providers.get_mut(&skill.id) {
+                providers.retain(|&provider| provider != idx);
+            }
+        }
+        // Supply is only counted for the skills persons start with
+        for skill in &person.own_skills {
+            self.market.decrement_skill_supply(&skill.id);
+        }
+    }
+
+    /// Registers a person as a provider of a skill they just acquired.
+    ///
+    /// Retired persons and employees do not sell, so they are left out.
+    fn add_skill_provider(&mut self, skill_id: &SkillId, idx: usize) {
+        if self.entities[idx].person_data.sells_skills() {
+            self.skill_providers
+                .entry(skill_id.clone())
+                .or_default()
+                .push(self.entities[idx].id);
+        }
+    }
+
+    /// Takes a person out of the simulation and settles their estate.
+    ///
+    /// The person stops trading and is removed from the skill providers and from
+    /// their friends and the trust network. Their money and savings first repay their
+    /// loans. The rest of the estate then passes to their active children: the money
+    /// is split equally, and the eldest child takes over the loans, investments and
+    /// assets the person held. Without children the money goes to the tax pool, the
+    /// loans they lent are written off and their investments and assets are dropped.
+    fn remove_person(&mut self, idx: usize, departure: Departure) {
+        let person = &self.entities[idx].person_data;
+        if !person.retired && !person.is_employed() {
+            self.withdraw_skills(idx);
+        }
+        self.entities[idx].active = false;
+        let person_id = self.entities[idx].id;
+
+        let friends: Vec<PersonId> = self.entities[idx].person_data.friends.drain().collect();
+        for friend in friends {
+            self.entities[friend].person_data.friends.remove(&person_id);
+        }
+        if let Some(ref mut trust_network) = self.trust_network {
+            trust_network.remove_person(person_id);
+        }
+        for policy_id in &self.entities[idx].person_data.insurance_policies {
+            if let Some(policy) = self.insurances.get_mut(policy_id) {
+                policy.deactivate();
+            }
+        }
+
+        let person = &mut self.entities[idx].person_data;
+        person.money += person.savings;
+        person.savings = 0.0;
+        self.settle_estate_debts(idx);
+
+        let heirs: Vec<usize> = (0..self.entities.len())
+            .filter(|&heir| {
+                self.entities[heir].active
+                    && self.entities[heir].person_data.parent_id == Some(person_id)
+            })
+            .collect();
+        self.pass_on_estate(idx, &heirs);
+
+        match departure {
+            Departure::Death => self.demographics.deaths += 1,
+            Departure::MarketExit => self.demographics.market_exits += 1,
+        }
+        debug!(
+            "Person {} left the simulation ({:?}) with {} heirs",
+            person_id,
+            departure,
+            heirs.len()
+        );
+    }
+
+    /// Repays a departing person's loans out of their money.
+    ///
+    /// Loans the estate covers count as repaid; any shortfall is written off by the
+    /// lender and the loan counts as defaulted.
+    fn settle_estate_debts(&mut self, idx: usize) {
+        let mut loan_ids = self.entities[idx].person_data.borrowed_loans.clone();
+        loan_ids.sort_unstable();
+        for loan_id in loan_ids {
+            let Some(loan) = self.loans.get(&loan_id) else {
+                continue;
+            };
+            let outstanding = loan.outstanding_balance();
+            let lender_idx = loan.lender_id;
+            let estate = self.entity_to_base_currency(self.entities[idx].person_data.money, idx);
+            let payment = outstanding.min(estate.max(0.0));
+            self.entities[idx].person_data.money -= self.base_to_entity_currency(payment, idx);
+            self.entities[lender_idx].person_data.money +=
+                self.base_to_entity_currency(payment, lender_idx);
+
+            let shortfall = outstanding - payment;
+            if shortfall > 0.0 {
+                self.loan_defaults.defaults += 1;
+            } else {
+                self.total_loans_repaid += 1;
+            }
+            self.write_off_loan(loan_id, shortfall);
+        }
+    }
+
+    /// Passes a departing person's money, loans, investments and assets on to their
+    /// heirs, or to the tax pool when there are none.
+    fn pass_on_estate(&mut self, idx: usize, heirs: &[usize]) {
+        let estate = self.entities[idx].person_data.money.max(0.0);
+        let estate_base = self.entity_to_base_currency(estate, idx);
+        let person = &mut self.entities[idx].person_data;
+        person.money -= estate;
+        let mut lent_loans = std::mem::take(&mut person.lent_loans);
+        lent_loans.sort_unstable();
+        let mut investments = std::mem::take(&mut person.active_investments);
+        investments.sort_unstable();
+        let assets = std::mem::take(&mut person.owned_assets);
+
+        let Some(&heir_idx) = heirs.first() else {
+            self.total_taxes_collected += estate_base;
+            self.demographics.estates_to_tax_pool += 1;
+            self.demographics.estate_tax_pool_money += estate_base;
+            for loan_id in lent_loans {
+                let outstanding =
+                    self.loans.get(&loan_id).map_or(0.0, |loan| loan.outstanding_balance());
+                self.write_off_loan(loan_id, outstanding);
+            }
+            for investment_id in investments {
+                self.investments.remove(&investment_id);
+            }
+            for asset_id in assets {
+                self.assets.remove(&asset_id);
+            }
+            return;
+        };
+
+        let share = estate_base / heirs.len() as f64;
+        for &heir in heirs {
+            let amount = self.base_to_entity_currency(share, heir);
+            self.entities[heir].person_data.money += amount;
+        }
+        self.demographics.estates_to_heirs += 1;
+        self.demographics.inherited_money += estate_base;
+
+        let heir_id = self.entities[heir_idx].id;
+        for loan_id in lent_loans {
+            if let Some(loan) = self.loans.get_mut(&loan_id) {
+                loan.lender_id = heir_id;
+                self.entities[heir_idx].person_data.lent_loans.push(loan_id);
+            }
+        }
+        for investment_id in investments {
+            if let Some(investment) = self.investments.get_mut(&investment_id) {
+                investment.investor_id = heir_id;
+                self.entities[heir_idx].person_data.active_investments.push(investment_id);
             }
-            // Note: If borrower can't afford payment, they skip it (could add penalties later)
         }
+        for asset_id in assets {
+            if let Some(asset) = self.assets.get_mut(&asset_id) {
+                asset.owner_id = heir_id;
+                self.entities[heir_idx].person_data.owned_assets.push(asset_id);
+            }
+        }
+    }
 
-        // Remove completed loans and update statistics
-        for loan_id in completed_loans {
-            let loan = self.loans.remove(&loan_id).unwrap();
+    /// Adds a child of the person at `parent_idx`.
+    ///
+    /// The child inherits the parent's skills, strategy, location, currency and group
+    /// and receives up to `initial_money_per_person` from the parent, at most half of
+    /// the parent's money.
+    fn add_child(&mut self, parent_idx: usize) {
+        let endowment_cap =
+            self.base_to_entity_currency(self.config.initial_money_per_person, parent_idx);
+        let parent = &self.entities[parent_idx].person_data;
+        let endowment = endowment_cap.min(parent.money * 0.5).max(0.0);
+        let skills = parent.own_skills.clone();
+        let strategy = parent.strategy;
+        let location = parent.location;
+        let discount_factor = parent.discount_factor;
+        let currency_id = parent.currency_id.clone();
+        let group_id = parent.group_id;
+        let region = parent.region;
+        let parent_id = parent.id;
+
+        self.entities[parent_idx].person_data.money -= endowment;
+        let idx = self.admit_person(skills, strategy, location, discount_factor);
+        let child = &mut self.entities[idx].person_data;
+        child.money = endowment;
+        child.currency_id = currency_id;
+        child.group_id = group_id;
+        child.region = region;
+        child.parent_id = Some(parent_id);
+        self.demographics.births += 1;
+        trace!("Person {} was born to person {}", idx, parent_id);
+    }
 
-            // Remove loan from person tracking
-            self.entities[loan.borrower_id]
-                .person_data
-                .borrowed_loans
-                .retain(|&id| id != loan_id);
-            self.entities[loan.lender_id].person_data.lent_loans.retain(|&id| id != loan_id);
+    /// Adds an immigrant with `skills_per_person` random skills from the market.
+    ///
+    /// Immigrants bring `initial_money_per_person` of new money with them.
+    fn add_immigrant(&mut self) {
+        let mut skill_ids = self.all_skill_ids.clone();
+        skill_ids.shuffle(&mut self.rng);
+        skill_ids.truncate(self.config.skills_per_person);
+        let skills: Vec<Skill> =
+            skill_ids.iter().filter_map(|id| self.market.skills.get(id).cloned()).collect();
+        if skills.is_empty() {
+            return;
+        }
 
-            self.total_loans_repaid += 1;
+        let strategies = Strategy::all_variants();
+        let strategy = strategies[self.entities.len() % strategies.len()];
+        let location = crate::person::Location::new(
+            self.rng.random_range(0.0..=100.0),
+            self.rng.random_range(0.0..=100.0),
+        );
+        let discount_factor = Self::sample_discount_factor(&self.config, &mut self.rng);
+        let idx = self.admit_person(skills, strategy, location, discount_factor);
+
+        if self.config.enable_multi_currency {
+            let currency_ids = self.config.currency_system.sorted_ids();
+            self.entities[idx].person_data.currency_id =
+                currency_ids[idx % currency_ids.len()].clone();
+        }
+        if let Some(num_groups) = self.config.num_groups {
+            self.entities[idx].person_data.group_id = Some(idx % num_groups);
+        }
+        let money = self.config.initial_money_per_person;
+        self.entities[idx].person_data.money = self.base_to_entity_currency(money, idx);
+        self.demographics.immigrants += 1;
+        self.demographics.immigrant_money += money;
+        trace!("Person {} immigrated", idx);
+    }
+
+    /// Appends a new person without money to the population and registers them with
+    /// the market, the skill providers and the trust network.
+    ///
+    /// Returns the new person's index, which is also their ID.
+    fn admit_person(
+        &mut self,
+        skills: Vec<Skill>,
+        strategy: Strategy,
+        location: crate::person::Location,
+        discount_factor: f64,
+    ) -> usize {
+        let idx = self.entities.len();
+        let mut entity = Entity::new(idx, 0.0, skills, strategy, location, discount_factor);
+        entity.person_data.region = geography::nearest_region(&self.config.regions, &location);
+        if self.config.enable_specialization {
+            let specializations = crate::person::SpecializationStrategy::all_variants();
+            entity.person_data.specialization_strategy =
+                specializations[idx % specializations.len()];
+        }
+        for skill in &entity.person_data.own_skills {
+            self.market.increment_skill_supply(&skill.id);
+            self.skill_providers.entry(skill.id.clone()).or_default().push(idx);
+            if self.config.enable_quality {
+                entity
+                    .person_data
+                    .skill_qualities
+                    .insert(skill.id.clone(), self.config.initial_quality);
+            }
+        }
+        if let Some(ref mut trust_network) = self.trust_network {
+            trust_network.add_person(idx);
+        }
+        self.entities.push(entity);
+        idx
+    }
+
+    /// Moves each person who considers leaving, with probability `migration_rate`, to
+    /// the region `geography::choose_destination` picks for them.
+    ///
+    /// Prospects are the average income of each region's residents in this step.
+    /// Migrants settle within `SETTLEMENT_RADIUS` of their new region's center.
+    fn process_migration(&mut self) {
+        let step = self.current_step;
+        let mut income = vec![0.0; self.config.regions.len()];
+        let mut residents = vec![0usize; self.config.regions.len()];
+        for entity in self.entities.iter().filter(|e| e.active) {
+            if let Some(region) = entity.person_data.region {
+                income[region] += entity.person_data.sales_in_step(step);
+                residents[region] += 1;
+            }
+        }
+        let prospects: Vec<f64> = income
+            .iter()
+            .zip(&residents)
+            .map(|(&total, &count)| if count > 0 { total / count as f64 } else { 0.0 })
+            .collect();
+
+        let radius = geography::SETTLEMENT_RADIUS;
+        for idx in 0..self.entities.len() {
+            let Some(home) = self.entities[idx].person_data.region else {
+                continue;
+            };
+            if !self.entities[idx].active || self.rng.random::<f64>() >= self.config.migration_rate
+            {
+                continue;
+            }
+            let Some(destination) = geography::choose_destination(
+                home,
+                &prospects,
+                &self.transport_network,
+                self.config.migration_threshold,
+            ) else {
+                continue;
+            };
+            let center = self.config.regions[destination].center();
+            let location = crate::person::Location::new(
+                (center.x + self.rng.random_range(-radius..=radius)).clamp(0.0, 100.0),
+                (center.y + self.rng.random_range(-radius..=radius)).clamp(0.0, 100.0),
+            );
+            let person = &mut self.entities[idx].person_data;
+            person.region = Some(destination);
+            person.location = location;
+            self.region_ledgers[home].emigrants += 1;
+            self.region_ledgers[destination].immigrants += 1;
+            trace!(
+                "Person {} moved from {} to {}",
+                idx,
+                self.config.regions[home].name,
+                self.config.regions[destination].name
+            );
         }
     }
 
@@ -5034,9 +8718,11 @@ impl SimulationEngine {
 
     /// Processes credit insurance payouts when loan defaults occur.
     ///
-    /// When a borrower faces financial distress (low money relative to outstanding debt),
-    /// their credit insurance provides funds to help pay off loans, reducing default risk.
-    /// This protects borrowers from defaulting and indirectly protects lenders from losses.
+    /// When a borrower faces financial distress (low money relative to outstanding debt,
+    /// or a loan already behind on payments), their credit insurance provides funds to
+    /// help pay off loans, reducing default risk. This protects borrowers from defaulting
+    /// and indirectly protects lenders from losses. Policies not claimed here pay the
+    /// lender directly once a loan defaults.
     fn process_credit_insurance_payouts(&mut self) {
         if !self.config.enable_insurance || !self.config.enable_loans {
             return;
@@ -5055,10 +8741,19 @@ impl SimulationEngine {
                 continue;
             }
 
-            // Check if person has borrowed loans and very low money (potential default)
+            // Check if person has borrowed loans and very low money or missed payments
+            // (potential default)
             let has_borrowed_loans = !entity.person_data.borrowed_loans.is_empty();
+            let behind_on_payments = entity
+                .person_data
+                .borrowed_loans
+                .iter()
+                .filter_map(|loan_id| self.loans.get(loan_id))
+                .any(|loan| loan.missed_payments > 0);
 
-            if has_borrowed_loans && entity.person_data.money < distress_threshold {
+            if has_borrowed_loans
+                && (entity.person_data.money < distress_threshold || behind_on_payments)
+            {
                 // Person is at risk of defaulting - check for credit insurance
                 // Calculate potential loss as remaining debt
                 let total_debt: f64 = entity
@@ -5720,8 +9415,7 @@ impl SimulationEngine {
         if self.entities.is_empty() {
             return 0.0;
         }
-        let total_money: f64 =
-            self.entities.iter().filter(|e| e.active).map(|e| e.person_data.money).sum();
+        let total_money: f64 = self.active_money_in_base_currency().iter().sum();
         let active_count = self.entities.iter().filter(|e| e.active).count();
         if active_count == 0 {
             return 0.0;
@@ -5743,7 +9437,7 @@ impl SimulationEngine {
             .iter()
             .enumerate()
             .filter(|(_, e)| e.active)
-            .map(|(idx, e)| (idx, e.person_data.money))
+            .map(|(idx, e)| (idx, self.entity_to_base_currency(e.person_data.money, idx)))
             .filter(|(_, money)| money.is_finite()) // Filter out NaN and infinite values
             .collect();
 
@@ -5790,7 +9484,7 @@ impl SimulationEngine {
             .iter()
             .enumerate()
             .filter(|(_, e)| e.active)
-            .map(|(idx, e)| (idx, e.person_data.money))
+            .map(|(idx, e)| (idx, self.entity_to_base_currency(e.person_data.money, idx)))
             .filter(|(_, money)| money.is_finite()) // Filter out NaN and infinite values
             .collect();
 
@@ -5974,18 +9668,127 @@ impl SimulationEngine {
         self.total_fees_collected
     }
 
+    /// Get the total learning costs investors have paid for education investments.
+    pub fn get_total_investment_education_spending(&self) -> f64 {
+        self.total_investment_education_spending
+    }
+
+    /// Get the total platform fees collected by the P2P lending marketplace so far.
+    pub fn get_total_lending_fees_collected(&self) -> f64 {
+        self.lending_marketplace
+            .as_ref()
+            .map_or(0.0, |marketplace| marketplace.total_fees_collected)
+    }
+
+    /// Get the total money immigrants have brought into the simulation so far.
+    pub fn get_total_immigrant_money(&self) -> f64 {
+        self.demographics.immigrant_money
+    }
+
+    /// Get the total sick pay paid out of the tax pool so far.
+    pub fn get_total_sick_pay(&self) -> f64 {
+        self.epidemic.sick_pay_paid
+    }
+
+    /// Get the cash currently held by firms.
+    pub fn get_total_firm_cash(&self) -> f64 {
+        self.firms.iter().map(|firm| firm.cash).sum()
+    }
+
+    /// Get the net money the central bank has issued so far (created less withdrawn).
+    pub fn get_total_central_bank_money_issued(&self) -> f64 {
+        self.central_bank.net_money_issued()
+    }
+
+    /// Get the central bank's current policy rate.
+    pub fn get_policy_rate(&self) -> f64 {
+        self.central_bank.policy_rate
+    }
+
+    /// Get the money and savings held by active persons.
+    pub fn get_money_supply(&self) -> f64 {
+        self.entities
+            .iter()
+            .filter(|e| e.active)
+            .map(|e| e.person_data.money + e.person_data.savings)
+            .sum()
+    }
+
+    /// Get the total taxes redistributed to persons so far.
+    pub fn get_total_taxes_redistributed(&self) -> f64 {
+        self.total_taxes_redistributed
+    }
+
+    /// Get the money created so far to pay for transfers.
+    pub fn get_total_transfer_money_created(&self) -> f64 {
+        self.transfer_ledger.money_created
+    }
+
+    /// Get the money and savings held by all persons, in the base currency.
+    pub fn get_total_person_money(&self) -> f64 {
+        self.total_person_money_in_base_currency()
+    }
+
+    /// Get the base value persons' holdings gained (positive) or lost (negative) as
+    /// exchange rates moved.
+    pub fn get_total_exchange_revaluation(&self) -> f64 {
+        self.foreign_exchange.as_ref().map_or(0.0, |fx| fx.revaluation)
+    }
+
+    /// Get the net money created (positive) or destroyed (negative) by custom crises.
+    pub fn get_total_crisis_money_change(&self) -> f64 {
+        self.crisis_ledger.money_changed
+    }
+
+    /// Get the money the government has paid to persons so far.
+    pub fn get_total_government_spending(&self) -> f64 {
+        self.government.total_spending()
+    }
+
+    /// Get the government's outstanding debt.
+    pub fn get_government_debt(&self) -> f64 {
+        self.government.debt
+    }
+
+    /// Get the firms founded so far, including bankrupt ones.
+    pub fn get_firms(&self) -> &[Firm] {
+        &self.firms
+    }
+
+    /// Register a custom price update strategy on the market.
+    ///
+    /// The strategy replaces the scenario's built-in price updater for all following steps.
+    /// See [`crate::scenario::PriceUpdateStrategy`].
+    pub fn set_price_strategy(&mut self, strategy: Box<dyn crate::scenario::PriceUpdateStrategy>) {
+        self.market.set_price_strategy(strategy);
+    }
+
     /// Get the total taxes collected so far.
     pub fn get_total_taxes_collected(&self) -> f64 {
         self.total_taxes_collected
     }
 
+    /// Get the net money injected by plugins through mutating hooks, in the base currency.
+    pub fn get_total_plugin_money_injected(&self) -> f64 {
+        self.total_plugin_money_injected
+    }
+
+    /// Get a loan by ID (if it exists).
+    pub fn get_loan(&self, loan_id: crate::loan::LoanId) -> Option<&Loan> {
+        self.loans.get(&loan_id)
+    }
+
+    /// Get the voting system (if voting is enabled).
+    pub fn get_voting_system(&self) -> Option<&crate::voting::VotingSystem> {
+        self.voting_system.as_ref()
+    }
+
     /// Get the current simulation result snapshot
     /// This creates a simplified SimulationResult for display in interactive mode
     /// Note: Some complex statistics are omitted for simplicity
     pub fn get_current_result(&self) -> SimulationResult {
         // Collect money distribution
-        let mut final_money_distribution: Vec<f64> =
-            self.entities.iter().filter(|e| e.active).map(|e| e.person_data.money).collect();
+        let mut final_money_distribution = self.active_money_in_base_currency();
         final_money_distribution
             .sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
 
@@ -6124,6 +9927,8 @@ impl SimulationEngine {
             total_steps: self.current_step,
             total_duration: 0.0, // Not meaningful in interactive mode
             step_times: vec![],  // Not tracked in interactive mode
+            phase_timings: self.phase_timings.clone(),
+            profile: self.get_profile_report(),
             active_persons: self.entities.iter().filter(|e| e.active).count(),
             failed_steps: self.failed_steps,
             final_money_distribution,
@@ -6167,20 +9972,25 @@ impl SimulationEngine {
             },
             failed_attempts_per_step: self.failed_attempts_per_step.clone(),
             black_market_statistics: None,
-            total_taxes_collected: if self.config.tax_rate > 0.0 {
+            // Taxes may have been collected before a vote lowered the rate to zero
+            total_taxes_collected: if self.config.tax_rate > 0.0 || self.total_taxes_collected > 0.0
+            {
                 Some(self.total_taxes_collected)
             } else {
                 None
             },
             total_taxes_redistributed: if self.config.enable_tax_redistribution
-                && self.config.tax_rate > 0.0
+                && (self.config.tax_rate > 0.0 || self.total_taxes_collected > 0.0)
             {
                 Some(self.total_taxes_redistributed)
             } else {
                 None
             },
-            loan_statistics: None,       // Simplified
-            investment_statistics: None, // Simplified for interactive mode
+            loan_statistics: self.calculate_loan_statistics(),
+            lending_marketplace_statistics: self.calculate_lending_marketplace_statistics(),
+            investment_statistics: self.calculate_investment_statistics(),
+            currency_statistics: self.calculate_currency_statistics(),
+            auction_statistics: self.calculate_auction_statistics(),
             contract_statistics: None,
             education_statistics: None,
             mentorship_statistics: None,
@@ -6191,8 +10001,9 @@ impl SimulationEngine {
             influence_statistics: None,   // Simplified
             trust_network_statistics: None, // Simplified
             trade_agreement_statistics: None, // Simplified
-            insurance_statistics: None,   // Simplified
-            asset_statistics: None,       // Simplified for interactive mode
+            voting_statistics: self.voting_system.as_ref().map(|v| v.statistics()),
+            insurance_statistics: None,               // Simplified
+            asset_statistics: None,                   // Simplified for interactive mode
             technology_breakthrough_statistics: None, // Simplified
             group_statistics: None,
             trading_partner_statistics: crate::result::TradingPartnerStats {
@@ -6207,6 +10018,15 @@ impl SimulationEngine {
             mobility_statistics: crate::result::calculate_mobility_statistics(
                 &self.mobility_quintiles,
             ),
+            demographics_statistics: self.calculate_demographics_statistics(),
+            epidemic_statistics: self.calculate_epidemic_statistics(),
+            firm_statistics: self.calculate_firm_statistics(),
+            monetary_policy_statistics: self.calculate_monetary_policy_statistics(),
+            government_statistics: self.calculate_government_statistics(),
+            transfer_statistics: self.calculate_transfer_statistics(),
+            timeline_statistics: self.calculate_timeline_statistics(),
+            custom_crisis_statistics: self.calculate_custom_crisis_statistics(),
+            geography_statistics: self.calculate_geography_statistics(),
             social_class_statistics: self.calculate_social_class_statistics(),
             quality_statistics: None, // Simplified for interactive mode
             strategy_evolution_statistics: None, // Simplified for interactive mode
@@ -6256,10 +10076,108 @@ impl SimulationEngine {
     pub fn save_checkpoint<P: AsRef<Path>>(&self, path: P) -> std::io::Result<()> {
         info!("Saving checkpoint at step {} to {:?}", self.current_step, path.as_ref());
 
-        let checkpoint = SimulationCheckpoint {
+        let file = File::create(path.as_ref())?;
+        let writer = BufWriter::new(file);
+        serde_json::to_writer_pretty(writer, &self.to_checkpoint())?;
+        // A delta log left by an earlier incremental run no longer matches this file
+        crate::checkpoint::remove_delta_log(path.as_ref())?;
+
+        debug!("Checkpoint saved successfully");
+        Ok(())
+    }
+
+    /// Saves the current simulation state as an incremental checkpoint.
+    ///
+    /// The first call (and every call after `checkpoint_rebase_interval` deltas or with
+    /// a different path) writes a full base snapshot to `path`. Later calls only append
+    /// the changes since the previous checkpoint to the delta log `<path>.deltas`, so
+    /// each save costs roughly the size of what changed rather than the whole state.
+    ///
+    /// [`SimulationEngine::load_checkpoint`] restores the latest checkpointed step;
+    /// earlier steps can be restored with [`SimulationEngine::load_checkpoint_at_step`].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use community_simulation::{SimulationConfig, SimulationEngine};
+    ///
+    /// let mut engine = SimulationEngine::new(SimulationConfig::default());
+    /// for _ in 0..10 {
+    ///     for _ in 0..100 {
+    ///         engine.step();
+    ///     }
+    ///     engine.save_checkpoint_incremental("checkpoint.json").expect("Failed to save checkpoint");
+    /// }
+    /// ```
+    pub fn save_checkpoint_incremental<P: AsRef<Path>>(&mut self, path: P) -> std::io::Result<()> {
+        let path = path.as_ref();
+        let state = serde_json::to_value(self.to_checkpoint())?;
+        let rebase_interval = self.config.checkpoint_rebase_interval;
+
+        match self.incremental_checkpointer.as_mut() {
+            Some(checkpointer) if checkpointer.can_append(path, rebase_interval) => {
+                debug!("Appending checkpoint delta for step {} to {:?}", self.current_step, path);
+                checkpointer.append(state, self.current_step)?;
+            },
+            _ => {
+                info!("Saving base checkpoint at step {} to {:?}", self.current_step, path);
+                self.incremental_checkpointer =
+                    Some(IncrementalCheckpointer::start(path, state, self.current_step)?);
+            },
+        }
+        Ok(())
+    }
+
+    /// Takes an in-memory snapshot of the current simulation state.
+    ///
+    /// The random number generator is not part of the snapshot: every step reseeds it
+    /// from the seed and the step number (see [`SimulationEngine::step`]), so taking a
+    /// snapshot does not change the run. Use [`SimulationEngine::restore_snapshot`] to
+    /// go back to it.
+    pub fn snapshot(&self) -> EngineSnapshot {
+        EngineSnapshot { state: self.to_checkpoint() }
+    }
+
+    /// Restores the simulation state of an in-memory snapshot.
+    ///
+    /// Registered plugins, custom phases, invariant checks and the open streaming
+    /// output are kept.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use community_simulation::{SimulationConfig, SimulationEngine};
+    ///
+    /// let config = SimulationConfig { entity_count: 10, max_steps: 20, ..Default::default() };
+    /// let mut engine = SimulationEngine::new(config);
+    /// engine.step();
+    /// let snapshot = engine.snapshot();
+    /// for _ in 0..5 {
+    ///     engine.step();
+    /// }
+    /// engine.restore_snapshot(&snapshot);
+    /// assert_eq!(engine.get_current_step(), 1);
+    /// ```
+    pub fn restore_snapshot(&mut self, snapshot: &EngineSnapshot) {
+        let mut state = snapshot.state.clone();
+        // Keep appending to the open stream instead of truncating the file
+        let stream_output_path = state.config.stream_output_path.take();
+        let mut restored = Self::from_checkpoint(state);
+        restored.config.stream_output_path = stream_output_path;
+        restored.stream_writer = self.stream_writer.take();
+        restored.plugin_registry = std::mem::take(&mut self.plugin_registry);
+        restored.custom_phases = std::mem::take(&mut self.custom_phases);
+        restored.invariant_checker = self.invariant_checker.take();
+        *self = restored;
+    }
+
+    /// Captures all persisted state of the engine.
+    fn to_checkpoint(&self) -> SimulationCheckpoint {
+        SimulationCheckpoint {
+            schema_version: crate::checkpoint::CHECKPOINT_SCHEMA_VERSION,
             config: self.config.clone(),
             entities: self.entities.clone(),
-            market: self.market.clone(),
+            market: self.market.clone_for_checkpoint(),
             black_market: self.black_market.clone(),
             current_step: self.current_step,
             all_skill_ids: self.all_skill_ids.clone(),
@@ -6268,12 +10186,25 @@ impl SimulationEngine {
             black_market_trades_per_step: self.black_market_trades_per_step.clone(),
             black_market_volume_per_step: self.black_market_volume_per_step.clone(),
             total_fees_collected: self.total_fees_collected,
+            total_plugin_money_injected: self.total_plugin_money_injected,
+            auction_stats: self.auction_stats.clone(),
             failed_steps: self.failed_steps,
             failed_trade_attempts: self.failed_trade_attempts,
             failed_attempts_per_step: self.failed_attempts_per_step.clone(),
             loans: self.loans.clone(),
             total_loans_issued: self.total_loans_issued,
             total_loans_repaid: self.total_loans_repaid,
+            loan_defaults: self.loan_defaults.clone(),
+            investments: self.investments.clone(),
+            total_investments_created: self.total_investments_created,
+            total_investments_completed: self.total_investments_completed,
+            total_investments_defaulted: self.total_investments_defaulted,
+            total_invested: self.total_invested,
+            total_investment_returns: self.total_investment_returns,
+            total_investment_losses: self.total_investment_losses,
+            total_investment_education_spending: self.total_investment_education_spending,
+            completed_investment_roi_sum: self.completed_investment_roi_sum,
+            foreign_exchange: self.foreign_exchange.clone(),
             lending_marketplace: self.lending_marketplace.clone(),
             total_taxes_collected: self.total_taxes_collected,
             total_taxes_redistributed: self.total_taxes_redistributed,
@@ -6287,6 +10218,29 @@ impl SimulationEngine {
             min_money: self.min_money,
             max_money: self.max_money,
             mobility_quintiles: self.mobility_quintiles.clone(),
+            demographics: self.demographics.clone(),
+            epidemic: self.epidemic.clone(),
+            firms: self.firms.clone(),
+            firm_ledger: self.firm_ledger.clone(),
+            central_bank: self.central_bank.clone(),
+            government: self.government.clone(),
+            transfer_ledger: self.transfer_ledger.clone(),
+            crisis_ledger: self.crisis_ledger.clone(),
+            region_ledgers: self.region_ledgers.clone(),
+            mentorships: self.mentorships.clone(),
+            total_mentorships_formed: self.total_mentorships_formed,
+            successful_mentored_learnings: self.successful_mentored_learnings,
+            total_mentorship_cost_savings: self.total_mentorship_cost_savings,
+            unique_mentors: self.unique_mentors.clone(),
+            unique_mentees: self.unique_mentees.clone(),
+            strategy_distribution_history: self.strategy_distribution_history.clone(),
+            total_strategy_changes: self.total_strategy_changes,
+            total_strategy_mutations: self.total_strategy_mutations,
+            total_strategy_imitations: self.total_strategy_imitations,
+            assets: self.assets.clone(),
+            asset_counter: self.asset_counter,
+            total_assets_purchased: self.total_assets_purchased,
+            total_assets_sold: self.total_assets_sold,
             environment: self.environment.clone(),
             voting_system: self.voting_system.clone(),
             total_certifications_issued: self.total_certifications_issued,
@@ -6307,14 +10261,7 @@ impl SimulationEngine {
             technology_breakthroughs: self.technology_breakthroughs.clone(),
             action_log: self.action_log.clone(),
             externality_stats: self.externality_stats.clone(),
-        };
-
-        let file = File::create(path)?;
-        let writer = BufWriter::new(file);
-        serde_json::to_writer_pretty(writer, &checkpoint)?;
-
-        debug!("Checkpoint saved successfully");
-        Ok(())
+        }
     }
 
     /// Loads a simulation state from a checkpoint file.
@@ -6326,6 +10273,8 @@ impl SimulationEngine {
     /// **Note:** Plugins are not persisted in checkpoints. After loading a checkpoint,
     /// you must re-register any plugins that were previously registered before
     /// continuing the simulation. This ensures plugin state is properly initialized.
+    /// The same applies to custom price strategies: until one is registered again with
+    /// [`SimulationEngine::set_price_strategy`], the scenario's price updater is used.
     ///
     /// # Arguments
     ///
@@ -6333,8 +10282,11 @@ impl SimulationEngine {
     ///
     /// # Returns
     ///
-    /// Returns a new `SimulationEngine` with the restored state, or an error if
-    /// the checkpoint file cannot be read or parsed.
+    /// Returns a new `SimulationEngine` with the restored state. Checkpoints written by
+    /// older versions are migrated to the current schema first. Returns
+    /// [`crate::SimulationError::CheckpointIncompatible`] if the checkpoint was written by
+    /// a newer version or cannot be migrated, and an I/O error if the file cannot be
+    /// read or parsed.
     ///
     /// # Example
     ///
@@ -6348,18 +10300,81 @@ impl SimulationEngine {
     /// // Continue simulation
     /// let result = engine.run();
     /// ```
-    pub fn load_checkpoint<P: AsRef<Path>>(path: P) -> std::io::Result<Self> {
+    pub fn load_checkpoint<P: AsRef<Path>>(path: P) -> crate::Result<Self> {
         info!("Loading checkpoint from {:?}", path.as_ref());
 
-        let file = File::open(path)?;
-        let reader = BufReader::new(file);
-        let checkpoint: SimulationCheckpoint = serde_json::from_reader(reader)?;
+        let (state, deltas) = crate::checkpoint::rebuild(path.as_ref(), None)?;
+        // The delta log continues from the stored (unmigrated) state
+        let stored_state = deltas.is_some().then(|| state.clone());
+        let mut engine = Self::from_checkpoint(Self::decode_checkpoint(state)?);
+
+        // Keep extending the delta log when the resumed run checkpoints incrementally
+        if let (Some(deltas), Some(stored_state)) = (deltas, stored_state) {
+            info!("Applied {} checkpoint deltas from {:?}", deltas, path.as_ref());
+            if engine.config.incremental_checkpoints {
+                engine.incremental_checkpointer =
+                    Some(IncrementalCheckpointer::resume(path.as_ref(), stored_state, deltas));
+            }
+        }
+        Ok(engine)
+    }
+
+    /// Loads the state of an earlier checkpointed step from an incremental checkpoint.
+    ///
+    /// Replays the delta log of `path` up to `step`. Saving incremental checkpoints to
+    /// the same path afterwards starts a new base, discarding the later deltas.
+    ///
+    /// # Errors
+    ///
+    /// Returns an I/O `NotFound` error if no checkpoint was saved at `step`, or any error of
+    /// [`SimulationEngine::load_checkpoint`].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use community_simulation::SimulationEngine;
+    ///
+    /// let mut engine = SimulationEngine::load_checkpoint_at_step("checkpoint.json", 5000)
+    ///     .expect("Failed to load checkpoint");
+    /// let result = engine.run();
+    /// ```
+    pub fn load_checkpoint_at_step<P: AsRef<Path>>(path: P, step: usize) -> crate::Result<Self> {
+        info!("Loading checkpoint of step {} from {:?}", step, path.as_ref());
+
+        let (state, _) = crate::checkpoint::rebuild(path.as_ref(), Some(step))?;
+        Ok(Self::from_checkpoint(Self::decode_checkpoint(state)?))
+    }
+
+    /// Migrates stored checkpoint JSON to the current schema and deserializes it.
+    fn decode_checkpoint(mut state: serde_json::Value) -> crate::Result<SimulationCheckpoint> {
+        let version = crate::checkpoint::migrate_checkpoint(&mut state)?;
+        serde_json::from_value(state).map_err(|e| crate::SimulationError::CheckpointIncompatible {
+            version,
+            reason: e.to_string(),
+        })
+    }
+
+    /// Returns the random number generator used for `step`.
+    ///
+    /// The seed and the step fill separate parts of the key, so consecutive seeds, as
+    /// Monte Carlo runs use, do not share the numbers of neighbouring steps.
+    fn step_rng(seed: u64, step: usize) -> StdRng {
+        let mut key = [0u8; 32];
+        key[..8].copy_from_slice(&seed.to_le_bytes());
+        key[8..16].copy_from_slice(&(step as u64).to_le_bytes());
+        StdRng::from_seed(key)
+    }
 
+    /// Builds an engine from restored checkpoint state.
+    fn from_checkpoint(checkpoint: SimulationCheckpoint) -> Self {
         // Reseed RNG based on the checkpoint's current step to ensure reproducibility
         // We combine the original seed with the current step to get a deterministic but
         // step-dependent seed
-        let seed = checkpoint.config.seed.wrapping_add(checkpoint.current_step as u64);
-        let rng = StdRng::seed_from_u64(seed);
+        let rng = Self::step_rng(checkpoint.config.seed, checkpoint.current_step);
+        let transport_network =
+            TransportNetwork::new(&checkpoint.config.regions, &checkpoint.config.transport_links);
+        let mut region_ledgers = checkpoint.region_ledgers;
+        region_ledgers.resize(checkpoint.config.regions.len(), RegionLedger::default());
 
         info!(
             "Checkpoint loaded: resuming from step {} with {} entities",
@@ -6386,6 +10401,21 @@ impl SimulationEngine {
         // Re-create demand generator from config
         let demand_generator = DemandGenerator::from(checkpoint.config.demand_strategy.clone());
 
+        // Price updaters are not serialized: restore the scenario's updater. A custom
+        // price strategy must be registered again (its saved state is restored then).
+        let mut market = checkpoint.market;
+        market.set_price_updater(PriceUpdater::from(checkpoint.config.scenario.clone()));
+        if let Some(name) = market.pending_price_strategy() {
+            warn!(
+                "Checkpoint was saved with custom price strategy '{}'; using the {} scenario updater until it is registered again",
+                name, checkpoint.config.scenario
+            );
+        }
+        let mut black_market = checkpoint.black_market;
+        if let Some(ref mut bm) = black_market {
+            bm.set_price_updater(PriceUpdater::from(checkpoint.config.scenario.clone()));
+        }
+
         // Cache production recipes if production is enabled
         let production_recipes = if checkpoint.config.enable_production {
             Some(crate::production::generate_default_recipes())
@@ -6395,12 +10425,13 @@ impl SimulationEngine {
 
         // Initialize event bus (events from previous run are not preserved)
         let event_bus = EventBus::new(checkpoint.config.enable_events);
+        let enable_profiling = checkpoint.config.enable_profiling;
 
         // Rebuild skill providers cache from checkpoint entities
         let mut skill_providers: HashMap<SkillId, Vec<usize>> =
             HashMap::with_capacity(checkpoint.all_skill_ids.len());
         for entity in &checkpoint.entities {
-            if entity.active {
+            if entity.active && !entity.person_data.is_employed() {
                 // Include both own_skills and learned_skills
                 for skill in &entity.person_data.own_skills {
                     skill_providers.entry(skill.id.clone()).or_default().push(entity.id);
@@ -6411,11 +10442,11 @@ impl SimulationEngine {
             }
         }
 
-        Ok(Self {
+        Self {
             config: checkpoint.config,
             entities: checkpoint.entities,
-            market: checkpoint.market,
-            black_market: checkpoint.black_market,
+            market,
+            black_market,
             current_step: checkpoint.current_step,
             rng,
             all_skill_ids: checkpoint.all_skill_ids,
@@ -6425,12 +10456,25 @@ impl SimulationEngine {
             black_market_trades_per_step: checkpoint.black_market_trades_per_step,
             black_market_volume_per_step: checkpoint.black_market_volume_per_step,
             total_fees_collected: checkpoint.total_fees_collected,
+            total_plugin_money_injected: checkpoint.total_plugin_money_injected,
+            auction_stats: checkpoint.auction_stats,
             failed_steps: checkpoint.failed_steps,
             failed_trade_attempts: checkpoint.failed_trade_attempts,
             failed_attempts_per_step: checkpoint.failed_attempts_per_step,
             loans: checkpoint.loans,
             total_loans_issued: checkpoint.total_loans_issued,
             total_loans_repaid: checkpoint.total_loans_repaid,
+            loan_defaults: checkpoint.loan_defaults,
+            investments: checkpoint.investments,
+            total_investments_created: checkpoint.total_investments_created,
+            total_investments_completed: checkpoint.total_investments_completed,
+            total_investments_defaulted: checkpoint.total_investments_defaulted,
+            total_invested: checkpoint.total_invested,
+            total_investment_returns: checkpoint.total_investment_returns,
+            total_investment_losses: checkpoint.total_investment_losses,
+            total_investment_education_spending: checkpoint.total_investment_education_spending,
+            completed_investment_roi_sum: checkpoint.completed_investment_roi_sum,
+            foreign_exchange: checkpoint.foreign_exchange,
             lending_marketplace: checkpoint.lending_marketplace,
             total_taxes_collected: checkpoint.total_taxes_collected,
             total_taxes_redistributed: checkpoint.total_taxes_redistributed,
@@ -6440,12 +10484,12 @@ impl SimulationEngine {
             contracts: checkpoint.contracts,
             total_contracts_created: checkpoint.total_contracts_created,
             total_contracts_completed: checkpoint.total_contracts_completed,
-            mentorships: Vec::new(), // Reset mentorship data on resume
-            total_mentorships_formed: 0,
-            successful_mentored_learnings: 0,
-            total_mentorship_cost_savings: 0.0,
-            unique_mentors: HashSet::new(),
-            unique_mentees: HashSet::new(),
+            mentorships: checkpoint.mentorships,
+            total_mentorships_formed: checkpoint.total_mentorships_formed,
+            successful_mentored_learnings: checkpoint.successful_mentored_learnings,
+            total_mentorship_cost_savings: checkpoint.total_mentorship_cost_savings,
+            unique_mentors: checkpoint.unique_mentors,
+            unique_mentees: checkpoint.unique_mentees,
             total_certifications_issued: checkpoint.total_certifications_issued,
             total_certifications_expired: checkpoint.total_certifications_expired,
             total_certification_cost: checkpoint.total_certification_cost,
@@ -6454,7 +10498,25 @@ impl SimulationEngine {
             min_money: checkpoint.min_money,
             max_money: checkpoint.max_money,
             mobility_quintiles: checkpoint.mobility_quintiles,
+            demographics: checkpoint.demographics,
+            epidemic: checkpoint.epidemic,
+            firms: checkpoint.firms,
+            firm_ledger: checkpoint.firm_ledger,
+            central_bank: checkpoint.central_bank,
+            government: checkpoint.government,
+            transfer_ledger: checkpoint.transfer_ledger,
+            crisis_ledger: checkpoint.crisis_ledger,
+            region_ledgers,
+            transport_network,
             plugin_registry: PluginRegistry::new(),
+            phase_pipeline: Vec::new(),
+            phase_pipeline_resolved: false,
+            custom_phases: Vec::new(),
+            phase_timings: Vec::new(),
+            step_tally: StepTally::default(),
+            profiler: enable_profiling.then(Profiler::new),
+            profiled_steps: 0,
+            incremental_checkpointer: None,
             resource_pools: checkpoint.resource_pools,
             production_recipes,
             environment: checkpoint.environment,
@@ -6472,23 +10534,229 @@ impl SimulationEngine {
             total_premiums_collected: checkpoint.total_premiums_collected,
             total_payouts_made: checkpoint.total_payouts_made,
             technology_breakthroughs: checkpoint.technology_breakthroughs,
-            strategy_distribution_history: Vec::new(), // Reset strategy evolution history on resume
-            total_strategy_changes: 0,
-            total_strategy_mutations: 0,
-            total_strategy_imitations: 0,
+            strategy_distribution_history: checkpoint.strategy_distribution_history,
+            total_strategy_changes: checkpoint.total_strategy_changes,
+            total_strategy_mutations: checkpoint.total_strategy_mutations,
+            total_strategy_imitations: checkpoint.total_strategy_imitations,
             action_log: checkpoint.action_log,
             externality_stats: checkpoint.externality_stats,
             skill_providers,
             invariant_checker: None, // Invariants will be re-initialized after loading
-            // NOTE: Assets are not persisted in checkpoints yet. When resuming,
-            // asset data is lost and persons' owned_assets vectors will be incorrect.
-            // This is a known limitation. To use assets, avoid checkpoint resume
-            // or add assets to SimulationCheckpoint struct in a future update.
-            assets: HashMap::new(),
-            asset_counter: 0,
-            total_assets_purchased: 0,
-            total_assets_sold: 0,
-        })
+            assets: checkpoint.assets,
+            asset_counter: checkpoint.asset_counter,
+            total_assets_purchased: checkpoint.total_assets_purchased,
+            total_assets_sold: checkpoint.total_assets_sold,
+        }
+    }
+
+    /// Runs one governance round: enacts proposals whose voting period ended, possibly
+    /// opens a new proposal, and lets persons vote on the open ones.
+    fn process_voting(&mut self) {
+        let Some(mut voting_system) = self.voting_system.take() else {
+            return;
+        };
+        let current_step = self.current_step;
+
+        // Close expired proposals and apply the ones that passed
+        for result in voting_system.tally_expired_proposals(current_step) {
+            if !result.passed {
+                debug!(
+                    "Proposal {} rejected at step {} (margin {:.2})",
+                    result.proposal_id,
+                    current_step,
+                    result.margin()
+                );
+                continue;
+            }
+            let Some(proposal_type) =
+                voting_system.get_proposal(result.proposal_id).map(|p| p.proposal_type.clone())
+            else {
+                continue;
+            };
+            if let Some((old_value, new_value)) = self.enact_policy(&proposal_type) {
+                info!(
+                    "Proposal {} passed at step {}: {} {:.4} -> {:.4} (margin {:.2})",
+                    result.proposal_id,
+                    current_step,
+                    proposal_type.parameter_name().unwrap_or("generic"),
+                    old_value,
+                    new_value,
+                    result.margin()
+                );
+                voting_system.record_policy_change(crate::voting::PolicyChange {
+                    step: current_step,
+                    proposal_id: result.proposal_id,
+                    proposal_type,
+                    old_value,
+                    new_value,
+                    votes_in_favor: result.votes_in_favor,
+                    votes_against: result.votes_against,
+                    total_voters: result.total_voters,
+                    vote_margin: result.margin(),
+                });
+            }
+        }
+
+        // Open a new proposal with the configured probability
+        if self.rng.random_range(0.0..1.0) < self.config.proposal_probability {
+            let (proposal_type, description) = self.generate_proposal();
+            let proposal_id = voting_system.create_proposal(
+                proposal_type,
+                description,
+                Some(self.config.proposal_duration),
+                current_step,
+            );
+            debug!("Proposal {} opened at step {}", proposal_id, current_step);
+        }
+
+        let mut open_proposals: Vec<(ProposalId, ProposalType)> = voting_system
+            .active_proposals()
+            .into_iter()
+            .map(|p| (p.id, p.proposal_type.clone()))
+            .collect();
+        if open_proposals.is_empty() {
+            self.voting_system = Some(voting_system);
+            return;
+        }
+        open_proposals.sort_unstable_by_key(|(id, _)| *id);
+
+        let mut wealth = self.active_money_in_base_currency();
+        wealth.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+        let median_wealth = if wealth.is_empty() {
+            0.0
+        } else {
+            wealth[wealth.len() / 2]
+        };
+
+        // Each person participates with the configured probability and votes on one open proposal
+        for i in 0..self.entities.len() {
+            if !self.entities[i].active {
+                continue;
+            }
+            if self.rng.random_range(0.0..1.0) >= self.config.voting_participation_rate {
+                continue;
+            }
+            let (proposal_id, proposal_type) =
+                &open_proposals[self.rng.random_range(0..open_proposals.len())];
+            let money = self.entity_to_base_currency(self.entities[i].person_data.money, i);
+            let in_favor = self.decide_vote(proposal_type, money, median_wealth);
+            voting_system.cast_vote(
+                *proposal_id,
+                self.entities[i].id,
+                in_favor,
+                money,
+                current_step,
+            );
+        }
+
+        self.voting_system = Some(voting_system);
+    }
+
+    /// Returns the current value of the policy parameter a proposal targets.
+    fn current_policy_value(&self, proposal_type: &ProposalType) -> Option<f64> {
+        match proposal_type {
+            ProposalType::TaxRateChange { .. } => Some(self.config.tax_rate),
+            ProposalType::BasePriceChange { .. } => Some(self.config.base_skill_price),
+            ProposalType::TransactionFeeChange { .. } => Some(self.config.transaction_fee),
+            ProposalType::Generic { .. } => None,
+        }
+    }
+
+    /// Creates a random proposal that moves one policy parameter away from its current value.
+    fn generate_proposal(&mut self) -> (ProposalType, String) {
+        match self.rng.random_range(0..4) {
+            0 => {
+                let new_rate = (self.config.tax_rate + self.rng.random_range(-0.05..=0.05))
+                    .clamp(0.0, MAX_VOTED_TAX_RATE);
+                (
+                    ProposalType::TaxRateChange { new_rate },
+                    format!("Set tax rate to {:.1}%", new_rate * 100.0),
+                )
+            },
+            1 => {
+                let new_price = (self.config.base_skill_price * self.rng.random_range(0.8..=1.2))
+                    .max(self.config.min_skill_price);
+                (
+                    ProposalType::BasePriceChange { new_price },
+                    format!("Set base skill price to {:.2}", new_price),
+                )
+            },
+            2 => {
+                let new_fee = (self.config.transaction_fee + self.rng.random_range(-0.02..=0.02))
+                    .clamp(0.0, MAX_VOTED_TRANSACTION_FEE);
+                (
+                    ProposalType::TransactionFeeChange { new_fee },
+                    format!("Set transaction fee to {:.1}%", new_fee * 100.0),
+                )
+            },
+            _ => {
+                let description = "Non-binding community resolution".to_string();
+                (ProposalType::Generic { description: description.clone() }, description)
+            },
+        }
+    }
+
+    /// Decides how a person votes based on economic self-interest.
+    ///
+    /// Persons below the median wealth favor higher taxes (they gain from redistribution)
+    /// and oppose higher base prices; persons at or above the median hold the opposite
+    /// preferences. Fee increases are opposed by everyone. A fraction of votes
+    /// (`VOTE_NOISE`) goes against these preferences to model idiosyncratic views.
+    fn decide_vote(
+        &mut self,
+        proposal_type: &ProposalType,
+        money: f64,
+        median_wealth: f64,
+    ) -> bool {
+        let below_median = money < median_wealth;
+        let preference = match (proposal_type, self.current_policy_value(proposal_type)) {
+            (ProposalType::TaxRateChange { new_rate }, Some(current)) => {
+                (*new_rate > current) == below_median
+            },
+            (ProposalType::BasePriceChange { new_price }, Some(current)) => {
+                (*new_price > current) != below_median
+            },
+            (ProposalType::TransactionFeeChange { new_fee }, Some(current)) => *new_fee < current,
+            _ => return self.rng.random_bool(0.5),
+        };
+        if self.rng.random_range(0.0..1.0) < VOTE_NOISE {
+            !preference
+        } else {
+            preference
+        }
+    }
+
+    /// Applies a passed proposal to the live configuration.
+    ///
+    /// Base price changes rescale all current market prices by the same ratio.
+    /// Returns the old and new parameter values, or `None` for proposals without
+    /// a direct effect.
+    fn enact_policy(&mut self, proposal_type: &ProposalType) -> Option<(f64, f64)> {
+        let old_value = self.current_policy_value(proposal_type)?;
+        let new_value = match proposal_type {
+            ProposalType::TaxRateChange { new_rate } => {
+                self.config.tax_rate = new_rate.clamp(0.0, 1.0);
+                self.config.tax_rate
+            },
+            ProposalType::BasePriceChange { new_price } => {
+                let new_price = new_price.max(self.config.min_skill_price);
+                let ratio = new_price / old_value;
+                self.config.base_skill_price = new_price;
+                self.market.base_skill_price = new_price;
+                let (min_price, max_price) =
+                    (self.market.min_skill_price, self.market.max_skill_price);
+                for skill in self.market.skills.values_mut() {
+                    skill.current_price = (skill.current_price * ratio).clamp(min_price, max_price);
+                }
+                new_price
+            },
+            ProposalType::TransactionFeeChange { new_fee } => {
+                self.config.transaction_fee = new_fee.clamp(0.0, 1.0);
+                self.config.transaction_fee
+            },
+            ProposalType::Generic { .. } => return None,
+        };
+        Some((old_value, new_value))
     }
 
     /// Attempts to form trade agreements between persons who are friends.
@@ -6525,8 +10793,9 @@ impl SimulationEngine {
             }
 
             let person_id = self.entities[i].id;
-            let friends: Vec<PersonId> =
+            let mut friends: Vec<PersonId> =
                 self.entities[i].person_data.friends.iter().copied().collect();
+            friends.sort_unstable();
 
             // Need at least one friend to form an agreement
             if friends.is_empty() {
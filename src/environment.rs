@@ -1,3 +1,4 @@
+use crate::skill::SkillId;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -32,12 +33,31 @@ impl Resource {
             Resource::Land => "Land",
         }
     }
+
+    /// Parses a resource name as used in configuration files (case-insensitive).
+    pub fn from_name(name: &str) -> Option<Resource> {
+        Resource::all()
+            .into_iter()
+            .find(|resource| resource.name().eq_ignore_ascii_case(name))
+    }
+
+    /// Returns whether this resource regenerates toward a carrying capacity.
+    ///
+    /// Water and Land recover like ecosystems: regeneration is fastest at half the
+    /// capacity and stops when the resource is exhausted. Energy and Materials
+    /// regenerate at a constant rate up to their initial reserves.
+    pub fn has_carrying_capacity(&self) -> bool {
+        matches!(self, Resource::Water | Resource::Land)
+    }
 }
 
 /// Tracks environmental resource consumption and sustainability metrics.
 ///
 /// The Environment tracks total resource consumption across all economic activities
 /// and calculates sustainability metrics based on resource availability and usage patterns.
+/// Reserves regenerate every step, consumption can be limited by per-step quotas and
+/// by the remaining reserves, and the scarcity of each resource raises the cost of
+/// the skills that depend on it.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Environment {
     /// Total consumption of each resource type across all transactions.
@@ -47,6 +67,36 @@ pub struct Environment {
     pub resource_reserves: HashMap<Resource, f64>,
     /// Current step number for tracking resource usage over time.
     pub current_step: usize,
+    /// Total amount regenerated for each resource type.
+    #[serde(default)]
+    pub total_regeneration: HashMap<Resource, f64>,
+    /// Regeneration rate per step for each resource type.
+    ///
+    /// For Water and Land this is the logistic growth rate toward the carrying
+    /// capacity; for Energy and Materials it is the share of the initial reserves
+    /// restored each step. Missing entries do not regenerate.
+    #[serde(default)]
+    pub regeneration_rates: HashMap<Resource, f64>,
+    /// Carrying capacity of Water and Land (defaults to the initial reserves).
+    #[serde(default)]
+    pub carrying_capacities: HashMap<Resource, f64>,
+    /// Maximum consumption per step for each resource type. Missing entries are unlimited.
+    #[serde(default)]
+    pub step_quotas: HashMap<Resource, f64>,
+    /// Whether consumption is refused once it would exceed the remaining reserves.
+    #[serde(default)]
+    pub enforce_reserves: bool,
+    /// Consumption of each resource type in the current step.
+    #[serde(default)]
+    pub consumption_this_step: HashMap<Resource, f64>,
+    /// Resource intensity of each skill per resource type (1.0 = average).
+    ///
+    /// Skills without an entry use 1.0 for every resource.
+    #[serde(default)]
+    pub skill_intensities: HashMap<SkillId, HashMap<Resource, f64>>,
+    /// Number of consumptions refused because of a quota or exhausted reserves.
+    #[serde(default)]
+    pub blocked_consumptions: usize,
 }
 
 impl Environment {
@@ -63,7 +113,19 @@ impl Environment {
             total_consumption.insert(resource, 0.0);
         }
 
-        Environment { total_consumption, resource_reserves, current_step: 0 }
+        Environment {
+            total_consumption,
+            resource_reserves,
+            current_step: 0,
+            total_regeneration: HashMap::new(),
+            regeneration_rates: HashMap::new(),
+            carrying_capacities: HashMap::new(),
+            step_quotas: HashMap::new(),
+            enforce_reserves: false,
+            consumption_this_step: HashMap::new(),
+            skill_intensities: HashMap::new(),
+            blocked_consumptions: 0,
+        }
     }
 
     /// Creates a new Environment with default resource reserves.
@@ -94,12 +156,159 @@ impl Environment {
     pub fn consume_resources(&mut self, resource_costs: &HashMap<Resource, f64>) {
         for (resource, &amount) in resource_costs {
             *self.total_consumption.entry(*resource).or_insert(0.0) += amount;
+            *self.consumption_this_step.entry(*resource).or_insert(0.0) += amount;
         }
     }
 
+    /// Returns the resources consumed by a transaction of the given skill.
+    ///
+    /// The base consumption is split evenly across resource types and weighted by
+    /// the skill's intensity for each of them.
+    ///
+    /// # Arguments
+    /// * `skill_id` - The skill being traded
+    /// * `base_consumption` - Total consumption of a skill with average intensity
+    pub fn resource_costs(
+        &self,
+        skill_id: &SkillId,
+        base_consumption: f64,
+    ) -> HashMap<Resource, f64> {
+        let share = base_consumption / Resource::all().len() as f64;
+        Resource::all()
+            .into_iter()
+            .map(|resource| (resource, share * self.intensity(skill_id, resource)))
+            .collect()
+    }
+
+    /// Checks whether the given consumption stays within the step quotas and, when
+    /// reserves are enforced, within the remaining reserves.
+    ///
+    /// # Returns
+    /// `true` if the consumption is allowed, `false` otherwise
+    pub fn can_consume(&self, resource_costs: &HashMap<Resource, f64>) -> bool {
+        resource_costs.iter().all(|(resource, &amount)| {
+            let within_quota = self.step_quotas.get(resource).is_none_or(|&quota| {
+                self.consumption_this_step.get(resource).copied().unwrap_or(0.0) + amount <= quota
+            });
+            let within_reserves =
+                !self.enforce_reserves || amount <= self.remaining_reserves(*resource);
+            within_quota && within_reserves
+        })
+    }
+
+    /// Returns whether quotas or enforced reserves can refuse consumption.
+    pub fn limits_consumption(&self) -> bool {
+        self.enforce_reserves || !self.step_quotas.is_empty()
+    }
+
+    /// Records a consumption that was refused by [`Environment::can_consume`].
+    pub fn record_blocked_consumption(&mut self) {
+        self.blocked_consumptions += 1;
+    }
+
     /// Advances the environment to the next simulation step.
+    ///
+    /// Regenerates every resource and resets the per-step consumption used for quotas.
     pub fn step(&mut self) {
         self.current_step += 1;
+        for resource in Resource::all() {
+            let regenerated = self.regeneration(resource);
+            if regenerated != 0.0 {
+                *self.total_regeneration.entry(resource).or_insert(0.0) += regenerated;
+            }
+        }
+        self.consumption_this_step.clear();
+    }
+
+    /// Calculates how much of a resource regenerates this step.
+    ///
+    /// Water and Land follow logistic growth, `rate * remaining * (1 - remaining / capacity)`,
+    /// which shrinks back toward the capacity when the capacity has fallen below the
+    /// remaining amount. Energy and Materials regain `rate * reserves` per step without
+    /// exceeding their initial reserves.
+    fn regeneration(&self, resource: Resource) -> f64 {
+        let rate = self.regeneration_rates.get(&resource).copied().unwrap_or(0.0);
+        let remaining = self.remaining_reserves(resource);
+        if rate <= 0.0 || (remaining <= 0.0 && resource.has_carrying_capacity()) {
+            return 0.0;
+        }
+
+        if resource.has_carrying_capacity() {
+            let capacity = self.carrying_capacity(resource);
+            if capacity <= 0.0 {
+                return -remaining;
+            }
+            // Never overshoot the capacity or drop below it in a single step
+            let growth = rate * remaining * (1.0 - remaining / capacity);
+            if growth >= 0.0 {
+                growth.min(capacity - remaining)
+            } else {
+                growth.max(capacity - remaining)
+            }
+        } else {
+            let reserves = self.resource_reserves.get(&resource).copied().unwrap_or(0.0);
+            (rate * reserves).min(reserves - remaining).max(0.0)
+        }
+    }
+
+    /// Gets the carrying capacity of a resource (its initial reserves unless configured).
+    pub fn carrying_capacity(&self, resource: Resource) -> f64 {
+        self.carrying_capacities
+            .get(&resource)
+            .copied()
+            .unwrap_or_else(|| self.resource_reserves.get(&resource).copied().unwrap_or(0.0))
+    }
+
+    /// Reduces the carrying capacity of Water and Land by the given share.
+    ///
+    /// Used by the ClimateChange scenario to model a degrading environment.
+    pub fn degrade_carrying_capacity(&mut self, share: f64) {
+        for resource in Resource::all().into_iter().filter(Resource::has_carrying_capacity) {
+            let capacity = self.carrying_capacity(resource);
+            self.carrying_capacities.insert(resource, capacity * (1.0 - share));
+        }
+    }
+
+    /// Gets the intensity of a skill for a resource (1.0 when not set).
+    pub fn intensity(&self, skill_id: &SkillId, resource: Resource) -> f64 {
+        self.skill_intensities
+            .get(skill_id)
+            .and_then(|intensities| intensities.get(&resource))
+            .copied()
+            .unwrap_or(1.0)
+    }
+
+    /// Calculates the scarcity of a resource.
+    ///
+    /// # Returns
+    /// A value between 0.0 (untouched reserves) and 1.0 (exhausted reserves)
+    pub fn scarcity(&self, resource: Resource) -> f64 {
+        let reserves = self.resource_reserves.get(&resource).copied().unwrap_or(0.0);
+        if reserves > 0.0 {
+            (1.0 - self.remaining_reserves(resource) / reserves).clamp(0.0, 1.0)
+        } else if self.total_consumption.get(&resource).copied().unwrap_or(0.0) > 0.0 {
+            1.0
+        } else {
+            0.0
+        }
+    }
+
+    /// Calculates the cost multiplier of a skill caused by resource scarcity.
+    ///
+    /// Each resource adds its scarcity weighted by the skill's intensity for it, so
+    /// a skill with average intensity costs `1 + price_factor` times as much once all
+    /// reserves are exhausted.
+    ///
+    /// # Arguments
+    /// * `skill_id` - The skill to price
+    /// * `price_factor` - Cost increase of an average skill at full scarcity
+    pub fn scarcity_multiplier(&self, skill_id: &SkillId, price_factor: f64) -> f64 {
+        let weighted_scarcity: f64 = Resource::all()
+            .into_iter()
+            .map(|resource| self.intensity(skill_id, resource) * self.scarcity(resource))
+            .sum::<f64>()
+            / Resource::all().len() as f64;
+        1.0 + price_factor * weighted_scarcity
     }
 
     /// Calculates the sustainability score for each resource type.
@@ -120,7 +329,8 @@ impl Environment {
             let reserves = self.resource_reserves.get(&resource).copied().unwrap_or(0.0); // Missing reserves treated as 0.0 (no reserves available)
 
             let score = if reserves > 0.0 {
-                1.0 - (consumed / reserves)
+                // Regeneration can restore a resource, but never beyond fully sustainable
+                (self.remaining_reserves(resource) / reserves).min(1.0)
             } else if consumed > 0.0 {
                 // No reserves but consumption occurred = unsustainable
                 -consumed // Negative score proportional to overconsumption
@@ -160,11 +370,12 @@ impl Environment {
     /// * `resource` - The resource type to check
     ///
     /// # Returns
-    /// The remaining amount (reserves - consumption), can be negative if overconsumed
+    /// The remaining amount (reserves + regeneration - consumption), can be negative if overconsumed
     pub fn remaining_reserves(&self, resource: Resource) -> f64 {
         let reserves = self.resource_reserves.get(&resource).copied().unwrap_or(0.0);
         let consumed = self.total_consumption.get(&resource).copied().unwrap_or(0.0);
-        reserves - consumed
+        let regenerated = self.total_regeneration.get(&resource).copied().unwrap_or(0.0);
+        reserves + regenerated - consumed
     }
 }
 
@@ -313,4 +524,110 @@ mod tests {
         assert_eq!(Resource::Materials.name(), "Materials");
         assert_eq!(Resource::Land.name(), "Land");
     }
+
+    #[test]
+    fn test_resource_from_name() {
+        assert_eq!(Resource::from_name("water"), Some(Resource::Water));
+        assert_eq!(Resource::from_name("LAND"), Some(Resource::Land));
+        assert_eq!(Resource::from_name("gold"), None);
+    }
+
+    fn consume(env: &mut Environment, resource: Resource, amount: f64) {
+        env.consume_resources(&HashMap::from([(resource, amount)]));
+    }
+
+    #[test]
+    fn test_linear_regeneration_stops_at_reserves() {
+        let mut env = Environment::with_default_reserves();
+        env.regeneration_rates.insert(Resource::Energy, 0.01);
+        consume(&mut env, Resource::Energy, 1_500.0);
+
+        env.step();
+        assert_eq!(env.remaining_reserves(Resource::Energy), 99_500.0);
+        env.step();
+        assert_eq!(env.remaining_reserves(Resource::Energy), 100_000.0);
+        assert_eq!(env.total_regeneration[&Resource::Energy], 1_500.0);
+        // Materials have no regeneration rate
+        consume(&mut env, Resource::Materials, 100.0);
+        env.step();
+        assert_eq!(env.remaining_reserves(Resource::Materials), 99_900.0);
+    }
+
+    #[test]
+    fn test_logistic_regeneration_toward_carrying_capacity() {
+        let mut env = Environment::with_default_reserves();
+        env.regeneration_rates.insert(Resource::Water, 0.5);
+        consume(&mut env, Resource::Water, 50_000.0);
+
+        // Growth is rate * remaining * (1 - remaining / capacity)
+        env.step();
+        assert_eq!(env.remaining_reserves(Resource::Water), 62_500.0);
+        for _ in 0..100 {
+            env.step();
+        }
+        let remaining = env.remaining_reserves(Resource::Water);
+        assert!(remaining <= 100_000.0 && remaining > 99_000.0);
+
+        // An exhausted resource does not recover
+        consume(&mut env, Resource::Water, remaining);
+        env.step();
+        assert_eq!(env.remaining_reserves(Resource::Water), 0.0);
+    }
+
+    #[test]
+    fn test_degraded_capacity_shrinks_reserves() {
+        let mut env = Environment::with_default_reserves();
+        env.regeneration_rates.insert(Resource::Land, 0.5);
+        env.degrade_carrying_capacity(0.5);
+        assert_eq!(env.carrying_capacity(Resource::Land), 5_000.0);
+        assert_eq!(env.carrying_capacity(Resource::Energy), 100_000.0);
+
+        env.step();
+        assert_eq!(env.remaining_reserves(Resource::Land), 5_000.0);
+    }
+
+    #[test]
+    fn test_quotas_and_enforced_reserves() {
+        let mut env = Environment::with_default_reserves();
+        env.step_quotas.insert(Resource::Energy, 100.0);
+        let costs = HashMap::from([(Resource::Energy, 60.0)]);
+        assert!(env.can_consume(&costs));
+        env.consume_resources(&costs);
+        assert!(!env.can_consume(&costs));
+        env.step();
+        assert!(env.can_consume(&costs));
+
+        let land = HashMap::from([(Resource::Land, 10_001.0)]);
+        assert!(env.can_consume(&land));
+        env.enforce_reserves = true;
+        assert!(!env.can_consume(&land));
+    }
+
+    #[test]
+    fn test_scarcity_multiplier_follows_skill_intensity() {
+        let mut env = Environment::with_default_reserves();
+        let skill = "Mining".to_string();
+        let other = "Teaching".to_string();
+        env.skill_intensities.insert(
+            skill.clone(),
+            HashMap::from([
+                (Resource::Energy, 2.0),
+                (Resource::Water, 0.0),
+                (Resource::Materials, 2.0),
+                (Resource::Land, 0.0),
+            ]),
+        );
+        assert_eq!(env.scarcity_multiplier(&skill, 1.0), 1.0);
+
+        consume(&mut env, Resource::Energy, 50_000.0);
+        assert_eq!(env.scarcity(Resource::Energy), 0.5);
+        // Mining uses twice the average energy: 1 + (2.0 * 0.5) / 4
+        assert_eq!(env.scarcity_multiplier(&skill, 1.0), 1.25);
+        assert_eq!(env.scarcity_multiplier(&other, 1.0), 1.125);
+        assert_eq!(env.scarcity_multiplier(&other, 0.0), 1.0);
+
+        let costs = env.resource_costs(&skill, 40.0);
+        assert_eq!(costs[&Resource::Energy], 20.0);
+        assert_eq!(costs[&Resource::Water], 0.0);
+    }
 }
@@ -0,0 +1,140 @@
+//! # Epidemic Module
+//!
+//! Tracks the course of a disease through the population when the health system is
+//! enabled. Persons move through the SEIR compartments of
+//! [`HealthStatus`](crate::person::HealthStatus): susceptible persons are exposed
+//! through trades and friendships with sick persons, become sick after the incubation
+//! period, and recover with immunity that may wane over time.
+//!
+//! The engine applies disease progression and the policy responses (lockdowns,
+//! vaccination campaigns and sick pay) in the `health` phase. This module holds the
+//! running totals kept across checkpoints and the epidemic curve.
+
+use crate::person::{HealthStatus, Person};
+use serde::{Deserialize, Serialize};
+
+/// Number of persons in each SEIR compartment.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompartmentCounts {
+    /// Persons who can catch the disease
+    pub susceptible: usize,
+    /// Persons who caught the disease but are not infectious yet
+    pub exposed: usize,
+    /// Sick, infectious persons
+    pub infected: usize,
+    /// Persons immune after recovery or vaccination
+    pub recovered: usize,
+}
+
+impl CompartmentCounts {
+    /// Counts the given persons by health status.
+    pub fn count<'a>(persons: impl IntoIterator<Item = &'a Person>) -> Self {
+        let mut counts = Self::default();
+        for person in persons {
+            match person.health_status {
+                HealthStatus::Healthy => counts.susceptible += 1,
+                HealthStatus::Exposed { .. } => counts.exposed += 1,
+                HealthStatus::Sick { .. } => counts.infected += 1,
+                HealthStatus::Recovered { .. } => counts.recovered += 1,
+            }
+        }
+        counts
+    }
+
+    /// Returns the number of persons counted.
+    pub fn total(&self) -> usize {
+        self.susceptible + self.exposed + self.infected + self.recovered
+    }
+
+    /// Returns the share of persons who are infectious (0.0 for an empty population).
+    pub fn infected_share(&self) -> f64 {
+        match self.total() {
+            0 => 0.0,
+            total => self.infected as f64 / total as f64,
+        }
+    }
+}
+
+/// SEIR counts at the end of one step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EpidemicSnapshot {
+    /// The simulation step
+    pub step: usize,
+    /// Persons in each compartment
+    #[serde(flatten)]
+    pub counts: CompartmentCounts,
+}
+
+/// Running totals of the epidemic and the policy responses to it.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EpidemicLedger {
+    /// Persons exposed through trades
+    pub trade_exposures: usize,
+    /// Persons exposed through friendships
+    pub contact_exposures: usize,
+    /// Recoveries from illness
+    pub recoveries: usize,
+    /// Persons vaccinated
+    pub vaccinations: usize,
+    /// Recovered persons whose immunity waned
+    pub immunity_losses: usize,
+    /// Whether a lockdown is in force
+    pub lockdown_active: bool,
+    /// Steps during which a lockdown was in force
+    pub lockdown_steps: usize,
+    /// Trades that did not happen because of a lockdown
+    pub lockdown_cancelled_trades: usize,
+    /// Sick pay paid out of the tax pool, in the base currency
+    pub sick_pay_paid: f64,
+    /// SEIR counts at the end of each step
+    pub history: Vec<EpidemicSnapshot>,
+}
+
+impl EpidemicLedger {
+    /// Returns the snapshot with the most infectious persons (the earliest on ties).
+    pub fn peak(&self) -> Option<&EpidemicSnapshot> {
+        self.history.iter().rev().max_by_key(|snapshot| snapshot.counts.infected)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::person::{Location, Strategy};
+
+    fn person(id: usize) -> Person {
+        Person::new(id, 100.0, Vec::new(), Strategy::default(), Location::new(0.0, 0.0), 0.95)
+    }
+
+    #[test]
+    fn test_compartment_counts() {
+        let mut persons: Vec<Person> = (0..5).map(person).collect();
+        persons[1].expose(0);
+        persons[2].infect(0);
+        persons[3].infect(0);
+        persons[4].vaccinate(0);
+
+        let counts = CompartmentCounts::count(&persons);
+        assert_eq!(
+            counts,
+            CompartmentCounts { susceptible: 1, exposed: 1, infected: 2, recovered: 1 }
+        );
+        assert_eq!(counts.total(), 5);
+        assert_eq!(counts.infected_share(), 0.4);
+        assert_eq!(CompartmentCounts::default().infected_share(), 0.0);
+    }
+
+    #[test]
+    fn test_peak_is_earliest_maximum() {
+        let snapshot = |step, infected| EpidemicSnapshot {
+            step,
+            counts: CompartmentCounts { infected, ..Default::default() },
+        };
+        let ledger = EpidemicLedger {
+            history: vec![snapshot(0, 1), snapshot(1, 4), snapshot(2, 4), snapshot(3, 2)],
+            ..Default::default()
+        };
+        assert_eq!(ledger.peak().unwrap().step, 1);
+        assert!(EpidemicLedger::default().peak().is_none());
+    }
+}
@@ -65,6 +65,14 @@ pub enum SimulationError {
 
     /// Error occurred while exporting to Parquet format
     ParquetExport(String),
+
+    /// A checkpoint cannot be loaded by this version of the simulation
+    CheckpointIncompatible {
+        /// Schema version found in the checkpoint
+        version: u32,
+        /// Why the checkpoint could not be loaded
+        reason: String,
+    },
 }
 
 impl fmt::Display for SimulationError {
@@ -110,6 +118,9 @@ impl fmt::Display for SimulationError {
             SimulationError::ParquetExport(msg) => {
                 write!(f, "Failed to export Parquet file: {}", msg)
             },
+            SimulationError::CheckpointIncompatible { version, reason } => {
+                write!(f, "Incompatible checkpoint (schema version {}): {}", version, reason)
+            },
         }
     }
 }
@@ -320,6 +331,18 @@ mod tests {
         assert!(display.contains("failed to write parquet"));
     }
 
+    #[test]
+    fn test_checkpoint_incompatible_error_display() {
+        let err = SimulationError::CheckpointIncompatible {
+            version: 7,
+            reason: "written by a newer version".to_string(),
+        };
+        let display = format!("{}", err);
+        assert!(display.contains("Incompatible checkpoint"));
+        assert!(display.contains("schema version 7"));
+        assert!(display.contains("written by a newer version"));
+    }
+
     #[test]
     fn test_error_debug_trait() {
         let err = SimulationError::ValidationError("test".to_string());
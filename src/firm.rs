@@ -0,0 +1,283 @@
+//! # Firm Module
+//!
+//! Firms are agents that employ persons instead of selling skills themselves. Each
+//! firm is founded by a person who puts up its capital, and it produces one output
+//! skill from a production [`Recipe`]: employees who provide the two input skills
+//! work in pairs, and every pair yields a fixed number of units per step. The firm
+//! sells its units on the market to persons who need the output skill, pays its
+//! employees a fixed wage and pays part of its profit to its owner. Employees stop
+//! selling their own skills while they work for a firm.
+//!
+//! A firm that cannot pay its wage bill goes bankrupt: it pays out what cash it has,
+//! lays off every employee and stops trading.
+//!
+//! The engine runs firms in the `firms` phase. This module holds the firms' state,
+//! their bookkeeping and the running totals of the labor market.
+
+use crate::person::PersonId;
+use crate::production::Recipe;
+use crate::skill::SkillId;
+use serde::{Deserialize, Serialize};
+
+/// Type alias for firm identifiers (the index of the firm in the engine's firm list).
+pub type FirmId = usize;
+
+/// A person working for a firm.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Employee {
+    /// The employed person
+    pub person_id: PersonId,
+    /// The recipe input skill this person provides to the firm
+    pub skill_id: SkillId,
+    /// Step at which the person was hired
+    pub hired_at_step: usize,
+}
+
+/// A firm's balance sheet, in the base currency.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct BalanceSheet {
+    /// Cash held by the firm
+    pub assets: f64,
+    /// Wages owed to employees but not paid
+    pub liabilities: f64,
+    /// Assets minus liabilities
+    pub equity: f64,
+}
+
+/// A firm employing persons to produce and sell one output skill.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Firm {
+    /// Unique identifier of the firm
+    pub id: FirmId,
+    /// The person who founded and owns the firm
+    pub owner_id: PersonId,
+    /// How the firm combines its employees' skills into its output skill
+    pub recipe: Recipe,
+    /// Wage paid to each employee per step, in the base currency
+    pub wage: f64,
+    /// Cash held by the firm, in the base currency
+    pub cash: f64,
+    /// Capital the owner put into the firm when founding it
+    pub capital: f64,
+    /// Current employees, in hiring order
+    pub employees: Vec<Employee>,
+    /// Step at which the firm was founded
+    pub founded_at_step: usize,
+    /// Step at which the firm went bankrupt (None while it is trading)
+    pub bankrupt_at_step: Option<usize>,
+    /// Units of the output skill sold so far
+    pub units_sold: usize,
+    /// Sales revenue so far
+    pub revenue: f64,
+    /// Wages paid so far
+    pub wages_paid: f64,
+    /// Wages owed but not paid when the firm went bankrupt
+    pub unpaid_wages: f64,
+    /// Profit paid to the owner so far
+    pub dividends_paid: f64,
+}
+
+impl Firm {
+    /// Creates a firm holding the owner's capital as cash and no employees.
+    pub fn new(
+        id: FirmId,
+        owner_id: PersonId,
+        recipe: Recipe,
+        wage: f64,
+        capital: f64,
+        founded_at_step: usize,
+    ) -> Self {
+        Firm {
+            id,
+            owner_id,
+            recipe,
+            wage,
+            cash: capital,
+            capital,
+            employees: Vec::new(),
+            founded_at_step,
+            bankrupt_at_step: None,
+            units_sold: 0,
+            revenue: 0.0,
+            wages_paid: 0.0,
+            unpaid_wages: 0.0,
+            dividends_paid: 0.0,
+        }
+    }
+
+    /// Returns true while the firm is trading (it has not gone bankrupt).
+    pub fn is_active(&self) -> bool {
+        self.bankrupt_at_step.is_none()
+    }
+
+    /// Returns the number of employees providing the given skill.
+    pub fn workers_with(&self, skill_id: &SkillId) -> usize {
+        self.employees.iter().filter(|employee| &employee.skill_id == skill_id).count()
+    }
+
+    /// Returns the units the firm can produce this step.
+    ///
+    /// Every pair of employees providing the two recipe inputs produces
+    /// `productivity` units; unpaired employees produce nothing.
+    pub fn capacity(&self, productivity: f64) -> usize {
+        let pairs = self
+            .workers_with(&self.recipe.input_skill_1)
+            .min(self.workers_with(&self.recipe.input_skill_2));
+        (pairs as f64 * productivity).floor() as usize
+    }
+
+    /// Returns the recipe input the fewest employees provide (the first input on ties).
+    pub fn scarcer_input(&self) -> &SkillId {
+        let recipe = &self.recipe;
+        if self.workers_with(&recipe.input_skill_2) < self.workers_with(&recipe.input_skill_1) {
+            &recipe.input_skill_2
+        } else {
+            &recipe.input_skill_1
+        }
+    }
+
+    /// Returns the wages due to all employees for one step.
+    pub fn wage_bill(&self) -> f64 {
+        self.wage * self.employees.len() as f64
+    }
+
+    /// Books the sale of one unit at the given price.
+    pub fn record_sale(&mut self, price: f64) {
+        self.cash += price;
+        self.revenue += price;
+        self.units_sold += 1;
+    }
+
+    /// Pays this step's wages out of cash and returns the amount each employee receives.
+    ///
+    /// When the cash does not cover the wage bill, it is shared out equally and the
+    /// shortfall is recorded as unpaid wages; the firm is then insolvent (see
+    /// [`Firm::is_insolvent`]).
+    pub fn pay_wages(&mut self) -> f64 {
+        if self.employees.is_empty() {
+            return 0.0;
+        }
+        let bill = self.wage_bill();
+        let paid = bill.min(self.cash.max(0.0));
+        self.cash -= paid;
+        self.wages_paid += paid;
+        self.unpaid_wages += bill - paid;
+        paid / self.employees.len() as f64
+    }
+
+    /// Returns true if the firm owes wages it could not pay.
+    pub fn is_insolvent(&self) -> bool {
+        self.unpaid_wages > 0.0
+    }
+
+    /// Pays the given share of a positive profit to the owner and returns the amount.
+    pub fn pay_dividend(&mut self, profit: f64, share: f64) -> f64 {
+        let dividend = (profit.max(0.0) * share).min(self.cash.max(0.0));
+        self.cash -= dividend;
+        self.dividends_paid += dividend;
+        dividend
+    }
+
+    /// Returns the firm's current balance sheet.
+    pub fn balance_sheet(&self) -> BalanceSheet {
+        BalanceSheet {
+            assets: self.cash,
+            liabilities: self.unpaid_wages,
+            equity: self.cash - self.unpaid_wages,
+        }
+    }
+}
+
+/// Running totals of the labor market.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FirmLedger {
+    /// Persons hired by firms
+    pub hires: usize,
+    /// Employees laid off because their firm sold too little
+    pub layoffs: usize,
+    /// Employees who lost their job when their firm went bankrupt
+    pub bankruptcy_job_losses: usize,
+    /// Persons employed by firms at the end of each step
+    pub employment_history: Vec<usize>,
+    /// Share of the labor force without a job or a sale at the end of each step
+    pub unemployment_history: Vec<f64>,
+}
+
+/// Returns the share of the labor force that is unemployed (0.0 without a labor force).
+pub fn unemployment_rate(labor_force: usize, unemployed: usize) -> f64 {
+    if labor_force == 0 {
+        0.0
+    } else {
+        unemployed as f64 / labor_force as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn firm() -> Firm {
+        let recipe = Recipe::new("A".to_string(), "B".to_string(), "A+B".to_string(), 1.3);
+        Firm::new(0, 7, recipe, 5.0, 20.0, 0)
+    }
+
+    fn employee(person_id: PersonId, skill: &str) -> Employee {
+        Employee { person_id, skill_id: skill.to_string(), hired_at_step: 0 }
+    }
+
+    #[test]
+    fn test_capacity_counts_worker_pairs() {
+        let mut firm = firm();
+        assert_eq!(firm.capacity(2.0), 0);
+        assert_eq!(firm.scarcer_input(), "A");
+
+        firm.employees.push(employee(1, "A"));
+        assert_eq!(firm.capacity(2.0), 0);
+        assert_eq!(firm.scarcer_input(), "B");
+
+        firm.employees.push(employee(2, "B"));
+        firm.employees.push(employee(3, "B"));
+        assert_eq!(firm.capacity(2.0), 2);
+        assert_eq!(firm.capacity(1.5), 1);
+        assert_eq!(firm.scarcer_input(), "A");
+        assert_eq!(firm.wage_bill(), 15.0);
+    }
+
+    #[test]
+    fn test_wages_and_dividends() {
+        let mut firm = firm();
+        firm.employees.push(employee(1, "A"));
+        firm.employees.push(employee(2, "B"));
+        firm.record_sale(26.0);
+        assert_eq!(firm.cash, 46.0);
+
+        assert_eq!(firm.pay_wages(), 5.0);
+        assert!(!firm.is_insolvent());
+        assert_eq!(firm.pay_dividend(26.0 - 10.0, 0.5), 8.0);
+        assert_eq!(firm.pay_dividend(-3.0, 0.5), 0.0);
+        assert_eq!(firm.cash, 28.0);
+        assert_eq!(firm.balance_sheet().equity, 28.0);
+    }
+
+    #[test]
+    fn test_short_cash_makes_firm_insolvent() {
+        let mut firm = firm();
+        firm.cash = 6.0;
+        firm.employees.push(employee(1, "A"));
+        firm.employees.push(employee(2, "B"));
+
+        assert_eq!(firm.pay_wages(), 3.0);
+        assert!(firm.is_insolvent());
+        assert_eq!(firm.cash, 0.0);
+        assert_eq!(
+            firm.balance_sheet(),
+            BalanceSheet { assets: 0.0, liabilities: 4.0, equity: -4.0 }
+        );
+    }
+
+    #[test]
+    fn test_unemployment_rate() {
+        assert_eq!(unemployment_rate(0, 0), 0.0);
+        assert_eq!(unemployment_rate(20, 5), 0.25);
+    }
+}
@@ -0,0 +1,290 @@
+//! # Geography Module
+//!
+//! Named regions, the transport network between them and migration. Regions are
+//! declared under `regions` in the configuration by their name and center; every
+//! person belongs to the region whose center is nearest to their location.
+//!
+//! - [`TransportLink`]: a two-way connection between two regions with a cost, the
+//!   share added to the price of skills bought along it
+//! - [`TransportNetwork`]: the cheapest route between every pair of regions; regions
+//!   that no route connects cannot trade with each other
+//! - [`choose_destination`]: where a person moves when another region offers better
+//!   prospects, the average income of its residents net of the transport cost
+//!
+//! When no transport links are configured, every region trades with every other at no
+//! cost. Regional price indices compare what the residents of a region paid with the
+//! market prices of the skills they bought.
+//!
+//! # Examples
+//!
+//! ```
+//! use community_simulation::geography::{Region, TransportLink, TransportNetwork};
+//!
+//! let regions = vec![Region::new("Port", 20.0, 50.0), Region::new("Hills", 80.0, 50.0)];
+//! let links = vec![TransportLink::new("Port", "Hills", 0.1)];
+//! let network = TransportNetwork::new(&regions, &links);
+//! assert_eq!(network.route_cost(0, 1), Some(0.1));
+//! assert_eq!(network.route_cost(1, 1), Some(0.0));
+//! ```
+
+use crate::person::Location;
+use crate::transfers::gini;
+use serde::{Deserialize, Serialize};
+
+/// How far from a region's center migrants settle, in location units.
+pub const SETTLEMENT_RADIUS: f64 = 5.0;
+
+/// A named region of the map.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Region {
+    /// Unique name of the region
+    pub name: String,
+    /// Horizontal coordinate of the region's center (0.0-100.0)
+    pub x: f64,
+    /// Vertical coordinate of the region's center (0.0-100.0)
+    pub y: f64,
+}
+
+impl Region {
+    /// Creates a region centered at the given coordinates.
+    pub fn new(name: &str, x: f64, y: f64) -> Self {
+        Region { name: name.to_string(), x, y }
+    }
+
+    /// The region's center.
+    pub fn center(&self) -> Location {
+        Location::new(self.x, self.y)
+    }
+}
+
+/// A two-way transport connection between two regions.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TransportLink {
+    /// Name of one region
+    pub from: String,
+    /// Name of the other region
+    pub to: String,
+    /// Share added to the price of skills bought across the link (0.1 = +10%)
+    pub cost: f64,
+}
+
+impl TransportLink {
+    /// Creates a link between two regions.
+    pub fn new(from: &str, to: &str, cost: f64) -> Self {
+        TransportLink { from: from.to_string(), to: to.to_string(), cost }
+    }
+}
+
+/// Cheapest route costs between every pair of regions.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TransportNetwork {
+    costs: Vec<Vec<Option<f64>>>,
+}
+
+impl TransportNetwork {
+    /// Computes the cheapest routes over the links, whose costs add up along a route.
+    ///
+    /// Links naming unknown regions are ignored. Without any link, every pair of
+    /// regions is connected at no cost.
+    pub fn new(regions: &[Region], links: &[TransportLink]) -> Self {
+        let n = regions.len();
+        let index = |name: &str| regions.iter().position(|r| r.name == name);
+        let mut costs = vec![vec![None; n]; n];
+        for (i, row) in costs.iter_mut().enumerate() {
+            for (j, cost) in row.iter_mut().enumerate() {
+                if i == j || links.is_empty() {
+                    *cost = Some(0.0);
+                }
+            }
+        }
+        for link in links {
+            if let (Some(a), Some(b)) = (index(&link.from), index(&link.to)) {
+                if a != b && costs[a][b].is_none_or(|c| link.cost < c) {
+                    costs[a][b] = Some(link.cost);
+                    costs[b][a] = Some(link.cost);
+                }
+            }
+        }
+        // Floyd-Warshall
+        for k in 0..n {
+            for i in 0..n {
+                for j in 0..n {
+                    if let (Some(ik), Some(kj)) = (costs[i][k], costs[k][j]) {
+                        if costs[i][j].is_none_or(|c| ik + kj < c) {
+                            costs[i][j] = Some(ik + kj);
+                        }
+                    }
+                }
+            }
+        }
+        TransportNetwork { costs }
+    }
+
+    /// Cost of the cheapest route between two regions, or None if none connects them.
+    pub fn route_cost(&self, from: usize, to: usize) -> Option<f64> {
+        self.costs.get(from).and_then(|row| row.get(to)).copied().flatten()
+    }
+}
+
+/// Returns the index of the region whose center is nearest to a location.
+pub fn nearest_region(regions: &[Region], location: &Location) -> Option<usize> {
+    regions
+        .iter()
+        .enumerate()
+        .min_by(|(_, a), (_, b)| {
+            location.distance_to(&a.center()).total_cmp(&location.distance_to(&b.center()))
+        })
+        .map(|(idx, _)| idx)
+}
+
+/// Picks the region a resident of `home` moves to, if any.
+///
+/// A region's prospects are the average income of its residents, divided by one plus
+/// the cost of the route to it. The person moves to the region with the best
+/// prospects if they exceed those at home by more than `threshold` (0.2 = 20%).
+pub fn choose_destination(
+    home: usize,
+    prospects: &[f64],
+    network: &TransportNetwork,
+    threshold: f64,
+) -> Option<usize> {
+    let (best, value) = prospects
+        .iter()
+        .enumerate()
+        .filter(|&(region, _)| region != home)
+        .filter_map(|(region, &income)| {
+            network.route_cost(home, region).map(|cost| (region, income / (1.0 + cost)))
+        })
+        .max_by(|(_, a), (_, b)| a.total_cmp(b))?;
+    (value > 0.0 && value > prospects[home] * (1.0 + threshold)).then_some(best)
+}
+
+/// Running totals kept for each region.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RegionLedger {
+    /// Trades bought by the region's residents
+    pub trades: usize,
+    /// What the residents paid for those trades
+    pub paid: f64,
+    /// The market prices of the skills they bought
+    pub market_value: f64,
+    /// Persons who moved into the region
+    pub immigrants: usize,
+    /// Persons who left the region
+    pub emigrants: usize,
+}
+
+impl RegionLedger {
+    /// Paid prices relative to market prices (1.0 before any trade).
+    pub fn price_index(&self) -> f64 {
+        if self.market_value > 0.0 {
+            self.paid / self.market_value
+        } else {
+            1.0
+        }
+    }
+}
+
+/// Population, wealth and prices of a region at the end of the run.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RegionStats {
+    /// Name of the region
+    pub name: String,
+    /// Active persons living in the region
+    pub population: usize,
+    /// Average money of its residents, in the base currency
+    pub average_money: f64,
+    /// Gini coefficient of its residents' money
+    pub gini_coefficient: f64,
+    /// Prices paid by its residents relative to market prices
+    pub price_index: f64,
+    /// Trades bought by its residents
+    pub trades: usize,
+    /// Persons who moved into the region
+    pub immigrants: usize,
+    /// Persons who left the region
+    pub emigrants: usize,
+}
+
+/// Summarizes a region from its residents' money and its ledger.
+pub fn region_stats(region: &Region, money: &[f64], ledger: &RegionLedger) -> RegionStats {
+    RegionStats {
+        name: region.name.clone(),
+        population: money.len(),
+        average_money: if money.is_empty() {
+            0.0
+        } else {
+            money.iter().sum::<f64>() / money.len() as f64
+        },
+        gini_coefficient: gini(money),
+        price_index: ledger.price_index(),
+        trades: ledger.trades,
+        immigrants: ledger.immigrants,
+        emigrants: ledger.emigrants,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn regions() -> Vec<Region> {
+        vec![
+            Region::new("A", 10.0, 10.0),
+            Region::new("B", 50.0, 50.0),
+            Region::new("C", 90.0, 90.0),
+            Region::new("D", 90.0, 10.0),
+        ]
+    }
+
+    #[test]
+    fn test_transport_network_routes() {
+        let links = vec![
+            TransportLink::new("A", "B", 0.3),
+            TransportLink::new("B", "C", 0.2),
+            TransportLink::new("A", "C", 0.6),
+            TransportLink::new("A", "Nowhere", 0.0),
+        ];
+        let network = TransportNetwork::new(&regions(), &links);
+        // A to C is cheaper through B, and no link reaches D
+        assert!((network.route_cost(0, 2).unwrap() - 0.5).abs() < 1e-12);
+        assert_eq!(network.route_cost(2, 0), network.route_cost(0, 2));
+        assert_eq!(network.route_cost(0, 3), None);
+        assert_eq!(network.route_cost(3, 3), Some(0.0));
+        assert_eq!(network.route_cost(0, 9), None);
+
+        // Without links every region is reachable for free
+        let open = TransportNetwork::new(&regions(), &[]);
+        assert_eq!(open.route_cost(0, 3), Some(0.0));
+    }
+
+    #[test]
+    fn test_nearest_region_and_destination() {
+        let regions = regions();
+        assert_eq!(nearest_region(&regions, &Location::new(60.0, 40.0)), Some(1));
+        assert_eq!(nearest_region(&regions, &Location::new(85.0, 0.0)), Some(3));
+        assert_eq!(nearest_region(&[], &Location::new(0.0, 0.0)), None);
+
+        let links = vec![TransportLink::new("A", "B", 0.5), TransportLink::new("A", "C", 0.0)];
+        let network = TransportNetwork::new(&regions, &links);
+        // D pays best but cannot be reached; B's lead over C is eaten by its transport cost
+        let prospects = [10.0, 18.0, 14.0, 50.0];
+        assert_eq!(choose_destination(0, &prospects, &network, 0.2), Some(2));
+        assert_eq!(choose_destination(0, &prospects, &network, 0.5), None);
+        assert_eq!(choose_destination(3, &prospects, &network, 0.0), None);
+        assert_eq!(choose_destination(0, &[0.0, 0.0, 0.0, 0.0], &network, 0.0), None);
+    }
+
+    #[test]
+    fn test_region_stats() {
+        let ledger =
+            RegionLedger { trades: 4, paid: 55.0, market_value: 50.0, immigrants: 2, emigrants: 1 };
+        let stats = region_stats(&Region::new("A", 0.0, 0.0), &[50.0, 50.0, 100.0, 200.0], &ledger);
+        assert_eq!(stats.population, 4);
+        assert_eq!(stats.average_money, 100.0);
+        assert!(stats.gini_coefficient > 0.0);
+        assert!((stats.price_index - 1.1).abs() < 1e-12);
+        assert_eq!(RegionLedger::default().price_index(), 1.0);
+        assert_eq!(region_stats(&Region::new("E", 0.0, 0.0), &[], &ledger).average_money, 0.0);
+    }
+}
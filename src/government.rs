@@ -0,0 +1,222 @@
+//! # Government Module
+//!
+//! A government with its own budget when enabled. Its revenue is every tax collected
+//! in the simulation: the flat trade tax (`tax_rate`), estate taxes and value added
+//! tax on purchases, plus the progressive income tax and the wealth tax it levies at
+//! the end of each step. It spends on public goods, which raise the efficiency of
+//! every skill, on unemployment benefits and on transfers to social classes.
+//!
+//! Spending is not limited by revenue: the government first spends its treasury and
+//! borrows the rest, up to an optional debt limit. It pays interest on its debt at
+//! the loan interest rate (the policy rate when the central bank is enabled) and
+//! repays debt out of any surplus.
+//!
+//! The engine runs the budget in the `government` phase. This module holds the
+//! budget, the tax schedules and the fiscal history.
+//!
+//! # Examples
+//!
+//! ```
+//! use community_simulation::government::{progressive_tax, TaxBracket};
+//!
+//! let brackets = vec![
+//!     TaxBracket { threshold: 0.0, rate: 0.1 },
+//!     TaxBracket { threshold: 50.0, rate: 0.3 },
+//! ];
+//! // 10% of the first 50, 30% of the remaining 30
+//! assert!((progressive_tax(80.0, &brackets) - 14.0).abs() < 1e-12);
+//! ```
+
+use crate::person::SocialClass;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// A marginal income tax bracket: income above `threshold` is taxed at `rate` up to
+/// the next bracket's threshold.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TaxBracket {
+    /// Income from which the bracket applies
+    pub threshold: f64,
+    /// Marginal tax rate (0.0-1.0)
+    pub rate: f64,
+}
+
+/// Returns the income tax due on `income` under the given marginal brackets.
+///
+/// The brackets may be listed in any order; income below the lowest threshold is
+/// not taxed.
+pub fn progressive_tax(income: f64, brackets: &[TaxBracket]) -> f64 {
+    let mut sorted: Vec<&TaxBracket> = brackets.iter().collect();
+    sorted.sort_by(|a, b| a.threshold.total_cmp(&b.threshold));
+    sorted
+        .iter()
+        .enumerate()
+        .map(|(i, bracket)| {
+            let upper = sorted.get(i + 1).map_or(f64::INFINITY, |next| next.threshold);
+            (income.min(upper) - bracket.threshold).max(0.0) * bracket.rate
+        })
+        .sum()
+}
+
+/// Returns the wealth tax due on `wealth` above `threshold`.
+pub fn wealth_tax(wealth: f64, threshold: f64, rate: f64) -> f64 {
+    (wealth - threshold).max(0.0) * rate
+}
+
+/// The government budget at the end of one step.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct BudgetSnapshot {
+    /// The simulation step
+    pub step: usize,
+    /// Taxes collected in the step
+    pub revenue: f64,
+    /// Public goods, benefits and transfers paid in the step
+    pub spending: f64,
+    /// Interest due on the debt in the step
+    pub interest: f64,
+    /// Revenue less spending and interest (negative for a deficit)
+    pub balance: f64,
+    /// Outstanding debt after the step
+    pub debt: f64,
+}
+
+/// The government's budget and running totals.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Government {
+    /// Cash held by the government
+    pub treasury: f64,
+    /// Outstanding debt
+    pub debt: f64,
+    /// Flat trade taxes and estate taxes received
+    pub trade_tax_revenue: f64,
+    /// Value added tax received
+    pub vat_revenue: f64,
+    /// Progressive income tax received
+    pub income_tax_revenue: f64,
+    /// Wealth tax received
+    pub wealth_tax_revenue: f64,
+    /// Spending on public goods
+    pub public_goods_spending: f64,
+    /// Unemployment benefits paid
+    pub unemployment_benefits_paid: f64,
+    /// Transfers paid to the members of each social class
+    pub class_transfers_paid: HashMap<SocialClass, f64>,
+    /// Interest paid or added to the debt
+    pub interest_due: f64,
+    /// Debt issued to cover deficits
+    pub debt_issued: f64,
+    /// Debt repaid out of surpluses
+    pub debt_repaid: f64,
+    /// Budget at the end of each step
+    pub history: Vec<BudgetSnapshot>,
+}
+
+impl Government {
+    /// Returns the taxes the government has received.
+    pub fn tax_revenue(&self) -> f64 {
+        self.trade_tax_revenue
+            + self.vat_revenue
+            + self.income_tax_revenue
+            + self.wealth_tax_revenue
+    }
+
+    /// Returns the money the government has paid to persons.
+    pub fn total_spending(&self) -> f64 {
+        self.public_goods_spending
+            + self.unemployment_benefits_paid
+            + self.class_transfers_paid.values().sum::<f64>()
+    }
+
+    /// Returns how much the government can spend: its treasury plus what it may still
+    /// borrow under the debt limit (None for no limit).
+    pub fn spending_capacity(&self, debt_limit: Option<f64>) -> f64 {
+        self.treasury + debt_limit.map_or(f64::INFINITY, |limit| (limit - self.debt).max(0.0))
+    }
+
+    /// Pays `amount` out of the treasury and borrows whatever the treasury cannot cover.
+    pub fn spend(&mut self, amount: f64) {
+        self.treasury -= amount;
+        if self.treasury < 0.0 {
+            self.debt += -self.treasury;
+            self.debt_issued += -self.treasury;
+            self.treasury = 0.0;
+        }
+    }
+
+    /// Pays interest on the debt at `rate` and repays debt out of the treasury.
+    ///
+    /// Interest the treasury cannot pay is added to the debt. Returns the interest due.
+    pub fn service_debt(&mut self, rate: f64) -> f64 {
+        let interest = self.debt * rate;
+        self.interest_due += interest;
+        let paid = interest.min(self.treasury);
+        self.treasury -= paid;
+        self.debt += interest - paid;
+
+        let repayment = self.treasury.min(self.debt);
+        self.treasury -= repayment;
+        self.debt -= repayment;
+        self.debt_repaid += repayment;
+        interest
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_progressive_tax_brackets() {
+        let brackets = vec![
+            TaxBracket { threshold: 100.0, rate: 0.4 },
+            TaxBracket { threshold: 20.0, rate: 0.2 },
+        ];
+        assert_eq!(progressive_tax(10.0, &brackets), 0.0);
+        assert!((progressive_tax(70.0, &brackets) - 10.0).abs() < 1e-12);
+        assert!((progressive_tax(150.0, &brackets) - 36.0).abs() < 1e-12);
+        assert_eq!(progressive_tax(150.0, &[]), 0.0);
+    }
+
+    #[test]
+    fn test_wealth_tax_above_threshold() {
+        assert_eq!(wealth_tax(80.0, 100.0, 0.01), 0.0);
+        assert!((wealth_tax(300.0, 100.0, 0.01) - 2.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_deficits_are_borrowed_and_surpluses_repay_debt() {
+        let mut government = Government { treasury: 30.0, ..Default::default() };
+        government.spend(50.0);
+        assert_eq!(government.treasury, 0.0);
+        assert_eq!(government.debt, 20.0);
+        assert_eq!(government.debt_issued, 20.0);
+        assert_eq!(government.spending_capacity(Some(25.0)), 5.0);
+        assert_eq!(government.spending_capacity(Some(10.0)), 0.0);
+
+        // Unpaid interest is added to the debt
+        assert_eq!(government.service_debt(0.1), 2.0);
+        assert_eq!(government.debt, 22.0);
+
+        government.treasury = 30.0;
+        government.service_debt(0.0);
+        assert_eq!(government.debt, 0.0);
+        assert_eq!(government.treasury, 8.0);
+        assert_eq!(government.debt_repaid, 22.0);
+    }
+
+    #[test]
+    fn test_revenue_and_spending_totals() {
+        let government = Government {
+            trade_tax_revenue: 1.0,
+            vat_revenue: 2.0,
+            income_tax_revenue: 3.0,
+            wealth_tax_revenue: 4.0,
+            public_goods_spending: 5.0,
+            unemployment_benefits_paid: 6.0,
+            class_transfers_paid: HashMap::from([(SocialClass::Lower, 7.0)]),
+            ..Default::default()
+        };
+        assert_eq!(government.tax_revenue(), 10.0);
+        assert_eq!(government.total_spending(), 18.0);
+    }
+}
@@ -136,21 +136,60 @@ impl Invariant for MoneyConservationInvariant {
     }
 
     fn check(&self, engine: &SimulationEngine) -> Result<(), InvariantViolation> {
-        // Calculate total money in the system
-        let total_person_money: f64 = engine
-            .get_entities()
-            .iter()
-            .map(|e| e.get_money() + e.person_data.savings)
-            .sum();
+        // Calculate total money in the system, with every balance in the base currency
+        let total_person_money = engine.get_total_person_money();
 
-        // Add fees collected by the system
-        let total_fees = engine.get_total_fees_collected();
+        // Add fees collected by the system, including P2P lending platform fees
+        let total_fees =
+            engine.get_total_fees_collected() + engine.get_total_lending_fees_collected();
 
         // Add tax revenue collected (whether redistributed or not)
         let total_taxes = engine.get_total_taxes_collected();
 
+        // Add the learning costs investors paid for education investments
+        let investment_education = engine.get_total_investment_education_spending();
+
+        // Money created (or withdrawn) by plugin hooks is not a conservation violation
+        let plugin_injections = engine.get_total_plugin_money_injected();
+
+        // Neither is the money immigrants bring with them
+        let immigrant_money = engine.get_total_immigrant_money();
+
+        // Sick pay and redistributed taxes move tax revenue back to persons, who
+        // already hold it
+        let sick_pay = engine.get_total_sick_pay();
+        let taxes_redistributed = engine.get_total_taxes_redistributed();
+
+        // Money created to pay for transfers was authorized
+        let transfer_money = engine.get_total_transfer_money_created();
+
+        // Cash held by firms
+        let firm_cash = engine.get_total_firm_cash();
+
+        // Money the central bank issued (or withdrew) was authorized
+        let central_bank_money = engine.get_total_central_bank_money_issued();
+
+        // Government spending, whether paid from taxes or borrowed, reached persons
+        let government_spending = engine.get_total_government_spending();
+
+        // Money that custom crises created or destroyed was part of their effect
+        let crisis_money = engine.get_total_crisis_money_change();
+
+        // Holdings in other currencies change value when exchange rates move
+        let revaluation = engine.get_total_exchange_revaluation();
+
         // Calculate current total
-        let current_total = total_person_money + total_fees + total_taxes;
+        let current_total =
+            total_person_money + total_fees + total_taxes + investment_education + firm_cash
+                - plugin_injections
+                - immigrant_money
+                - sick_pay
+                - taxes_redistributed
+                - transfer_money
+                - central_bank_money
+                - government_spending
+                - crisis_money
+                - revaluation;
 
         // Check if money is conserved within tolerance
         let difference = (current_total - self.initial_total_money).abs();
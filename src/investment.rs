@@ -13,6 +13,11 @@ pub enum InvestmentType {
         /// The skill being learned by the target person
         skill_id: SkillId,
     },
+    /// Investment in another person's existing skill (working capital for their trade)
+    Skill {
+        /// The skill the target person trades with
+        skill_id: SkillId,
+    },
     /// Investment in production capacity
     Production {
         /// The production recipe being enhanced
@@ -144,6 +149,13 @@ impl Investment {
     pub fn net_profit(&self) -> f64 {
         self.total_returns_paid - self.principal
     }
+
+    /// Returns the part of the principal not yet recovered through returns
+    ///
+    /// This is the loss the investor absorbs if the investment defaults now.
+    pub fn outstanding_principal(&self) -> f64 {
+        (self.principal - self.total_returns_paid).max(0.0)
+    }
 }
 
 #[cfg(test)]
@@ -256,6 +268,34 @@ mod tests {
         assert_eq!(investment.net_profit(), 20.0); // Profit!
     }
 
+    #[test]
+    fn test_investment_outstanding_principal() {
+        let mut investment = Investment::new(
+            0,
+            1,
+            Some(2),
+            InvestmentType::Skill { skill_id: "Carpentry".to_string() },
+            100.0,
+            0.02,
+            10,
+            0,
+        );
+
+        assert_eq!(investment.outstanding_principal(), 100.0);
+
+        // Each return is 12.0, so 5 returns recover 60.0 of the principal
+        for _ in 0..5 {
+            investment.collect_return();
+        }
+        assert_eq!(investment.outstanding_principal(), 40.0);
+
+        // Once returns exceed the principal nothing is at risk
+        for _ in 0..5 {
+            investment.collect_return();
+        }
+        assert_eq!(investment.outstanding_principal(), 0.0);
+    }
+
     #[test]
     fn test_profitable_investment() {
         // Create an investment with high return rate to ensure profitability
@@ -10,9 +10,12 @@
 //! - **Risk-Based Pricing**: Interest rates adjusted based on credit scores
 //! - **Automatic Matching**: Algorithm matches borrowers with suitable lenders
 //! - **Platform Fees**: Optional transaction fees for marketplace operation
+//! - **Market Statistics**: Offer book depth, matched volume and rates per credit band
 
+use crate::credit_rating::rating_category_for;
 use crate::person::PersonId;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 /// Unique identifier for a lending offer
 pub type LendingOfferId = usize;
@@ -77,6 +80,28 @@ impl LendingOffer {
     }
 }
 
+/// Loans matched on the marketplace for borrowers of one credit band.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CreditBandTotals {
+    /// Number of matched loans
+    pub loans: usize,
+    /// Total principal of the matched loans
+    pub volume: f64,
+    /// Sum of the interest rates of the matched loans
+    pub interest_rate_sum: f64,
+}
+
+impl CreditBandTotals {
+    /// Average interest rate of the matched loans (0.0 without loans).
+    pub fn avg_interest_rate(&self) -> f64 {
+        if self.loans == 0 {
+            0.0
+        } else {
+            self.interest_rate_sum / self.loans as f64
+        }
+    }
+}
+
 /// Represents the peer-to-peer lending marketplace.
 ///
 /// Manages lending offers and matches lenders with borrowers based on
@@ -91,6 +116,27 @@ pub struct LendingMarketplace {
     pub platform_fee_rate: f64,
     /// Total fees collected by the platform
     pub total_fees_collected: f64,
+    /// Number of offers posted over the marketplace's lifetime
+    #[serde(default)]
+    pub total_offers_posted: usize,
+    /// Number of loan requests matched with an offer
+    #[serde(default)]
+    pub total_matched_loans: usize,
+    /// Total principal of all matched loans
+    #[serde(default)]
+    pub total_matched_volume: f64,
+    /// Number of loan requests no offer could serve
+    #[serde(default)]
+    pub unmatched_requests: usize,
+    /// Sum of the offer book depth over all recorded steps
+    #[serde(default)]
+    pub offer_book_depth_sum: usize,
+    /// Number of steps whose offer book depth was recorded
+    #[serde(default)]
+    pub offer_book_samples: usize,
+    /// Matched loans per borrower credit band (see [`rating_category_for`])
+    #[serde(default)]
+    pub credit_band_totals: BTreeMap<String, CreditBandTotals>,
 }
 
 impl LendingMarketplace {
@@ -119,6 +165,13 @@ impl LendingMarketplace {
             offer_counter: 0,
             platform_fee_rate,
             total_fees_collected: 0.0,
+            total_offers_posted: 0,
+            total_matched_loans: 0,
+            total_matched_volume: 0.0,
+            unmatched_requests: 0,
+            offer_book_depth_sum: 0,
+            offer_book_samples: 0,
+            credit_band_totals: BTreeMap::new(),
         }
     }
 
@@ -156,6 +209,7 @@ impl LendingMarketplace {
         );
 
         self.offers.push(offer);
+        self.total_offers_posted += 1;
         offer_id
     }
 
@@ -209,6 +263,75 @@ impl LendingMarketplace {
         }
     }
 
+    /// Lends `amount` out of an offer, removing the offer once it is exhausted.
+    ///
+    /// # Arguments
+    ///
+    /// * `offer_id` - The offer the loan is funded from
+    /// * `amount` - The principal taken from the offer
+    ///
+    /// # Returns
+    ///
+    /// `true` if the offer existed and had enough funds, `false` otherwise
+    pub fn fill_offer(&mut self, offer_id: LendingOfferId, amount: f64) -> bool {
+        let Some(pos) = self.offers.iter().position(|o| o.id == offer_id) else {
+            return false;
+        };
+        let offer = &mut self.offers[pos];
+        if offer.max_amount < amount {
+            return false;
+        }
+        offer.max_amount -= amount;
+        if offer.max_amount < 0.01 {
+            self.offers.remove(pos);
+        }
+        true
+    }
+
+    /// Records a loan matched with an offer.
+    ///
+    /// # Arguments
+    ///
+    /// * `borrower_credit_score` - Credit score of the borrower
+    /// * `amount` - The principal of the loan
+    /// * `interest_rate` - The interest rate of the loan
+    pub fn record_match(&mut self, borrower_credit_score: u16, amount: f64, interest_rate: f64) {
+        self.total_matched_loans += 1;
+        self.total_matched_volume += amount;
+        let band = self
+            .credit_band_totals
+            .entry(rating_category_for(borrower_credit_score).to_string())
+            .or_default();
+        band.loans += 1;
+        band.volume += amount;
+        band.interest_rate_sum += interest_rate;
+    }
+
+    /// Records a loan request that no offer could serve.
+    pub fn record_unmatched_request(&mut self) {
+        self.unmatched_requests += 1;
+    }
+
+    /// Records the current number of open offers as one offer book sample.
+    pub fn record_offer_book_depth(&mut self) {
+        self.offer_book_depth_sum += self.offers.len();
+        self.offer_book_samples += 1;
+    }
+
+    /// Average number of open offers over all recorded steps.
+    pub fn avg_offer_book_depth(&self) -> f64 {
+        if self.offer_book_samples == 0 {
+            0.0
+        } else {
+            self.offer_book_depth_sum as f64 / self.offer_book_samples as f64
+        }
+    }
+
+    /// Total amount still available across all open offers.
+    pub fn offer_book_volume(&self) -> f64 {
+        self.offers.iter().fold(0.0, |total, offer| total + offer.max_amount)
+    }
+
     /// Records a platform fee for a successful loan.
     ///
     /// # Arguments
@@ -370,4 +493,49 @@ mod tests {
         marketplace.clear_offers();
         assert_eq!(marketplace.offers.len(), 0);
     }
+
+    #[test]
+    fn test_marketplace_fill_offer() {
+        let mut marketplace = LendingMarketplace::new(0.01);
+        let offer_id = marketplace.add_offer(1, 1000.0, 0.02, None, 0);
+
+        assert!(marketplace.fill_offer(offer_id, 400.0));
+        assert_eq!(marketplace.offers[0].max_amount, 600.0);
+
+        // More than remains is rejected and leaves the offer untouched
+        assert!(!marketplace.fill_offer(offer_id, 700.0));
+        assert_eq!(marketplace.offers[0].max_amount, 600.0);
+
+        // Exhausted offers leave the book
+        assert!(marketplace.fill_offer(offer_id, 600.0));
+        assert!(marketplace.offers.is_empty());
+        assert!(!marketplace.fill_offer(offer_id, 1.0));
+    }
+
+    #[test]
+    fn test_marketplace_statistics() {
+        let mut marketplace = LendingMarketplace::new(0.01);
+        marketplace.add_offer(1, 1000.0, 0.02, None, 0);
+        marketplace.add_offer(2, 500.0, 0.03, None, 0);
+        marketplace.record_offer_book_depth();
+        marketplace.clear_offers();
+        marketplace.record_offer_book_depth();
+
+        marketplace.record_match(820, 100.0, 0.01);
+        marketplace.record_match(810, 300.0, 0.03);
+        marketplace.record_match(600, 50.0, 0.04);
+        marketplace.record_unmatched_request();
+
+        assert_eq!(marketplace.total_offers_posted, 2);
+        assert_eq!(marketplace.avg_offer_book_depth(), 1.0);
+        assert_eq!(marketplace.total_matched_loans, 3);
+        assert_eq!(marketplace.total_matched_volume, 450.0);
+        assert_eq!(marketplace.unmatched_requests, 1);
+
+        let excellent = &marketplace.credit_band_totals["Excellent"];
+        assert_eq!(excellent.loans, 2);
+        assert_eq!(excellent.volume, 400.0);
+        assert!((excellent.avg_interest_rate() - 0.02).abs() < 1e-12);
+        assert_eq!(marketplace.credit_band_totals["Fair"].loans, 1);
+    }
 }
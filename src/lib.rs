@@ -63,6 +63,7 @@
 //! - [`asset`] - Asset system for long-term wealth building (property, equipment, stocks)
 //! - [`auction`] - Auction mechanisms for alternative price discovery
 //! - [`causal_analysis`] - Causal inference framework for policy evaluation
+//! - [`central_bank`] - Central bank with a policy rate, money creation and withdrawal
 //! - [`centrality`] - Network centrality analysis for trading networks
 //! - [`component`] - Component-based architecture foundation for extensible person capabilities
 //! - [`config`] - Simulation configuration parameters
@@ -70,11 +71,17 @@
 //! - [`credit_rating`] - Credit scoring system for evaluating creditworthiness
 //! - [`currency`] - Multi-currency system with exchange rates for international trade
 //! - [`database`] - SQLite database export functionality
+//! - [`debugger`] - Snapshots, rewinding, watch expressions and breakpoints for interactive mode
+//! - [`demographics`] - Ageing, births, deaths, immigration and market exit
 //! - [`engine`] - Main simulation engine and execution loop
 //! - [`entity`] - Entity wrapper around Person for simulation framework
 //! - [`environment`] - Environmental resource tracking and sustainability metrics
+//! - [`epidemic`] - SEIR epidemic tracking and disease policy statistics
 //! - [`error`] - Custom error types for robust error handling
 //! - [`event`] - Event system for tracking simulation events
+//! - [`firm`] - Firms that employ persons, produce from recipes and pay wages
+//! - [`geography`] - Named regions, transport links and migration between them
+//! - [`government`] - Government budget, progressive taxes, public spending and debt
 //! - [`invariant`] - Invariant checking framework for simulation validation
 //! - [`investment`] - Investment system for capital allocation and returns
 //! - [`loan`] - Loan system for credit between persons
@@ -87,7 +94,9 @@
 //! - [`result`] - Simulation results, statistics, and output formatting
 //! - [`scenario`] - Price update strategies for different simulation scenarios
 //! - [`skill`] - Skill definitions and generation
+//! - [`timeline`] - Interventions scheduled at given steps of a run
 //! - [`trade_agreement`] - Trade agreements between persons for preferential trading
+//! - [`transfers`] - Universal basic income, negative income tax and targeted transfers
 //! - [`trust_network`] - Trust network system for transitive trust relationships
 //! - [`voting`] - Voting system for governance and collective decision-making
 //! - [`wizard`] - Interactive configuration wizard for guided setup
@@ -95,7 +104,9 @@
 pub mod asset;
 pub mod auction;
 pub mod causal_analysis;
+pub mod central_bank;
 pub mod centrality;
+pub mod checkpoint;
 pub mod completion;
 pub mod component;
 pub mod config;
@@ -104,12 +115,18 @@ pub mod credit_rating;
 pub mod crisis;
 pub mod currency;
 pub mod database;
+pub mod debugger;
+pub mod demographics;
 pub mod engine;
 pub mod entity; // Represents a Person in the simulation
 pub mod environment;
+pub mod epidemic;
 pub mod error;
 pub mod event;
 pub mod externality;
+pub mod firm;
+pub mod geography;
+pub mod government;
 pub mod insurance;
 pub mod invariant;
 pub mod investment;
@@ -119,16 +136,20 @@ pub mod loan;
 pub mod market;
 pub mod parameter_sweep;
 pub mod person;
+pub mod phase;
 // pub mod physics; // Removed
 pub mod plugin;
 pub mod pool;
 pub mod production;
+pub mod profiler;
 pub mod replay;
 pub mod result;
 pub mod scenario;
 pub mod scenario_comparison;
 pub mod skill;
+pub mod timeline;
 pub mod trade_agreement;
+pub mod transfers;
 pub mod trust_network;
 pub mod utils;
 pub mod voting;
@@ -144,8 +165,8 @@ pub use config::{PresetName, SimulationConfig};
 pub use contract::{Contract, ContractId};
 pub use credit_rating::CreditScore;
 pub use crisis::CrisisEvent;
-pub use currency::{Currency, CurrencyId, CurrencySystem};
-pub use engine::{SimulationCheckpoint, SimulationEngine};
+pub use currency::{Currency, CurrencyId, CurrencySystem, ForeignExchange};
+pub use engine::{EngineSnapshot, SimulationCheckpoint, SimulationEngine};
 pub use entity::Entity; // This is our Person struct, wrapped for the engine
 pub use environment::{Environment, Resource};
 pub use error::{Result, SimulationError};
@@ -164,18 +185,20 @@ pub use parameter_sweep::{ParameterRange, ParameterSweepResult};
 pub use person::{
     ClassChange, Location, Person, PersonId, SocialClass, Strategy, Transaction, TransactionType,
 };
-pub use plugin::{Plugin, PluginContext, PluginRegistry};
+pub use phase::{BuiltinPhase, StepPhase};
+pub use plugin::{EngineView, Plugin, PluginContext, PluginRegistry, ProposedTrade};
 pub use pool::VecPool;
 pub use production::{generate_default_recipes, Recipe};
-pub use replay::{ActionLog, SimulationAction};
+pub use profiler::{CountingAllocator, ProfileReport, Profiler};
+pub use replay::{ActionLog, ReplayDivergence, ReplayReport, SimulationAction};
 pub use result::{
     calculate_statistics, calculate_wealth_concentration, detect_business_cycles,
     write_step_to_stream, BusinessCycle, BusinessCycleStats, ContractStats, CyclePhase,
-    IncrementalStats, MonteCarloResult, MonteCarloStats, SimulationMetadata, SimulationResult,
-    SocialClassStats, StepData,
+    IncrementalStats, MonteCarloResult, MonteCarloStats, PhaseTiming, SimulationMetadata,
+    SimulationResult, SocialClassStats, StepData,
 };
-pub use scenario::{PriceUpdater, Scenario};
-pub use scenario_comparison::ScenarioComparisonResult;
+pub use scenario::{PriceUpdateStrategy, PriceUpdater, Scenario};
+pub use scenario_comparison::{ScenarioComparisonResult, TransferSchemeComparisonResult};
 pub use skill::{Skill, SkillId};
 pub use trade_agreement::{TradeAgreement, TradeAgreementStatistics};
 pub use trust_network::{TrustLevel, TrustNetwork, TrustNetworkStats};
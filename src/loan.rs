@@ -32,6 +32,10 @@ pub struct Loan {
     pub payments_made: usize,
     /// Whether the loan has been fully repaid
     pub is_repaid: bool,
+    /// Number of scheduled payments the borrower missed since the loan was
+    /// created or last restructured
+    #[serde(default)]
+    pub missed_payments: usize,
 }
 
 impl Loan {
@@ -76,6 +80,7 @@ impl Loan {
             payment_per_step,
             payments_made: 0,
             is_repaid: false,
+            missed_payments: 0,
         }
     }
 
@@ -111,6 +116,45 @@ impl Loan {
         self.payment_per_step
     }
 
+    /// Records a scheduled payment the borrower could not make.
+    ///
+    /// # Returns
+    ///
+    /// The number of missed payments so far
+    pub fn record_missed_payment(&mut self) -> usize {
+        self.missed_payments += 1;
+        self.missed_payments
+    }
+
+    /// Returns whether the loan is in default after `default_threshold` missed payments.
+    pub fn is_in_default(&self, default_threshold: usize) -> bool {
+        !self.is_repaid && self.missed_payments >= default_threshold
+    }
+
+    /// Returns the amount still owed: the remaining scheduled payments.
+    pub fn outstanding_balance(&self) -> f64 {
+        if self.is_repaid {
+            return 0.0;
+        }
+        let remaining_payments = self.repayment_period.saturating_sub(self.payments_made);
+        self.payment_per_step * remaining_payments as f64
+    }
+
+    /// Rewrites the loan as an interest-free loan of `balance` repaid over
+    /// `repayment_period` steps, as agreed in a bankruptcy restructuring.
+    ///
+    /// The missed payment count starts over.
+    pub fn restructure(&mut self, balance: f64, repayment_period: usize) {
+        let repayment_period = repayment_period.max(1);
+        self.principal = balance;
+        self.remaining_principal = balance;
+        self.interest_rate = 0.0;
+        self.repayment_period = repayment_period;
+        self.payment_per_step = balance / repayment_period as f64;
+        self.payments_made = 0;
+        self.missed_payments = 0;
+    }
+
     /// Returns the total amount that will be repaid over the life of the loan
     pub fn total_repayment_amount(&self) -> f64 {
         self.payment_per_step * self.repayment_period as f64
@@ -122,6 +166,27 @@ impl Loan {
     }
 }
 
+/// Running totals of loan defaults and how their losses were absorbed.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DefaultLedger {
+    /// Loans that reached the default threshold
+    pub defaults: usize,
+    /// Defaults of borrowers who had already taken write-offs as lenders
+    pub cascade_defaults: usize,
+    /// Debt lenders wrote off (defaults, restructuring haircuts and discharges)
+    pub written_off: f64,
+    /// Value of the assets seized as collateral
+    pub collateral_seized: f64,
+    /// Number of assets seized as collateral
+    pub assets_seized: usize,
+    /// Debt covered by borrowers' credit insurance at default
+    pub insurance_recovered: f64,
+    /// Bankruptcies settled by restructuring the debt
+    pub bankruptcies_restructured: usize,
+    /// Bankruptcies settled by discharging the debt
+    pub bankruptcies_discharged: usize,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -184,6 +249,36 @@ mod tests {
         assert_eq!(payment, 0.0);
     }
 
+    #[test]
+    fn test_loan_default_after_missed_payments() {
+        let mut loan = Loan::new(0, 1, 2, 100.0, 0.01, 10, 0);
+        loan.make_payment();
+        assert_eq!(loan.record_missed_payment(), 1);
+        assert!(!loan.is_in_default(2));
+        loan.record_missed_payment();
+        assert!(loan.is_in_default(2));
+
+        // Nine payments of 11.0 are still owed
+        assert!((loan.outstanding_balance() - 99.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_loan_restructure() {
+        let mut loan = Loan::new(0, 1, 2, 100.0, 0.01, 10, 0);
+        loan.record_missed_payment();
+        loan.restructure(60.0, 20);
+
+        assert_eq!(loan.interest_rate, 0.0);
+        assert_eq!(loan.missed_payments, 0);
+        assert_eq!(loan.payment_per_step, 3.0);
+        assert_eq!(loan.outstanding_balance(), 60.0);
+        for _ in 0..20 {
+            loan.make_payment();
+        }
+        assert!(loan.is_repaid);
+        assert_eq!(loan.outstanding_balance(), 0.0);
+    }
+
     #[test]
     fn test_loan_zero_interest() {
         let loan = Loan::new(0, 1, 2, 100.0, 0.0, 10, 0);
@@ -10,10 +10,17 @@ use std::str::FromStr;
 use std::time::Instant;
 
 use community_simulation::completion;
+use community_simulation::crisis::CrisisEvent;
+use community_simulation::debugger::{Debugger, Metric, DEFAULT_SNAPSHOT_INTERVAL};
 use community_simulation::list_commands;
+use community_simulation::profiler::CountingAllocator;
 use community_simulation::scenario::Scenario;
 use community_simulation::utils::certification_duration_from_arg;
 
+// Count allocations for the opt-in profiler (`--profile`)
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
 #[derive(Parser)]
 #[command(name = "community-simulation")]
 #[command(about = "Economic simulation framework with configurable agent-based modeling")]
@@ -49,6 +56,48 @@ enum Commands {
         #[arg(value_name = "SHELL")]
         shell: String,
     },
+
+    /// Re-execute a recorded action log and verify it step by step
+    Replay {
+        /// Path to the action log written by `run --record-actions`
+        #[arg(value_name = "ACTION_LOG")]
+        action_log: String,
+
+        /// Configuration file used for the recorded run (YAML or TOML).
+        /// Seed, persons and steps are always taken from the action log.
+        #[arg(short, long)]
+        config: Option<String>,
+    },
+
+    /// Inspect and rebuild incremental checkpoints
+    Checkpoint {
+        #[command(subcommand)]
+        action: CheckpointAction,
+    },
+}
+
+#[derive(Subcommand)]
+enum CheckpointAction {
+    /// List the steps that can be restored from a checkpoint
+    List {
+        /// Path to the base checkpoint file
+        #[arg(value_name = "CHECKPOINT")]
+        checkpoint: String,
+    },
+    /// Rebuild a full checkpoint file for one checkpointed step
+    Rebuild {
+        /// Path to the base checkpoint file
+        #[arg(value_name = "CHECKPOINT")]
+        checkpoint: String,
+
+        /// Step to restore (defaults to the latest checkpointed step)
+        #[arg(long)]
+        step: Option<usize>,
+
+        /// Path of the rebuilt full checkpoint, usable with `run --resume --checkpoint-file`
+        #[arg(short, long)]
+        output: String,
+    },
 }
 
 #[derive(Subcommand)]
@@ -212,6 +261,11 @@ struct RunArgs {
     #[arg(long, default_value_t = false)]
     resume: bool,
 
+    /// Save automatic checkpoints as a base snapshot plus compact binary deltas
+    /// (written to <checkpoint-file>.deltas) instead of full JSON snapshots
+    #[arg(long, default_value_t = false)]
+    incremental_checkpoints: bool,
+
     /// Run parameter sweep analysis over a parameter range
     /// Format: "parameter:min:max:steps" (e.g., "initial_money:50:150:5")
     /// Available parameters: initial_money, base_price, savings_rate, transaction_fee
@@ -229,10 +283,44 @@ struct RunArgs {
     tax_rate: Option<f64>,
 
     /// Enable redistribution of collected taxes to all persons
-    /// When enabled, taxes are distributed equally among all persons at the end of each step
+    /// When enabled, taxes are paid out at the end of each step according to --transfer-scheme
     #[arg(long, default_value_t = false)]
     enable_tax_redistribution: bool,
 
+    /// Who receives redistributed taxes (EqualShare, UniversalBasicIncome,
+    /// NegativeIncomeTax, MeansTested, Conditional; default: EqualShare)
+    /// Only used when --enable-tax-redistribution is set
+    #[arg(long)]
+    transfer_scheme: Option<community_simulation::transfers::TransferScheme>,
+
+    /// How transfers are paid for (Taxes, MoneyCreation; default: Taxes)
+    /// Only used when --enable-tax-redistribution is set
+    #[arg(long)]
+    transfer_funding: Option<community_simulation::transfers::TransferFunding>,
+
+    /// Transfer per step to each recipient of the UniversalBasicIncome, MeansTested and
+    /// Conditional schemes (default: 5.0)
+    #[arg(long)]
+    transfer_amount: Option<f64>,
+
+    /// Income per step below which the negative income tax pays out (default: 50.0)
+    #[arg(long)]
+    negative_income_tax_threshold: Option<f64>,
+
+    /// Share of the income gap the negative income tax pays out (0.0-1.0, default: 0.5)
+    #[arg(long)]
+    negative_income_tax_rate: Option<f64>,
+
+    /// Wealth percentile below which persons receive means-tested and conditional
+    /// transfers (0.0-1.0, default: 0.25)
+    #[arg(long)]
+    means_test_percentile: Option<f64>,
+
+    /// What conditional transfers require of their recipients (Education, Health;
+    /// default: Education)
+    #[arg(long)]
+    transfer_condition: Option<community_simulation::transfers::TransferCondition>,
+
     /// Number of skills each person can provide (default: 1)
     /// Higher values create more versatile persons who can participate in multiple markets
     #[arg(long)]
@@ -250,6 +338,12 @@ struct RunArgs {
     #[arg(long)]
     compare_scenarios: Option<String>,
 
+    /// Compare transfer schemes on poverty, inequality and fiscal cost
+    /// Provide comma-separated scheme names (e.g., "UniversalBasicIncome,NegativeIncomeTax")
+    /// Tax redistribution is enabled for every run; uses --comparison-runs runs per scheme
+    #[arg(long)]
+    compare_transfer_schemes: Option<String>,
+
     /// Number of simulation runs per scenario in comparison mode (default: 3)
     /// Higher values provide more reliable statistics but take longer to execute
     #[arg(long)]
@@ -356,6 +450,34 @@ struct RunArgs {
     #[arg(long)]
     min_money_to_lend: Option<f64>,
 
+    /// Number of missed payments after which a loan defaults (e.g., 3)
+    /// Only used when --enable-loans is set
+    #[arg(long)]
+    loan_default_threshold: Option<usize>,
+
+    /// Enable personal bankruptcy for borrowers whose loans default
+    /// The first bankruptcy restructures a person's debt, later ones discharge it.
+    /// Requires --enable-loans
+    #[arg(long, default_value_t = false)]
+    enable_bankruptcy: bool,
+
+    /// Share of debt forgiven when a bankruptcy restructures it (0.0-1.0, e.g., 0.3 = 30%)
+    /// Only used when --enable-bankruptcy is set
+    #[arg(long)]
+    bankruptcy_haircut: Option<f64>,
+
+    /// Enable the peer-to-peer lending marketplace
+    /// Wealthy persons post lending offers; persons short of money for their needs
+    /// borrow from the cheapest offer their credit score qualifies for.
+    /// Requires --enable-loans
+    #[arg(long, default_value_t = false)]
+    enable_p2p_lending: bool,
+
+    /// Platform fee rate charged on P2P loans (0.0-0.1, e.g., 0.01 = 1%)
+    /// Only used when --enable-p2p-lending is set
+    #[arg(long)]
+    p2p_platform_fee_rate: Option<f64>,
+
     /// Enable insurance system where persons can purchase insurance policies
     /// When enabled, persons can buy insurance to protect against economic risks
     /// Available types: Credit (loan defaults), Income (low earnings), Crisis (economic shocks)
@@ -399,6 +521,17 @@ struct RunArgs {
     #[arg(long)]
     distance_cost_factor: Option<f64>,
 
+    /// Probability per step (0.0-1.0) that a person considers moving to another region
+    /// Persons move where residents earn more, net of transport costs
+    /// Requires regions in the configuration file. Default: 0.0 (no migration)
+    #[arg(long)]
+    migration_rate: Option<f64>,
+
+    /// How much better another region's prospects must be for a person to move there
+    /// (e.g., 0.2 = 20% higher average income). Default: 0.2
+    #[arg(long)]
+    migration_threshold: Option<f64>,
+
     /// Price elasticity factor (0.0-1.0) controlling sensitivity to supply/demand imbalances
     /// Higher values = more volatile prices, Lower values = more stable prices
     /// Default: 0.1 (10% price adjustment per unit imbalance)
@@ -418,6 +551,17 @@ struct RunArgs {
     #[arg(long, default_value_t = false)]
     enable_events: bool,
 
+    /// Profile the simulation step by step
+    /// Records wall-clock time and allocation counts per step phase, prints them as a
+    /// table after the summary and adds a `profile` section to the JSON output
+    #[arg(long, default_value_t = false)]
+    profile: bool,
+
+    /// Path to save the profile as JSON (implies --profile)
+    /// Useful for tracking performance regressions between versions
+    #[arg(long)]
+    profile_output: Option<String>,
+
     /// Enable production system where persons can combine skills to create new skills
     /// When enabled, persons use recipes to combine two input skills into more valuable output skills
     /// Simulates supply chains, skill composition, and economic specialization
@@ -446,12 +590,20 @@ struct RunArgs {
 
     /// Run simulation in interactive mode (REPL)
     /// Allows step-by-step execution with commands for debugging and exploration
-    /// Available commands: step, run N, stats, save <path>, help, exit
+    /// Available commands: step, run N, stats, save <path>, rewind <step>, watch, break, help, exit
     #[arg(long, default_value_t = false)]
     interactive: bool,
 
+    /// Steps between in-memory snapshots kept by interactive mode (default: 10)
+    /// `rewind <step>` restores the latest snapshot at or before the step and replays the rest
+    /// Lower values make rewinding faster at the cost of memory
+    /// Only used when --interactive is set
+    #[arg(long)]
+    snapshot_interval: Option<usize>,
+
     /// Record simulation actions to a JSON file for replay and debugging
     /// When enabled, logs all trades, failed trades, price updates, and crisis events
+    /// In interactive mode, parameter changes and triggered crises are logged as well
     /// The action log can be used for bug reproduction, debugging, and analysis
     #[arg(long)]
     record_actions: Option<String>,
@@ -510,17 +662,23 @@ struct RunArgs {
     enable_market_segments: bool,
 
     /// Enable auction-based trading for price discovery
-    /// When enabled, a portion of trades use auctions where buyers submit bids
-    /// and the highest bidder wins (English auction). Provides an alternative to bilateral trading.
+    /// When enabled, contested skills (demand above supply) are sold by auction to
+    /// participating buyers before bilateral trading. Provides an alternative to bilateral trading.
     #[arg(long, default_value_t = false)]
     enable_auctions: bool,
 
-    /// Percentage of trades using auctions instead of bilateral trading (0.0-1.0, default: 0.2)
-    /// For example, 0.2 means 20% of trades go through auctions
+    /// Fraction of buyers of a contested skill who bid in its auctions (0.0-1.0, default: 0.2)
+    /// For example, 0.2 means 20% of the buyers take part in auctions
     /// Only used when --enable-auctions is set
     #[arg(long)]
     auction_participation_rate: Option<f64>,
 
+    /// Comma-separated auction types; each auction picks one at random
+    /// (English, Dutch, FirstPriceSealedBid, Vickrey; default: English)
+    /// Only used when --enable-auctions is set
+    #[arg(long, value_delimiter = ',')]
+    auction_types: Option<Vec<community_simulation::auction::AuctionType>>,
+
     /// Enable community resource pools for groups (requires --num-groups)
     /// Groups maintain shared pools where members contribute money each step
     /// Pools provide collective support and mutual aid to members in need
@@ -560,6 +718,198 @@ struct RunArgs {
     #[arg(long)]
     initial_sick_persons: Option<usize>,
 
+    /// Steps between catching the disease and becoming sick (default: 0 = SIR model)
+    /// Only used when --enable-health is set
+    #[arg(long)]
+    disease_incubation_period: Option<usize>,
+
+    /// Steps recovered or vaccinated persons stay immune (default: 0 = permanent)
+    /// Only used when --enable-health is set
+    #[arg(long)]
+    immunity_duration: Option<usize>,
+
+    /// Chance per step that a sick person infects each susceptible friend (0.0-1.0, default: 0.02)
+    /// Only used when --enable-health and --enable-friendships are set
+    #[arg(long)]
+    contact_transmission_rate: Option<f64>,
+
+    /// Sick share of the population that triggers a lockdown (0.0-1.0, default: 0.0 = never)
+    /// Only used when --enable-health is set
+    #[arg(long)]
+    lockdown_threshold: Option<f64>,
+
+    /// Share of trades cancelled during a lockdown (0.0-1.0, default: 0.5)
+    /// Only used when --enable-health is set
+    #[arg(long)]
+    lockdown_trade_reduction: Option<f64>,
+
+    /// Chance per step that a susceptible person is vaccinated (0.0-1.0, default: 0.0)
+    /// Only used when --enable-health is set
+    #[arg(long)]
+    vaccination_rate: Option<f64>,
+
+    /// Step at which the vaccination campaign starts (default: 0)
+    /// Only used when --enable-health is set
+    #[arg(long)]
+    vaccination_start_step: Option<usize>,
+
+    /// Sick pay per sick person per step, paid from the tax pool (default: 0.0 = none)
+    /// Only used when --enable-health is set
+    #[arg(long)]
+    sick_pay: Option<f64>,
+
+    /// Enable demographics: persons age, retire and die, children are born,
+    /// immigrants arrive and broke persons leave the market
+    #[arg(long, default_value_t = false)]
+    enable_demographics: bool,
+
+    /// Age in steps at which persons retire and stop selling skills (default: 60)
+    /// Only used when --enable-demographics is set
+    #[arg(long)]
+    retirement_age: Option<usize>,
+
+    /// Probability that a retiree dies in a step (0.0-1.0, default: 0.05)
+    /// Only used when --enable-demographics is set
+    #[arg(long)]
+    mortality_rate: Option<f64>,
+
+    /// Probability that a working-age person has a child in a step (0.0-1.0, default: 0.01)
+    /// Only used when --enable-demographics is set
+    #[arg(long)]
+    birth_rate: Option<f64>,
+
+    /// Expected number of immigrants per step (default: 0.1)
+    /// Only used when --enable-demographics is set
+    #[arg(long)]
+    immigration_rate: Option<f64>,
+
+    /// Consecutive broke steps after which a person leaves the market (default: 10, 0 = never)
+    /// Only used when --enable-demographics is set
+    #[arg(long)]
+    exit_after_broke_steps: Option<usize>,
+
+    /// Enable firms: founders start firms that hire persons at a wage, produce
+    /// from recipes and sell their output
+    #[arg(long, default_value_t = false)]
+    enable_firms: bool,
+
+    /// Number of firms founded before the first step (default: 5)
+    /// Only used when --enable-firms is set
+    #[arg(long)]
+    firm_count: Option<usize>,
+
+    /// Probability that a new firm is founded in a step (0.0-1.0, default: 0.05)
+    /// Only used when --enable-firms is set
+    #[arg(long)]
+    firm_founding_rate: Option<f64>,
+
+    /// Capital a founder puts into a new firm (default: 50.0)
+    /// Only used when --enable-firms is set
+    #[arg(long)]
+    firm_initial_capital: Option<f64>,
+
+    /// Wage per employee per step (default: 5.0)
+    /// Only used when --enable-firms is set
+    #[arg(long)]
+    firm_wage: Option<f64>,
+
+    /// Maximum number of employees per firm (default: 10)
+    /// Only used when --enable-firms is set
+    #[arg(long)]
+    firm_max_employees: Option<usize>,
+
+    /// Output units per pair of workers per step (default: 2.0)
+    /// Only used when --enable-firms is set
+    #[arg(long)]
+    firm_productivity: Option<f64>,
+
+    /// Share of a firm's profit paid to its owner (0.0-1.0, default: 0.5)
+    /// Only used when --enable-firms is set
+    #[arg(long)]
+    firm_dividend_share: Option<f64>,
+
+    /// Enable the central bank: a policy rate replaces the loan interest rate and
+    /// money can be created or withdrawn
+    #[arg(long, default_value_t = false)]
+    enable_central_bank: bool,
+
+    /// How the central bank sets its policy (TaylorRule, Scripted; default: TaylorRule)
+    /// Scripted follows monetary_policy_path from the configuration file
+    /// Only used when --enable-central-bank is set
+    #[arg(long)]
+    monetary_policy_rule: Option<community_simulation::central_bank::MonetaryPolicyRule>,
+
+    /// Inflation target per step for the Taylor rule (default: 0.0)
+    /// Only used when --enable-central-bank is set
+    #[arg(long)]
+    inflation_target: Option<f64>,
+
+    /// Weight of the inflation gap in the Taylor rule (default: 1.5)
+    /// Only used when --enable-central-bank is set
+    #[arg(long)]
+    taylor_inflation_weight: Option<f64>,
+
+    /// Weight of the output gap in the Taylor rule (default: 0.5)
+    /// Only used when --enable-central-bank is set
+    #[arg(long)]
+    taylor_output_weight: Option<f64>,
+
+    /// How the central bank creates and withdraws money (None, OpenMarket, Helicopter;
+    /// default: None)
+    /// Only used when --enable-central-bank is set
+    #[arg(long)]
+    money_operation: Option<community_simulation::central_bank::MoneyOperation>,
+
+    /// Money created per unit of inflation below target, as a share of the money
+    /// supply (default: 1.0)
+    /// Only used when --enable-central-bank is set
+    #[arg(long)]
+    money_growth_response: Option<f64>,
+
+    /// Enable the government budget: taxes fund public goods, unemployment benefits
+    /// and class transfers, and deficits are borrowed
+    /// Income tax brackets and class transfers are set in the configuration file
+    /// Cannot be combined with --enable-tax-redistribution
+    #[arg(long, default_value_t = false)]
+    enable_government: bool,
+
+    /// Wealth tax rate per step on money and savings above the threshold (0.0-1.0,
+    /// default: 0.0)
+    /// Only used when --enable-government is set
+    #[arg(long)]
+    wealth_tax_rate: Option<f64>,
+
+    /// Wealth exempt from the wealth tax (default: 500.0)
+    /// Only used when --enable-government is set
+    #[arg(long)]
+    wealth_tax_threshold: Option<f64>,
+
+    /// Value added tax paid by buyers on top of the price (0.0-1.0, default: 0.0)
+    /// Only used when --enable-government is set
+    #[arg(long)]
+    vat_rate: Option<f64>,
+
+    /// Government spending on public goods per step (default: 0.0)
+    /// Only used when --enable-government is set
+    #[arg(long)]
+    public_goods_spending: Option<f64>,
+
+    /// Skill efficiency gain per step when public goods spending equals one base
+    /// skill price per person (default: 0.01)
+    /// Only used when --enable-government is set
+    #[arg(long)]
+    public_goods_efficiency: Option<f64>,
+
+    /// Benefit paid per step to each unemployed working-age person (default: 0.0)
+    /// Only used when --enable-government is set
+    #[arg(long)]
+    unemployment_benefit: Option<f64>,
+
+    /// Maximum government debt; 0 requires a balanced budget (default: no limit)
+    /// Only used when --enable-government is set
+    #[arg(long)]
+    government_debt_limit: Option<f64>,
+
     /// Enable invariant checking during simulation to validate correctness
     /// Invariants check conditions that should always hold true (e.g., money conservation)
     /// Useful for debugging and ensuring simulation validity
@@ -651,9 +1001,43 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         Commands::Wizard { no_color } => run_wizard(no_color),
         Commands::List { list_type } => run_list(list_type),
         Commands::Completion { shell } => run_completion(&shell),
+        Commands::Replay { action_log, config } => run_replay(&action_log, config.as_deref()),
+        Commands::Checkpoint { action } => run_checkpoint(action),
     }
 }
 
+/// Run the checkpoint subcommand
+fn run_checkpoint(action: CheckpointAction) -> Result<(), Box<dyn std::error::Error>> {
+    use community_simulation::checkpoint::{checkpoint_steps, rebuild_checkpoint};
+    use std::fs::File;
+    use std::path::Path;
+
+    match action {
+        CheckpointAction::List { checkpoint } => {
+            let steps = checkpoint_steps(Path::new(&checkpoint))?;
+            println!(
+                "{}",
+                format!("{} checkpointed steps in {}:", steps.len(), checkpoint).bright_cyan()
+            );
+            for step in steps {
+                println!("  {}", step);
+            }
+        },
+        CheckpointAction::Rebuild { checkpoint, step, output } => {
+            let state = rebuild_checkpoint(Path::new(&checkpoint), step)?;
+            let writer = io::BufWriter::new(File::create(&output)?);
+            serde_json::to_writer_pretty(writer, &state)?;
+            let restored_step = state.get("current_step").and_then(|s| s.as_u64()).unwrap_or(0);
+            println!(
+                "{}",
+                format!("✅ Rebuilt checkpoint of step {} to {}", restored_step, output)
+                    .bright_green()
+            );
+        },
+    }
+    Ok(())
+}
+
 /// Run the list subcommand
 fn run_list(list_type: ListType) -> Result<(), Box<dyn std::error::Error>> {
     match list_type {
@@ -679,6 +1063,76 @@ fn run_completion(shell_name: &str) -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+/// Replay a recorded action log and report the first divergence, if any
+fn run_replay(
+    action_log_path: &str,
+    config_path: Option<&str>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    use community_simulation::replay::{replay_action_log, ActionLog};
+
+    let log = ActionLog::load_from_file(action_log_path)?;
+    let base_config = match config_path {
+        Some(path) => SimulationConfig::from_file(path)?,
+        None => SimulationConfig::default(),
+    };
+
+    println!(
+        "{}",
+        format!(
+            "Replaying {} actions ({} persons, {} steps, seed {})",
+            log.len(),
+            log.entity_count,
+            log.max_steps,
+            log.seed
+        )
+        .bright_cyan()
+    );
+
+    let start = Instant::now();
+    let report = replay_action_log(&log, &base_config)?;
+    let duration = start.elapsed();
+
+    match &report.divergence {
+        None => {
+            println!(
+                "{}",
+                format!(
+                    "✅ Replay is deterministic: {}/{} actions verified over {} steps in {:.2}s",
+                    report.actions_verified,
+                    report.total_actions,
+                    report.steps_replayed,
+                    duration.as_secs_f64()
+                )
+                .bright_green()
+            );
+            Ok(())
+        },
+        Some(divergence) => {
+            println!(
+                "{}",
+                format!(
+                    "❌ Replay diverged at step {} (action #{} of that step)",
+                    divergence.step, divergence.action_index
+                )
+                .bright_red()
+            );
+            match &divergence.expected {
+                Some(action) => println!("   expected: {}", action),
+                None => println!("   expected: <no further actions>"),
+            }
+            match &divergence.actual {
+                Some(action) => println!("   actual:   {}", action),
+                None => println!("   actual:   <no further actions>"),
+            }
+            println!(
+                "   {}/{} actions verified before divergence",
+                report.actions_verified, report.total_actions
+            );
+            Err(format!("Replay diverged at step {}", divergence.step).into())
+        },
+    }
+}
+
 /// Run the interactive configuration wizard
 fn run_wizard(no_color: bool) -> Result<(), Box<dyn std::error::Error>> {
     // Handle --no-color flag to disable colored output globally
@@ -863,12 +1317,36 @@ fn run_simulation(args: RunArgs) -> Result<(), Box<dyn std::error::Error>> {
         if args.resume {
             cfg.resume_from_checkpoint = true;
         }
+        if args.incremental_checkpoints {
+            cfg.incremental_checkpoints = true;
+        }
         if let Some(tax_rate) = args.tax_rate {
             cfg.tax_rate = tax_rate;
         }
         if args.enable_tax_redistribution {
             cfg.enable_tax_redistribution = true;
         }
+        if let Some(scheme) = args.transfer_scheme {
+            cfg.transfer_scheme = scheme;
+        }
+        if let Some(funding) = args.transfer_funding {
+            cfg.transfer_funding = funding;
+        }
+        if let Some(amount) = args.transfer_amount {
+            cfg.transfer_amount = amount;
+        }
+        if let Some(threshold) = args.negative_income_tax_threshold {
+            cfg.negative_income_tax_threshold = threshold;
+        }
+        if let Some(rate) = args.negative_income_tax_rate {
+            cfg.negative_income_tax_rate = rate;
+        }
+        if let Some(percentile) = args.means_test_percentile {
+            cfg.means_test_percentile = percentile;
+        }
+        if let Some(condition) = args.transfer_condition {
+            cfg.transfer_condition = condition;
+        }
         if let Some(skills_per_person) = args.skills_per_person {
             cfg.skills_per_person = skills_per_person;
         }
@@ -920,6 +1398,108 @@ fn run_simulation(args: RunArgs) -> Result<(), Box<dyn std::error::Error>> {
         if let Some(min_money) = args.min_money_to_lend {
             cfg.min_money_to_lend = min_money;
         }
+        if let Some(threshold) = args.loan_default_threshold {
+            cfg.loan_default_threshold = threshold;
+        }
+        if args.enable_bankruptcy {
+            cfg.enable_bankruptcy = true;
+        }
+        if let Some(haircut) = args.bankruptcy_haircut {
+            cfg.bankruptcy_haircut = haircut;
+        }
+        if args.enable_demographics {
+            cfg.enable_demographics = true;
+        }
+        if let Some(age) = args.retirement_age {
+            cfg.retirement_age = age;
+        }
+        if let Some(rate) = args.mortality_rate {
+            cfg.mortality_rate = rate;
+        }
+        if let Some(rate) = args.birth_rate {
+            cfg.birth_rate = rate;
+        }
+        if let Some(rate) = args.immigration_rate {
+            cfg.immigration_rate = rate;
+        }
+        if let Some(steps) = args.exit_after_broke_steps {
+            cfg.exit_after_broke_steps = steps;
+        }
+        if args.enable_firms {
+            cfg.enable_firms = true;
+        }
+        if let Some(count) = args.firm_count {
+            cfg.firm_count = count;
+        }
+        if let Some(rate) = args.firm_founding_rate {
+            cfg.firm_founding_rate = rate;
+        }
+        if let Some(capital) = args.firm_initial_capital {
+            cfg.firm_initial_capital = capital;
+        }
+        if let Some(wage) = args.firm_wage {
+            cfg.firm_wage = wage;
+        }
+        if let Some(max) = args.firm_max_employees {
+            cfg.firm_max_employees = max;
+        }
+        if let Some(productivity) = args.firm_productivity {
+            cfg.firm_productivity = productivity;
+        }
+        if let Some(share) = args.firm_dividend_share {
+            cfg.firm_dividend_share = share;
+        }
+        if args.enable_central_bank {
+            cfg.enable_central_bank = true;
+        }
+        if let Some(rule) = args.monetary_policy_rule {
+            cfg.monetary_policy_rule = rule;
+        }
+        if let Some(target) = args.inflation_target {
+            cfg.inflation_target = target;
+        }
+        if let Some(weight) = args.taylor_inflation_weight {
+            cfg.taylor_inflation_weight = weight;
+        }
+        if let Some(weight) = args.taylor_output_weight {
+            cfg.taylor_output_weight = weight;
+        }
+        if let Some(operation) = args.money_operation {
+            cfg.money_operation = operation;
+        }
+        if let Some(response) = args.money_growth_response {
+            cfg.money_growth_response = response;
+        }
+        if args.enable_government {
+            cfg.enable_government = true;
+        }
+        if let Some(rate) = args.wealth_tax_rate {
+            cfg.wealth_tax_rate = rate;
+        }
+        if let Some(threshold) = args.wealth_tax_threshold {
+            cfg.wealth_tax_threshold = threshold;
+        }
+        if let Some(rate) = args.vat_rate {
+            cfg.vat_rate = rate;
+        }
+        if let Some(spending) = args.public_goods_spending {
+            cfg.public_goods_spending = spending;
+        }
+        if let Some(efficiency) = args.public_goods_efficiency {
+            cfg.public_goods_efficiency = efficiency;
+        }
+        if let Some(benefit) = args.unemployment_benefit {
+            cfg.unemployment_benefit = benefit;
+        }
+        if let Some(limit) = args.government_debt_limit {
+            cfg.government_debt_limit = Some(limit);
+        }
+        if args.enable_p2p_lending {
+            cfg.enable_p2p_lending = true;
+        }
+        if let Some(fee_rate) = args.p2p_platform_fee_rate {
+            cfg.p2p_platform_fee_rate = fee_rate;
+        }
         if args.enable_insurance {
             cfg.enable_insurance = true;
         }
@@ -947,6 +1527,18 @@ fn run_simulation(args: RunArgs) -> Result<(), Box<dyn std::error::Error>> {
         if args.enable_production {
             cfg.enable_production = true;
         }
+        if args.enable_auctions {
+            cfg.enable_auctions = true;
+        }
+        if let Some(rate) = args.auction_participation_rate {
+            cfg.auction_participation_rate = rate;
+        }
+        if let Some(auction_types) = &args.auction_types {
+            cfg.auction_types = auction_types.clone();
+        }
+        if args.profile || args.profile_output.is_some() {
+            cfg.enable_profiling = true;
+        }
         if let Some(production_prob) = args.production_probability {
             cfg.production_probability = production_prob;
         }
@@ -1019,12 +1611,36 @@ fn run_simulation(args: RunArgs) -> Result<(), Box<dyn std::error::Error>> {
             if args.resume {
                 cfg.resume_from_checkpoint = true;
             }
+            if args.incremental_checkpoints {
+                cfg.incremental_checkpoints = true;
+            }
             if let Some(tax_rate) = args.tax_rate {
                 cfg.tax_rate = tax_rate;
             }
             if args.enable_tax_redistribution {
                 cfg.enable_tax_redistribution = true;
             }
+            if let Some(scheme) = args.transfer_scheme {
+                cfg.transfer_scheme = scheme;
+            }
+            if let Some(funding) = args.transfer_funding {
+                cfg.transfer_funding = funding;
+            }
+            if let Some(amount) = args.transfer_amount {
+                cfg.transfer_amount = amount;
+            }
+            if let Some(threshold) = args.negative_income_tax_threshold {
+                cfg.negative_income_tax_threshold = threshold;
+            }
+            if let Some(rate) = args.negative_income_tax_rate {
+                cfg.negative_income_tax_rate = rate;
+            }
+            if let Some(percentile) = args.means_test_percentile {
+                cfg.means_test_percentile = percentile;
+            }
+            if let Some(condition) = args.transfer_condition {
+                cfg.transfer_condition = condition;
+            }
             if let Some(skills_per_person) = args.skills_per_person {
                 cfg.skills_per_person = skills_per_person;
             }
@@ -1085,6 +1701,21 @@ fn run_simulation(args: RunArgs) -> Result<(), Box<dyn std::error::Error>> {
             if let Some(min_money) = args.min_money_to_lend {
                 cfg.min_money_to_lend = min_money;
             }
+            if let Some(threshold) = args.loan_default_threshold {
+                cfg.loan_default_threshold = threshold;
+            }
+            if args.enable_bankruptcy {
+                cfg.enable_bankruptcy = true;
+            }
+            if let Some(haircut) = args.bankruptcy_haircut {
+                cfg.bankruptcy_haircut = haircut;
+            }
+            if args.enable_p2p_lending {
+                cfg.enable_p2p_lending = true;
+            }
+            if let Some(fee_rate) = args.p2p_platform_fee_rate {
+                cfg.p2p_platform_fee_rate = fee_rate;
+            }
             if args.enable_insurance {
                 cfg.enable_insurance = true;
             }
@@ -1106,6 +1737,12 @@ fn run_simulation(args: RunArgs) -> Result<(), Box<dyn std::error::Error>> {
             if let Some(distance_cost_factor) = args.distance_cost_factor {
                 cfg.distance_cost_factor = distance_cost_factor;
             }
+            if let Some(migration_rate) = args.migration_rate {
+                cfg.migration_rate = migration_rate;
+            }
+            if let Some(migration_threshold) = args.migration_threshold {
+                cfg.migration_threshold = migration_threshold;
+            }
             if let Some(price_elasticity) = args.price_elasticity {
                 cfg.price_elasticity_factor = price_elasticity;
             }
@@ -1115,6 +1752,18 @@ fn run_simulation(args: RunArgs) -> Result<(), Box<dyn std::error::Error>> {
             if args.enable_production {
                 cfg.enable_production = true;
             }
+            if args.enable_auctions {
+                cfg.enable_auctions = true;
+            }
+            if let Some(rate) = args.auction_participation_rate {
+                cfg.auction_participation_rate = rate;
+            }
+            if let Some(auction_types) = &args.auction_types {
+                cfg.auction_types = auction_types.clone();
+            }
+            if args.profile || args.profile_output.is_some() {
+                cfg.enable_profiling = true;
+            }
             if let Some(production_prob) = args.production_probability {
                 cfg.production_probability = production_prob;
             }
@@ -1151,6 +1800,117 @@ fn run_simulation(args: RunArgs) -> Result<(), Box<dyn std::error::Error>> {
             if let Some(count) = args.initial_sick_persons {
                 cfg.initial_sick_persons = count;
             }
+            if let Some(period) = args.disease_incubation_period {
+                cfg.disease_incubation_period = period;
+            }
+            if let Some(duration) = args.immunity_duration {
+                cfg.immunity_duration = duration;
+            }
+            if let Some(rate) = args.contact_transmission_rate {
+                cfg.contact_transmission_rate = rate;
+            }
+            if let Some(threshold) = args.lockdown_threshold {
+                cfg.lockdown_threshold = threshold;
+            }
+            if let Some(reduction) = args.lockdown_trade_reduction {
+                cfg.lockdown_trade_reduction = reduction;
+            }
+            if let Some(rate) = args.vaccination_rate {
+                cfg.vaccination_rate = rate;
+            }
+            if let Some(step) = args.vaccination_start_step {
+                cfg.vaccination_start_step = step;
+            }
+            if let Some(amount) = args.sick_pay {
+                cfg.sick_pay = amount;
+            }
+            if args.enable_demographics {
+                cfg.enable_demographics = true;
+            }
+            if let Some(age) = args.retirement_age {
+                cfg.retirement_age = age;
+            }
+            if let Some(rate) = args.mortality_rate {
+                cfg.mortality_rate = rate;
+            }
+            if let Some(rate) = args.birth_rate {
+                cfg.birth_rate = rate;
+            }
+            if let Some(rate) = args.immigration_rate {
+                cfg.immigration_rate = rate;
+            }
+            if let Some(steps) = args.exit_after_broke_steps {
+                cfg.exit_after_broke_steps = steps;
+            }
+            if args.enable_firms {
+                cfg.enable_firms = true;
+            }
+            if let Some(count) = args.firm_count {
+                cfg.firm_count = count;
+            }
+            if let Some(rate) = args.firm_founding_rate {
+                cfg.firm_founding_rate = rate;
+            }
+            if let Some(capital) = args.firm_initial_capital {
+                cfg.firm_initial_capital = capital;
+            }
+            if let Some(wage) = args.firm_wage {
+                cfg.firm_wage = wage;
+            }
+            if let Some(max) = args.firm_max_employees {
+                cfg.firm_max_employees = max;
+            }
+            if let Some(productivity) = args.firm_productivity {
+                cfg.firm_productivity = productivity;
+            }
+            if let Some(share) = args.firm_dividend_share {
+                cfg.firm_dividend_share = share;
+            }
+            if args.enable_central_bank {
+                cfg.enable_central_bank = true;
+            }
+            if let Some(rule) = args.monetary_policy_rule {
+                cfg.monetary_policy_rule = rule;
+            }
+            if let Some(target) = args.inflation_target {
+                cfg.inflation_target = target;
+            }
+            if let Some(weight) = args.taylor_inflation_weight {
+                cfg.taylor_inflation_weight = weight;
+            }
+            if let Some(weight) = args.taylor_output_weight {
+                cfg.taylor_output_weight = weight;
+            }
+            if let Some(operation) = args.money_operation {
+                cfg.money_operation = operation;
+            }
+            if let Some(response) = args.money_growth_response {
+                cfg.money_growth_response = response;
+            }
+            if args.enable_government {
+                cfg.enable_government = true;
+            }
+            if let Some(rate) = args.wealth_tax_rate {
+                cfg.wealth_tax_rate = rate;
+            }
+            if let Some(threshold) = args.wealth_tax_threshold {
+                cfg.wealth_tax_threshold = threshold;
+            }
+            if let Some(rate) = args.vat_rate {
+                cfg.vat_rate = rate;
+            }
+            if let Some(spending) = args.public_goods_spending {
+                cfg.public_goods_spending = spending;
+            }
+            if let Some(efficiency) = args.public_goods_efficiency {
+                cfg.public_goods_efficiency = efficiency;
+            }
+            if let Some(benefit) = args.unemployment_benefit {
+                cfg.unemployment_benefit = benefit;
+            }
+            if let Some(limit) = args.government_debt_limit {
+                cfg.government_debt_limit = Some(limit);
+            }
 
             // Apply social systems overrides
             apply_social_systems_overrides(cfg, &args);
@@ -1210,8 +1970,17 @@ fn run_simulation(args: RunArgs) -> Result<(), Box<dyn std::error::Error>> {
             min_money_to_lend: args
                 .min_money_to_lend
                 .unwrap_or(SimulationConfig::default().min_money_to_lend),
-            enable_p2p_lending: SimulationConfig::default().enable_p2p_lending,
-            p2p_platform_fee_rate: SimulationConfig::default().p2p_platform_fee_rate,
+            loan_default_threshold: args
+                .loan_default_threshold
+                .unwrap_or(SimulationConfig::default().loan_default_threshold),
+            enable_bankruptcy: args.enable_bankruptcy,
+            bankruptcy_haircut: args
+                .bankruptcy_haircut
+                .unwrap_or(SimulationConfig::default().bankruptcy_haircut),
+            enable_p2p_lending: args.enable_p2p_lending,
+            p2p_platform_fee_rate: args
+                .p2p_platform_fee_rate
+                .unwrap_or(SimulationConfig::default().p2p_platform_fee_rate),
             enable_investments: SimulationConfig::default().enable_investments,
             investment_return_rate: SimulationConfig::default().investment_return_rate,
             investment_duration: SimulationConfig::default().investment_duration,
@@ -1222,8 +1991,25 @@ fn run_simulation(args: RunArgs) -> Result<(), Box<dyn std::error::Error>> {
                 .unwrap_or(SimulationConfig::default().checkpoint_interval),
             checkpoint_file: args.checkpoint_file.clone(),
             resume_from_checkpoint: args.resume,
+            incremental_checkpoints: args.incremental_checkpoints,
+            checkpoint_rebase_interval: SimulationConfig::default().checkpoint_rebase_interval,
             tax_rate: args.tax_rate.unwrap_or(SimulationConfig::default().tax_rate),
             enable_tax_redistribution: args.enable_tax_redistribution,
+            transfer_scheme: args.transfer_scheme.unwrap_or_default(),
+            transfer_funding: args.transfer_funding.unwrap_or_default(),
+            transfer_amount: args
+                .transfer_amount
+                .unwrap_or(SimulationConfig::default().transfer_amount),
+            negative_income_tax_threshold: args
+                .negative_income_tax_threshold
+                .unwrap_or(SimulationConfig::default().negative_income_tax_threshold),
+            negative_income_tax_rate: args
+                .negative_income_tax_rate
+                .unwrap_or(SimulationConfig::default().negative_income_tax_rate),
+            means_test_percentile: args
+                .means_test_percentile
+                .unwrap_or(SimulationConfig::default().means_test_percentile),
+            transfer_condition: args.transfer_condition.unwrap_or_default(),
             skills_per_person: args
                 .skills_per_person
                 .unwrap_or(SimulationConfig::default().skills_per_person),
@@ -1242,6 +2028,10 @@ fn run_simulation(args: RunArgs) -> Result<(), Box<dyn std::error::Error>> {
             auction_participation_rate: args
                 .auction_participation_rate
                 .unwrap_or(SimulationConfig::default().auction_participation_rate),
+            auction_types: args
+                .auction_types
+                .clone()
+                .unwrap_or(SimulationConfig::default().auction_types),
             enable_contracts: args.enable_contracts,
             max_contract_duration: args
                 .max_contract_duration
@@ -1276,8 +2066,10 @@ fn run_simulation(args: RunArgs) -> Result<(), Box<dyn std::error::Error>> {
             crisis_severity: args
                 .crisis_severity
                 .unwrap_or(SimulationConfig::default().crisis_severity),
+            custom_crises: SimulationConfig::default().custom_crises,
             currency_system: SimulationConfig::default().currency_system,
             enable_multi_currency: SimulationConfig::default().enable_multi_currency,
+            fx_adjustment_rate: SimulationConfig::default().fx_adjustment_rate,
             enable_insurance: args.enable_insurance,
             insurance_premium_rate: args
                 .insurance_premium_rate
@@ -1308,6 +2100,14 @@ fn run_simulation(args: RunArgs) -> Result<(), Box<dyn std::error::Error>> {
             distance_cost_factor: args
                 .distance_cost_factor
                 .unwrap_or(SimulationConfig::default().distance_cost_factor),
+            regions: SimulationConfig::default().regions,
+            transport_links: SimulationConfig::default().transport_links,
+            migration_rate: args
+                .migration_rate
+                .unwrap_or(SimulationConfig::default().migration_rate),
+            migration_threshold: args
+                .migration_threshold
+                .unwrap_or(SimulationConfig::default().migration_threshold),
             price_elasticity_factor: args
                 .price_elasticity
                 .unwrap_or(SimulationConfig::default().price_elasticity_factor),
@@ -1315,6 +2115,7 @@ fn run_simulation(args: RunArgs) -> Result<(), Box<dyn std::error::Error>> {
                 .volatility
                 .unwrap_or(SimulationConfig::default().volatility_percentage),
             enable_events: args.enable_events,
+            enable_profiling: args.profile || args.profile_output.is_some(),
             enable_production: args.enable_production,
             production_probability: args
                 .production_probability
@@ -1327,6 +2128,12 @@ fn run_simulation(args: RunArgs) -> Result<(), Box<dyn std::error::Error>> {
             resource_cost_per_transaction: SimulationConfig::default()
                 .resource_cost_per_transaction,
             custom_resource_reserves: None,
+            resource_regeneration_rates: HashMap::new(),
+            resource_carrying_capacities: HashMap::new(),
+            resource_quotas: HashMap::new(),
+            enforce_resource_reserves: SimulationConfig::default().enforce_resource_reserves,
+            resource_scarcity_price_factor: SimulationConfig::default()
+                .resource_scarcity_price_factor,
             enable_voting: args.enable_voting,
             voting_method: SimulationConfig::default().voting_method,
             proposal_duration: args
@@ -1389,6 +2196,100 @@ fn run_simulation(args: RunArgs) -> Result<(), Box<dyn std::error::Error>> {
             initial_sick_persons: args
                 .initial_sick_persons
                 .unwrap_or(SimulationConfig::default().initial_sick_persons),
+            disease_incubation_period: args
+                .disease_incubation_period
+                .unwrap_or(SimulationConfig::default().disease_incubation_period),
+            immunity_duration: args
+                .immunity_duration
+                .unwrap_or(SimulationConfig::default().immunity_duration),
+            contact_transmission_rate: args
+                .contact_transmission_rate
+                .unwrap_or(SimulationConfig::default().contact_transmission_rate),
+            lockdown_threshold: args
+                .lockdown_threshold
+                .unwrap_or(SimulationConfig::default().lockdown_threshold),
+            lockdown_trade_reduction: args
+                .lockdown_trade_reduction
+                .unwrap_or(SimulationConfig::default().lockdown_trade_reduction),
+            vaccination_rate: args
+                .vaccination_rate
+                .unwrap_or(SimulationConfig::default().vaccination_rate),
+            vaccination_start_step: args
+                .vaccination_start_step
+                .unwrap_or(SimulationConfig::default().vaccination_start_step),
+            sick_pay: args.sick_pay.unwrap_or(SimulationConfig::default().sick_pay),
+            enable_demographics: args.enable_demographics,
+            retirement_age: args
+                .retirement_age
+                .unwrap_or(SimulationConfig::default().retirement_age),
+            mortality_rate: args
+                .mortality_rate
+                .unwrap_or(SimulationConfig::default().mortality_rate),
+            birth_rate: args.birth_rate.unwrap_or(SimulationConfig::default().birth_rate),
+            immigration_rate: args
+                .immigration_rate
+                .unwrap_or(SimulationConfig::default().immigration_rate),
+            exit_after_broke_steps: args
+                .exit_after_broke_steps
+                .unwrap_or(SimulationConfig::default().exit_after_broke_steps),
+            enable_firms: args.enable_firms,
+            firm_count: args.firm_count.unwrap_or(SimulationConfig::default().firm_count),
+            firm_founding_rate: args
+                .firm_founding_rate
+                .unwrap_or(SimulationConfig::default().firm_founding_rate),
+            firm_initial_capital: args
+                .firm_initial_capital
+                .unwrap_or(SimulationConfig::default().firm_initial_capital),
+            firm_wage: args.firm_wage.unwrap_or(SimulationConfig::default().firm_wage),
+            firm_max_employees: args
+                .firm_max_employees
+                .unwrap_or(SimulationConfig::default().firm_max_employees),
+            firm_productivity: args
+                .firm_productivity
+                .unwrap_or(SimulationConfig::default().firm_productivity),
+            firm_dividend_share: args
+                .firm_dividend_share
+                .unwrap_or(SimulationConfig::default().firm_dividend_share),
+            enable_central_bank: args.enable_central_bank,
+            monetary_policy_rule: args
+                .monetary_policy_rule
+                .unwrap_or(SimulationConfig::default().monetary_policy_rule),
+            inflation_target: args
+                .inflation_target
+                .unwrap_or(SimulationConfig::default().inflation_target),
+            taylor_inflation_weight: args
+                .taylor_inflation_weight
+                .unwrap_or(SimulationConfig::default().taylor_inflation_weight),
+            taylor_output_weight: args
+                .taylor_output_weight
+                .unwrap_or(SimulationConfig::default().taylor_output_weight),
+            money_operation: args
+                .money_operation
+                .unwrap_or(SimulationConfig::default().money_operation),
+            money_growth_response: args
+                .money_growth_response
+                .unwrap_or(SimulationConfig::default().money_growth_response),
+            monetary_policy_path: SimulationConfig::default().monetary_policy_path,
+            enable_government: args.enable_government,
+            income_tax_brackets: Vec::new(), // Not configurable via CLI
+            wealth_tax_rate: args
+                .wealth_tax_rate
+                .unwrap_or(SimulationConfig::default().wealth_tax_rate),
+            wealth_tax_threshold: args
+                .wealth_tax_threshold
+                .unwrap_or(SimulationConfig::default().wealth_tax_threshold),
+            vat_rate: args.vat_rate.unwrap_or(SimulationConfig::default().vat_rate),
+            public_goods_spending: args
+                .public_goods_spending
+                .unwrap_or(SimulationConfig::default().public_goods_spending),
+            public_goods_efficiency: args
+                .public_goods_efficiency
+                .unwrap_or(SimulationConfig::default().public_goods_efficiency),
+            unemployment_benefit: args
+                .unemployment_benefit
+                .unwrap_or(SimulationConfig::default().unemployment_benefit),
+            class_transfers: HashMap::new(), // Not configurable via CLI
+            government_debt_limit: args.government_debt_limit,
             enable_automation: SimulationConfig::default().enable_automation,
             automation_rate: SimulationConfig::default().automation_rate,
             automation_risks_per_skill: HashMap::new(), // Not configurable via CLI
@@ -1415,6 +2316,9 @@ fn run_simulation(args: RunArgs) -> Result<(), Box<dyn std::error::Error>> {
             rental_income_rate: SimulationConfig::default().rental_income_rate,
             stock_return_rate: SimulationConfig::default().stock_return_rate,
             asset_price_multiplier: SimulationConfig::default().asset_price_multiplier,
+            step_phases: SimulationConfig::default().step_phases,
+            disabled_step_phases: SimulationConfig::default().disabled_step_phases,
+            timeline: SimulationConfig::default().timeline,
         }
     };
 
@@ -1430,11 +2334,12 @@ fn run_simulation(args: RunArgs) -> Result<(), Box<dyn std::error::Error>> {
         if args.parameter_sweep.is_some() {
             return Err("Interactive mode cannot be combined with parameter sweep".into());
         }
-        if args.compare_scenarios.is_some() {
+        if args.compare_scenarios.is_some() || args.compare_transfer_schemes.is_some() {
             return Err("Interactive mode cannot be combined with scenario comparison".into());
         }
 
-        return run_interactive_mode(config);
+        let snapshot_interval = args.snapshot_interval.unwrap_or(DEFAULT_SNAPSHOT_INTERVAL);
+        return run_interactive_mode(config, snapshot_interval, args.record_actions);
     }
 
     // Check if scenario comparison mode is enabled
@@ -1445,6 +2350,13 @@ fn run_simulation(args: RunArgs) -> Result<(), Box<dyn std::error::Error>> {
         }
 
         run_scenario_comparison(config, &scenario_spec, comparison_runs, args.output)?;
+    } else if let Some(scheme_spec) = args.compare_transfer_schemes {
+        let comparison_runs = args.comparison_runs.unwrap_or(3);
+        if comparison_runs < 1 {
+            return Err("Comparison runs must be at least 1".into());
+        }
+
+        run_transfer_scheme_comparison(config, &scheme_spec, comparison_runs, args.output)?;
     } else if let Some(sweep_spec) = args.parameter_sweep {
         let sweep_runs = args.sweep_runs.unwrap_or(3);
         if sweep_runs < 1 {
@@ -1561,6 +2473,11 @@ fn run_simulation(args: RunArgs) -> Result<(), Box<dyn std::error::Error>> {
             info!("{}", format!("Parquet data saved to: {}", parquet_path).bright_blue());
         }
 
+        if let (Some(profile_path), Some(profile)) = (&args.profile_output, &result.profile) {
+            profile.save_to_json(profile_path)?;
+            info!("{}", format!("Profile saved to: {}", profile_path).bright_blue());
+        }
+
         result.print_summary_with_options(!args.no_histogram, args.show_price_chart);
     }
 
@@ -1568,7 +2485,11 @@ fn run_simulation(args: RunArgs) -> Result<(), Box<dyn std::error::Error>> {
 }
 
 /// Run simulation in interactive mode (REPL) for step-by-step execution
-fn run_interactive_mode(config: SimulationConfig) -> Result<(), Box<dyn std::error::Error>> {
+fn run_interactive_mode(
+    config: SimulationConfig,
+    snapshot_interval: usize,
+    record_actions: Option<String>,
+) -> Result<(), Box<dyn std::error::Error>> {
     info!(
         "{}",
         "Starting interactive mode. Type 'help' for available commands.".bright_cyan()
@@ -1595,6 +2516,14 @@ fn run_interactive_mode(config: SimulationConfig) -> Result<(), Box<dyn std::err
         SimulationEngine::new(config.clone())
     };
 
+    // Record the session, including parameter changes, so that it can be replayed
+    if record_actions.is_some() {
+        engine.enable_action_recording();
+    }
+
+    // Keep snapshots for rewinding, plus watch expressions and breakpoints
+    let mut debugger = Debugger::new(&engine, snapshot_interval);
+
     // Create readline editor for interactive input
     let mut rl = DefaultEditor::new()?;
 
@@ -1639,7 +2568,40 @@ fn run_interactive_mode(config: SimulationConfig) -> Result<(), Box<dyn std::err
                         println!("  {} - Show current simulation status", "status".bright_green());
                         println!(
                             "  {} - Show detailed state of a specific person",
-                            "inspect <id>".bright_green()
+                            "inspect [person] <id>".bright_green()
+                        );
+                        println!(
+                            "  {} - Go back to an earlier step",
+                            "rewind <step>".bright_green()
+                        );
+                        println!(
+                            "  {} - List the steps of the in-memory snapshots",
+                            "snapshots".bright_green()
+                        );
+                        println!(
+                            "  {} - Print a metric after every step (e.g. gini, person 12 savings)",
+                            "watch [<metric>]".bright_green()
+                        );
+                        println!(
+                            "  {} - Remove a watch expression",
+                            "unwatch <n|all>".bright_green()
+                        );
+                        println!(
+                            "  {} - Stop when a condition becomes true (e.g. gini > 0.6, person 12 < 0)",
+                            "break [<condition>]".bright_green()
+                        );
+                        println!("  {} - Remove a breakpoint", "delete <n|all>".bright_green());
+                        println!(
+                            "  {} - Change a configuration parameter (e.g. set tax_rate 0.2)",
+                            "set <param> <value>".bright_green()
+                        );
+                        println!(
+                            "  {} - Show a configuration parameter",
+                            "get <param>".bright_green()
+                        );
+                        println!(
+                            "  {} - Trigger a crisis now (e.g. trigger crisis MarketCrash 0.8)",
+                            "trigger crisis <type> [severity]".bright_green()
                         );
                         println!(
                             "  {} - List all persons with summary info",
@@ -1675,7 +2637,7 @@ fn run_interactive_mode(config: SimulationConfig) -> Result<(), Box<dyn std::err
                         }
 
                         let start = Instant::now();
-                        engine.step();
+                        let hits = debugger.step(&mut engine);
                         let duration = start.elapsed();
                         println!(
                             "{}",
@@ -1686,6 +2648,8 @@ fn run_interactive_mode(config: SimulationConfig) -> Result<(), Box<dyn std::err
                             )
                             .bright_green()
                         );
+                        print_watches(&debugger, &engine);
+                        print_breakpoint_hits(&debugger, &hits, &engine);
                     },
                     "run" => {
                         if parts.len() < 2 {
@@ -1730,12 +2694,15 @@ fn run_interactive_mode(config: SimulationConfig) -> Result<(), Box<dyn std::err
                         }
 
                         let start = Instant::now();
-                        for i in 0..steps_to_run {
-                            engine.step();
-                            if (i + 1) % 10 == 0 || (i + 1) == steps_to_run {
+                        let mut steps_run = 0;
+                        let mut hits = Vec::new();
+                        while steps_run < steps_to_run && hits.is_empty() {
+                            hits = debugger.step(&mut engine);
+                            steps_run += 1;
+                            if steps_run % 10 == 0 || steps_run == steps_to_run {
                                 println!(
                                     "{}",
-                                    format!("  Progress: {}/{} steps", i + 1, steps_to_run)
+                                    format!("  Progress: {}/{} steps", steps_run, steps_to_run)
                                         .bright_blue()
                                 );
                             }
@@ -1745,16 +2712,18 @@ fn run_interactive_mode(config: SimulationConfig) -> Result<(), Box<dyn std::err
                             "{}",
                             format!(
                                 "Executed {} steps in {:.3}s ({:.1} steps/s)",
-                                steps_to_run,
+                                steps_run,
                                 duration.as_secs_f64(),
                                 if duration.as_secs_f64() > 0.0 {
-                                    steps_to_run as f64 / duration.as_secs_f64()
+                                    steps_run as f64 / duration.as_secs_f64()
                                 } else {
                                     0.0
                                 }
                             )
                             .bright_green()
                         );
+                        print_watches(&debugger, &engine);
+                        print_breakpoint_hits(&debugger, &hits, &engine);
                     },
                     "stats" => {
                         let result = engine.get_current_result();
@@ -1794,12 +2763,18 @@ fn run_interactive_mode(config: SimulationConfig) -> Result<(), Box<dyn std::err
                         }
                     },
                     "inspect" => {
-                        if parts.len() < 2 {
-                            println!("{}", "Usage: inspect <person_id>".bright_red());
+                        // `inspect person <id>` and `inspect <id>` are equivalent
+                        let id_index = if parts.get(1) == Some(&"person") {
+                            2
+                        } else {
+                            1
+                        };
+                        if parts.len() <= id_index {
+                            println!("{}", "Usage: inspect [person] <person_id>".bright_red());
                             continue;
                         }
 
-                        let person_id: usize = match parts[1].parse() {
+                        let person_id: usize = match parts[id_index].parse() {
                             Ok(id) => id,
                             Err(_) => {
                                 println!("{}", "Invalid person ID".bright_red());
@@ -1876,14 +2851,42 @@ fn run_interactive_mode(config: SimulationConfig) -> Result<(), Box<dyn std::err
                                 }
                             }
 
+                            // Display friends
+                            if !person.friends.is_empty() {
+                                let mut friends: Vec<_> = person.friends.iter().collect();
+                                friends.sort();
+                                let friends: Vec<String> =
+                                    friends.iter().map(|id| id.to_string()).collect();
+                                println!(
+                                    "\n  {}: {}",
+                                    "Friend IDs".bright_green(),
+                                    friends.join(", ")
+                                );
+                            }
+
                             // Display loans
                             if !person.borrowed_loans.is_empty() || !person.lent_loans.is_empty() {
                                 println!("\n  {}:", "Loans".bright_magenta());
-                                if !person.borrowed_loans.is_empty() {
-                                    println!("    Borrowed: {} loans", person.borrowed_loans.len());
-                                }
-                                if !person.lent_loans.is_empty() {
-                                    println!("    Lent: {} loans", person.lent_loans.len());
+                                let loans = person
+                                    .borrowed_loans
+                                    .iter()
+                                    .map(|id| ("Borrowed", id))
+                                    .chain(person.lent_loans.iter().map(|id| ("Lent", id)));
+                                for (direction, loan_id) in loans {
+                                    match engine.get_loan(*loan_id) {
+                                        Some(loan) => println!(
+                                            "    {} loan {}: {:.2} of {:.2} outstanding at {:.2}% ({} -> {}){}",
+                                            direction,
+                                            loan.id,
+                                            loan.remaining_principal,
+                                            loan.principal,
+                                            loan.interest_rate * 100.0,
+                                            loan.lender_id,
+                                            loan.borrower_id,
+                                            if loan.is_repaid { ", repaid" } else { "" }
+                                        ),
+                                        None => println!("    {} loan {}", direction, loan_id),
+                                    }
                                 }
                             }
 
@@ -1909,6 +2912,184 @@ fn run_interactive_mode(config: SimulationConfig) -> Result<(), Box<dyn std::err
                             println!("{}", format!("Person {} not found", person_id).bright_red());
                         }
                     },
+                    "rewind" => {
+                        let target: usize = match parts.get(1).map(|arg| arg.parse()) {
+                            Some(Ok(step)) => step,
+                            _ => {
+                                println!("{}", "Usage: rewind <step>".bright_red());
+                                continue;
+                            },
+                        };
+
+                        let start = Instant::now();
+                        match debugger.rewind(&mut engine, target) {
+                            Ok(snapshot_step) => {
+                                println!(
+                                    "{}",
+                                    format!(
+                                        "Rewound to step {} (restored snapshot of step {}, replayed {} steps in {:.3}s)",
+                                        target,
+                                        snapshot_step,
+                                        target - snapshot_step,
+                                        start.elapsed().as_secs_f64()
+                                    )
+                                    .bright_green()
+                                );
+                                print_watches(&debugger, &engine);
+                            },
+                            Err(e) => println!("{}", e.bright_red()),
+                        }
+                    },
+                    "snapshots" => {
+                        let steps: Vec<String> =
+                            debugger.snapshot_steps().iter().map(|step| step.to_string()).collect();
+                        println!(
+                            "{} snapshots (every {} steps): {}",
+                            steps.len(),
+                            snapshot_interval.max(1),
+                            steps.join(", ")
+                        );
+                    },
+                    "watch" => {
+                        if parts.len() > 1 {
+                            match parts[1..].join(" ").parse() {
+                                Ok(metric) => debugger.add_watch(metric),
+                                Err(e) => {
+                                    println!("{}", e.bright_red());
+                                    continue;
+                                },
+                            }
+                        }
+                        if debugger.watches().is_empty() {
+                            println!("{}", "No watch expressions".bright_yellow());
+                        }
+                        for (i, metric) in debugger.watches().iter().enumerate() {
+                            println!("  [{}] {} = {}", i, metric, format_metric(*metric, &engine));
+                        }
+                    },
+                    "unwatch" => match parts.get(1) {
+                        Some(&"all") => debugger.clear_watches(),
+                        Some(index) => {
+                            match index.parse().ok().and_then(|i| debugger.remove_watch(i)) {
+                                Some(metric) => println!("Removed watch {}", metric),
+                                None => println!(
+                                    "{}",
+                                    format!("No watch expression {}", index).bright_red()
+                                ),
+                            }
+                        },
+                        None => println!("{}", "Usage: unwatch <n|all>".bright_red()),
+                    },
+                    "break" => {
+                        if parts.len() > 1 {
+                            match parts[1..].join(" ").parse() {
+                                Ok(condition) => debugger.add_breakpoint(condition, &engine),
+                                Err(e) => {
+                                    println!("{}", e.bright_red());
+                                    continue;
+                                },
+                            }
+                        }
+                        if debugger.breakpoints().is_empty() {
+                            println!("{}", "No breakpoints".bright_yellow());
+                        }
+                        for (i, breakpoint) in debugger.breakpoints().iter().enumerate() {
+                            println!("  [{}] {}", i, breakpoint.condition);
+                        }
+                    },
+                    "delete" => match parts.get(1) {
+                        Some(&"all") => debugger.clear_breakpoints(),
+                        Some(index) => {
+                            match index.parse().ok().and_then(|i| debugger.remove_breakpoint(i)) {
+                                Some(condition) => println!("Removed breakpoint {}", condition),
+                                None => {
+                                    println!("{}", format!("No breakpoint {}", index).bright_red())
+                                },
+                            }
+                        },
+                        None => println!("{}", "Usage: delete <n|all>".bright_red()),
+                    },
+                    "set" => {
+                        if parts.len() < 3 {
+                            println!("{}", "Usage: set <param> <value>".bright_red());
+                            continue;
+                        }
+
+                        let value = parts[2..].join(" ");
+                        match engine.set_parameter(parts[1], &value) {
+                            Ok(()) => println!(
+                                "{}",
+                                format!(
+                                    "{} set to {} (from step {})",
+                                    parts[1],
+                                    config_value(&engine, parts[1]).unwrap_or(value),
+                                    current_step + 1
+                                )
+                                .bright_green()
+                            ),
+                            Err(e) => println!("{}", e.to_string().bright_red()),
+                        }
+                    },
+                    "get" => {
+                        if parts.len() < 2 {
+                            println!("{}", "Usage: get <param>".bright_red());
+                            continue;
+                        }
+
+                        match config_value(&engine, parts[1]) {
+                            Some(value) => println!("{} = {}", parts[1], value),
+                            None => println!(
+                                "{}",
+                                format!("Unknown parameter: '{}'", parts[1]).bright_red()
+                            ),
+                        }
+                    },
+                    "trigger" => {
+                        if parts.len() < 3 || parts[1] != "crisis" {
+                            println!("{}", "Usage: trigger crisis <type> [severity]".bright_red());
+                            continue;
+                        }
+
+                        let crisis: CrisisEvent = match parts[2].parse() {
+                            Ok(crisis) => crisis,
+                            Err(_) => {
+                                let types: Vec<String> = CrisisEvent::all_types()
+                                    .iter()
+                                    .map(|crisis| format!("{:?}", crisis))
+                                    .collect();
+                                println!(
+                                    "{}",
+                                    format!(
+                                        "Unknown crisis type '{}' (expected one of: {})",
+                                        parts[2],
+                                        types.join(", ")
+                                    )
+                                    .bright_red()
+                                );
+                                continue;
+                            },
+                        };
+                        let severity = match parts.get(3).map(|arg| arg.parse::<f64>()) {
+                            Some(Ok(severity)) => severity,
+                            Some(Err(_)) => {
+                                println!("{}", "Invalid severity".bright_red());
+                                continue;
+                            },
+                            None => engine.get_config().crisis_severity,
+                        };
+                        match engine.trigger_crisis(crisis, severity) {
+                            Ok(()) => println!(
+                                "{}",
+                                format!(
+                                    "Triggered {} with severity {:.2}",
+                                    crisis.name(),
+                                    severity
+                                )
+                                .bright_green()
+                            ),
+                            Err(e) => println!("{}", e.to_string().bright_red()),
+                        }
+                    },
                     "persons" | "list-persons" => {
                         let entities = engine.get_entities();
                         println!(
@@ -2158,10 +3339,59 @@ fn run_interactive_mode(config: SimulationConfig) -> Result<(), Box<dyn std::err
         }
     }
 
+    if let Some(action_log_path) = record_actions {
+        engine.save_action_log(&action_log_path)?;
+        info!("{}", format!("Action log saved to: {}", action_log_path).bright_blue());
+    }
+
     println!("{}", "Interactive mode ended.".bright_green());
     Ok(())
 }
 
+/// Format the current value of a configuration parameter as JSON for interactive mode
+fn config_value(engine: &SimulationEngine, name: &str) -> Option<String> {
+    let config = serde_json::to_value(engine.get_config()).ok()?;
+    config.get(name).map(|value| value.to_string())
+}
+
+/// Format the value of a watched metric for interactive mode
+fn format_metric(metric: Metric, engine: &SimulationEngine) -> String {
+    match metric.evaluate(engine) {
+        Some(value) if metric == Metric::Gini => format!("{:.4}", value),
+        Some(value) if matches!(metric, Metric::ActivePersons | Metric::Step) => {
+            format!("{}", value)
+        },
+        Some(value) => format!("{:.2}", value),
+        None => "n/a".to_string(),
+    }
+}
+
+/// Print the values of all watch expressions after a step in interactive mode
+fn print_watches(debugger: &Debugger, engine: &SimulationEngine) {
+    for metric in debugger.watches() {
+        println!("  {} {} = {}", "watch:".bright_cyan(), metric, format_metric(*metric, engine));
+    }
+}
+
+/// Report the breakpoints that stopped a step in interactive mode
+fn print_breakpoint_hits(debugger: &Debugger, hits: &[usize], engine: &SimulationEngine) {
+    for &index in hits {
+        let condition = debugger.breakpoints()[index].condition;
+        println!(
+            "{}",
+            format!(
+                "Breakpoint [{}] hit at step {}: {} ({} = {})",
+                index,
+                engine.get_current_step(),
+                condition,
+                condition.metric,
+                format_metric(condition.metric, engine)
+            )
+            .bright_magenta()
+        );
+    }
+}
+
 /// Run multiple simulations in parallel with different seeds (Monte Carlo method)
 fn run_monte_carlo(
     base_config: SimulationConfig,
@@ -2403,3 +3633,55 @@ fn run_scenario_comparison(
 
     Ok(())
 }
+
+/// Parse and run a transfer scheme comparison
+fn run_transfer_scheme_comparison(
+    base_config: SimulationConfig,
+    scheme_spec: &str,
+    runs_per_scheme: usize,
+    output: Option<String>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    use community_simulation::transfers::TransferScheme;
+    use community_simulation::TransferSchemeComparisonResult;
+    use std::str::FromStr;
+
+    // Parse scheme specification: comma-separated list of scheme names
+    let mut schemes = Vec::new();
+    for name in scheme_spec.split(',').map(|s| s.trim()) {
+        let scheme = TransferScheme::from_str(name).map_err(|e| {
+            format!(
+                "Invalid transfer scheme '{}': {}. Available: EqualShare, UniversalBasicIncome, \
+                 NegativeIncomeTax, MeansTested, Conditional",
+                name, e
+            )
+        })?;
+        schemes.push(scheme);
+    }
+
+    // Remove duplicates while preserving order
+    schemes.dedup();
+
+    let start_time = Instant::now();
+    let result = TransferSchemeComparisonResult::run(base_config, schemes, runs_per_scheme)?;
+    info!(
+        "{}",
+        format!(
+            "Transfer scheme comparison completed in {:.2}s",
+            start_time.elapsed().as_secs_f64()
+        )
+        .bright_green()
+    );
+
+    // Save results if output path specified
+    if let Some(output_path) = output {
+        result.save_to_file(&output_path)?;
+        info!(
+            "{}",
+            format!("Transfer scheme comparison results saved to {}", output_path).bright_blue()
+        );
+    }
+
+    result.print_summary();
+
+    Ok(())
+}
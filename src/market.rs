@@ -4,9 +4,10 @@
 //! and manages skill prices based on supply, demand, and configured pricing strategies.
 
 use crate::{
-    scenario::PriceUpdater,
+    scenario::{PriceStrategyCheckpoint, PriceUpdateStrategy, PriceUpdater},
     skill::{Skill, SkillId},
 };
+use log::{info, warn};
 use rand::Rng;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -37,6 +38,7 @@ struct MarketStatsCache {
 /// Prices can be updated using different strategies:
 /// - **Original**: Supply/demand-based with random volatility
 /// - **DynamicPricing**: Sales-based (increase if sold, decrease if not)
+/// - **Custom**: Any [`PriceUpdateStrategy`] registered with [`Market::set_price_strategy`]
 ///
 /// # Examples
 ///
@@ -102,6 +104,14 @@ pub struct Market {
     #[serde(default, skip_serializing_if = "HashMap::is_empty")]
     pub per_skill_price_limits: HashMap<SkillId, (Option<f64>, Option<f64>)>,
 
+    /// Cost multiplier of each skill caused by the scarcity of the resources it uses.
+    ///
+    /// Set each step by the environment when resource tracking is enabled and applied
+    /// on top of the market price when trades are priced. Skills without an entry
+    /// have a multiplier of 1.0.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub resource_cost_multipliers: HashMap<SkillId, f64>,
+
     /// Historical price data for each skill across all simulation steps
     ///
     /// Maps skill IDs to a vector of prices, with one entry per simulation step.
@@ -127,6 +137,17 @@ pub struct Market {
     #[serde(skip)]
     price_updater: PriceUpdater,
 
+    /// Custom price update strategy; takes precedence over `price_updater` when set
+    #[serde(skip)]
+    custom_price_strategy: Option<Box<dyn PriceUpdateStrategy>>,
+
+    /// Name and state of the custom price strategy, as stored in checkpoints.
+    ///
+    /// Filled when a checkpoint is saved and consumed when the strategy is registered
+    /// again after loading it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    price_strategy_checkpoint: Option<PriceStrategyCheckpoint>,
+
     /// Count of sales for each skill in the current step
     ///
     /// Used by the DynamicPricing scenario to track which skills were purchased.
@@ -179,10 +200,13 @@ impl Market {
             min_skill_price,
             max_skill_price: 1000.0,
             per_skill_price_limits: HashMap::new(),
+            resource_cost_multipliers: HashMap::new(),
             skill_price_history: HashMap::new(),
             demand_history: HashMap::new(),
             supply_history: HashMap::new(),
             price_updater,
+            custom_price_strategy: None,
+            price_strategy_checkpoint: None,
             sales_this_step: HashMap::new(),
             cache: MarketStatsCache::default(),
         }
@@ -218,6 +242,19 @@ impl Market {
         *self.supply_counts.entry(skill_id.clone()).or_insert(0) += 1;
     }
 
+    /// Decrements the supply counter for a skill.
+    ///
+    /// Should be called once for each person who stops providing this skill.
+    ///
+    /// # Arguments
+    ///
+    /// * `skill_id` - Identifier of the skill
+    pub fn decrement_skill_supply(&mut self, skill_id: &SkillId) {
+        if let Some(count) = self.supply_counts.get_mut(skill_id) {
+            *count = count.saturating_sub(1);
+        }
+    }
+
     /// Resets demand counters and sales tracking for a new simulation step.
     ///
     /// This should be called at the beginning of each simulation step before
@@ -290,6 +327,29 @@ impl Market {
         self.skills.get(skill_id).map(|s| (s.current_price, s.efficiency_multiplier))
     }
 
+    /// Gets the resource scarcity cost multiplier of a skill.
+    ///
+    /// # Arguments
+    ///
+    /// * `skill_id` - Identifier of the skill
+    ///
+    /// # Returns
+    ///
+    /// * `f64` - The multiplier (1.0 if no resource costs apply to the skill)
+    pub fn get_resource_cost_multiplier(&self, skill_id: &SkillId) -> f64 {
+        self.resource_cost_multipliers.get(skill_id).copied().unwrap_or(1.0)
+    }
+
+    /// Sets the resource scarcity cost multiplier of a skill.
+    ///
+    /// # Arguments
+    ///
+    /// * `skill_id` - Identifier of the skill
+    /// * `multiplier` - Factor applied to the skill's price when trades are priced
+    pub fn set_resource_cost_multiplier(&mut self, skill_id: &SkillId, multiplier: f64) {
+        self.resource_cost_multipliers.insert(skill_id.clone(), multiplier);
+    }
+
     /// Updates all skill prices based on current supply, demand, and the configured pricing strategy.
     ///
     /// This method delegates to the configured [`PriceUpdater`] to perform the actual
@@ -300,12 +360,73 @@ impl Market {
     ///
     /// * `rng` - Random number generator for adding volatility (if applicable)
     pub fn update_prices<R: Rng + ?Sized>(&mut self, rng: &mut R) {
-        let updater = self.price_updater.clone();
-        updater.update_prices(self, rng);
+        if let Some(mut strategy) = self.custom_price_strategy.take() {
+            let mut rng = rng;
+            strategy.update_prices(self, &mut rng);
+            self.custom_price_strategy = Some(strategy);
+        } else {
+            let updater = self.price_updater.clone();
+            updater.update_prices(self, rng);
+        }
         // Invalidate cache after price update
         self.invalidate_cache();
     }
 
+    /// Replaces the built-in price updater (used when no custom strategy is set).
+    pub fn set_price_updater(&mut self, price_updater: PriceUpdater) {
+        self.price_updater = price_updater;
+    }
+
+    /// Registers a custom price update strategy, replacing any previous one.
+    ///
+    /// If this market was restored from a checkpoint saved with a strategy of the same
+    /// name, the saved state is handed to [`PriceUpdateStrategy::restore_state`].
+    pub fn set_price_strategy(&mut self, mut strategy: Box<dyn PriceUpdateStrategy>) {
+        if let Some(saved) = self.price_strategy_checkpoint.take() {
+            if saved.name == strategy.name() {
+                if let Some(state) = &saved.state {
+                    strategy.restore_state(state);
+                }
+            } else {
+                warn!(
+                    "Registering price strategy '{}' on a market checkpointed with '{}'; saved state discarded",
+                    strategy.name(),
+                    saved.name
+                );
+            }
+        }
+        info!("Using custom price strategy: {}", strategy.name());
+        self.custom_price_strategy = Some(strategy);
+    }
+
+    /// Removes the custom price strategy, falling back to the built-in updater.
+    pub fn clear_price_strategy(&mut self) -> Option<Box<dyn PriceUpdateStrategy>> {
+        self.custom_price_strategy.take()
+    }
+
+    /// Name of the registered custom price strategy, if any.
+    pub fn price_strategy_name(&self) -> Option<&str> {
+        self.custom_price_strategy.as_ref().map(|s| s.name())
+    }
+
+    /// Name of a custom price strategy recorded in the checkpoint this market was
+    /// restored from that has not been registered again yet.
+    pub fn pending_price_strategy(&self) -> Option<&str> {
+        self.price_strategy_checkpoint.as_ref().map(|c| c.name.as_str())
+    }
+
+    /// Clones the market for a checkpoint, recording the custom strategy's name and state.
+    pub(crate) fn clone_for_checkpoint(&self) -> Market {
+        let mut market = self.clone();
+        if let Some(strategy) = &self.custom_price_strategy {
+            market.price_strategy_checkpoint = Some(PriceStrategyCheckpoint {
+                name: strategy.name().to_string(),
+                state: strategy.checkpoint_state(),
+            });
+        }
+        market
+    }
+
     /// Records the current demand and supply counts to history for elasticity analysis.
     ///
     /// This should be called once per simulation step after demand counts are collected
@@ -504,6 +625,88 @@ mod tests {
     use super::*;
     use crate::scenario::Scenario;
 
+    /// Sets every price to a fixed value and counts its invocations.
+    #[derive(Debug, Clone)]
+    struct FixedPriceStrategy {
+        price: f64,
+        calls: u64,
+    }
+
+    impl PriceUpdateStrategy for FixedPriceStrategy {
+        fn name(&self) -> &str {
+            "FixedPrice"
+        }
+
+        fn update_prices(&mut self, market: &mut Market, _rng: &mut dyn Rng) {
+            self.calls += 1;
+            for skill in market.skills.values_mut() {
+                skill.current_price = self.price;
+            }
+        }
+
+        fn checkpoint_state(&self) -> Option<serde_json::Value> {
+            Some(serde_json::json!(self.calls))
+        }
+
+        fn restore_state(&mut self, state: &serde_json::Value) {
+            self.calls = state.as_u64().unwrap_or(0);
+        }
+    }
+
+    #[test]
+    fn test_custom_price_strategy_takes_precedence() {
+        let mut market = Market::new(10.0, 1.0, 0.1, 0.02, PriceUpdater::default());
+        market.add_skill(Skill::new("Programming".to_string(), 50.0));
+        market.set_price_strategy(Box::new(FixedPriceStrategy { price: 42.0, calls: 0 }));
+        assert_eq!(market.price_strategy_name(), Some("FixedPrice"));
+
+        let mut rng = rand::rng();
+        market.update_prices(&mut rng);
+        assert_eq!(market.get_price(&"Programming".to_string()), Some(42.0));
+        // Cache is invalidated after custom updates too
+        assert_eq!(market.get_average_price(), 42.0);
+
+        // Cloned markets keep their own copy of the strategy
+        let clone = market.clone();
+        assert_eq!(clone.price_strategy_name(), Some("FixedPrice"));
+
+        assert!(market.clear_price_strategy().is_some());
+        assert_eq!(market.price_strategy_name(), None);
+    }
+
+    #[test]
+    fn test_custom_price_strategy_checkpoint_roundtrip() {
+        let mut market = Market::new(10.0, 1.0, 0.1, 0.02, PriceUpdater::default());
+        market.add_skill(Skill::new("Programming".to_string(), 50.0));
+        market.set_price_strategy(Box::new(FixedPriceStrategy { price: 42.0, calls: 0 }));
+        let mut rng = rand::rng();
+        market.update_prices(&mut rng);
+        market.update_prices(&mut rng);
+
+        let json = serde_json::to_string(&market.clone_for_checkpoint()).unwrap();
+        let mut restored: Market = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.price_strategy_name(), None);
+        assert_eq!(restored.pending_price_strategy(), Some("FixedPrice"));
+
+        // Re-registering the same strategy restores its saved state
+        restored.set_price_strategy(Box::new(FixedPriceStrategy { price: 42.0, calls: 0 }));
+        assert_eq!(restored.pending_price_strategy(), None);
+        let restored_calls = restored
+            .custom_price_strategy
+            .as_ref()
+            .unwrap()
+            .checkpoint_state()
+            .and_then(|v| v.as_u64());
+        assert_eq!(restored_calls, Some(2));
+    }
+
+    #[test]
+    fn test_checkpoint_without_custom_strategy_has_no_record() {
+        let market = Market::new(10.0, 1.0, 0.1, 0.02, PriceUpdater::default());
+        let json = serde_json::to_string(&market.clone_for_checkpoint()).unwrap();
+        assert!(!json.contains("price_strategy_checkpoint"));
+    }
+
     #[test]
     fn test_cache_average_price() {
         let price_updater = PriceUpdater::from(Scenario::Original);
@@ -704,4 +907,17 @@ mod tests {
         assert_eq!(market.get_effective_min_price(&skill_id), 1.0); // Back to global
         assert_eq!(market.get_effective_max_price(&skill_id), 1000.0); // Back to global
     }
+
+    #[test]
+    fn test_resource_cost_multiplier() {
+        let mut market = Market::new(10.0, 1.0, 0.1, 0.02, PriceUpdater::default());
+        let skill_id = "Farming".to_string();
+        market.add_skill(Skill::new(skill_id.clone(), 20.0));
+        assert_eq!(market.get_resource_cost_multiplier(&skill_id), 1.0);
+
+        market.set_resource_cost_multiplier(&skill_id, 1.5);
+        assert_eq!(market.get_resource_cost_multiplier(&skill_id), 1.5);
+        // The multiplier applies when trades are priced, not to the market price itself
+        assert_eq!(market.get_price(&skill_id), Some(20.0));
+    }
 }
@@ -21,13 +21,23 @@ fn default_currency_id() -> String {
 /// Health status affects a person's ability to trade and participate in the economy.
 /// Sick persons have reduced trading capacity and can potentially transmit illness
 /// to others during trade interactions.
+///
+/// The variants form the compartments of an SEIR epidemic model: `Healthy` persons
+/// are susceptible, `Exposed` persons are infected but not yet infectious, `Sick`
+/// persons are infectious and `Recovered` persons are immune.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum HealthStatus {
-    /// Person is healthy and can trade normally.
+    /// Person is healthy and can trade normally, but can catch the disease (susceptible).
     Healthy,
+    /// Person has caught the disease but is not infectious yet.
+    /// Contains the step of exposure for incubation tracking.
+    Exposed { exposed_at_step: usize },
     /// Person is sick and has reduced trading capacity.
     /// Contains the step when the person became sick for recovery tracking.
     Sick { infected_at_step: usize },
+    /// Person has recovered or been vaccinated and is immune.
+    /// Contains the step immunity was gained for tracking waning immunity.
+    Recovered { recovered_at_step: usize },
 }
 
 /// Represents a 2D location in the economic simulation.
@@ -366,6 +376,23 @@ impl Strategy {
         }
     }
 
+    /// Returns the minimum borrower credit score this strategy accepts when lending
+    /// on the P2P marketplace.
+    ///
+    /// # Returns
+    /// * `Conservative`: 740 (Very Good credit and better)
+    /// * `Frugal`: 670 (Good credit and better)
+    /// * `Balanced`: 580 (Fair credit and better)
+    /// * `Aggressive`: no minimum, lends to any borrower
+    pub fn lending_min_credit_score(&self) -> Option<u16> {
+        match self {
+            Strategy::Conservative => Some(740),
+            Strategy::Frugal => Some(670),
+            Strategy::Balanced => Some(580),
+            Strategy::Aggressive => None,
+        }
+    }
+
     /// Returns all strategy variants for random distribution.
     pub fn all_variants() -> [Strategy; 4] {
         [
@@ -740,6 +767,40 @@ pub struct Person {
     /// Valid range: 0.0 to 1.0 (0% to 100% valuation of future rewards)
     /// Default: 0.95 (moderately patient)
     pub discount_factor: f64,
+    /// Number of times this person went bankrupt.
+    /// The first bankruptcy restructures their debt; later ones discharge it.
+    #[serde(default)]
+    pub bankruptcies: usize,
+    /// Total debt this person wrote off as a lender when borrowers defaulted.
+    #[serde(default)]
+    pub loan_losses: f64,
+    /// Age in steps. Only advances when demographics are enabled.
+    #[serde(default)]
+    pub age: usize,
+    /// Whether this person has retired and stopped selling skills.
+    /// Only meaningful when demographics are enabled.
+    #[serde(default)]
+    pub retired: bool,
+    /// Parent of a person born during the simulation; children are their parent's heirs.
+    /// None for initial persons and immigrants.
+    #[serde(default)]
+    pub parent_id: Option<PersonId>,
+    /// Consecutive steps this person has been broke (unable to pay the minimum price).
+    /// Only tracked when demographics are enabled.
+    #[serde(default)]
+    pub broke_steps: usize,
+    /// Firm this person works for; employees do not sell their own skills.
+    /// Only set when firms are enabled.
+    #[serde(default)]
+    pub employer: Option<crate::firm::FirmId>,
+    /// Total transfers this person has received from redistributed taxes or
+    /// transfer schemes, in the base currency.
+    #[serde(default)]
+    pub transfers_received: f64,
+    /// Index of the region this person lives in, among the configured regions.
+    /// Only set when regions are configured.
+    #[serde(default)]
+    pub region: Option<usize>,
 }
 
 impl Person {
@@ -791,6 +852,15 @@ impl Person {
             market_segment: MarketSegment::default(), // Start with Mittelklasse segment (will be updated based on wealth)
             currency_id: default_currency_id(),       // Start with base currency
             discount_factor: discount_factor.clamp(0.0, 1.0), // Clamp to valid range
+            bankruptcies: 0,                          // Never bankrupt
+            loan_losses: 0.0,                         // No write-offs yet
+            age: 0,                                   // Newborn until demographics assign an age
+            retired: false,                           // Working age
+            parent_id: None,                          // No parent in the simulation
+            broke_steps: 0,                           // Not broke
+            employer: None,                           // Self-employed
+            transfers_received: 0.0,                  // No transfers yet
+            region: None,                             // No regions
         }
     }
 
@@ -815,10 +885,15 @@ impl Person {
     /// - An Aggressive person with $100 and a 1.3x multiplier can afford items up to $130
     /// - With adaptation, the multiplier is further adjusted by the adjustment_factor
     pub fn can_afford_with_strategy(&self, amount: f64) -> bool {
+        self.spending_budget() >= amount
+    }
+
+    /// Most the person's strategy lets them spend on a single purchase: their money
+    /// times the strategy's spending multiplier and the learned adjustment factor.
+    pub fn spending_budget(&self) -> f64 {
         let base_multiplier = self.strategy.spending_multiplier();
         let effective_multiplier = base_multiplier * self.strategy_params.adjustment_factor;
-        let effective_money = self.money * effective_multiplier;
-        effective_money >= amount
+        self.money * effective_multiplier
     }
 
     pub fn record_transaction(
@@ -860,23 +935,99 @@ impl Person {
         }
     }
 
-    /// Returns true if the person is currently healthy.
+    /// Returns true if the person works for a firm.
+    pub fn is_employed(&self) -> bool {
+        self.employer.is_some()
+    }
+
+    /// Returns true if the person sells their skills on the market, i.e. they are
+    /// neither retired nor employed by a firm.
+    pub fn sells_skills(&self) -> bool {
+        !self.retired && !self.is_employed()
+    }
+
+    /// Returns true if the person sold a skill in the given step.
+    pub fn sold_in_step(&self, step: usize) -> bool {
+        self.transaction_history
+            .iter()
+            .rev()
+            .take_while(|transaction| transaction.step == step)
+            .any(|transaction| matches!(transaction.transaction_type, TransactionType::Sell))
+    }
+
+    /// Returns the value of the skills the person sold in the given step.
+    pub fn sales_in_step(&self, step: usize) -> f64 {
+        self.transaction_history
+            .iter()
+            .rev()
+            .take_while(|transaction| transaction.step == step)
+            .filter(|transaction| matches!(transaction.transaction_type, TransactionType::Sell))
+            .map(|transaction| transaction.amount)
+            .sum()
+    }
+
+    /// Returns true if the person is of working age but neither works for a firm nor
+    /// sold a skill in the given step.
+    pub fn is_unemployed(&self, step: usize) -> bool {
+        self.sells_skills() && !self.sold_in_step(step)
+    }
+
+    /// Returns true if the person is currently healthy (susceptible or immune).
     pub fn is_healthy(&self) -> bool {
+        matches!(self.health_status, HealthStatus::Healthy | HealthStatus::Recovered { .. })
+    }
+
+    /// Returns true if the person can catch the disease.
+    pub fn is_susceptible(&self) -> bool {
         matches!(self.health_status, HealthStatus::Healthy)
     }
 
+    /// Returns true if the person has caught the disease but is not infectious yet.
+    pub fn is_exposed(&self) -> bool {
+        matches!(self.health_status, HealthStatus::Exposed { .. })
+    }
+
     /// Returns true if the person is currently sick.
     pub fn is_sick(&self) -> bool {
         matches!(self.health_status, HealthStatus::Sick { .. })
     }
 
+    /// Returns true if the person is immune after recovery or vaccination.
+    pub fn is_recovered(&self) -> bool {
+        matches!(self.health_status, HealthStatus::Recovered { .. })
+    }
+
     /// Infects the person with illness at the given step.
     pub fn infect(&mut self, current_step: usize) {
         self.health_status = HealthStatus::Sick { infected_at_step: current_step };
     }
 
+    /// Exposes the person to the disease at the given step.
+    ///
+    /// The person becomes sick once the incubation period has passed
+    /// (see [`Person::try_become_sick`]).
+    pub fn expose(&mut self, current_step: usize) {
+        self.health_status = HealthStatus::Exposed { exposed_at_step: current_step };
+    }
+
+    /// Makes an exposed person sick once the incubation period has passed.
+    /// Returns true if the person became sick, false otherwise.
+    ///
+    /// # Arguments
+    /// * `current_step` - The current simulation step
+    /// * `incubation_period` - Number of steps between exposure and illness
+    pub fn try_become_sick(&mut self, current_step: usize, incubation_period: usize) -> bool {
+        if let HealthStatus::Exposed { exposed_at_step } = self.health_status {
+            if current_step >= exposed_at_step + incubation_period {
+                self.infect(current_step);
+                return true;
+            }
+        }
+        false
+    }
+
     /// Attempts to recover the person if they have been sick long enough.
-    /// Returns true if recovery occurred, false otherwise.
+    /// Recovered persons are immune. Returns true if recovery occurred, false otherwise.
     ///
     /// # Arguments
     /// * `current_step` - The current simulation step
@@ -884,6 +1035,32 @@ impl Person {
     pub fn try_recover(&mut self, current_step: usize, recovery_duration: usize) -> bool {
         if let HealthStatus::Sick { infected_at_step } = self.health_status {
             if current_step >= infected_at_step + recovery_duration {
+                self.health_status = HealthStatus::Recovered { recovered_at_step: current_step };
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Makes a susceptible person immune through vaccination.
+    /// Returns true if the person was vaccinated, false if they were not susceptible.
+    pub fn vaccinate(&mut self, current_step: usize) -> bool {
+        if self.is_susceptible() {
+            self.health_status = HealthStatus::Recovered { recovered_at_step: current_step };
+            return true;
+        }
+        false
+    }
+
+    /// Makes an immune person susceptible again once their immunity has waned.
+    /// Returns true if immunity was lost, false otherwise.
+    ///
+    /// # Arguments
+    /// * `current_step` - The current simulation step
+    /// * `immunity_duration` - Number of steps immunity lasts (0 = permanent)
+    pub fn try_lose_immunity(&mut self, current_step: usize, immunity_duration: usize) -> bool {
+        if let HealthStatus::Recovered { recovered_at_step } = self.health_status {
+            if immunity_duration > 0 && current_step >= recovered_at_step + immunity_duration {
                 self.health_status = HealthStatus::Healthy;
                 return true;
             }
@@ -895,7 +1072,9 @@ impl Person {
     /// Sick persons have reduced productivity (e.g., 0.5 = 50% productivity).
     pub fn health_productivity_multiplier(&self) -> f64 {
         match self.health_status {
-            HealthStatus::Healthy => 1.0,
+            HealthStatus::Healthy
+            | HealthStatus::Exposed { .. }
+            | HealthStatus::Recovered { .. } => 1.0,
             HealthStatus::Sick { .. } => 0.5, // 50% productivity when sick
         }
     }
@@ -2324,6 +2503,60 @@ mod tests {
         assert!(recovered);
         assert!(person.is_healthy());
         assert!(!person.is_sick());
+        // Recovery grants immunity
+        assert!(person.is_recovered());
+        assert!(!person.is_susceptible());
+    }
+
+    #[test]
+    fn test_seir_progression() {
+        let skill = Skill::new("TestSkill".to_string(), 10.0);
+        let mut person = Person::new(
+            1,
+            100.0,
+            vec![skill],
+            Strategy::default(),
+            test_location(),
+            test_discount_factor(),
+        );
+        assert!(person.is_susceptible());
+
+        person.expose(10);
+        assert!(person.is_exposed());
+        assert!(!person.is_healthy());
+        assert_eq!(person.health_productivity_multiplier(), 1.0);
+        assert!(!person.try_become_sick(12, 3));
+        assert!(person.try_become_sick(13, 3));
+        assert!(person.is_sick());
+
+        assert!(person.try_recover(20, 5));
+        assert_eq!(person.health_status, HealthStatus::Recovered { recovered_at_step: 20 });
+        // Permanent immunity never wanes
+        assert!(!person.try_lose_immunity(1_000, 0));
+        assert!(!person.try_lose_immunity(29, 10));
+        assert!(person.try_lose_immunity(30, 10));
+        assert!(person.is_susceptible());
+    }
+
+    #[test]
+    fn test_vaccinate_only_susceptible() {
+        let skill = Skill::new("TestSkill".to_string(), 10.0);
+        let mut person = Person::new(
+            1,
+            100.0,
+            vec![skill],
+            Strategy::default(),
+            test_location(),
+            test_discount_factor(),
+        );
+
+        assert!(person.vaccinate(5));
+        assert!(person.is_recovered());
+        assert!(!person.vaccinate(6));
+
+        person.infect(7);
+        assert!(!person.vaccinate(8));
+        assert!(person.is_sick());
     }
 
     #[test]
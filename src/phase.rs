@@ -0,0 +1,270 @@
+//! Step phase pipeline.
+//!
+//! Every simulation step is a sequence of named phases: trade agreements, demand
+//! generation, pricing, trading, taxes and so on. The built-in phases are listed in
+//! [`BuiltinPhase`]; their order and which of them run are configured through
+//! `step_phases` and `disabled_step_phases` in the simulation configuration.
+//!
+//! Custom phases implement [`StepPhase`] and are registered with
+//! [`SimulationEngine::register_phase`]. They run at the position their name has in
+//! `step_phases`, or after the built-in phases when no explicit order is configured.
+//!
+//! # Example
+//!
+//! ```rust
+//! use community_simulation::{SimulationConfig, SimulationEngine, StepPhase};
+//!
+//! /// Pays every person a small dividend each step.
+//! struct Dividend;
+//!
+//! impl StepPhase for Dividend {
+//!     fn name(&self) -> &str {
+//!         "dividend"
+//!     }
+//!
+//!     fn run(&mut self, engine: &mut SimulationEngine) {
+//!         engine.with_engine_view(|view| {
+//!             let ids: Vec<_> = view.persons().map(|p| p.id).collect();
+//!             for id in ids {
+//!                 view.inject_money(id, 1.0);
+//!             }
+//!         });
+//!     }
+//! }
+//!
+//! let config = SimulationConfig {
+//!     max_steps: 5,
+//!     entity_count: 5,
+//!     // Pay the dividend before taxes are redistributed
+//!     step_phases: ["trade_agreements", "demand", "pricing", "trades", "dividend", "tax_redistribution"]
+//!         .iter()
+//!         .map(|name| name.to_string())
+//!         .collect(),
+//!     ..Default::default()
+//! };
+//! let mut engine = SimulationEngine::new(config);
+//! engine.register_phase(Box::new(Dividend)).unwrap();
+//! let result = engine.run();
+//! assert!(result.phase_timings.iter().any(|timing| timing.name == "dividend"));
+//! ```
+
+use crate::SimulationEngine;
+
+/// A named unit of work executed once per simulation step.
+pub trait StepPhase: Send + Sync {
+    /// Unique name used to place the phase in `step_phases`.
+    fn name(&self) -> &str;
+
+    /// Runs the phase for the engine's current step.
+    fn run(&mut self, engine: &mut SimulationEngine);
+}
+
+/// The phases that make up a step out of the box, in their default order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BuiltinPhase {
+    TradeAgreements,
+    Demand,
+    Pricing,
+    Crises,
+    Insurance,
+    Trades,
+    ReputationDecay,
+    Health,
+    StrategyAdaptation,
+    CreditRating,
+    Savings,
+    Assets,
+    ResourcePools,
+    Education,
+    Certification,
+    CentralBank,
+    Loans,
+    Investments,
+    Technology,
+    Production,
+    Firms,
+    Demographics,
+    Migration,
+    TaxRedistribution,
+    Government,
+    Voting,
+    Streaming,
+    WealthStatistics,
+    Environment,
+    QualityDecay,
+    ReinforcementLearning,
+}
+
+impl BuiltinPhase {
+    /// All built-in phases in their default order.
+    pub const ALL: [BuiltinPhase; 31] = [
+        BuiltinPhase::TradeAgreements,
+        BuiltinPhase::Demand,
+        BuiltinPhase::Pricing,
+        BuiltinPhase::Crises,
+        BuiltinPhase::Insurance,
+        BuiltinPhase::Trades,
+        BuiltinPhase::ReputationDecay,
+        BuiltinPhase::Health,
+        BuiltinPhase::StrategyAdaptation,
+        BuiltinPhase::CreditRating,
+        BuiltinPhase::Savings,
+        BuiltinPhase::Assets,
+        BuiltinPhase::ResourcePools,
+        BuiltinPhase::Education,
+        BuiltinPhase::Certification,
+        BuiltinPhase::CentralBank,
+        BuiltinPhase::Loans,
+        BuiltinPhase::Investments,
+        BuiltinPhase::Technology,
+        BuiltinPhase::Production,
+        BuiltinPhase::Firms,
+        BuiltinPhase::Demographics,
+        BuiltinPhase::Migration,
+        BuiltinPhase::TaxRedistribution,
+        BuiltinPhase::Government,
+        BuiltinPhase::Voting,
+        BuiltinPhase::Streaming,
+        BuiltinPhase::WealthStatistics,
+        BuiltinPhase::Environment,
+        BuiltinPhase::QualityDecay,
+        BuiltinPhase::ReinforcementLearning,
+    ];
+
+    /// The configuration name of the phase.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BuiltinPhase::TradeAgreements => "trade_agreements",
+            BuiltinPhase::Demand => "demand",
+            BuiltinPhase::Pricing => "pricing",
+            BuiltinPhase::Crises => "crises",
+            BuiltinPhase::Insurance => "insurance",
+            BuiltinPhase::Trades => "trades",
+            BuiltinPhase::ReputationDecay => "reputation_decay",
+            BuiltinPhase::Health => "health",
+            BuiltinPhase::StrategyAdaptation => "strategy_adaptation",
+            BuiltinPhase::CreditRating => "credit_rating",
+            BuiltinPhase::Savings => "savings",
+            BuiltinPhase::Assets => "assets",
+            BuiltinPhase::ResourcePools => "resource_pools",
+            BuiltinPhase::Education => "education",
+            BuiltinPhase::Certification => "certification",
+            BuiltinPhase::CentralBank => "central_bank",
+            BuiltinPhase::Loans => "loans",
+            BuiltinPhase::Investments => "investments",
+            BuiltinPhase::Technology => "technology",
+            BuiltinPhase::Production => "production",
+            BuiltinPhase::Firms => "firms",
+            BuiltinPhase::Demographics => "demographics",
+            BuiltinPhase::Migration => "migration",
+            BuiltinPhase::TaxRedistribution => "tax_redistribution",
+            BuiltinPhase::Government => "government",
+            BuiltinPhase::Voting => "voting",
+            BuiltinPhase::Streaming => "streaming",
+            BuiltinPhase::WealthStatistics => "wealth_statistics",
+            BuiltinPhase::Environment => "environment",
+            BuiltinPhase::QualityDecay => "quality_decay",
+            BuiltinPhase::ReinforcementLearning => "reinforcement_learning",
+        }
+    }
+
+    /// Looks up a built-in phase by its configuration name.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|phase| phase.as_str() == name)
+    }
+}
+
+impl StepPhase for BuiltinPhase {
+    fn name(&self) -> &str {
+        self.as_str()
+    }
+
+    fn run(&mut self, engine: &mut SimulationEngine) {
+        engine.run_builtin_phase(*self);
+    }
+}
+
+/// A resolved pipeline entry: a built-in phase or an index into the custom phases.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum PhaseRef {
+    Builtin(BuiltinPhase),
+    Custom(usize),
+}
+
+/// Resolves the configured phase order into the pipeline executed each step.
+///
+/// An empty `order` runs every built-in phase in its default order followed by the
+/// custom phases in registration order. Disabled phases are dropped. Returns the
+/// pipeline and the configured names that matched no phase.
+pub(crate) fn resolve_pipeline(
+    order: &[String],
+    disabled: &[String],
+    custom_names: &[&str],
+) -> (Vec<PhaseRef>, Vec<String>) {
+    let names: Vec<&str> = if order.is_empty() {
+        BuiltinPhase::ALL
+            .iter()
+            .map(|phase| phase.as_str())
+            .chain(custom_names.iter().copied())
+            .collect()
+    } else {
+        order.iter().map(String::as_str).collect()
+    };
+
+    let mut pipeline = Vec::with_capacity(names.len());
+    let mut unknown = Vec::new();
+    for name in names {
+        if disabled.iter().any(|d| d == name) {
+            continue;
+        }
+        if let Some(index) = custom_names.iter().position(|custom| *custom == name) {
+            pipeline.push(PhaseRef::Custom(index));
+        } else if let Some(phase) = BuiltinPhase::from_name(name) {
+            pipeline.push(PhaseRef::Builtin(phase));
+        } else {
+            unknown.push(name.to_string());
+        }
+    }
+    (pipeline, unknown)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(list: &[&str]) -> Vec<String> {
+        list.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[test]
+    fn test_builtin_names_round_trip() {
+        for phase in BuiltinPhase::ALL {
+            assert_eq!(BuiltinPhase::from_name(phase.as_str()), Some(phase));
+        }
+        assert_eq!(BuiltinPhase::from_name("unknown"), None);
+    }
+
+    #[test]
+    fn test_default_pipeline_runs_all_builtins_then_custom() {
+        let (pipeline, unknown) = resolve_pipeline(&[], &[], &["custom"]);
+        assert!(unknown.is_empty());
+        assert_eq!(pipeline.len(), BuiltinPhase::ALL.len() + 1);
+        assert_eq!(pipeline[0], PhaseRef::Builtin(BuiltinPhase::TradeAgreements));
+        assert_eq!(pipeline.last(), Some(&PhaseRef::Custom(0)));
+    }
+
+    #[test]
+    fn test_explicit_order_and_disabled_phases() {
+        let order = names(&["savings", "tax_redistribution", "custom", "trades", "missing"]);
+        let (pipeline, unknown) = resolve_pipeline(&order, &names(&["trades"]), &["custom"]);
+        assert_eq!(
+            pipeline,
+            vec![
+                PhaseRef::Builtin(BuiltinPhase::Savings),
+                PhaseRef::Builtin(BuiltinPhase::TaxRedistribution),
+                PhaseRef::Custom(0),
+            ]
+        );
+        assert_eq!(unknown, names(&["missing"]));
+    }
+}
@@ -30,11 +30,52 @@
 //!     }
 //! }
 //! ```
+//!
+//! # Mutating Hooks
+//!
+//! Besides the read-only lifecycle hooks, plugins can intervene in each step through
+//! [`Plugin::on_after_price_update`] and [`Plugin::on_before_trades`]. Both receive an
+//! [`EngineView`], a controlled mutable view of the engine that can adjust prices,
+//! inject or withdraw money, add skills and veto matched trades. This is enough to
+//! express policy experiments such as subsidies or price controls as plugins:
+//!
+//! ```rust
+//! use community_simulation::plugin::{EngineView, Plugin};
+//! use std::any::Any;
+//!
+//! /// Pays every person a small subsidy each step.
+//! struct Subsidy;
+//!
+//! impl Plugin for Subsidy {
+//!     fn name(&self) -> &str {
+//!         "Subsidy"
+//!     }
+//!
+//!     fn on_before_trades(&mut self, view: &mut EngineView) {
+//!         let ids: Vec<_> = view.persons().map(|p| p.id).collect();
+//!         for id in ids {
+//!             view.inject_money(id, 1.0);
+//!         }
+//!     }
+//!
+//!     fn as_any(&self) -> &dyn Any {
+//!         self
+//!     }
+//!
+//!     fn as_any_mut(&mut self) -> &mut dyn Any {
+//!         self
+//!     }
+//! }
+//! ```
 
 use crate::config::SimulationConfig;
-use crate::person::Person;
+use crate::contract::{Contract, ContractId};
+use crate::loan::{Loan, LoanId};
+use crate::person::{Person, PersonId};
 use crate::result::SimulationResult;
+use crate::{Entity, Market, Skill, SkillId};
 use std::any::Any;
+use std::collections::HashMap;
 
 /// Context provided to plugins containing simulation state.
 ///
@@ -55,6 +96,176 @@ pub struct PluginContext<'a> {
     pub persons: &'a [&'a Person],
 }
 
+/// A trade that has been matched but not yet executed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProposedTrade {
+    /// ID of the buying person
+    pub buyer_id: PersonId,
+    /// ID of the selling person
+    pub seller_id: PersonId,
+    /// Skill being traded
+    pub skill_id: SkillId,
+    /// Agreed price (in base currency units)
+    pub price: f64,
+}
+
+/// Controlled mutable view of the engine passed to mutating plugin hooks.
+///
+/// Exposes read access to the market, persons, loans and contracts, and a small set of
+/// interventions that keep the engine's internal caches consistent:
+/// - [`EngineView::set_skill_price`] adjusts a market price within the price limits
+/// - [`EngineView::inject_money`] adds (or withdraws) base-currency money from a person
+/// - [`EngineView::add_skill`] introduces a new skill to the market
+/// - [`EngineView::veto_trade`] cancels a matched trade (only in `on_before_trades`)
+///
+/// Money injected by plugins is tracked so that money conservation checks stay valid.
+pub struct EngineView<'a> {
+    pub(crate) current_step: usize,
+    pub(crate) config: &'a SimulationConfig,
+    pub(crate) market: &'a mut Market,
+    pub(crate) entities: &'a mut [Entity],
+    pub(crate) loans: &'a HashMap<LoanId, Loan>,
+    pub(crate) contracts: &'a HashMap<ContractId, Contract>,
+    pub(crate) all_skill_ids: &'a mut Vec<SkillId>,
+    pub(crate) skill_providers: &'a mut HashMap<SkillId, Vec<usize>>,
+    pub(crate) proposed_trades: &'a [ProposedTrade],
+    pub(crate) vetoed: Vec<bool>,
+    pub(crate) money_injected: f64,
+}
+
+impl<'a> EngineView<'a> {
+    /// Current simulation step.
+    pub fn current_step(&self) -> usize {
+        self.current_step
+    }
+
+    /// The simulation configuration.
+    pub fn config(&self) -> &SimulationConfig {
+        self.config
+    }
+
+    /// Read access to the market (prices, supply and demand).
+    pub fn market(&self) -> &Market {
+        self.market
+    }
+
+    /// Iterates over all persons in the simulation.
+    pub fn persons(&self) -> impl Iterator<Item = &Person> {
+        self.entities.iter().map(|e| &e.person_data)
+    }
+
+    /// Gets a person by ID.
+    pub fn person(&self, id: PersonId) -> Option<&Person> {
+        self.entities.get(id).map(|e| &e.person_data)
+    }
+
+    /// All loans in the simulation.
+    pub fn loans(&self) -> &HashMap<LoanId, Loan> {
+        self.loans
+    }
+
+    /// All contracts in the simulation.
+    pub fn contracts(&self) -> &HashMap<ContractId, Contract> {
+        self.contracts
+    }
+
+    /// Sets the market price of a skill, clamped to its effective price limits.
+    ///
+    /// Returns the price actually applied, or `None` if the skill does not exist.
+    pub fn set_skill_price(&mut self, skill_id: &SkillId, price: f64) -> Option<f64> {
+        let min = self.market.get_effective_min_price(skill_id);
+        let max = self.market.get_effective_max_price(skill_id);
+        let skill = self.market.skills.get_mut(skill_id)?;
+        skill.current_price = price.max(min).min(max);
+        Some(skill.current_price)
+    }
+
+    /// Adds money to an active person (a negative amount withdraws money).
+    ///
+    /// `amount` is in the base currency. With multi-currency enabled it is converted
+    /// into the person's own currency before it is credited.
+    ///
+    /// Returns `false` if the person does not exist or is inactive.
+    pub fn inject_money(&mut self, person_id: PersonId, amount: f64) -> bool {
+        let config = self.config;
+        match self.entities.get_mut(person_id) {
+            Some(entity) if entity.active => {
+                let local_amount = if config.enable_multi_currency {
+                    let system = &config.currency_system;
+                    system
+                        .convert(amount, &system.base_currency_id, &entity.person_data.currency_id)
+                        .unwrap_or(amount)
+                } else {
+                    amount
+                };
+                entity.person_data.money += local_amount;
+                self.money_injected += amount;
+                true
+            },
+            _ => false,
+        }
+    }
+
+    /// Net money injected through this view so far.
+    pub fn money_injected(&self) -> f64 {
+        self.money_injected
+    }
+
+    /// Adds a new skill to the market, optionally taught to a provider.
+    ///
+    /// The provider gets the skill as one of their own and counts towards its supply;
+    /// they sell it unless they are retired or employed.
+    /// Returns `false` if the skill already exists or the provider is unknown or inactive.
+    pub fn add_skill(&mut self, skill: Skill, provider_id: Option<PersonId>) -> bool {
+        if self.market.skills.contains_key(&skill.id) {
+            return false;
+        }
+        let provider = match provider_id {
+            Some(id) => match self.entities.get_mut(id) {
+                Some(entity) if entity.active => {
+                    entity.person_data.own_skills.push(skill.clone());
+                    Some((id, entity.person_data.sells_skills()))
+                },
+                _ => return false,
+            },
+            None => None,
+        };
+        let skill_id = skill.id.clone();
+        self.all_skill_ids.push(skill_id.clone());
+        self.market.add_skill(skill);
+        if let Some((id, sells)) = provider {
+            self.market.increment_skill_supply(&skill_id);
+            if sells {
+                self.skill_providers.entry(skill_id).or_default().push(id);
+            }
+        }
+        true
+    }
+
+    /// Trades matched this step that are about to be executed.
+    ///
+    /// Empty outside of `on_before_trades`.
+    pub fn proposed_trades(&self) -> &[ProposedTrade] {
+        self.proposed_trades
+    }
+
+    /// Cancels the proposed trade at `index`. Returns `false` if the index is out of range.
+    pub fn veto_trade(&mut self, index: usize) -> bool {
+        match self.vetoed.get_mut(index) {
+            Some(vetoed) => {
+                *vetoed = true;
+                true
+            },
+            None => false,
+        }
+    }
+
+    /// Returns true if the proposed trade at `index` has been vetoed.
+    pub fn is_vetoed(&self, index: usize) -> bool {
+        self.vetoed.get(index).copied().unwrap_or(false)
+    }
+}
+
 /// Trait that all plugins must implement.
 ///
 /// Plugins can hook into various points in the simulation lifecycle
@@ -78,6 +289,17 @@ pub trait Plugin: Send + Sync {
     /// This allows plugins to observe state changes after trading.
     fn on_step_end(&mut self, _context: &PluginContext) {}
 
+    /// Called during each step right after market prices have been updated.
+    ///
+    /// Unlike the lifecycle hooks, this runs inside `SimulationEngine::step`, so it also
+    /// fires when steps are driven manually. Use it to override or regulate prices.
+    fn on_after_price_update(&mut self, _view: &mut EngineView) {}
+
+    /// Called during each step after trades have been matched and before they execute.
+    ///
+    /// [`EngineView::proposed_trades`] lists the matched trades; vetoed trades are dropped.
+    fn on_before_trades(&mut self, _view: &mut EngineView) {}
+
     /// Called once when the simulation completes.
     ///
     /// This is called after all simulation steps have completed.
@@ -175,6 +397,20 @@ impl PluginRegistry {
         }
     }
 
+    /// Invokes on_after_price_update for all plugins.
+    pub fn on_after_price_update(&mut self, view: &mut EngineView) {
+        for plugin in &mut self.plugins {
+            plugin.on_after_price_update(view);
+        }
+    }
+
+    /// Invokes on_before_trades for all plugins.
+    pub fn on_before_trades(&mut self, view: &mut EngineView) {
+        for plugin in &mut self.plugins {
+            plugin.on_before_trades(view);
+        }
+    }
+
     /// Invokes on_simulation_end for all plugins.
     pub fn on_simulation_end(&mut self, context: &PluginContext, result: &mut SimulationResult) {
         for plugin in &mut self.plugins {
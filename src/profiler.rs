@@ -0,0 +1,349 @@
+//! Opt-in per-phase performance profiler.
+//!
+//! When `enable_profiling` is set, the engine records the wall-clock time of every step
+//! phase, and of the trade matching and execution inside the trades phase, into a
+//! [`Profiler`]. The resulting [`ProfileReport`] is attached to the simulation result,
+//! printed as a flamegraph-style table and can be exported to JSON for regression
+//! tracking.
+//!
+//! Allocation counts require the [`CountingAllocator`] to be installed as the global
+//! allocator of the final binary; the `community-simulation` binary does this. Without
+//! it the report still contains timings and marks allocation tracking as unavailable.
+//!
+//! ```rust,ignore
+//! use community_simulation::profiler::CountingAllocator;
+//!
+//! #[global_allocator]
+//! static ALLOCATOR: CountingAllocator = CountingAllocator;
+//! ```
+
+use crate::error::{Result, SimulationError};
+use colored::Colorize;
+use serde::{Deserialize, Serialize};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fs::File;
+use std::io::Write;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::time::Instant;
+
+/// Number of live profilers; allocations are only counted while one exists.
+static ACTIVE_PROFILERS: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static ALLOCATED_BYTES: AtomicU64 = AtomicU64::new(0);
+
+/// Width of the share bar in the printed profile table.
+const BAR_WIDTH: usize = 30;
+
+/// Global allocator wrapper that counts allocations while a profiler is active.
+///
+/// Counts are process-wide, so allocations made by other threads during a profiled
+/// phase are attributed to that phase as well.
+pub struct CountingAllocator;
+
+// SAFETY: every call is forwarded unchanged to the system allocator; the wrapper only
+// updates atomic counters.
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        count_allocation(layout.size());
+        // SAFETY: the caller upholds `GlobalAlloc::alloc`'s contract.
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        count_allocation(layout.size());
+        // SAFETY: the caller upholds `GlobalAlloc::alloc_zeroed`'s contract.
+        unsafe { System.alloc_zeroed(layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        count_allocation(new_size);
+        // SAFETY: the caller upholds `GlobalAlloc::realloc`'s contract.
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        // SAFETY: the caller upholds `GlobalAlloc::dealloc`'s contract.
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[inline]
+fn count_allocation(size: usize) {
+    if ACTIVE_PROFILERS.load(Ordering::Relaxed) > 0 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED_BYTES.fetch_add(size as u64, Ordering::Relaxed);
+    }
+}
+
+/// Start of a profiled span, taken with [`Profiler::start`].
+#[derive(Debug, Clone, Copy)]
+pub struct ProfileMark {
+    started: Instant,
+    allocations: u64,
+    allocated_bytes: u64,
+}
+
+/// Accumulated measurements of one profiled span.
+///
+/// Nested spans use `/`-separated names, e.g. `trades/matching`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProfileEntry {
+    pub name: String,
+    pub calls: usize,
+    pub total_ms: f64,
+    pub min_ms: f64,
+    pub max_ms: f64,
+    pub allocations: u64,
+    pub allocated_bytes: u64,
+}
+
+impl ProfileEntry {
+    fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            calls: 0,
+            total_ms: 0.0,
+            min_ms: 0.0,
+            max_ms: 0.0,
+            allocations: 0,
+            allocated_bytes: 0,
+        }
+    }
+
+    /// Nesting depth of the span (0 for phases).
+    pub fn depth(&self) -> usize {
+        self.name.matches('/').count()
+    }
+
+    /// Average time per call in milliseconds.
+    pub fn avg_ms(&self) -> f64 {
+        if self.calls == 0 {
+            0.0
+        } else {
+            self.total_ms / self.calls as f64
+        }
+    }
+}
+
+/// Collects per-span timings and allocation counts.
+#[derive(Debug)]
+pub struct Profiler {
+    entries: Vec<ProfileEntry>,
+    allocation_tracking: bool,
+}
+
+impl Profiler {
+    /// Creates a profiler and starts counting allocations.
+    pub fn new() -> Self {
+        ACTIVE_PROFILERS.fetch_add(1, Ordering::Relaxed);
+
+        // Probe whether the counting allocator is installed
+        let before = ALLOCATIONS.load(Ordering::Relaxed);
+        std::hint::black_box(Box::new(0u64));
+        let allocation_tracking = ALLOCATIONS.load(Ordering::Relaxed) > before;
+
+        Self { entries: Vec::new(), allocation_tracking }
+    }
+
+    /// Whether allocation counts are available.
+    pub fn tracks_allocations(&self) -> bool {
+        self.allocation_tracking
+    }
+
+    /// Marks the start of a span.
+    pub fn start(&self) -> ProfileMark {
+        ProfileMark {
+            started: Instant::now(),
+            allocations: ALLOCATIONS.load(Ordering::Relaxed),
+            allocated_bytes: ALLOCATED_BYTES.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Records the span started at `mark` under `name`.
+    pub fn record(&mut self, name: &str, mark: ProfileMark) {
+        let elapsed_ms = mark.started.elapsed().as_secs_f64() * 1000.0;
+        let allocations = ALLOCATIONS.load(Ordering::Relaxed).saturating_sub(mark.allocations);
+        let allocated_bytes =
+            ALLOCATED_BYTES.load(Ordering::Relaxed).saturating_sub(mark.allocated_bytes);
+
+        let index = self.entry_index(name);
+        let entry = &mut self.entries[index];
+        entry.min_ms = if entry.calls == 0 {
+            elapsed_ms
+        } else {
+            entry.min_ms.min(elapsed_ms)
+        };
+        entry.max_ms = entry.max_ms.max(elapsed_ms);
+        entry.calls += 1;
+        entry.total_ms += elapsed_ms;
+        entry.allocations += allocations;
+        entry.allocated_bytes += allocated_bytes;
+    }
+
+    /// Finds or creates the entry for `name`, creating missing parents first so the
+    /// entries stay in tree order.
+    fn entry_index(&mut self, name: &str) -> usize {
+        if let Some(index) = self.entries.iter().position(|entry| entry.name == name) {
+            return index;
+        }
+        match name.rsplit_once('/') {
+            Some((parent, _)) => {
+                let parent_index = self.entry_index(parent);
+                // Insert after the parent's last descendant
+                let prefix = format!("{}/", parent);
+                let mut index = parent_index + 1;
+                while index < self.entries.len() && self.entries[index].name.starts_with(&prefix) {
+                    index += 1;
+                }
+                self.entries.insert(index, ProfileEntry::new(name));
+                index
+            },
+            None => {
+                self.entries.push(ProfileEntry::new(name));
+                self.entries.len() - 1
+            },
+        }
+    }
+
+    /// Builds a report over `steps` profiled steps.
+    pub fn report(&self, steps: usize) -> ProfileReport {
+        let total_ms =
+            self.entries.iter().filter(|entry| entry.depth() == 0).map(|e| e.total_ms).sum();
+        ProfileReport {
+            steps,
+            total_ms,
+            allocation_tracking: self.allocation_tracking,
+            entries: self.entries.clone(),
+        }
+    }
+}
+
+impl Default for Profiler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for Profiler {
+    fn drop(&mut self) {
+        ACTIVE_PROFILERS.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+/// Profile of a simulation run, in step pipeline order.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProfileReport {
+    /// Number of profiled steps
+    pub steps: usize,
+    /// Total time of all top-level spans in milliseconds
+    pub total_ms: f64,
+    /// Whether allocation counts were recorded (requires [`CountingAllocator`])
+    pub allocation_tracking: bool,
+    /// Measurements per span; nested spans follow their parent
+    pub entries: Vec<ProfileEntry>,
+}
+
+impl ProfileReport {
+    /// Looks up the entry of a span by name.
+    pub fn entry(&self, name: &str) -> Option<&ProfileEntry> {
+        self.entries.iter().find(|entry| entry.name == name)
+    }
+
+    /// Prints the profile as a table with bars proportional to each span's share of
+    /// the step time.
+    pub fn print_table(&self) {
+        println!("\n{}", format!("--- Profile ({} steps) ---", self.steps).bright_cyan().bold());
+        println!(
+            "{:<28} {:>10} {:>7} {:>10} {:>12} {:>12}",
+            "Span", "Total ms", "Share", "Avg ms", "Allocs", "Bytes"
+        );
+        for entry in &self.entries {
+            let share = if self.total_ms > 0.0 {
+                entry.total_ms / self.total_ms
+            } else {
+                0.0
+            };
+            let label = format!(
+                "{}{}",
+                "  ".repeat(entry.depth()),
+                entry.name.rsplit('/').next().unwrap_or(&entry.name)
+            );
+            let (allocations, bytes) = if self.allocation_tracking {
+                (entry.allocations.to_string(), entry.allocated_bytes.to_string())
+            } else {
+                ("-".to_string(), "-".to_string())
+            };
+            let bar = "█".repeat((share * BAR_WIDTH as f64).round() as usize);
+            println!(
+                "{:<28} {:>10.2} {:>6.1}% {:>10.4} {:>12} {:>12}  {}",
+                label,
+                entry.total_ms,
+                share * 100.0,
+                entry.avg_ms(),
+                allocations,
+                bytes,
+                bar.bright_yellow()
+            );
+        }
+        if !self.allocation_tracking {
+            println!("(allocation counts need the CountingAllocator as global allocator)");
+        }
+    }
+
+    /// Saves the profile as pretty-printed JSON.
+    pub fn save_to_json(&self, path: &str) -> Result<()> {
+        let json = serde_json::to_string_pretty(self)
+            .map_err(|e| SimulationError::JsonSerialize(e.to_string()))?;
+        let mut file = File::create(path)?;
+        file.write_all(json.as_bytes())?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_accumulates_calls() {
+        let mut profiler = Profiler::new();
+        for _ in 0..3 {
+            let mark = profiler.start();
+            profiler.record("pricing", mark);
+        }
+        let report = profiler.report(3);
+        let entry = report.entry("pricing").unwrap();
+        assert_eq!(entry.calls, 3);
+        assert!(entry.min_ms <= entry.max_ms);
+        assert!((report.total_ms - entry.total_ms).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_nested_spans_follow_their_parent() {
+        let mut profiler = Profiler::new();
+        let mark = profiler.start();
+        profiler.record("trades/matching", mark);
+        profiler.record("pricing", mark);
+        profiler.record("trades/execution", mark);
+        profiler.record("trades", mark);
+
+        let report = profiler.report(1);
+        let names: Vec<&str> = report.entries.iter().map(|entry| entry.name.as_str()).collect();
+        assert_eq!(names, vec!["trades", "trades/matching", "trades/execution", "pricing"]);
+    }
+
+    #[test]
+    fn test_allocations_counted_with_counting_allocator() {
+        // The test binary installs the CountingAllocator
+        let mut profiler = Profiler::new();
+        assert!(profiler.tracks_allocations());
+        let mark = profiler.start();
+        let data: Vec<u64> = std::hint::black_box((0..1000).collect());
+        profiler.record("work", mark);
+        drop(data);
+
+        let entry = profiler.report(1).entry("work").cloned().unwrap();
+        assert!(entry.allocations >= 1);
+        assert!(entry.allocated_bytes >= 8000);
+    }
+}
@@ -1,9 +1,19 @@
+use crate::crisis::CrisisEvent;
 use crate::error::{Result, SimulationError};
+use crate::{SimulationConfig, SimulationEngine};
+use log::{debug, info, warn};
 use serde::{Deserialize, Serialize};
+use std::fmt;
 use std::fs::File;
 use std::io::{BufReader, BufWriter, Write};
 use std::path::Path;
 
+/// Relative tolerance used when comparing recorded and replayed amounts.
+///
+/// Action logs round-trip through JSON, which may lose the last bit of an `f64`,
+/// so prices and severities are compared with a tiny tolerance instead of exactly.
+const REPLAY_VALUE_TOLERANCE: f64 = 1e-9;
+
 /// Represents a single action in the simulation that can be logged and replayed.
 ///
 /// This enum captures the key decisions and events that occur during simulation,
@@ -18,6 +28,189 @@ pub enum SimulationAction {
     PriceUpdate { step: usize, skill_id: String, old_price: f64, new_price: f64 },
     /// A crisis event occurred
     CrisisEvent { step: usize, event_type: String, severity: f64 },
+    /// A configuration parameter was changed before the step (value as JSON)
+    ParameterChange { step: usize, parameter: String, value: String },
+    /// A crisis was triggered by hand before the step
+    TriggeredCrisis { step: usize, crisis: CrisisEvent, severity: f64 },
+}
+
+impl SimulationAction {
+    /// Returns the simulation step at which this action was recorded.
+    pub fn step(&self) -> usize {
+        match self {
+            SimulationAction::Trade { step, .. }
+            | SimulationAction::FailedTrade { step, .. }
+            | SimulationAction::PriceUpdate { step, .. }
+            | SimulationAction::CrisisEvent { step, .. }
+            | SimulationAction::ParameterChange { step, .. }
+            | SimulationAction::TriggeredCrisis { step, .. } => *step,
+        }
+    }
+
+    /// Returns true for actions made from outside the simulation (parameter changes
+    /// and triggered crises), which a replay has to apply rather than reproduce.
+    pub fn is_intervention(&self) -> bool {
+        matches!(
+            self,
+            SimulationAction::ParameterChange { .. } | SimulationAction::TriggeredCrisis { .. }
+        )
+    }
+
+    /// Applies an intervention to an engine about to execute the action's step.
+    /// Other actions are ignored.
+    pub fn apply_intervention(&self, engine: &mut SimulationEngine) -> Result<()> {
+        match self {
+            SimulationAction::ParameterChange { parameter, value, .. } => {
+                engine.set_parameter(parameter, value)
+            },
+            SimulationAction::TriggeredCrisis { crisis, severity, .. } => {
+                engine.trigger_crisis(*crisis, *severity)
+            },
+            _ => Ok(()),
+        }
+    }
+
+    /// Checks whether two actions describe the same event.
+    ///
+    /// Identifiers must match exactly; monetary values are compared with a small
+    /// relative tolerance to absorb JSON round-trip errors.
+    pub fn matches(&self, other: &SimulationAction) -> bool {
+        fn close(a: f64, b: f64) -> bool {
+            (a - b).abs() <= REPLAY_VALUE_TOLERANCE * a.abs().max(b.abs()).max(1.0)
+        }
+
+        match (self, other) {
+            (
+                SimulationAction::Trade {
+                    step: s1,
+                    buyer_id: b1,
+                    seller_id: x1,
+                    skill_id: k1,
+                    price: p1,
+                },
+                SimulationAction::Trade {
+                    step: s2,
+                    buyer_id: b2,
+                    seller_id: x2,
+                    skill_id: k2,
+                    price: p2,
+                },
+            )
+            | (
+                SimulationAction::FailedTrade {
+                    step: s1,
+                    buyer_id: b1,
+                    seller_id: x1,
+                    skill_id: k1,
+                    price: p1,
+                },
+                SimulationAction::FailedTrade {
+                    step: s2,
+                    buyer_id: b2,
+                    seller_id: x2,
+                    skill_id: k2,
+                    price: p2,
+                },
+            ) => s1 == s2 && b1 == b2 && x1 == x2 && k1 == k2 && close(*p1, *p2),
+            (
+                SimulationAction::PriceUpdate {
+                    step: s1,
+                    skill_id: k1,
+                    old_price: o1,
+                    new_price: n1,
+                },
+                SimulationAction::PriceUpdate {
+                    step: s2,
+                    skill_id: k2,
+                    old_price: o2,
+                    new_price: n2,
+                },
+            ) => s1 == s2 && k1 == k2 && close(*o1, *o2) && close(*n1, *n2),
+            (
+                SimulationAction::CrisisEvent { step: s1, event_type: e1, severity: v1 },
+                SimulationAction::CrisisEvent { step: s2, event_type: e2, severity: v2 },
+            ) => s1 == s2 && e1 == e2 && close(*v1, *v2),
+            (
+                SimulationAction::ParameterChange { step: s1, parameter: p1, value: v1 },
+                SimulationAction::ParameterChange { step: s2, parameter: p2, value: v2 },
+            ) => s1 == s2 && p1 == p2 && v1 == v2,
+            (
+                SimulationAction::TriggeredCrisis { step: s1, crisis: c1, severity: v1 },
+                SimulationAction::TriggeredCrisis { step: s2, crisis: c2, severity: v2 },
+            ) => s1 == s2 && c1 == c2 && close(*v1, *v2),
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for SimulationAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SimulationAction::Trade { step, buyer_id, seller_id, skill_id, price } => write!(
+                f,
+                "step {}: trade {} -> {} for '{}' at ${:.4}",
+                step, seller_id, buyer_id, skill_id, price
+            ),
+            SimulationAction::FailedTrade { step, buyer_id, seller_id, skill_id, price } => {
+                write!(
+                    f,
+                    "step {}: failed trade {} -> {} for '{}' at ${:.4}",
+                    step, seller_id, buyer_id, skill_id, price
+                )
+            },
+            SimulationAction::PriceUpdate { step, skill_id, old_price, new_price } => write!(
+                f,
+                "step {}: price of '{}' ${:.4} -> ${:.4}",
+                step, skill_id, old_price, new_price
+            ),
+            SimulationAction::CrisisEvent { step, event_type, severity } => {
+                write!(f, "step {}: crisis {} (severity {:.2})", step, event_type, severity)
+            },
+            SimulationAction::ParameterChange { step, parameter, value } => {
+                write!(f, "step {}: set {} to {}", step, parameter, value)
+            },
+            SimulationAction::TriggeredCrisis { step, crisis, severity } => write!(
+                f,
+                "step {}: triggered crisis {} (severity {:.2})",
+                step,
+                crisis.name(),
+                severity
+            ),
+        }
+    }
+}
+
+/// The first point at which a replay deviated from the recorded action log.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReplayDivergence {
+    /// Simulation step at which the divergence was detected
+    pub step: usize,
+    /// Index of the divergent action within that step's actions
+    pub action_index: usize,
+    /// Action found in the recorded log (None if the replay produced extra actions)
+    pub expected: Option<SimulationAction>,
+    /// Action produced by the replay (None if the replay produced fewer actions)
+    pub actual: Option<SimulationAction>,
+}
+
+/// Outcome of replaying an action log against a freshly built engine.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReplayReport {
+    /// Number of steps that were re-executed
+    pub steps_replayed: usize,
+    /// Number of recorded actions that matched the replay
+    pub actions_verified: usize,
+    /// Total number of actions in the recorded log
+    pub total_actions: usize,
+    /// First divergence, if the replay did not reproduce the log
+    pub divergence: Option<ReplayDivergence>,
+}
+
+impl ReplayReport {
+    /// Returns true if every recorded action was reproduced.
+    pub fn is_deterministic(&self) -> bool {
+        self.divergence.is_none()
+    }
 }
 
 /// Action log for recording simulation events.
@@ -90,6 +283,154 @@ impl ActionLog {
     pub fn is_empty(&self) -> bool {
         self.actions.is_empty()
     }
+
+    /// Builds the configuration used to replay this log.
+    ///
+    /// The log only stores the seed, entity count and step count, so all other
+    /// parameters are taken from `base_config`. Pass the same configuration file
+    /// that was used for the recorded run when it differed from the defaults.
+    pub fn replay_config(&self, base_config: &SimulationConfig) -> SimulationConfig {
+        SimulationConfig {
+            seed: self.seed,
+            entity_count: self.entity_count,
+            max_steps: self.max_steps,
+            // Replays must never clobber the recorded run's checkpoint or stream files
+            checkpoint_interval: 0,
+            resume_from_checkpoint: false,
+            stream_output_path: None,
+            ..base_config.clone()
+        }
+    }
+}
+
+/// Re-executes a recorded action log and verifies each step against it.
+///
+/// A new [`SimulationEngine`] is built from the log's seed, entity count and
+/// step count (all other settings come from `base_config`) and stepped one step
+/// at a time. After every step, the actions produced by the engine are compared
+/// in order with the actions recorded for that step. The replay stops at the
+/// first mismatch, which makes it useful for bisecting nondeterminism introduced
+/// by refactors of `SimulationEngine::step`. Parameter changes and triggered
+/// crises recorded for a step (see [`SimulationAction::is_intervention`]) are
+/// applied again before it, so interactive sessions replay as well.
+///
+/// # Arguments
+/// * `log` - The recorded action log to verify
+/// * `base_config` - Configuration for all parameters not stored in the log
+///
+/// # Returns
+/// * `Ok(ReplayReport)` describing how far the replay matched
+/// * `Err(SimulationError)` if the derived configuration is invalid or a recorded
+///   intervention cannot be applied
+///
+/// # Example
+///
+/// ```
+/// use community_simulation::replay::replay_action_log;
+/// use community_simulation::{SimulationConfig, SimulationEngine};
+///
+/// let config = SimulationConfig { max_steps: 5, entity_count: 5, ..Default::default() };
+/// let mut engine = SimulationEngine::new(config.clone());
+/// engine.enable_action_recording();
+/// engine.run();
+///
+/// let log = engine.get_action_log().unwrap();
+/// let report = replay_action_log(log, &config).unwrap();
+/// assert!(report.is_deterministic());
+/// ```
+pub fn replay_action_log(log: &ActionLog, base_config: &SimulationConfig) -> Result<ReplayReport> {
+    let config = log.replay_config(base_config);
+    config.validate()?;
+
+    info!(
+        "Replaying action log: {} actions over {} steps (seed {})",
+        log.len(),
+        log.max_steps,
+        log.seed
+    );
+
+    let mut engine = SimulationEngine::new(config);
+    engine.enable_action_recording();
+
+    let mut report = ReplayReport {
+        steps_replayed: 0,
+        actions_verified: 0,
+        total_actions: log.len(),
+        divergence: None,
+    };
+    let mut expected_cursor = 0;
+    let mut actual_cursor = 0;
+
+    for step in 0..log.max_steps {
+        for action in log.actions[expected_cursor..]
+            .iter()
+            .take_while(|a| a.step() == step && a.is_intervention())
+        {
+            action.apply_intervention(&mut engine)?;
+        }
+        engine.step();
+        report.steps_replayed += 1;
+
+        let expected_end = expected_cursor
+            + log.actions[expected_cursor..].iter().take_while(|a| a.step() == step).count();
+        let expected = &log.actions[expected_cursor..expected_end];
+        let actual = match engine.get_action_log() {
+            Some(replayed) => &replayed.actions[actual_cursor..],
+            None => &[][..],
+        };
+
+        if let Some(divergence) = compare_step(step, expected, actual) {
+            warn!("Replay diverged at step {}, action {}", step, divergence.action_index);
+            report.actions_verified += divergence.action_index;
+            report.divergence = Some(divergence);
+            return Ok(report);
+        }
+
+        report.actions_verified += expected.len();
+        expected_cursor = expected_end;
+        actual_cursor += actual.len();
+        debug!("Replay step {} verified ({} actions)", step, expected.len());
+    }
+
+    // Any leftover recorded actions belong to steps the replay never reached
+    if let Some(leftover) = log.actions.get(expected_cursor) {
+        report.divergence = Some(ReplayDivergence {
+            step: leftover.step(),
+            action_index: 0,
+            expected: Some(leftover.clone()),
+            actual: None,
+        });
+    }
+
+    Ok(report)
+}
+
+/// Compares the recorded and replayed actions of a single step.
+///
+/// Returns the first divergence, or None if both sequences match exactly.
+fn compare_step(
+    step: usize,
+    expected: &[SimulationAction],
+    actual: &[SimulationAction],
+) -> Option<ReplayDivergence> {
+    let len = expected.len().max(actual.len());
+    for action_index in 0..len {
+        let e = expected.get(action_index);
+        let a = actual.get(action_index);
+        let matched = match (e, a) {
+            (Some(e), Some(a)) => e.matches(a),
+            _ => false,
+        };
+        if !matched {
+            return Some(ReplayDivergence {
+                step,
+                action_index,
+                expected: e.cloned(),
+                actual: a.cloned(),
+            });
+        }
+    }
+    None
 }
 
 #[cfg(test)]
@@ -179,4 +520,92 @@ mod tests {
 
         assert_eq!(log.len(), 3);
     }
+
+    #[test]
+    fn test_action_matches_with_tolerance() {
+        let a = SimulationAction::Trade {
+            step: 3,
+            buyer_id: 0,
+            seller_id: 1,
+            skill_id: "Skill1".to_string(),
+            price: 10.0,
+        };
+        let b = SimulationAction::Trade {
+            step: 3,
+            buyer_id: 0,
+            seller_id: 1,
+            skill_id: "Skill1".to_string(),
+            price: 10.0 + 1e-12,
+        };
+        let c = SimulationAction::FailedTrade {
+            step: 3,
+            buyer_id: 0,
+            seller_id: 1,
+            skill_id: "Skill1".to_string(),
+            price: 10.0,
+        };
+        assert!(a.matches(&b));
+        assert!(!a.matches(&c));
+        assert_eq!(a.step(), 3);
+    }
+
+    #[test]
+    fn test_replay_reproduces_recorded_run() {
+        let config = SimulationConfig {
+            max_steps: 20,
+            entity_count: 10,
+            seed: 7,
+            enable_crisis_events: true,
+            crisis_probability: 0.2,
+            ..Default::default()
+        };
+        let mut engine = SimulationEngine::new(config.clone());
+        engine.enable_action_recording();
+        engine.run();
+        let log = engine.get_action_log().unwrap().clone();
+        assert!(!log.is_empty());
+
+        let report = replay_action_log(&log, &config).unwrap();
+        assert!(report.is_deterministic(), "unexpected divergence: {:?}", report.divergence);
+        assert_eq!(report.steps_replayed, 20);
+        assert_eq!(report.actions_verified, log.len());
+    }
+
+    #[test]
+    fn test_replay_reports_first_divergence() {
+        let config = SimulationConfig { max_steps: 10, entity_count: 10, ..Default::default() };
+        let mut engine = SimulationEngine::new(config.clone());
+        engine.enable_action_recording();
+        engine.run();
+        let mut log = engine.get_action_log().unwrap().clone();
+
+        // Tamper with the first trade to simulate a behavioural change
+        let tampered = log
+            .actions
+            .iter()
+            .position(|a| matches!(a, SimulationAction::Trade { .. }))
+            .expect("run should contain at least one trade");
+        if let SimulationAction::Trade { ref mut price, .. } = log.actions[tampered] {
+            *price += 1.0;
+        }
+        let expected_step = log.actions[tampered].step();
+
+        let report = replay_action_log(&log, &config).unwrap();
+        let divergence = report.divergence.expect("replay should diverge");
+        assert_eq!(divergence.step, expected_step);
+        assert!(divergence.expected.is_some());
+        assert!(divergence.actual.is_some());
+        assert!(report.actions_verified < log.len());
+    }
+
+    #[test]
+    fn test_replay_config_uses_log_parameters() {
+        let log = ActionLog::new(99, 12, 34);
+        let base = SimulationConfig { checkpoint_interval: 10, ..Default::default() };
+        let config = log.replay_config(&base);
+        assert_eq!(config.seed, 99);
+        assert_eq!(config.entity_count, 12);
+        assert_eq!(config.max_steps, 34);
+        assert_eq!(config.checkpoint_interval, 0);
+    }
 }
@@ -1,4 +1,5 @@
 use crate::error::{Result, SimulationError};
+use crate::profiler::ProfileReport;
 use crate::{Entity, SkillId}; // Entity now wraps Person
 use colored::Colorize;
 use flate2::write::GzEncoder;
@@ -265,6 +266,36 @@ pub struct StepData {
     pub avg_reputation: f64,
     /// Skill prices at this step (top 5 by price)
     pub top_skill_prices: Vec<SkillPriceInfo>,
+    /// SEIR counts at this step (only present if the health system is enabled)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub epidemic: Option<crate::epidemic::CompartmentCounts>,
+}
+
+/// Wall-clock time spent in one step phase over the whole run.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct PhaseTiming {
+    /// Phase name as used in `step_phases`
+    pub name: String,
+    /// Number of steps the phase ran in
+    pub calls: usize,
+    /// Total time spent in the phase, in milliseconds
+    pub total_ms: f64,
+    /// Average time per call, in milliseconds
+    pub avg_ms: f64,
+}
+
+impl PhaseTiming {
+    /// Creates an empty timing record for a phase.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self { name: name.into(), calls: 0, total_ms: 0.0, avg_ms: 0.0 }
+    }
+
+    /// Adds one run of the phase.
+    pub fn record(&mut self, elapsed: std::time::Duration) {
+        self.calls += 1;
+        self.total_ms += elapsed.as_secs_f64() * 1000.0;
+        self.avg_ms = self.total_ms / self.calls as f64;
+    }
 }
 
 /// Snapshot of wealth distribution statistics at a single simulation step.
@@ -759,7 +790,7 @@ pub struct BlackMarketStats {
 }
 
 /// Statistics about the loan system
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
 pub struct LoanStats {
     /// Total number of loans issued during the simulation
     pub total_loans_issued: usize,
@@ -767,6 +798,68 @@ pub struct LoanStats {
     pub total_loans_repaid: usize,
     /// Number of active (not yet fully repaid) loans at simulation end
     pub active_loans: usize,
+    /// Number of loans that defaulted after too many missed payments
+    #[serde(default)]
+    pub total_loans_defaulted: usize,
+    /// Defaults of borrowers who had already taken write-offs as lenders
+    #[serde(default)]
+    pub cascade_defaults: usize,
+    /// Debt written off by lenders (defaults, restructuring haircuts and discharges)
+    #[serde(default)]
+    pub total_written_off: f64,
+    /// Value of assets seized from defaulting borrowers
+    #[serde(default)]
+    pub collateral_seized_value: f64,
+    /// Number of assets seized from defaulting borrowers
+    #[serde(default)]
+    pub assets_seized: usize,
+    /// Defaulted debt covered by credit insurance
+    #[serde(default)]
+    pub credit_insurance_recovered: f64,
+    /// Bankruptcies settled by restructuring debt
+    #[serde(default)]
+    pub bankruptcies_restructured: usize,
+    /// Bankruptcies settled by discharging debt
+    #[serde(default)]
+    pub bankruptcies_discharged: usize,
+}
+
+/// Loans matched on the P2P marketplace for borrowers of one credit band
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CreditBandLendingStats {
+    /// Credit rating category of the borrowers (e.g. "Good", "Fair")
+    pub credit_band: String,
+    /// Number of matched loans
+    pub loans: usize,
+    /// Total principal of the matched loans
+    pub volume: f64,
+    /// Average interest rate per step of the matched loans
+    pub avg_interest_rate: f64,
+}
+
+/// Statistics about the peer-to-peer lending marketplace
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct LendingMarketplaceStats {
+    /// Total lending offers posted by lenders
+    pub total_offers_posted: usize,
+    /// Average number of open offers per step
+    pub avg_offer_book_depth: f64,
+    /// Open offers at simulation end
+    pub open_offers: usize,
+    /// Amount still available across the open offers at simulation end
+    pub open_offer_volume: f64,
+    /// Loan requests matched with an offer
+    pub total_matched_loans: usize,
+    /// Total principal of the matched loans
+    pub total_matched_volume: f64,
+    /// Loan requests no offer could serve
+    pub unmatched_requests: usize,
+    /// Average interest rate per step over all matched loans
+    pub avg_interest_rate: f64,
+    /// Platform fees paid by borrowers
+    pub total_platform_fees: f64,
+    /// Matched loans per borrower credit band, best band first
+    pub by_credit_band: Vec<CreditBandLendingStats>,
 }
 
 /// Statistics about the investment system
@@ -784,6 +877,127 @@ pub struct InvestmentStats {
     pub total_returns_paid: f64,
     /// Average ROI percentage across all completed investments
     pub avg_roi_percentage: f64,
+    /// Number of investments that defaulted because the investee could not pay
+    #[serde(default)]
+    pub total_investments_defaulted: usize,
+    /// Total principal lost by investors through defaults
+    #[serde(default)]
+    pub total_losses: f64,
+}
+
+/// Outcomes of all auctions of one auction type
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AuctionTypeStats {
+    /// Auction mechanism these statistics cover
+    pub auction_type: crate::auction::AuctionType,
+    /// Number of auctions held
+    pub auctions: usize,
+    /// Number of auctions that ended in a sale
+    pub sold: usize,
+    /// Total bids placed
+    pub total_bids: usize,
+    /// Total money paid by auction winners
+    pub total_volume: f64,
+    /// Average clearing price of sold auctions
+    pub avg_clearing_price: f64,
+    /// Lowest clearing price
+    pub min_clearing_price: f64,
+    /// Highest clearing price
+    pub max_clearing_price: f64,
+    /// Total amount by which clearing prices exceeded the sellers' reserve prices
+    pub total_seller_surplus: f64,
+    /// Average seller surplus per sale
+    pub avg_seller_surplus: f64,
+}
+
+impl AuctionTypeStats {
+    /// Creates empty statistics for an auction type.
+    pub fn new(auction_type: crate::auction::AuctionType) -> Self {
+        Self {
+            auction_type,
+            auctions: 0,
+            sold: 0,
+            total_bids: 0,
+            total_volume: 0.0,
+            avg_clearing_price: 0.0,
+            min_clearing_price: 0.0,
+            max_clearing_price: 0.0,
+            total_seller_surplus: 0.0,
+            avg_seller_surplus: 0.0,
+        }
+    }
+
+    /// Records one auction with `bids` bids and its sale as `(price, reserve_price)`,
+    /// if it cleared.
+    pub fn record(&mut self, bids: usize, sale: Option<(f64, f64)>) {
+        self.auctions += 1;
+        self.total_bids += bids;
+        let Some((price, reserve_price)) = sale else {
+            return;
+        };
+        self.min_clearing_price = if self.sold == 0 {
+            price
+        } else {
+            self.min_clearing_price.min(price)
+        };
+        self.max_clearing_price = self.max_clearing_price.max(price);
+        self.sold += 1;
+        self.total_volume += price;
+        self.total_seller_surplus += price - reserve_price;
+        self.avg_clearing_price = self.total_volume / self.sold as f64;
+        self.avg_seller_surplus = self.total_seller_surplus / self.sold as f64;
+    }
+}
+
+/// Statistics about auction-based trading
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AuctionStats {
+    /// Total auctions held across all types
+    pub total_auctions: usize,
+    /// Total auctions that ended in a sale
+    pub total_sold: usize,
+    /// Total money paid by auction winners
+    pub total_volume: f64,
+    /// Total seller surplus over reserve prices
+    pub total_seller_surplus: f64,
+    /// Per-type outcomes, for every auction type that was used
+    pub by_type: Vec<AuctionTypeStats>,
+}
+
+/// Statistics about a single currency zone (all persons holding one currency)
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CurrencyZoneStats {
+    /// Currency identifier
+    pub currency_id: String,
+    /// Number of active persons holding this currency
+    pub persons: usize,
+    /// Total money held by active persons, in this currency
+    pub money_supply: f64,
+    /// Total money held by active persons, converted to the base currency
+    pub money_supply_in_base: f64,
+    /// Exchange rate at simulation end (units of this currency per base unit)
+    pub final_exchange_rate: f64,
+    /// Cumulative value sold to other currency zones (base units)
+    pub total_exports: f64,
+    /// Cumulative value bought from other currency zones (base units)
+    pub total_imports: f64,
+    /// Number of devaluation crises that hit this currency
+    pub devaluations: usize,
+}
+
+/// Statistics about the multi-currency system
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CurrencyStats {
+    /// Base currency that market prices are denominated in
+    pub base_currency_id: String,
+    /// Number of trades settled between persons holding different currencies
+    pub cross_currency_trades: usize,
+    /// Value of cross-currency trades (base units)
+    pub cross_currency_volume: f64,
+    /// Per-currency statistics, sorted by currency ID
+    pub currencies: Vec<CurrencyZoneStats>,
+    /// Exchange rate of each currency at the end of every step
+    pub exchange_rate_history: HashMap<String, Vec<f64>>,
 }
 
 /// Statistics about the contract system
@@ -927,6 +1141,21 @@ pub struct EnvironmentStats {
     pub overall_sustainability_score: f64,
     /// Whether the environment is sustainable (all resources >= 0)
     pub is_sustainable: bool,
+    /// Total regeneration by resource type
+    #[serde(default)]
+    pub total_regeneration: HashMap<String, f64>,
+    /// Carrying capacity of Water and Land at simulation end
+    #[serde(default)]
+    pub carrying_capacities: HashMap<String, f64>,
+    /// Trades that did not happen because of a resource quota or exhausted reserves
+    #[serde(default)]
+    pub blocked_transactions: usize,
+    /// Average resource scarcity cost multiplier across skills at simulation end
+    #[serde(default)]
+    pub avg_resource_cost_multiplier: f64,
+    /// Highest resource scarcity cost multiplier of any skill at simulation end
+    #[serde(default)]
+    pub max_resource_cost_multiplier: f64,
 }
 
 /// Statistics about education system activity (skill learning)
@@ -1266,6 +1495,283 @@ pub struct MobilityStatistics {
     pub avg_quintile_changes: f64,
 }
 
+/// Population changes over the simulation (only present if demographics are enabled)
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct DemographicsStats {
+    /// Children born during the simulation
+    pub total_births: usize,
+    /// Immigrants who arrived during the simulation
+    pub total_immigrants: usize,
+    /// Persons who reached retirement age
+    pub total_retirements: usize,
+    /// Retirees who died
+    pub total_deaths: usize,
+    /// Broke persons who left the market
+    pub total_market_exits: usize,
+    /// Estates split among the deceased's children
+    pub estates_to_heirs: usize,
+    /// Estates paid into the tax pool because there were no heirs
+    pub estates_to_tax_pool: usize,
+    /// Money passed to heirs
+    pub total_inherited: f64,
+    /// Estate money paid into the tax pool
+    pub total_estate_tax_pool: f64,
+    /// New money brought in by immigrants
+    pub total_immigrant_money: f64,
+    /// Active population at simulation end
+    pub final_population: usize,
+    /// Retired persons among the active population at simulation end
+    pub retired_population: usize,
+    /// Average age in steps of the active population at simulation end
+    pub average_age: f64,
+    /// Active population at the end of each step
+    pub population_history: Vec<usize>,
+}
+
+/// Course of the epidemic and the health policies (only present if health is enabled)
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct EpidemicStats {
+    /// Persons in each SEIR compartment at simulation end
+    pub final_counts: crate::epidemic::CompartmentCounts,
+    /// Highest number of sick persons at the end of any step
+    pub peak_infected: usize,
+    /// Step at which the number of sick persons peaked
+    pub peak_step: usize,
+    /// Persons exposed through trades
+    pub trade_exposures: usize,
+    /// Persons exposed through friendships
+    pub contact_exposures: usize,
+    /// Recoveries from illness
+    pub recoveries: usize,
+    /// Persons vaccinated
+    pub vaccinations: usize,
+    /// Recovered persons whose immunity waned
+    pub immunity_losses: usize,
+    /// Steps during which a lockdown was in force
+    pub lockdown_steps: usize,
+    /// Trades cancelled by lockdowns
+    pub lockdown_cancelled_trades: usize,
+    /// Sick pay paid out of the tax pool
+    pub total_sick_pay: f64,
+    /// SEIR counts at the end of each step
+    pub history: Vec<crate::epidemic::EpidemicSnapshot>,
+}
+
+/// Outcome of a single firm
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct FirmSummary {
+    /// Firm identifier
+    pub id: usize,
+    /// Person who founded and owns the firm
+    pub owner_id: usize,
+    /// Output skill the firm sells
+    pub product: String,
+    /// Employees at simulation end
+    pub employees: usize,
+    /// Units of output sold
+    pub units_sold: usize,
+    /// Sales revenue
+    pub revenue: f64,
+    /// Wages paid
+    pub wages_paid: f64,
+    /// Profit paid to the owner
+    pub dividends_paid: f64,
+    /// Balance sheet at simulation end
+    pub balance_sheet: crate::firm::BalanceSheet,
+    /// Step at which the firm was founded
+    pub founded_at_step: usize,
+    /// Step at which the firm went bankrupt, if it did
+    pub bankrupt_at_step: Option<usize>,
+}
+
+/// Firms and the labor market (only present if firms are enabled)
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct FirmStats {
+    /// Firms founded during the simulation, including bankrupt ones
+    pub total_firms_founded: usize,
+    /// Firms still trading at simulation end
+    pub active_firms: usize,
+    /// Firms that went bankrupt
+    pub bankruptcies: usize,
+    /// Persons hired by firms
+    pub total_hires: usize,
+    /// Employees laid off because their firm sold too little
+    pub total_layoffs: usize,
+    /// Employees who lost their job when their firm went bankrupt
+    pub bankruptcy_job_losses: usize,
+    /// Persons employed by firms at simulation end
+    pub employed_persons: usize,
+    /// Share of the labor force without a job or a sale in the last step
+    pub unemployment_rate: f64,
+    /// Average unemployment rate over all steps
+    pub avg_unemployment_rate: f64,
+    /// Units of output sold by all firms
+    pub total_units_sold: usize,
+    /// Sales revenue of all firms
+    pub total_revenue: f64,
+    /// Wages paid by all firms
+    pub total_wages_paid: f64,
+    /// Profit paid to owners by all firms
+    pub total_dividends_paid: f64,
+    /// Cash held by all firms at simulation end
+    pub firm_cash: f64,
+    /// Average number of employees of the firms still trading
+    pub avg_firm_size: f64,
+    /// Employees of the largest firm
+    pub largest_firm_size: usize,
+    /// Employees of each firm still trading, largest first
+    pub firm_size_distribution: Vec<usize>,
+    /// Persons employed by firms at the end of each step
+    pub employment_history: Vec<usize>,
+    /// Unemployment rate at the end of each step
+    pub unemployment_history: Vec<f64>,
+    /// Outcome of every firm, in founding order
+    pub firms: Vec<FirmSummary>,
+}
+
+/// Monetary policy and money issued by the central bank (only present if the central
+/// bank is enabled)
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct MonetaryPolicyStats {
+    /// How the policy rate was set
+    pub rule: crate::central_bank::MonetaryPolicyRule,
+    /// How money was created and withdrawn
+    pub money_operation: crate::central_bank::MoneyOperation,
+    /// Rate the Taylor rule returns to at target inflation and no output gap
+    pub neutral_rate: f64,
+    /// Policy rate at simulation end
+    pub policy_rate: f64,
+    /// Average policy rate over all steps
+    pub avg_policy_rate: f64,
+    /// Lowest policy rate set
+    pub min_policy_rate: f64,
+    /// Highest policy rate set
+    pub max_policy_rate: f64,
+    /// Average change of the average market price per step
+    pub avg_inflation: f64,
+    /// Money created by the central bank
+    pub total_money_created: f64,
+    /// Money withdrawn by the central bank
+    pub total_money_withdrawn: f64,
+    /// Money created less money withdrawn
+    pub net_money_issued: f64,
+    /// Bonds bought in open-market operations less bonds sold
+    pub bond_holdings: f64,
+    /// Money and savings held by persons at simulation end
+    pub money_supply: f64,
+    /// Policy rate, inflation, output gap and money supply at the end of each step
+    pub history: Vec<crate::central_bank::MonetarySnapshot>,
+}
+
+/// Government budget, taxes, spending and debt (only present if the government is
+/// enabled)
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct GovernmentStats {
+    /// Flat trade taxes and estate taxes received
+    pub trade_tax_revenue: f64,
+    /// Value added tax received
+    pub vat_revenue: f64,
+    /// Progressive income tax received
+    pub income_tax_revenue: f64,
+    /// Wealth tax received
+    pub wealth_tax_revenue: f64,
+    /// All taxes received
+    pub total_revenue: f64,
+    /// Spending on public goods
+    pub public_goods_spending: f64,
+    /// Unemployment benefits paid
+    pub unemployment_benefits_paid: f64,
+    /// Transfers paid to the members of each social class
+    pub class_transfers_paid: HashMap<crate::person::SocialClass, f64>,
+    /// All money paid to persons
+    pub total_spending: f64,
+    /// Interest due on the debt
+    pub interest_due: f64,
+    /// Cash held by the government at simulation end
+    pub treasury: f64,
+    /// Outstanding debt at simulation end
+    pub debt: f64,
+    /// Highest debt at the end of any step
+    pub peak_debt: f64,
+    /// Debt issued to cover deficits
+    pub debt_issued: f64,
+    /// Debt repaid out of surpluses
+    pub debt_repaid: f64,
+    /// Number of steps with a budget deficit
+    pub deficit_steps: usize,
+    /// Revenue, spending, interest and debt at the end of each step
+    pub history: Vec<crate::government::BudgetSnapshot>,
+}
+
+/// Transfers, their fiscal cost and their effect on poverty and inequality (only
+/// present if tax redistribution is enabled)
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct TransferStats {
+    /// Who received the transfers
+    pub scheme: crate::transfers::TransferScheme,
+    /// How the transfers were paid for
+    pub funding: crate::transfers::TransferFunding,
+    /// Fiscal cost: all transfers paid, however funded
+    pub total_paid: f64,
+    /// Transfers paid out of taxes
+    pub paid_from_taxes: f64,
+    /// Transfers paid with newly created money
+    pub money_created: f64,
+    /// Average transfers paid per step
+    pub cost_per_step: f64,
+    /// Number of payments made to persons
+    pub payments: usize,
+    /// Average payment
+    pub avg_payment: f64,
+    /// Steps in which the taxes could not cover the transfers in full
+    pub underfunded_steps: usize,
+    /// Half the median wealth before transfers
+    pub poverty_line: f64,
+    /// Share of persons below the poverty line without the transfers they received
+    pub poverty_rate_before: f64,
+    /// Share of persons below the poverty line at simulation end
+    pub poverty_rate_after: f64,
+    /// Gini coefficient of wealth without the transfers persons received
+    pub gini_before: f64,
+    /// Gini coefficient of wealth at simulation end
+    pub gini_after: f64,
+    /// Transfers paid in each step
+    pub history: Vec<f64>,
+}
+
+/// Custom crises and the recovery from each (only present if custom crises are
+/// defined)
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct CustomCrisisStats {
+    /// Every crisis started, including those spread by contagion, in order
+    pub crises: Vec<crate::crisis::CrisisRecovery>,
+    /// Crises that spread from another crisis
+    pub contagions: usize,
+    /// Net money created (positive) or destroyed (negative) by money effects, in the
+    /// base currency
+    pub money_changed: f64,
+}
+
+/// Regions at the end of the run and the migration between them (only present if
+/// regions are configured)
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct GeographyStats {
+    /// Persons who moved to another region
+    pub migrations: usize,
+    /// Each region, in configuration order
+    pub regions: Vec<crate::geography::RegionStats>,
+}
+
+/// Before/after comparison of each timeline event applied (only present if the
+/// configuration has a timeline)
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct TimelineStats {
+    /// Number of steps compared before and after each event
+    pub window: usize,
+    /// Impact of each event applied, in step order
+    pub interventions: Vec<crate::timeline::InterventionImpact>,
+}
+
 /// Elasticity classification based on absolute value
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub enum ElasticityClassification {
@@ -1433,7 +1939,13 @@ pub struct SimulationResult {
     // Core simulation metrics
     pub total_steps: usize,
     pub total_duration: f64,
-    pub step_times: Vec<f64>,  // Time taken for each step
+    pub step_times: Vec<f64>, // Time taken for each step
+    /// Time spent in each step phase, in pipeline order
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub phase_timings: Vec<PhaseTiming>,
+    /// Per-phase profile with timings and allocation counts (if profiling was enabled)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile: Option<ProfileReport>,
     pub active_persons: usize, // Renamed from active_entities for clarity
     /// Number of steps that failed due to panics but were recovered gracefully
     pub failed_steps: usize,
@@ -1510,10 +2022,23 @@ pub struct SimulationResult {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub loan_statistics: Option<LoanStats>,
 
+    /// P2P lending marketplace statistics (only present if P2P lending is enabled)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lending_marketplace_statistics: Option<LendingMarketplaceStats>,
+
     /// Investment system statistics (only present if investments are enabled)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub investment_statistics: Option<InvestmentStats>,
 
+    /// Multi-currency statistics: per-currency money supply and exchange rate history
+    /// (only present if multi-currency is enabled)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency_statistics: Option<CurrencyStats>,
+
+    /// Auction statistics per auction type (only present if auctions are enabled)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auction_statistics: Option<AuctionStats>,
+
     /// Contract system statistics (only present if contracts are enabled)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub contract_statistics: Option<ContractStats>,
@@ -1554,6 +2079,11 @@ pub struct SimulationResult {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub trade_agreement_statistics: Option<crate::trade_agreement::TradeAgreementStatistics>,
 
+    /// Voting and governance statistics, including enacted policy changes and their
+    /// vote margins (only present if voting is enabled)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub voting_statistics: Option<crate::voting::VotingStatistics>,
+
     /// Insurance system statistics (only present if insurance is enabled)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub insurance_statistics: Option<InsuranceStats>,
@@ -1586,6 +2116,46 @@ pub struct SimulationResult {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub mobility_statistics: Option<MobilityStatistics>,
 
+    /// Births, deaths, immigration and market exits (only present if demographics are enabled)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub demographics_statistics: Option<DemographicsStats>,
+
+    /// SEIR epidemic curve and health policy totals (only present if health is enabled)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub epidemic_statistics: Option<EpidemicStats>,
+
+    /// Firms, employment and unemployment (only present if firms are enabled)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub firm_statistics: Option<FirmStats>,
+
+    /// Policy rate and money issuance (only present if the central bank is enabled)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub monetary_policy_statistics: Option<MonetaryPolicyStats>,
+
+    /// Government budget and debt (only present if the government is enabled)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub government_statistics: Option<GovernmentStats>,
+
+    /// Transfers, poverty and inequality before and after transfers (only present if
+    /// tax redistribution is enabled)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub transfer_statistics: Option<TransferStats>,
+
+    /// Trading and wealth before and after each timeline event (only present if the
+    /// configuration has a timeline)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeline_statistics: Option<TimelineStats>,
+
+    /// Custom crises and the time GDP took to recover from each (only present if
+    /// custom crises are defined)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_crisis_statistics: Option<CustomCrisisStats>,
+
+    /// Population, wealth, prices and migration of each region (only present if
+    /// regions are configured)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub geography_statistics: Option<GeographyStats>,
+
     /// Social class distribution and mobility statistics.
     /// Tracks the distribution of persons across social classes (Lower, Middle, Upper, Elite)
     /// and measures class transitions over time. Always present (uses default if no class changes occurred).
@@ -1673,6 +2243,8 @@ impl SimulationResult {
     /// #     total_steps: 0,
     /// #     total_duration: 0.0,
     /// #     step_times: vec![],
+    /// #     phase_timings: vec![],
+    /// #     profile: None,
     /// #     active_persons: 0,
     /// #     failed_steps: 0,
     /// #     final_money_distribution: vec![],
@@ -1721,7 +2293,10 @@ impl SimulationResult {
     /// #     total_taxes_collected: None,
     /// #     total_taxes_redistributed: None,
     /// #     loan_statistics: None,
+    /// #     lending_marketplace_statistics: None,
     /// #     investment_statistics: None,
+    /// #     currency_statistics: None,
+    /// #     auction_statistics: None,
     /// #     contract_statistics: None,
     /// #     education_statistics: None,
     /// #     mentorship_statistics: None,
@@ -1732,6 +2307,7 @@ impl SimulationResult {
     /// #     influence_statistics: None,
     /// #     trust_network_statistics: None,
     /// #     trade_agreement_statistics: None,
+    /// #     voting_statistics: None,
     /// #     insurance_statistics: None,
     /// #     asset_statistics: None,
     /// #     technology_breakthrough_statistics: None,
@@ -1746,6 +2322,15 @@ impl SimulationResult {
     /// #     },
     /// #     centrality_analysis: None,
     /// #     mobility_statistics: None,
+    /// #     demographics_statistics: None,
+    /// #     epidemic_statistics: None,
+    /// #     firm_statistics: None,
+    /// #     monetary_policy_statistics: None,
+    /// #     government_statistics: None,
+    /// #     transfer_statistics: None,
+    /// #     timeline_statistics: None,
+    /// #     custom_crisis_statistics: None,
+    /// #     geography_statistics: None,
     /// #     social_class_statistics: community_simulation::result::SocialClassStats::default(),
     /// #     quality_statistics: None,
     /// #     strategy_evolution_statistics: None,
@@ -1921,6 +2506,316 @@ impl SimulationResult {
         writeln!(file, "Min Failed Per Step,{}", self.failed_trade_statistics.min_failed_per_step)?;
         writeln!(file, "Max Failed Per Step,{}", self.failed_trade_statistics.max_failed_per_step)?;
 
+        if let Some(ref loan_stats) = self.loan_statistics {
+            writeln!(file)?;
+            writeln!(file, "Loan Statistics")?;
+            writeln!(file, "Loans Issued,{}", loan_stats.total_loans_issued)?;
+            writeln!(file, "Loans Repaid,{}", loan_stats.total_loans_repaid)?;
+            writeln!(file, "Active Loans,{}", loan_stats.active_loans)?;
+            writeln!(file, "Loans Defaulted,{}", loan_stats.total_loans_defaulted)?;
+            writeln!(file, "Cascade Defaults,{}", loan_stats.cascade_defaults)?;
+            writeln!(file, "Debt Written Off,{:.4}", loan_stats.total_written_off)?;
+            writeln!(file, "Assets Seized,{}", loan_stats.assets_seized)?;
+            writeln!(file, "Collateral Seized Value,{:.4}", loan_stats.collateral_seized_value)?;
+            writeln!(
+                file,
+                "Credit Insurance Recovered,{:.4}",
+                loan_stats.credit_insurance_recovered
+            )?;
+            writeln!(file, "Bankruptcies Restructured,{}", loan_stats.bankruptcies_restructured)?;
+            writeln!(file, "Bankruptcies Discharged,{}", loan_stats.bankruptcies_discharged)?;
+        }
+
+        if let Some(ref lending_stats) = self.lending_marketplace_statistics {
+            writeln!(file)?;
+            writeln!(file, "P2P Lending Marketplace")?;
+            writeln!(file, "Offers Posted,{}", lending_stats.total_offers_posted)?;
+            writeln!(file, "Avg Offer Book Depth,{:.4}", lending_stats.avg_offer_book_depth)?;
+            writeln!(file, "Open Offers,{}", lending_stats.open_offers)?;
+            writeln!(file, "Open Offer Volume,{:.4}", lending_stats.open_offer_volume)?;
+            writeln!(file, "Matched Loans,{}", lending_stats.total_matched_loans)?;
+            writeln!(file, "Matched Volume,{:.4}", lending_stats.total_matched_volume)?;
+            writeln!(file, "Unmatched Requests,{}", lending_stats.unmatched_requests)?;
+            writeln!(file, "Avg Interest Rate,{:.6}", lending_stats.avg_interest_rate)?;
+            writeln!(file, "Platform Fees,{:.4}", lending_stats.total_platform_fees)?;
+            writeln!(file, "Credit Band,Loans,Volume,Avg Interest Rate")?;
+            for band in &lending_stats.by_credit_band {
+                writeln!(
+                    file,
+                    "{},{},{:.4},{:.6}",
+                    band.credit_band, band.loans, band.volume, band.avg_interest_rate
+                )?;
+            }
+        }
+
+        if let Some(ref demographics) = self.demographics_statistics {
+            writeln!(file)?;
+            writeln!(file, "Demographics")?;
+            writeln!(file, "Births,{}", demographics.total_births)?;
+            writeln!(file, "Immigrants,{}", demographics.total_immigrants)?;
+            writeln!(file, "Retirements,{}", demographics.total_retirements)?;
+            writeln!(file, "Deaths,{}", demographics.total_deaths)?;
+            writeln!(file, "Market Exits,{}", demographics.total_market_exits)?;
+            writeln!(file, "Estates To Heirs,{}", demographics.estates_to_heirs)?;
+            writeln!(file, "Estates To Tax Pool,{}", demographics.estates_to_tax_pool)?;
+            writeln!(file, "Total Inherited,{:.4}", demographics.total_inherited)?;
+            writeln!(file, "Total Estate Tax Pool,{:.4}", demographics.total_estate_tax_pool)?;
+            writeln!(file, "Total Immigrant Money,{:.4}", demographics.total_immigrant_money)?;
+            writeln!(file, "Final Population,{}", demographics.final_population)?;
+            writeln!(file, "Retired Population,{}", demographics.retired_population)?;
+            writeln!(file, "Average Age,{:.4}", demographics.average_age)?;
+        }
+
+        if let Some(ref epidemic) = self.epidemic_statistics {
+            writeln!(file)?;
+            writeln!(file, "Epidemic")?;
+            writeln!(file, "Final Susceptible,{}", epidemic.final_counts.susceptible)?;
+            writeln!(file, "Final Exposed,{}", epidemic.final_counts.exposed)?;
+            writeln!(file, "Final Infected,{}", epidemic.final_counts.infected)?;
+            writeln!(file, "Final Recovered,{}", epidemic.final_counts.recovered)?;
+            writeln!(file, "Peak Infected,{}", epidemic.peak_infected)?;
+            writeln!(file, "Peak Step,{}", epidemic.peak_step)?;
+            writeln!(file, "Trade Exposures,{}", epidemic.trade_exposures)?;
+            writeln!(file, "Contact Exposures,{}", epidemic.contact_exposures)?;
+            writeln!(file, "Recoveries,{}", epidemic.recoveries)?;
+            writeln!(file, "Vaccinations,{}", epidemic.vaccinations)?;
+            writeln!(file, "Immunity Losses,{}", epidemic.immunity_losses)?;
+            writeln!(file, "Lockdown Steps,{}", epidemic.lockdown_steps)?;
+            writeln!(file, "Lockdown Cancelled Trades,{}", epidemic.lockdown_cancelled_trades)?;
+            writeln!(file, "Total Sick Pay,{:.4}", epidemic.total_sick_pay)?;
+        }
+
+        if let Some(ref firms) = self.firm_statistics {
+            writeln!(file)?;
+            writeln!(file, "Firms")?;
+            writeln!(file, "Firms Founded,{}", firms.total_firms_founded)?;
+            writeln!(file, "Active Firms,{}", firms.active_firms)?;
+            writeln!(file, "Bankruptcies,{}", firms.bankruptcies)?;
+            writeln!(file, "Hires,{}", firms.total_hires)?;
+            writeln!(file, "Layoffs,{}", firms.total_layoffs)?;
+            writeln!(file, "Bankruptcy Job Losses,{}", firms.bankruptcy_job_losses)?;
+            writeln!(file, "Employed Persons,{}", firms.employed_persons)?;
+            writeln!(file, "Unemployment Rate,{:.6}", firms.unemployment_rate)?;
+            writeln!(file, "Avg Unemployment Rate,{:.6}", firms.avg_unemployment_rate)?;
+            writeln!(file, "Units Sold,{}", firms.total_units_sold)?;
+            writeln!(file, "Total Revenue,{:.4}", firms.total_revenue)?;
+            writeln!(file, "Total Wages Paid,{:.4}", firms.total_wages_paid)?;
+            writeln!(file, "Total Dividends Paid,{:.4}", firms.total_dividends_paid)?;
+            writeln!(file, "Firm Cash,{:.4}", firms.firm_cash)?;
+            writeln!(file, "Avg Firm Size,{:.4}", firms.avg_firm_size)?;
+            writeln!(file, "Largest Firm Size,{}", firms.largest_firm_size)?;
+            writeln!(file)?;
+            writeln!(
+                file,
+                "Firm,Owner,Product,Employees,Units Sold,Revenue,Wages Paid,Dividends Paid,Equity,Bankrupt At Step"
+            )?;
+            for firm in &firms.firms {
+                writeln!(
+                    file,
+                    "{},{},{},{},{},{:.4},{:.4},{:.4},{:.4},{}",
+                    firm.id,
+                    firm.owner_id,
+                    firm.product,
+                    firm.employees,
+                    firm.units_sold,
+                    firm.revenue,
+                    firm.wages_paid,
+                    firm.dividends_paid,
+                    firm.balance_sheet.equity,
+                    firm.bankrupt_at_step.map_or(String::new(), |step| step.to_string())
+                )?;
+            }
+        }
+
+        if let Some(ref monetary) = self.monetary_policy_statistics {
+            writeln!(file)?;
+            writeln!(file, "Central Bank")?;
+            writeln!(file, "Policy Rule,{:?}", monetary.rule)?;
+            writeln!(file, "Money Operation,{:?}", monetary.money_operation)?;
+            writeln!(file, "Neutral Rate,{:.6}", monetary.neutral_rate)?;
+            writeln!(file, "Policy Rate,{:.6}", monetary.policy_rate)?;
+            writeln!(file, "Avg Policy Rate,{:.6}", monetary.avg_policy_rate)?;
+            writeln!(file, "Min Policy Rate,{:.6}", monetary.min_policy_rate)?;
+            writeln!(file, "Max Policy Rate,{:.6}", monetary.max_policy_rate)?;
+            writeln!(file, "Avg Inflation,{:.6}", monetary.avg_inflation)?;
+            writeln!(file, "Money Created,{:.4}", monetary.total_money_created)?;
+            writeln!(file, "Money Withdrawn,{:.4}", monetary.total_money_withdrawn)?;
+            writeln!(file, "Net Money Issued,{:.4}", monetary.net_money_issued)?;
+            writeln!(file, "Bond Holdings,{:.4}", monetary.bond_holdings)?;
+            writeln!(file, "Money Supply,{:.4}", monetary.money_supply)?;
+        }
+
+        if let Some(ref government) = self.government_statistics {
+            writeln!(file)?;
+            writeln!(file, "Government")?;
+            writeln!(file, "Trade Tax Revenue,{:.4}", government.trade_tax_revenue)?;
+            writeln!(file, "VAT Revenue,{:.4}", government.vat_revenue)?;
+            writeln!(file, "Income Tax Revenue,{:.4}", government.income_tax_revenue)?;
+            writeln!(file, "Wealth Tax Revenue,{:.4}", government.wealth_tax_revenue)?;
+            writeln!(file, "Total Revenue,{:.4}", government.total_revenue)?;
+            writeln!(file, "Public Goods Spending,{:.4}", government.public_goods_spending)?;
+            writeln!(file, "Unemployment Benefits,{:.4}", government.unemployment_benefits_paid)?;
+            let mut transfers: Vec<_> = government.class_transfers_paid.iter().collect();
+            transfers.sort_by_key(|(class, _)| **class);
+            for (class, paid) in transfers {
+                writeln!(file, "Transfers to {:?},{:.4}", class, paid)?;
+            }
+            writeln!(file, "Total Spending,{:.4}", government.total_spending)?;
+            writeln!(file, "Interest Due,{:.4}", government.interest_due)?;
+            writeln!(file, "Treasury,{:.4}", government.treasury)?;
+            writeln!(file, "Debt,{:.4}", government.debt)?;
+            writeln!(file, "Peak Debt,{:.4}", government.peak_debt)?;
+            writeln!(file, "Debt Issued,{:.4}", government.debt_issued)?;
+            writeln!(file, "Debt Repaid,{:.4}", government.debt_repaid)?;
+            writeln!(file, "Deficit Steps,{}", government.deficit_steps)?;
+        }
+
+        if let Some(ref transfers) = self.transfer_statistics {
+            writeln!(file)?;
+            writeln!(file, "Transfers")?;
+            writeln!(file, "Scheme,{:?}", transfers.scheme)?;
+            writeln!(file, "Funding,{:?}", transfers.funding)?;
+            writeln!(file, "Fiscal Cost,{:.4}", transfers.total_paid)?;
+            writeln!(file, "Paid From Taxes,{:.4}", transfers.paid_from_taxes)?;
+            writeln!(file, "Money Created,{:.4}", transfers.money_created)?;
+            writeln!(file, "Cost Per Step,{:.4}", transfers.cost_per_step)?;
+            writeln!(file, "Payments,{}", transfers.payments)?;
+            writeln!(file, "Avg Payment,{:.4}", transfers.avg_payment)?;
+            writeln!(file, "Underfunded Steps,{}", transfers.underfunded_steps)?;
+            writeln!(file, "Poverty Line,{:.4}", transfers.poverty_line)?;
+            writeln!(file, "Poverty Rate Before Transfers,{:.6}", transfers.poverty_rate_before)?;
+            writeln!(file, "Poverty Rate After Transfers,{:.6}", transfers.poverty_rate_after)?;
+            writeln!(file, "Gini Before Transfers,{:.6}", transfers.gini_before)?;
+            writeln!(file, "Gini After Transfers,{:.6}", transfers.gini_after)?;
+        }
+
+        if let Some(ref timeline) = self.timeline_statistics {
+            writeln!(file)?;
+            writeln!(file, "Timeline (averages over {} steps before/after)", timeline.window)?;
+            writeln!(
+                file,
+                "Intervention,Trades Before,Trades After,Volume Before,Volume After,\
+                 Avg Money Before,Avg Money After,Gini Before,Gini After"
+            )?;
+            for impact in &timeline.interventions {
+                writeln!(
+                    file,
+                    "\"{}\",{:.4},{:.4},{:.4},{:.4},{:.4},{:.4},{:.6},{:.6}",
+                    impact.description,
+                    impact.trades_before,
+                    impact.trades_after,
+                    impact.volume_before,
+                    impact.volume_after,
+                    impact.avg_money_before,
+                    impact.avg_money_after,
+                    impact.gini_before,
+                    impact.gini_after
+                )?;
+            }
+        }
+
+        if let Some(ref crises) = self.custom_crisis_statistics {
+            writeln!(file)?;
+            writeln!(file, "Custom Crises")?;
+            writeln!(file, "Contagions,{}", crises.contagions)?;
+            writeln!(file, "Money Changed,{:.4}", crises.money_changed)?;
+            writeln!(
+                file,
+                "Crisis,Start Step,End Step,Groups,Spread From,Pre-Crisis GDP,Trough GDP,\
+                 Output Loss,Recovery Steps"
+            )?;
+            for crisis in &crises.crises {
+                let groups: Vec<String> = crisis.groups.iter().map(|g| g.to_string()).collect();
+                writeln!(
+                    file,
+                    "\"{}\",{},{},{},{},{:.4},{:.4},{:.4},{}",
+                    crisis.name,
+                    crisis.start_step,
+                    crisis.end_step,
+                    groups.join(" "),
+                    crisis.source.map(|s| s.to_string()).unwrap_or_default(),
+                    crisis.pre_crisis_gdp,
+                    crisis.trough_gdp,
+                    crisis.output_loss,
+                    crisis.recovery_steps.map(|s| s.to_string()).unwrap_or_default()
+                )?;
+            }
+        }
+
+        if let Some(ref geography) = self.geography_statistics {
+            writeln!(file)?;
+            writeln!(file, "Regions")?;
+            writeln!(file, "Migrations,{}", geography.migrations)?;
+            writeln!(
+                file,
+                "Region,Population,Average Money,Gini Coefficient,Price Index,Trades,\
+                 Immigrants,Emigrants"
+            )?;
+            for region in &geography.regions {
+                writeln!(
+                    file,
+                    "\"{}\",{},{:.4},{:.4},{:.4},{},{},{}",
+                    region.name,
+                    region.population,
+                    region.average_money,
+                    region.gini_coefficient,
+                    region.price_index,
+                    region.trades,
+                    region.immigrants,
+                    region.emigrants
+                )?;
+            }
+        }
+
+        if let Some(ref investment_stats) = self.investment_statistics {
+            writeln!(file)?;
+            writeln!(file, "Investment Statistics")?;
+            writeln!(file, "Investments Created,{}", investment_stats.total_investments_created)?;
+            writeln!(
+                file,
+                "Investments Completed,{}",
+                investment_stats.total_investments_completed
+            )?;
+            writeln!(
+                file,
+                "Investments Defaulted,{}",
+                investment_stats.total_investments_defaulted
+            )?;
+            writeln!(file, "Active Investments,{}", investment_stats.active_investments)?;
+            writeln!(file, "Total Invested,{:.4}", investment_stats.total_invested)?;
+            writeln!(file, "Total Returns Paid,{:.4}", investment_stats.total_returns_paid)?;
+            writeln!(file, "Total Losses,{:.4}", investment_stats.total_losses)?;
+            writeln!(file, "Avg ROI (%),{:.4}", investment_stats.avg_roi_percentage)?;
+        }
+
+        if let Some(ref auction_stats) = self.auction_statistics {
+            writeln!(file)?;
+            writeln!(file, "Auction Statistics")?;
+            writeln!(file, "Auctions Held,{}", auction_stats.total_auctions)?;
+            writeln!(file, "Auctions Sold,{}", auction_stats.total_sold)?;
+            writeln!(file, "Auction Volume,{:.4}", auction_stats.total_volume)?;
+            writeln!(file, "Seller Surplus,{:.4}", auction_stats.total_seller_surplus)?;
+            writeln!(
+                file,
+                "Auction Type,Held,Sold,Bids,Avg Clearing Price,Min Clearing Price,Max Clearing Price,Total Seller Surplus,Avg Seller Surplus"
+            )?;
+            for t in &auction_stats.by_type {
+                writeln!(
+                    file,
+                    "{:?},{},{},{},{:.4},{:.4},{:.4},{:.4},{:.4}",
+                    t.auction_type,
+                    t.auctions,
+                    t.sold,
+                    t.total_bids,
+                    t.avg_clearing_price,
+                    t.min_clearing_price,
+                    t.max_clearing_price,
+                    t.total_seller_surplus,
+                    t.avg_seller_surplus
+                )?;
+            }
+        }
+
         Ok(())
     }
 
@@ -2472,6 +3367,474 @@ impl SimulationResult {
             );
         }
 
+        // Print governance outcomes if voting was enabled
+        if let Some(ref voting_stats) = self.voting_statistics {
+            println!("\n{}", "--- Voting & Governance ---".bright_cyan().bold());
+            println!("{} {:?}", "Voting Method:".bold(), voting_stats.voting_method);
+            println!(
+                "{} {} ({} passed, {} failed, {} open)",
+                "Proposals:".bold(),
+                voting_stats.total_proposals,
+                voting_stats.passed_proposals,
+                voting_stats.failed_proposals,
+                voting_stats.active_proposals
+            );
+            println!(
+                "{} {:.1} {}",
+                "Avg Voters per Proposal:".bold(),
+                voting_stats.avg_participation,
+                format!("({} votes cast)", voting_stats.total_votes_cast).dimmed()
+            );
+            println!(
+                "{} {:+.2} {}",
+                "Avg Vote Margin:".bold(),
+                voting_stats.avg_vote_margin,
+                "(-1 = unanimous no, +1 = unanimous yes)".dimmed()
+            );
+            for change in &voting_stats.policy_changes {
+                println!(
+                    "  Step {}: {} {:.4} -> {:.4} {}",
+                    change.step,
+                    change.proposal_type.parameter_name().unwrap_or("generic"),
+                    change.old_value,
+                    change.new_value,
+                    format!("(margin {:+.2})", change.vote_margin).dimmed()
+                );
+            }
+        }
+
+        // Print loan activity if the loan system was enabled
+        if let Some(ref loan_stats) = self.loan_statistics {
+            println!("\n{}", "--- Loans ---".bright_green().bold());
+            println!(
+                "{} {} ({} repaid, {} active)",
+                "Loans Issued:".bold(),
+                loan_stats.total_loans_issued,
+                loan_stats.total_loans_repaid,
+                loan_stats.active_loans
+            );
+            if loan_stats.total_loans_defaulted > 0 {
+                println!(
+                    "{} {} ({} cascading), {:.2} written off, {} assets seized ({:.2}), {:.2} insured",
+                    "Loan Defaults:".bold(),
+                    loan_stats.total_loans_defaulted,
+                    loan_stats.cascade_defaults,
+                    loan_stats.total_written_off,
+                    loan_stats.assets_seized,
+                    loan_stats.collateral_seized_value,
+                    loan_stats.credit_insurance_recovered
+                );
+                println!(
+                    "{} {} restructured, {} discharged",
+                    "Bankruptcies:".bold(),
+                    loan_stats.bankruptcies_restructured,
+                    loan_stats.bankruptcies_discharged
+                );
+            }
+            if let Some(ref lending_stats) = self.lending_marketplace_statistics {
+                println!(
+                    "{} {} offers posted, avg book depth {:.1} ({} open, {:.2} available)",
+                    "P2P Marketplace:".bold(),
+                    lending_stats.total_offers_posted,
+                    lending_stats.avg_offer_book_depth,
+                    lending_stats.open_offers,
+                    lending_stats.open_offer_volume
+                );
+                println!(
+                    "{} {} loans, volume {:.2}, avg rate {:.2}%, {} unmatched, fees {:.2}",
+                    "P2P Matched:".bold(),
+                    lending_stats.total_matched_loans,
+                    lending_stats.total_matched_volume,
+                    lending_stats.avg_interest_rate * 100.0,
+                    lending_stats.unmatched_requests,
+                    lending_stats.total_platform_fees
+                );
+                for band in &lending_stats.by_credit_band {
+                    println!(
+                        "  {}: {} loans, volume {:.2}, avg rate {:.2}%",
+                        band.credit_band,
+                        band.loans,
+                        band.volume,
+                        band.avg_interest_rate * 100.0
+                    );
+                }
+            }
+        }
+
+        // Print population changes if demographics were enabled
+        if let Some(ref demographics) = self.demographics_statistics {
+            println!("\n{}", "--- Demographics ---".bright_cyan().bold());
+            println!(
+                "{} {} births, {} immigrants",
+                "Arrivals:".bold(),
+                demographics.total_births,
+                demographics.total_immigrants
+            );
+            println!(
+                "{} {} deaths, {} market exits ({} retirements)",
+                "Departures:".bold(),
+                demographics.total_deaths,
+                demographics.total_market_exits,
+                demographics.total_retirements
+            );
+            println!(
+                "{} {} to heirs ({:.2}), {} to tax pool ({:.2})",
+                "Estates:".bold(),
+                demographics.estates_to_heirs,
+                demographics.total_inherited,
+                demographics.estates_to_tax_pool,
+                demographics.total_estate_tax_pool
+            );
+            println!(
+                "{} {} ({} retired, avg age {:.1})",
+                "Final Population:".bold(),
+                demographics.final_population,
+                demographics.retired_population,
+                demographics.average_age
+            );
+        }
+
+        // Print the course of the epidemic if the health system was enabled
+        if let Some(ref epidemic) = self.epidemic_statistics {
+            println!("\n{}", "--- Epidemic ---".bright_red().bold());
+            let counts = epidemic.final_counts;
+            println!(
+                "{} S {} / E {} / I {} / R {}",
+                "Final Compartments:".bold(),
+                counts.susceptible,
+                counts.exposed,
+                counts.infected,
+                counts.recovered
+            );
+            println!(
+                "{} {} sick at step {}",
+                "Peak:".bold(),
+                epidemic.peak_infected,
+                epidemic.peak_step
+            );
+            println!(
+                "{} {} through trades, {} through friendships ({} recoveries)",
+                "Exposures:".bold(),
+                epidemic.trade_exposures,
+                epidemic.contact_exposures,
+                epidemic.recoveries
+            );
+            println!(
+                "{} {} vaccinations, {} immunity losses",
+                "Immunity:".bold(),
+                epidemic.vaccinations,
+                epidemic.immunity_losses
+            );
+            println!(
+                "{} {} lockdown steps ({} trades cancelled), {:.2} sick pay",
+                "Policies:".bold(),
+                epidemic.lockdown_steps,
+                epidemic.lockdown_cancelled_trades,
+                epidemic.total_sick_pay
+            );
+        }
+
+        // Print the labor market if firms were enabled
+        if let Some(ref firms) = self.firm_statistics {
+            println!("\n{}", "--- Firms ---".bright_blue().bold());
+            println!(
+                "{} {} founded, {} active, {} bankrupt",
+                "Firms:".bold(),
+                firms.total_firms_founded,
+                firms.active_firms,
+                firms.bankruptcies
+            );
+            println!(
+                "{} {} employed, unemployment {:.1}% (avg {:.1}%)",
+                "Labor Market:".bold(),
+                firms.employed_persons,
+                firms.unemployment_rate * 100.0,
+                firms.avg_unemployment_rate * 100.0
+            );
+            println!(
+                "{} {} hires, {} layoffs, {} jobs lost to bankruptcies",
+                "Turnover:".bold(),
+                firms.total_hires,
+                firms.total_layoffs,
+                firms.bankruptcy_job_losses
+            );
+            println!(
+                "{} {} units for {:.2}, wages {:.2}, dividends {:.2}",
+                "Sales:".bold(),
+                firms.total_units_sold,
+                firms.total_revenue,
+                firms.total_wages_paid,
+                firms.total_dividends_paid
+            );
+            println!(
+                "{} avg {:.1}, largest {}",
+                "Firm Size:".bold(),
+                firms.avg_firm_size,
+                firms.largest_firm_size
+            );
+        }
+
+        // Print monetary policy if the central bank was enabled
+        if let Some(ref monetary) = self.monetary_policy_statistics {
+            println!("\n{}", "--- Central Bank ---".bright_blue().bold());
+            println!(
+                "{} {:?} with {:?} operations",
+                "Policy:".bold(),
+                monetary.rule,
+                monetary.money_operation
+            );
+            println!(
+                "{} {:.4} at end (avg {:.4}, range {:.4}-{:.4}, neutral {:.4})",
+                "Policy Rate:".bold(),
+                monetary.policy_rate,
+                monetary.avg_policy_rate,
+                monetary.min_policy_rate,
+                monetary.max_policy_rate,
+                monetary.neutral_rate
+            );
+            println!("{} {:.4}% per step", "Avg Inflation:".bold(), monetary.avg_inflation * 100.0);
+            println!(
+                "{} {:.2} created, {:.2} withdrawn, {:.2} net; money supply {:.2}",
+                "Money:".bold(),
+                monetary.total_money_created,
+                monetary.total_money_withdrawn,
+                monetary.net_money_issued,
+                monetary.money_supply
+            );
+        }
+
+        // Print the government budget if the government was enabled
+        if let Some(ref government) = self.government_statistics {
+            println!("\n{}", "--- Government ---".bright_blue().bold());
+            println!(
+                "{} {:.2} (trade tax {:.2}, VAT {:.2}, income tax {:.2}, wealth tax {:.2})",
+                "Revenue:".bold(),
+                government.total_revenue,
+                government.trade_tax_revenue,
+                government.vat_revenue,
+                government.income_tax_revenue,
+                government.wealth_tax_revenue
+            );
+            println!(
+                "{} {:.2} (public goods {:.2}, unemployment benefits {:.2}, class transfers {:.2})",
+                "Spending:".bold(),
+                government.total_spending,
+                government.public_goods_spending,
+                government.unemployment_benefits_paid,
+                government.class_transfers_paid.values().sum::<f64>()
+            );
+            println!(
+                "{} {:.2} (peak {:.2}), interest due {:.2}, deficits in {} steps",
+                "Debt:".bold(),
+                government.debt,
+                government.peak_debt,
+                government.interest_due,
+                government.deficit_steps
+            );
+        }
+
+        // Print transfer outcomes if tax redistribution was enabled
+        if let Some(ref transfers) = self.transfer_statistics {
+            println!("\n{}", "--- Transfers ---".bright_blue().bold());
+            println!(
+                "{} {:?} funded by {:?}",
+                "Scheme:".bold(),
+                transfers.scheme,
+                transfers.funding
+            );
+            println!(
+                "{} {:.2} ({:.2} per step, {} payments averaging {:.2}, {:.2} created)",
+                "Fiscal Cost:".bold(),
+                transfers.total_paid,
+                transfers.cost_per_step,
+                transfers.payments,
+                transfers.avg_payment,
+                transfers.money_created
+            );
+            println!(
+                "{} {:.1}% before, {:.1}% after transfers (line {:.2})",
+                "Poverty Rate:".bold(),
+                transfers.poverty_rate_before * 100.0,
+                transfers.poverty_rate_after * 100.0,
+                transfers.poverty_line
+            );
+            println!(
+                "{} {:.4} before, {:.4} after transfers",
+                "Gini:".bold(),
+                transfers.gini_before,
+                transfers.gini_after
+            );
+        }
+
+        // Print the before/after comparison of each timeline event applied
+        if let Some(ref timeline) = self.timeline_statistics {
+            println!(
+                "\n{} (averages over {} steps before -> after)",
+                "--- Timeline ---".bright_blue().bold(),
+                timeline.window
+            );
+            for impact in &timeline.interventions {
+                println!("{}", impact.description.bold());
+                println!(
+                    "  Trades/step {:.1} -> {:.1}, volume/step {:.2} -> {:.2}, \
+                     avg money {:.2} -> {:.2}, Gini {:.4} -> {:.4}",
+                    impact.trades_before,
+                    impact.trades_after,
+                    impact.volume_before,
+                    impact.volume_after,
+                    impact.avg_money_before,
+                    impact.avg_money_after,
+                    impact.gini_before,
+                    impact.gini_after
+                );
+            }
+        }
+
+        // Print the recovery from each custom crisis
+        if let Some(ref crises) = self.custom_crisis_statistics {
+            println!("\n{}", "--- Custom Crises ---".bright_red().bold());
+            println!(
+                "{} {} ({} by contagion), money changed {:.2}",
+                "Crises:".bold(),
+                crises.crises.len(),
+                crises.contagions,
+                crises.money_changed
+            );
+            for crisis in &crises.crises {
+                let recovery = match crisis.recovery_steps {
+                    Some(steps) => format!("recovered after {} steps", steps),
+                    None => "not recovered".to_string(),
+                };
+                let groups = if crisis.groups.is_empty() {
+                    String::new()
+                } else {
+                    format!(" groups {:?}", crisis.groups)
+                };
+                println!(
+                    "  {} (steps {}-{}{}): GDP {:.2} -> trough {:.2}, loss {:.2}, {}",
+                    crisis.name,
+                    crisis.start_step,
+                    crisis.end_step,
+                    groups,
+                    crisis.pre_crisis_gdp,
+                    crisis.trough_gdp,
+                    crisis.output_loss,
+                    recovery
+                );
+            }
+        }
+
+        // Print population, inequality and prices per region
+        if let Some(ref geography) = self.geography_statistics {
+            println!("\n{}", "--- Regions ---".bright_cyan().bold());
+            println!("{} {}", "Migrations:".bold(), geography.migrations);
+            for region in &geography.regions {
+                println!(
+                    "  {}: {} persons, avg money {:.2}, Gini {:.3}, price index {:.3}, \
+                     {} trades, +{} / -{} migrants",
+                    region.name,
+                    region.population,
+                    region.average_money,
+                    region.gini_coefficient,
+                    region.price_index,
+                    region.trades,
+                    region.immigrants,
+                    region.emigrants
+                );
+            }
+        }
+
+        // Print investment outcomes if the investment system was enabled
+        if let Some(ref investment_stats) = self.investment_statistics {
+            println!("\n{}", "--- Investments ---".bright_green().bold());
+            println!(
+                "{} {} ({} completed, {} defaulted, {} active)",
+                "Investments Created:".bold(),
+                investment_stats.total_investments_created,
+                investment_stats.total_investments_completed,
+                investment_stats.total_investments_defaulted,
+                investment_stats.active_investments
+            );
+            println!("{} {:.2}", "Total Invested:".bold(), investment_stats.total_invested);
+            println!("{} {:.2}", "Total Returns Paid:".bold(), investment_stats.total_returns_paid);
+            println!("{} {:.2}", "Losses from Defaults:".bold(), investment_stats.total_losses);
+            println!(
+                "{} {:.2}%",
+                "Avg ROI (completed):".bold(),
+                investment_stats.avg_roi_percentage
+            );
+        }
+
+        // Print auction outcomes if auctions were enabled
+        if let Some(ref auction_stats) = self.auction_statistics {
+            println!("\n{}", "--- Auctions ---".bright_yellow().bold());
+            println!(
+                "{} {} ({} sold, volume {:.2}, seller surplus {:.2})",
+                "Auctions Held:".bold(),
+                auction_stats.total_auctions,
+                auction_stats.total_sold,
+                auction_stats.total_volume,
+                auction_stats.total_seller_surplus
+            );
+            for type_stats in &auction_stats.by_type {
+                println!(
+                    "  {:?}: {} held, {} sold, {} bids, clearing price avg {:.2} (min {:.2}, max {:.2}), avg surplus {:.2}",
+                    type_stats.auction_type,
+                    type_stats.auctions,
+                    type_stats.sold,
+                    type_stats.total_bids,
+                    type_stats.avg_clearing_price,
+                    type_stats.min_clearing_price,
+                    type_stats.max_clearing_price,
+                    type_stats.avg_seller_surplus
+                );
+            }
+        }
+
+        // Print currency zones if multi-currency was enabled
+        if let Some(ref currency_stats) = self.currency_statistics {
+            println!("\n{}", "--- Currencies ---".bright_cyan().bold());
+            println!(
+                "{} {} ({:.2} base volume)",
+                "Cross-Currency Trades:".bold(),
+                currency_stats.cross_currency_trades,
+                currency_stats.cross_currency_volume
+            );
+            for zone in &currency_stats.currencies {
+                println!(
+                    "  {}: {} persons, supply {:.2} ({:.2} base), rate {:.4}, exports {:.2}, imports {:.2}, {} devaluations",
+                    zone.currency_id.bright_cyan(),
+                    zone.persons,
+                    zone.money_supply,
+                    zone.money_supply_in_base,
+                    zone.final_exchange_rate,
+                    zone.total_exports,
+                    zone.total_imports,
+                    zone.devaluations
+                );
+            }
+        }
+
+        // Print the full profile if enabled, otherwise the slowest step phases
+        if let Some(ref profile) = self.profile {
+            profile.print_table();
+        } else if !self.phase_timings.is_empty() {
+            println!("\n{}", "--- Phase Timings (slowest 5) ---".bright_cyan().bold());
+            let mut timings: Vec<&PhaseTiming> = self.phase_timings.iter().collect();
+            timings.sort_by(|a, b| {
+                b.total_ms.partial_cmp(&a.total_ms).unwrap_or(std::cmp::Ordering::Equal)
+            });
+            for timing in timings.into_iter().take(5) {
+                println!(
+                    "  {}: {:.2}ms total, {:.4}ms avg over {} steps",
+                    timing.name.bright_cyan(),
+                    timing.total_ms,
+                    timing.avg_ms,
+                    timing.calls
+                );
+            }
+        }
+
         println!("\n{}", "--- Skill Valuations ---".bright_blue().bold());
         if let Some(skill) = &self.most_valuable_skill {
             println!(
@@ -2803,6 +4166,13 @@ impl SimulationResult {
     /// - Wealth distribution statistics: `avg_money`, `median_money`, `gini_coefficient`,
     ///   `top_10_percent_share`, `top_1_percent_share`, `bottom_50_percent_share`
     /// - Individual skill prices: `price_Skill_X` for each skill X
+    /// - Epidemic curve (if health is enabled): `susceptible`, `exposed`, `infected`,
+    ///   `recovered`
+    /// - Labor market (if firms are enabled): `employed_persons`, `unemployment_rate`
+    /// - Monetary policy (if the central bank is enabled): `policy_rate`, `inflation`,
+    ///   `money_supply`
+    /// - Government budget (if the government is enabled): `government_revenue`,
+    ///   `government_spending`, `government_debt`
     ///
     /// # Arguments
     ///
@@ -2864,6 +4234,47 @@ impl SimulationResult {
             )?;
         }
 
+        // Export the epidemic curve
+        if let Some(ref epidemic) = self.epidemic_statistics {
+            for snapshot in &epidemic.history {
+                let step = snapshot.step;
+                writeln!(file, "{},susceptible,{}", step, snapshot.counts.susceptible)?;
+                writeln!(file, "{},exposed,{}", step, snapshot.counts.exposed)?;
+                writeln!(file, "{},infected,{}", step, snapshot.counts.infected)?;
+                writeln!(file, "{},recovered,{}", step, snapshot.counts.recovered)?;
+            }
+        }
+
+        // Export the labor market
+        if let Some(ref firms) = self.firm_statistics {
+            for (step, &employed) in firms.employment_history.iter().enumerate() {
+                writeln!(file, "{},employed_persons,{}", step, employed)?;
+            }
+            for (step, &rate) in firms.unemployment_history.iter().enumerate() {
+                writeln!(file, "{},unemployment_rate,{:.6}", step, rate)?;
+            }
+        }
+
+        // Export monetary policy
+        if let Some(ref monetary) = self.monetary_policy_statistics {
+            for snapshot in &monetary.history {
+                let step = snapshot.step;
+                writeln!(file, "{},policy_rate,{:.6}", step, snapshot.policy_rate)?;
+                writeln!(file, "{},inflation,{:.6}", step, snapshot.inflation)?;
+                writeln!(file, "{},money_supply,{:.4}", step, snapshot.money_supply)?;
+            }
+        }
+
+        // Export government budget
+        if let Some(ref government) = self.government_statistics {
+            for snapshot in &government.history {
+                let step = snapshot.step;
+                writeln!(file, "{},government_revenue,{:.4}", step, snapshot.revenue)?;
+                writeln!(file, "{},government_spending,{:.4}", step, snapshot.spending)?;
+                writeln!(file, "{},government_debt,{:.4}", step, snapshot.debt)?;
+            }
+        }
+
         // Export skill price history
         // Sort skill IDs for consistent output
         let mut skill_ids: Vec<_> = self.skill_price_history.keys().collect();
@@ -2902,6 +4313,12 @@ impl SimulationResult {
     /// - Failed trades: `failed_attempts`
     /// - Wealth stats: `avg_money`, `median_money`, `gini_coefficient`, etc.
     /// - Skill prices: `price_skill_<ID>` for each skill
+    /// - Epidemic curve (if health is enabled): `susceptible`, `exposed`, `infected`, `recovered`
+    /// - Labor market (if firms are enabled): `employed_persons`, `unemployment_rate`
+    /// - Monetary policy (if the central bank is enabled): `policy_rate`, `inflation`,
+    ///   `money_supply`
+    /// - Government budget (if the government is enabled): `government_revenue`,
+    ///   `government_spending`, `government_debt`
     ///
     /// # Arguments
     ///
@@ -2996,6 +4413,48 @@ impl SimulationResult {
             ));
         }
 
+        // Export the epidemic curve
+        if let Some(ref epidemic) = self.epidemic_statistics {
+            for snapshot in &epidemic.history {
+                let step = snapshot.step as i64;
+                let counts = snapshot.counts;
+                rows.push((step, "susceptible".to_string(), counts.susceptible as f64));
+                rows.push((step, "exposed".to_string(), counts.exposed as f64));
+                rows.push((step, "infected".to_string(), counts.infected as f64));
+                rows.push((step, "recovered".to_string(), counts.recovered as f64));
+            }
+        }
+
+        // Export the labor market
+        if let Some(ref firms) = self.firm_statistics {
+            for (step, &employed) in firms.employment_history.iter().enumerate() {
+                rows.push((step as i64, "employed_persons".to_string(), employed as f64));
+            }
+            for (step, &rate) in firms.unemployment_history.iter().enumerate() {
+                rows.push((step as i64, "unemployment_rate".to_string(), rate));
+            }
+        }
+
+        // Export monetary policy
+        if let Some(ref monetary) = self.monetary_policy_statistics {
+            for snapshot in &monetary.history {
+                let step = snapshot.step as i64;
+                rows.push((step, "policy_rate".to_string(), snapshot.policy_rate));
+                rows.push((step, "inflation".to_string(), snapshot.inflation));
+                rows.push((step, "money_supply".to_string(), snapshot.money_supply));
+            }
+        }
+
+        // Export government budget
+        if let Some(ref government) = self.government_statistics {
+            for snapshot in &government.history {
+                let step = snapshot.step as i64;
+                rows.push((step, "government_revenue".to_string(), snapshot.revenue));
+                rows.push((step, "government_spending".to_string(), snapshot.spending));
+                rows.push((step, "government_debt".to_string(), snapshot.debt));
+            }
+        }
+
         // Export skill price history
         let mut skill_ids: Vec<_> = self.skill_price_history.keys().collect();
         skill_ids.sort();
@@ -4002,18 +5461,17 @@ impl MonteCarloResult {
 /// # Arguments
 /// * `mobility_quintiles` - Map of person_id to vector of quintile assignments (0-4) at each step
 ///
+/// Persons tracked for fewer than 2 steps (e.g. born in the last step) are skipped.
+///
 /// # Returns
 /// * `Option<MobilityStatistics>` - Mobility statistics if sufficient data exists (at least 2 steps)
 pub fn calculate_mobility_statistics(
     mobility_quintiles: &HashMap<usize, Vec<usize>>,
 ) -> Option<MobilityStatistics> {
-    if mobility_quintiles.is_empty() {
-        return None;
-    }
-
-    // Check if we have at least 2 time points (need transitions)
-    let min_length = mobility_quintiles.values().map(|v| v.len()).min()?;
-    if min_length < 2 {
+    // Only persons with at least 2 time points have transitions
+    let tracked: Vec<&Vec<usize>> =
+        mobility_quintiles.values().filter(|quintiles| quintiles.len() >= 2).collect();
+    if tracked.is_empty() {
         return None;
     }
 
@@ -4026,7 +5484,7 @@ pub fn calculate_mobility_statistics(
     let mut total_quintile_changes_count = 0;
 
     // Count transitions for each person
-    for quintiles in mobility_quintiles.values() {
+    for quintiles in &tracked {
         let mut person_changes = 0;
         for i in 0..quintiles.len() - 1 {
             let from_quintile = quintiles[i];
@@ -4079,11 +5537,7 @@ pub fn calculate_mobility_statistics(
         0.0
     };
 
-    let avg_quintile_changes = if !mobility_quintiles.is_empty() {
-        total_quintile_changes_count as f64 / mobility_quintiles.len() as f64
-    } else {
-        0.0
-    };
+    let avg_quintile_changes = total_quintile_changes_count as f64 / tracked.len() as f64;
 
     Some(MobilityStatistics {
         transition_matrix,
@@ -4389,6 +5843,8 @@ mod tests {
             total_steps: 10,
             total_duration: 1.23,
             step_times: vec![0.1, 0.12, 0.1, 0.13, 0.1, 0.11, 0.1, 0.14, 0.1, 0.13],
+            phase_timings: vec![],
+            profile: None,
             active_persons: 5,
             failed_steps: 0,
             final_money_distribution: vec![50.0, 80.0, 100.0, 120.0, 150.0],
@@ -4464,7 +5920,10 @@ mod tests {
             total_taxes_collected: None,
             total_taxes_redistributed: None,
             loan_statistics: None,
+            lending_marketplace_statistics: None,
             investment_statistics: None,
+            currency_statistics: None,
+            auction_statistics: None,
             contract_statistics: None,
             education_statistics: None,
             mentorship_statistics: None,
@@ -4475,6 +5934,7 @@ mod tests {
             influence_statistics: None,
             trust_network_statistics: None,
             trade_agreement_statistics: None,
+            voting_statistics: None,
             insurance_statistics: None,
             asset_statistics: None,
             technology_breakthrough_statistics: None,
@@ -4489,6 +5949,15 @@ mod tests {
             },
             centrality_analysis: None,
             mobility_statistics: None,
+            demographics_statistics: None,
+            epidemic_statistics: None,
+            firm_statistics: None,
+            monetary_policy_statistics: None,
+            government_statistics: None,
+            transfer_statistics: None,
+            timeline_statistics: None,
+            custom_crisis_statistics: None,
+            geography_statistics: None,
             social_class_statistics: crate::result::SocialClassStats::default(),
             quality_statistics: None,
             strategy_evolution_statistics: None,
@@ -5030,6 +6499,375 @@ mod tests {
         assert!(contents.contains("Gini Coefficient,0.2"));
     }
 
+    #[test]
+    fn test_save_to_csv_summary_with_investments() {
+        let mut result = get_test_result();
+        let contents = read_csv_file_from_test(&result, "summary");
+        assert!(!contents.contains("Investment Statistics"));
+
+        result.investment_statistics = Some(InvestmentStats {
+            total_investments_created: 8,
+            total_investments_completed: 5,
+            active_investments: 2,
+            total_invested: 400.0,
+            total_returns_paid: 450.0,
+            avg_roi_percentage: 40.0,
+            total_investments_defaulted: 1,
+            total_losses: 30.0,
+        });
+        let contents = read_csv_file_from_test(&result, "summary");
+        assert!(contents.contains("Investment Statistics"));
+        assert!(contents.contains("Investments Created,8"));
+        assert!(contents.contains("Investments Defaulted,1"));
+        assert!(contents.contains("Total Losses,30.0000"));
+        assert!(contents.contains("Avg ROI (%),40.0000"));
+    }
+
+    #[test]
+    fn test_save_to_csv_summary_with_lending_marketplace() {
+        let mut result = get_test_result();
+        let contents = read_csv_file_from_test(&result, "summary");
+        assert!(!contents.contains("P2P Lending Marketplace"));
+
+        result.loan_statistics = Some(LoanStats {
+            total_loans_issued: 3,
+            total_loans_repaid: 1,
+            active_loans: 2,
+            ..Default::default()
+        });
+        result.lending_marketplace_statistics = Some(LendingMarketplaceStats {
+            total_offers_posted: 12,
+            avg_offer_book_depth: 4.0,
+            open_offers: 2,
+            open_offer_volume: 80.0,
+            total_matched_loans: 3,
+            total_matched_volume: 150.0,
+            unmatched_requests: 5,
+            avg_interest_rate: 0.012,
+            total_platform_fees: 1.5,
+            by_credit_band: vec![CreditBandLendingStats {
+                credit_band: "Fair".to_string(),
+                loans: 3,
+                volume: 150.0,
+                avg_interest_rate: 0.012,
+            }],
+        });
+        let contents = read_csv_file_from_test(&result, "summary");
+        assert!(contents.contains("Loans Issued,3"));
+        assert!(contents.contains("P2P Lending Marketplace"));
+        assert!(contents.contains("Matched Volume,150.0000"));
+        assert!(contents.contains("Platform Fees,1.5000"));
+        assert!(contents.contains("Fair,3,150.0000,0.012000"));
+        result.print_summary(false);
+    }
+
+    #[test]
+    fn test_save_to_csv_summary_with_demographics() {
+        let mut result = get_test_result();
+        let contents = read_csv_file_from_test(&result, "summary");
+        assert!(!contents.contains("Demographics"));
+
+        result.demographics_statistics = Some(DemographicsStats {
+            total_births: 4,
+            total_immigrants: 2,
+            total_retirements: 3,
+            total_deaths: 1,
+            total_market_exits: 2,
+            estates_to_heirs: 1,
+            estates_to_tax_pool: 2,
+            total_inherited: 25.0,
+            total_estate_tax_pool: 12.5,
+            total_immigrant_money: 200.0,
+            final_population: 13,
+            retired_population: 2,
+            average_age: 31.5,
+            population_history: vec![10, 12, 13],
+        });
+        let contents = read_csv_file_from_test(&result, "summary");
+        assert!(contents.contains("Demographics"));
+        assert!(contents.contains("Births,4"));
+        assert!(contents.contains("Market Exits,2"));
+        assert!(contents.contains("Total Inherited,25.0000"));
+        assert!(contents.contains("Final Population,13"));
+        result.print_summary(false);
+    }
+
+    #[test]
+    fn test_epidemic_summary_and_timeseries() {
+        use crate::epidemic::{CompartmentCounts, EpidemicSnapshot};
+
+        let mut result = get_test_result();
+        let snapshot = |step, infected| EpidemicSnapshot {
+            step,
+            counts: CompartmentCounts {
+                susceptible: 10 - infected,
+                infected,
+                ..Default::default()
+            },
+        };
+        result.epidemic_statistics = Some(EpidemicStats {
+            final_counts: CompartmentCounts {
+                susceptible: 6,
+                exposed: 1,
+                infected: 0,
+                recovered: 3,
+            },
+            peak_infected: 3,
+            peak_step: 1,
+            trade_exposures: 2,
+            contact_exposures: 2,
+            recoveries: 3,
+            vaccinations: 1,
+            lockdown_steps: 2,
+            lockdown_cancelled_trades: 5,
+            total_sick_pay: 7.5,
+            history: vec![snapshot(0, 1), snapshot(1, 3)],
+            ..Default::default()
+        });
+        let contents = read_csv_file_from_test(&result, "summary");
+        assert!(contents.contains("Epidemic"));
+        assert!(contents.contains("Final Recovered,3"));
+        assert!(contents.contains("Peak Infected,3"));
+        assert!(contents.contains("Lockdown Cancelled Trades,5"));
+        assert!(contents.contains("Total Sick Pay,7.5000"));
+        result.print_summary(false);
+
+        let file = NamedTempFile::new().unwrap();
+        result.save_timeseries_csv(file.path().to_str().unwrap()).unwrap();
+        let contents = std::fs::read_to_string(file.path()).unwrap();
+        assert!(contents.contains("0,infected,1"));
+        assert!(contents.contains("1,susceptible,7"));
+        assert!(contents.contains("1,exposed,0"));
+    }
+
+    #[test]
+    fn test_firm_summary_and_timeseries() {
+        let mut result = get_test_result();
+        result.firm_statistics = Some(FirmStats {
+            total_firms_founded: 2,
+            active_firms: 1,
+            bankruptcies: 1,
+            total_hires: 5,
+            employed_persons: 3,
+            unemployment_rate: 0.25,
+            total_revenue: 120.0,
+            largest_firm_size: 3,
+            firm_size_distribution: vec![3],
+            employment_history: vec![2, 3],
+            unemployment_history: vec![0.5, 0.25],
+            firms: vec![FirmSummary {
+                id: 1,
+                owner_id: 4,
+                product: "Skill0+Skill1".to_string(),
+                employees: 0,
+                units_sold: 3,
+                revenue: 60.0,
+                wages_paid: 70.0,
+                dividends_paid: 0.0,
+                balance_sheet: crate::firm::BalanceSheet {
+                    assets: 0.0,
+                    liabilities: 10.0,
+                    equity: -10.0,
+                },
+                founded_at_step: 0,
+                bankrupt_at_step: Some(7),
+            }],
+            ..Default::default()
+        });
+        let contents = read_csv_file_from_test(&result, "summary");
+        assert!(contents.contains("Firms Founded,2"));
+        assert!(contents.contains("Unemployment Rate,0.250000"));
+        assert!(contents.contains("1,4,Skill0+Skill1,0,3,60.0000,70.0000,0.0000,-10.0000,7"));
+        result.print_summary(false);
+
+        let file = NamedTempFile::new().unwrap();
+        result.save_timeseries_csv(file.path().to_str().unwrap()).unwrap();
+        let contents = std::fs::read_to_string(file.path()).unwrap();
+        assert!(contents.contains("1,employed_persons,3"));
+        assert!(contents.contains("0,unemployment_rate,0.500000"));
+    }
+
+    #[test]
+    fn test_monetary_policy_summary_and_timeseries() {
+        use crate::central_bank::{MonetarySnapshot, MoneyOperation};
+
+        let mut result = get_test_result();
+        let snapshot = |step, policy_rate, money_supply| MonetarySnapshot {
+            step,
+            policy_rate,
+            inflation: 0.01,
+            output_gap: 0.0,
+            money_created: 0.0,
+            money_supply,
+        };
+        result.monetary_policy_statistics = Some(MonetaryPolicyStats {
+            money_operation: MoneyOperation::Helicopter,
+            neutral_rate: 0.01,
+            policy_rate: 0.03,
+            total_money_created: 25.0,
+            net_money_issued: 25.0,
+            history: vec![snapshot(0, 0.02, 1000.0), snapshot(1, 0.03, 1025.0)],
+            ..Default::default()
+        });
+        let contents = read_csv_file_from_test(&result, "summary");
+        assert!(contents.contains("Money Operation,Helicopter"));
+        assert!(contents.contains("Policy Rate,0.030000"));
+        assert!(contents.contains("Net Money Issued,25.0000"));
+        result.print_summary(false);
+
+        let file = NamedTempFile::new().unwrap();
+        result.save_timeseries_csv(file.path().to_str().unwrap()).unwrap();
+        let contents = std::fs::read_to_string(file.path()).unwrap();
+        assert!(contents.contains("0,policy_rate,0.020000"));
+        assert!(contents.contains("1,inflation,0.010000"));
+        assert!(contents.contains("1,money_supply,1025.0000"));
+    }
+
+    #[test]
+    fn test_transfer_summary() {
+        use crate::transfers::{TransferFunding, TransferScheme};
+
+        let mut result = get_test_result();
+        result.transfer_statistics = Some(TransferStats {
+            scheme: TransferScheme::NegativeIncomeTax,
+            funding: TransferFunding::MoneyCreation,
+            total_paid: 40.0,
+            money_created: 40.0,
+            poverty_rate_before: 0.3,
+            poverty_rate_after: 0.1,
+            gini_before: 0.45,
+            gini_after: 0.3,
+            ..Default::default()
+        });
+        let contents = read_csv_file_from_test(&result, "summary");
+        assert!(contents.contains("Scheme,NegativeIncomeTax"));
+        assert!(contents.contains("Fiscal Cost,40.0000"));
+        assert!(contents.contains("Poverty Rate After Transfers,0.100000"));
+        assert!(contents.contains("Gini Before Transfers,0.450000"));
+        result.print_summary(false);
+    }
+
+    #[test]
+    fn test_timeline_summary() {
+        use crate::timeline::InterventionImpact;
+
+        let mut result = get_test_result();
+        result.timeline_statistics = Some(TimelineStats {
+            window: 20,
+            interventions: vec![InterventionImpact {
+                step: 200,
+                description: "step 200: set tax_rate = 0.3".to_string(),
+                trades_before: 12.0,
+                trades_after: 9.5,
+                gini_before: 0.25,
+                gini_after: 0.2,
+                ..Default::default()
+            }],
+        });
+        let contents = read_csv_file_from_test(&result, "summary");
+        assert!(contents.contains("Timeline (averages over 20 steps before/after)"));
+        assert!(contents.contains("\"step 200: set tax_rate = 0.3\",12.0000,9.5000"));
+        assert!(contents.contains("0.250000,0.200000"));
+        result.print_summary(false);
+    }
+
+    #[test]
+    fn test_custom_crisis_summary() {
+        use crate::crisis::CrisisRecovery;
+
+        let mut result = get_test_result();
+        let recovery = CrisisRecovery {
+            name: "Harvest Failure".to_string(),
+            start_step: 10,
+            end_step: 20,
+            groups: vec![0, 1],
+            pre_crisis_gdp: 150.0,
+            trough_gdp: 90.0,
+            output_loss: 240.0,
+            recovery_steps: Some(12),
+            ..Default::default()
+        };
+        result.custom_crisis_statistics = Some(CustomCrisisStats {
+            crises: vec![
+                recovery.clone(),
+                CrisisRecovery { source: Some(0), recovery_steps: None, ..recovery },
+            ],
+            contagions: 1,
+            money_changed: -35.0,
+        });
+        let contents = read_csv_file_from_test(&result, "summary");
+        assert!(contents.contains("Money Changed,-35.0000"));
+        assert!(contents.contains("\"Harvest Failure\",10,20,0 1,,150.0000,90.0000,240.0000,12"));
+        assert!(contents.contains("0 1,0,150.0000,90.0000,240.0000,\n"));
+        result.print_summary(false);
+    }
+
+    #[test]
+    fn test_geography_summary() {
+        use crate::geography::RegionStats;
+
+        let mut result = get_test_result();
+        let port = RegionStats {
+            name: "Port".to_string(),
+            population: 12,
+            average_money: 110.0,
+            gini_coefficient: 0.25,
+            price_index: 1.05,
+            trades: 40,
+            immigrants: 3,
+            emigrants: 1,
+        };
+        result.geography_statistics = Some(GeographyStats {
+            migrations: 4,
+            regions: vec![
+                port.clone(),
+                RegionStats { name: "Hills".to_string(), immigrants: 1, emigrants: 3, ..port },
+            ],
+        });
+        let contents = read_csv_file_from_test(&result, "summary");
+        assert!(contents.contains("Migrations,4"));
+        assert!(contents.contains("\"Port\",12,110.0000,0.2500,1.0500,40,3,1"));
+        assert!(contents.contains("\"Hills\",12,110.0000,0.2500,1.0500,40,1,3"));
+        result.print_summary(false);
+    }
+
+    #[test]
+    fn test_government_summary_and_timeseries() {
+        use crate::government::BudgetSnapshot;
+        use crate::person::SocialClass;
+
+        let mut result = get_test_result();
+        let snapshot = |step, revenue, spending, debt| BudgetSnapshot {
+            step,
+            revenue,
+            spending,
+            interest: 0.0,
+            balance: revenue - spending,
+            debt,
+        };
+        result.government_statistics = Some(GovernmentStats {
+            vat_revenue: 4.0,
+            total_revenue: 12.5,
+            class_transfers_paid: HashMap::from([(SocialClass::Lower, 7.5)]),
+            debt: 20.0,
+            deficit_steps: 1,
+            history: vec![snapshot(0, 5.0, 25.0, 20.0), snapshot(1, 7.5, 7.5, 20.0)],
+            ..Default::default()
+        });
+        let contents = read_csv_file_from_test(&result, "summary");
+        assert!(contents.contains("VAT Revenue,4.0000"));
+        assert!(contents.contains("Transfers to Lower,7.5000"));
+        assert!(contents.contains("Deficit Steps,1"));
+        result.print_summary(false);
+
+        let file = NamedTempFile::new().unwrap();
+        result.save_timeseries_csv(file.path().to_str().unwrap()).unwrap();
+        let contents = std::fs::read_to_string(file.path()).unwrap();
+        assert!(contents.contains("0,government_spending,25.0000"));
+        assert!(contents.contains("1,government_revenue,7.5000"));
+        assert!(contents.contains("1,government_debt,20.0000"));
+    }
+
     #[test]
     fn test_save_to_csv_money_distribution() {
         let result = get_test_result();
@@ -5720,6 +7558,19 @@ mod tests {
         assert!(result.is_none()); // Need at least 2 time points for transitions
     }
 
+    #[test]
+    fn test_calculate_mobility_statistics_skips_late_entrants() {
+        let mut mobility_quintiles = HashMap::new();
+        mobility_quintiles.insert(0, vec![0, 1, 1]);
+        mobility_quintiles.insert(1, vec![4, 3, 4]);
+        mobility_quintiles.insert(2, vec![2]); // Joined in the last step
+
+        let stats = calculate_mobility_statistics(&mobility_quintiles).unwrap();
+        assert_eq!(stats.upward_mobility_probability, 0.5);
+        assert_eq!(stats.downward_mobility_probability, 0.25);
+        assert_eq!(stats.avg_quintile_changes, 1.5);
+    }
+
     #[test]
     fn test_calculate_mobility_statistics_no_mobility() {
         // All persons stay in their initial quintile
@@ -6151,8 +8002,12 @@ mod tests {
     #[test]
     fn test_print_summary_with_loan_statistics() {
         let mut result = get_test_result();
-        result.loan_statistics =
-            Some(LoanStats { total_loans_issued: 50, total_loans_repaid: 40, active_loans: 10 });
+        result.loan_statistics = Some(LoanStats {
+            total_loans_issued: 50,
+            total_loans_repaid: 40,
+            active_loans: 10,
+            ..Default::default()
+        });
 
         // Should display loan statistics
         result.print_summary(false);
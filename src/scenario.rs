@@ -1014,6 +1014,120 @@ mod tests {
     }
 }
 
+/// A custom price update rule that can be registered on a [`Market`].
+///
+/// The built-in rules are selected through [`Scenario`] and wrapped in [`PriceUpdater`].
+/// Implement this trait to plug in other rules (for example Walrasian tâtonnement or
+/// posted-price learning) without modifying this module, then register the strategy
+/// with [`Market::set_price_strategy`] or `SimulationEngine::set_price_strategy`.
+/// A registered strategy takes precedence over the scenario's updater.
+///
+/// The strategy receives the whole market, including `demand_counts`, `supply_counts`,
+/// `sales_this_step`, `demand_history`, `supply_history` and `skill_price_history`.
+/// Demand and supply history hold previous steps only: the current step's counts are
+/// recorded right after the price update. Use [`Market::get_effective_min_price`] and
+/// [`Market::get_effective_max_price`] to respect price limits.
+///
+/// Strategies are cloned together with the market, so implementors should derive
+/// `Clone`. Trait objects cannot be serialized: checkpoints store the strategy's name
+/// and the optional state from [`PriceUpdateStrategy::checkpoint_state`]. After
+/// loading a checkpoint the scenario updater is used until the strategy is
+/// registered again, at which point [`PriceUpdateStrategy::restore_state`] receives
+/// the saved state.
+///
+/// # Examples
+///
+/// ```
+/// use community_simulation::scenario::{PriceUpdateStrategy, PriceUpdater};
+/// use community_simulation::{Market, Skill};
+/// use rand::Rng;
+///
+/// /// Raises prices by 10% of excess demand per step (Walrasian tâtonnement).
+/// #[derive(Debug, Clone)]
+/// struct Tatonnement;
+///
+/// impl PriceUpdateStrategy for Tatonnement {
+///     fn name(&self) -> &str {
+///         "Tatonnement"
+///     }
+///
+///     fn update_prices(&mut self, market: &mut Market, _rng: &mut dyn Rng) {
+///         let ids: Vec<_> = market.skills.keys().cloned().collect();
+///         for id in ids {
+///             let demand = *market.demand_counts.get(&id).unwrap_or(&0) as f64;
+///             let supply = *market.supply_counts.get(&id).unwrap_or(&0) as f64;
+///             let (min, max) = (market.get_effective_min_price(&id), market.get_effective_max_price(&id));
+///             let skill = market.skills.get_mut(&id).unwrap();
+///             skill.current_price = (skill.current_price * (1.0 + 0.1 * (demand - supply))).clamp(min, max);
+///         }
+///     }
+/// }
+///
+/// let mut market = Market::new(10.0, 1.0, 0.1, 0.02, PriceUpdater::default());
+/// market.add_skill(Skill::new("Baking".to_string(), 10.0));
+/// market.increment_demand(&"Baking".to_string());
+/// market.set_price_strategy(Box::new(Tatonnement));
+///
+/// let mut rng = rand::rng();
+/// market.update_prices(&mut rng);
+/// assert!((market.get_price(&"Baking".to_string()).unwrap() - 11.0).abs() < 1e-9);
+/// ```
+pub trait PriceUpdateStrategy: PriceUpdateStrategyClone + Send + Sync + Debug {
+    /// Returns a unique name identifying the strategy (stored in checkpoints).
+    fn name(&self) -> &str;
+
+    /// Updates skill prices in the market.
+    ///
+    /// # Arguments
+    ///
+    /// * `market` - The market whose skill prices should be updated
+    /// * `rng` - The simulation's random number generator (use it to stay reproducible)
+    fn update_prices(&mut self, market: &mut Market, rng: &mut dyn Rng);
+
+    /// Returns the strategy's internal state to store in checkpoints.
+    ///
+    /// Stateless strategies can keep the default, which stores nothing.
+    fn checkpoint_state(&self) -> Option<serde_json::Value> {
+        None
+    }
+
+    /// Restores internal state saved by [`PriceUpdateStrategy::checkpoint_state`].
+    ///
+    /// Called when the strategy is registered on a market restored from a checkpoint
+    /// that was saved with a strategy of the same name.
+    fn restore_state(&mut self, _state: &serde_json::Value) {}
+}
+
+/// Helper trait that makes boxed [`PriceUpdateStrategy`] objects clonable.
+///
+/// Implemented automatically for every strategy that implements `Clone`.
+pub trait PriceUpdateStrategyClone {
+    /// Clones the strategy into a new box.
+    fn clone_box(&self) -> Box<dyn PriceUpdateStrategy>;
+}
+
+impl<T: PriceUpdateStrategy + Clone + 'static> PriceUpdateStrategyClone for T {
+    fn clone_box(&self) -> Box<dyn PriceUpdateStrategy> {
+        Box::new(self.clone())
+    }
+}
+
+impl Clone for Box<dyn PriceUpdateStrategy> {
+    fn clone(&self) -> Self {
+        self.clone_box()
+    }
+}
+
+/// Checkpoint record of a custom [`PriceUpdateStrategy`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PriceStrategyCheckpoint {
+    /// Name of the strategy that was registered when the checkpoint was saved
+    pub name: String,
+    /// State returned by [`PriceUpdateStrategy::checkpoint_state`], if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<serde_json::Value>,
+}
+
 /// Enum representing different price update strategies.
 ///
 /// Each variant wraps a specific updater implementation that defines how
@@ -1109,7 +1223,11 @@ impl OriginalPriceUpdater {
     /// * `market` - The market containing skills to update
     /// * `rng` - Random number generator for volatility
     pub fn update_prices<R: Rng + ?Sized>(&self, market: &mut Market, rng: &mut R) {
-        for (skill_id, skill) in market.skills.iter_mut() {
+        // Visit skills in a stable order so the per-skill RNG draws are reproducible
+        // for a given seed (HashMap iteration order differs between market instances)
+        let mut skills: Vec<_> = market.skills.iter_mut().collect();
+        skills.sort_unstable_by(|a, b| a.0.cmp(b.0));
+        for (skill_id, skill) in skills {
             // Cache price limits to avoid duplicate HashMap lookups for better performance
             let (min_opt, max_opt) = market
                 .per_skill_price_limits
@@ -1360,7 +1478,11 @@ impl AuctionPricingUpdater {
     /// * `market` - The market containing skills to update
     /// * `rng` - Random number generator for volatility
     pub fn update_prices<R: Rng + ?Sized>(&self, market: &mut Market, rng: &mut R) {
-        for (skill_id, skill) in market.skills.iter_mut() {
+        // Visit skills in a stable order so the per-skill RNG draws are reproducible
+        // for a given seed (HashMap iteration order differs between market instances)
+        let mut skills: Vec<_> = market.skills.iter_mut().collect();
+        skills.sort_unstable_by(|a, b| a.0.cmp(b.0));
+        for (skill_id, skill) in skills {
             // Cache price limits to avoid duplicate HashMap lookups for better performance
             let (min_opt, max_opt) = market
                 .per_skill_price_limits
@@ -3,9 +3,14 @@
 /// This module provides automated comparison of different simulation scenarios to understand
 /// how different pricing mechanisms and market behaviors affect outcomes. It enables researchers
 /// to perform rigorous A/B testing and identify the most effective policies.
+///
+/// Transfer schemes can be compared the same way with [`TransferSchemeComparisonResult`],
+/// which reports the poverty rate, the Gini coefficient before and after transfers and
+/// the fiscal cost of each scheme.
 use crate::error::{Result, SimulationError};
 use crate::result::{calculate_statistics, MonteCarloStats, SimulationResult};
 use crate::scenario::Scenario;
+use crate::transfers::TransferScheme;
 use crate::{SimulationConfig, SimulationEngine};
 use colored::Colorize;
 use log::info;
@@ -283,6 +288,207 @@ impl ScenarioComparisonResult {
     }
 }
 
+/// Result from a single transfer scheme in a comparison
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TransferSchemeComparisonPoint {
+    /// The transfer scheme being tested
+    pub scheme: TransferScheme,
+    /// Results from all runs with this scheme
+    pub results: Vec<SimulationResult>,
+    /// Aggregated statistics across runs
+    pub poverty_rate_stats: MonteCarloStats,
+    pub gini_before_stats: MonteCarloStats,
+    pub gini_after_stats: MonteCarloStats,
+    pub fiscal_cost_stats: MonteCarloStats,
+}
+
+/// Complete results from a transfer scheme comparison
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TransferSchemeComparisonResult {
+    /// Transfer schemes being compared
+    pub schemes: Vec<TransferScheme>,
+    /// Number of runs per scheme
+    pub runs_per_scheme: usize,
+    /// Base seed used (each run uses seed + offset)
+    pub base_seed: u64,
+    /// Results for each scheme tested
+    pub comparison_points: Vec<TransferSchemeComparisonPoint>,
+    /// Total number of simulations run
+    pub total_simulations: usize,
+    /// Winner based on different criteria
+    pub winners: TransferSchemeWinners,
+}
+
+/// Winning transfer schemes across different comparison criteria
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TransferSchemeWinners {
+    /// Scheme with the lowest poverty rate after transfers
+    pub lowest_poverty: TransferScheme,
+    /// Scheme with the lowest Gini coefficient after transfers
+    pub lowest_inequality: TransferScheme,
+    /// Scheme with the lowest fiscal cost
+    pub lowest_fiscal_cost: TransferScheme,
+}
+
+impl TransferSchemeComparisonResult {
+    /// Runs simulations for each transfer scheme, with tax redistribution enabled
+    pub fn run(
+        base_config: SimulationConfig,
+        schemes: Vec<TransferScheme>,
+        runs_per_scheme: usize,
+    ) -> Result<Self> {
+        if schemes.len() < 2 {
+            return Err(SimulationError::ValidationError(
+                "At least two different transfer schemes must be provided for comparison"
+                    .to_string(),
+            ));
+        }
+
+        if runs_per_scheme < 1 {
+            return Err(SimulationError::ValidationError(
+                "Runs per scheme must be at least 1".to_string(),
+            ));
+        }
+
+        // Every scheme must be valid with the base configuration
+        for &scheme in &schemes {
+            let mut config = base_config.clone();
+            config.enable_tax_redistribution = true;
+            config.transfer_scheme = scheme;
+            config.validate()?;
+        }
+
+        info!(
+            "{}",
+            format!(
+                "Starting transfer scheme comparison: {} schemes × {} runs = {} total simulations",
+                schemes.len(),
+                runs_per_scheme,
+                schemes.len() * runs_per_scheme
+            )
+            .bright_cyan()
+        );
+
+        let base_seed = base_config.seed;
+        let total_simulations = schemes.len() * runs_per_scheme;
+
+        let comparison_points: Vec<_> = schemes
+            .par_iter()
+            .map(|&scheme| {
+                let results: Vec<_> = (0..runs_per_scheme)
+                    .into_par_iter()
+                    .map(|run_idx| {
+                        let mut config = base_config.clone();
+                        config.enable_tax_redistribution = true;
+                        config.transfer_scheme = scheme;
+                        config.seed = base_seed + run_idx as u64;
+
+                        let mut engine = SimulationEngine::new(config);
+                        engine.run_with_progress(false)
+                    })
+                    .collect();
+
+                // Every run has transfer statistics since redistribution is enabled
+                let stat = |f: fn(&crate::result::TransferStats) -> f64| {
+                    let values: Vec<f64> = results
+                        .iter()
+                        .filter_map(|r| r.transfer_statistics.as_ref())
+                        .map(f)
+                        .collect();
+                    calculate_statistics(&values)
+                };
+
+                TransferSchemeComparisonPoint {
+                    scheme,
+                    poverty_rate_stats: stat(|t| t.poverty_rate_after),
+                    gini_before_stats: stat(|t| t.gini_before),
+                    gini_after_stats: stat(|t| t.gini_after),
+                    fiscal_cost_stats: stat(|t| t.total_paid),
+                    results,
+                }
+            })
+            .collect();
+
+        let lowest = |f: fn(&TransferSchemeComparisonPoint) -> f64| {
+            comparison_points
+                .iter()
+                .min_by(|a, b| f(a).partial_cmp(&f(b)).unwrap_or(std::cmp::Ordering::Equal))
+                .map(|p| p.scheme)
+                .unwrap_or_default()
+        };
+        let winners = TransferSchemeWinners {
+            lowest_poverty: lowest(|p| p.poverty_rate_stats.mean),
+            lowest_inequality: lowest(|p| p.gini_after_stats.mean),
+            lowest_fiscal_cost: lowest(|p| p.fiscal_cost_stats.mean),
+        };
+
+        info!("{}", "Transfer scheme comparison completed successfully!".bright_green());
+
+        Ok(TransferSchemeComparisonResult {
+            schemes,
+            runs_per_scheme,
+            base_seed,
+            comparison_points,
+            total_simulations,
+            winners,
+        })
+    }
+
+    /// Save the comparison results to a JSON file
+    pub fn save_to_file(&self, path: &str) -> Result<()> {
+        let file = File::create(path).map_err(SimulationError::from)?;
+
+        serde_json::to_writer_pretty(file, self).map_err(|e| {
+            SimulationError::JsonSerialize(format!(
+                "Failed to write comparison results to {}: {}",
+                path, e
+            ))
+        })?;
+
+        Ok(())
+    }
+
+    /// Print a summary of the comparison to the console
+    pub fn print_summary(&self) {
+        println!("\n{}", "=== Transfer Scheme Comparison Summary ===".bright_cyan().bold());
+        println!(
+            "Total simulations: {} ({} schemes × {} runs)",
+            self.total_simulations,
+            self.schemes.len(),
+            self.runs_per_scheme
+        );
+
+        println!("\n{}", "Results by Scheme:".bright_yellow());
+        for point in &self.comparison_points {
+            println!("\n  {} {:?}", "Scheme:".bright_green(), point.scheme);
+            println!(
+                "    Poverty Rate:    {:.2}% ± {:.2}%",
+                point.poverty_rate_stats.mean * 100.0,
+                point.poverty_rate_stats.std_dev * 100.0
+            );
+            println!(
+                "    Gini Coeff:      {:.4} before, {:.4} ± {:.4} after transfers",
+                point.gini_before_stats.mean,
+                point.gini_after_stats.mean,
+                point.gini_after_stats.std_dev
+            );
+            println!(
+                "    Fiscal Cost:     {:.2} ± {:.2} (min: {:.2}, max: {:.2})",
+                point.fiscal_cost_stats.mean,
+                point.fiscal_cost_stats.std_dev,
+                point.fiscal_cost_stats.min,
+                point.fiscal_cost_stats.max
+            );
+        }
+
+        println!("\n{}", "Winners by Criterion:".bright_magenta().bold());
+        println!("  Lowest Poverty:       {:?}", self.winners.lowest_poverty);
+        println!("  Lowest Inequality:    {:?}", self.winners.lowest_inequality);
+        println!("  Lowest Fiscal Cost:   {:?}", self.winners.lowest_fiscal_cost);
+        println!();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -573,4 +779,45 @@ mod tests {
             assert!(msg.contains("Runs per scenario must be at least 1"));
         }
     }
+
+    #[test]
+    fn test_transfer_scheme_comparison() {
+        use crate::transfers::TransferFunding;
+
+        let config = SimulationConfig {
+            max_steps: 20,
+            entity_count: 10,
+            seed: 42,
+            base_skill_price: 10.0,
+            min_skill_price: 1.0,
+            tax_rate: 0.1,
+            transfer_funding: TransferFunding::MoneyCreation,
+            ..Default::default()
+        };
+
+        // EqualShare cannot be funded by money creation
+        let schemes = vec![TransferScheme::EqualShare, TransferScheme::UniversalBasicIncome];
+        assert!(TransferSchemeComparisonResult::run(config.clone(), schemes, 1).is_err());
+        let schemes = vec![TransferScheme::MeansTested];
+        assert!(TransferSchemeComparisonResult::run(config.clone(), schemes, 1).is_err());
+
+        let schemes = vec![TransferScheme::UniversalBasicIncome, TransferScheme::MeansTested];
+        let comparison = TransferSchemeComparisonResult::run(config, schemes, 2).unwrap();
+        assert_eq!(comparison.total_simulations, 4);
+        assert_eq!(comparison.comparison_points.len(), 2);
+
+        // UBI pays every person, so it costs more than paying the poorest quarter
+        let cost = |i: usize| comparison.comparison_points[i].fiscal_cost_stats.mean;
+        assert!((cost(0) - 5.0 * 10.0 * 20.0).abs() < 1e-6);
+        assert!(cost(1) < cost(0));
+        assert_eq!(comparison.winners.lowest_fiscal_cost, TransferScheme::MeansTested);
+        comparison.print_summary();
+
+        let temp_file = tempfile::NamedTempFile::new().unwrap();
+        let path = temp_file.path().to_str().unwrap();
+        comparison.save_to_file(path).unwrap();
+        let content = std::fs::read_to_string(path).unwrap();
+        assert!(content.contains("runs_per_scheme"));
+        assert!(content.contains("lowest_poverty"));
+    }
 }
@@ -0,0 +1,107 @@
+use crate::auction::AuctionType;
+use crate::tests::test_helpers::test_config;
+use crate::SimulationEngine;
+use tempfile::NamedTempFile;
+
+fn auction_config(auction_types: Vec<AuctionType>) -> crate::SimulationConfig {
+    test_config().entity_count(20).max_steps(50).build_with(|cfg| {
+        cfg.enable_auctions = true;
+        cfg.auction_participation_rate = 1.0;
+        cfg.auction_types = auction_types;
+    })
+}
+
+#[test]
+fn test_auctions_disabled_has_no_statistics() {
+    let mut engine = SimulationEngine::new(test_config().max_steps(10).build());
+    let result = engine.run();
+    assert!(result.auction_statistics.is_none());
+}
+
+#[test]
+fn test_contested_skills_are_auctioned() {
+    let mut engine = SimulationEngine::new(auction_config(vec![AuctionType::English]));
+    let result = engine.run();
+
+    let stats = result.auction_statistics.expect("auction statistics should be present");
+    assert!(stats.total_auctions > 0);
+    assert!(stats.total_sold > 0);
+    assert_eq!(stats.by_type.len(), 1);
+    assert_eq!(stats.by_type[0].auction_type, AuctionType::English);
+    assert!(result.trade_volume_statistics.total_trades >= stats.total_sold);
+}
+
+#[test]
+fn test_every_auction_type_clears_at_or_above_reserve() {
+    let mut engine = SimulationEngine::new(auction_config(AuctionType::ALL.to_vec()));
+    let result = engine.run();
+
+    let stats = result.auction_statistics.unwrap();
+    let types: Vec<AuctionType> = stats.by_type.iter().map(|t| t.auction_type).collect();
+    assert_eq!(types, AuctionType::ALL.to_vec());
+    for type_stats in &stats.by_type {
+        assert!(type_stats.sold > 0, "{:?} never sold", type_stats.auction_type);
+        assert!(type_stats.sold <= type_stats.auctions);
+        assert!(type_stats.total_bids >= type_stats.sold);
+        assert!(type_stats.min_clearing_price <= type_stats.avg_clearing_price);
+        assert!(type_stats.avg_clearing_price <= type_stats.max_clearing_price);
+        // Clearing prices never fall below the seller's reserve price
+        assert!(type_stats.total_seller_surplus >= -1e-9);
+    }
+    let sold: usize = stats.by_type.iter().map(|t| t.sold).sum();
+    assert_eq!(sold, stats.total_sold);
+}
+
+#[test]
+fn test_no_participation_holds_no_auctions() {
+    let config = test_config().max_steps(20).build_with(|cfg| {
+        cfg.enable_auctions = true;
+        cfg.auction_participation_rate = 0.0;
+    });
+    let mut engine = SimulationEngine::new(config);
+    let stats = engine.run().auction_statistics.unwrap();
+    assert_eq!(stats.total_auctions, 0);
+    assert!(stats.by_type.is_empty());
+}
+
+#[test]
+fn test_auctions_conserve_money() {
+    let config = auction_config(AuctionType::ALL.to_vec());
+    let initial_total = config.initial_money_per_person * config.entity_count as f64;
+    let mut engine = SimulationEngine::new(config);
+    let result = engine.run();
+
+    let final_total: f64 = result.final_money_distribution.iter().sum();
+    assert!((final_total - initial_total).abs() < 1e-6);
+}
+
+#[test]
+fn test_auctions_are_deterministic() {
+    let run = || {
+        let mut engine = SimulationEngine::new(auction_config(AuctionType::ALL.to_vec()));
+        engine.run()
+    };
+    let (first, second) = (run(), run());
+    assert_eq!(first.final_money_distribution, second.final_money_distribution);
+    let (a, b) = (first.auction_statistics.unwrap(), second.auction_statistics.unwrap());
+    assert_eq!(a.total_sold, b.total_sold);
+    assert_eq!(a.total_volume, b.total_volume);
+}
+
+#[test]
+fn test_auction_statistics_survive_checkpoint() {
+    let mut engine = SimulationEngine::new(auction_config(vec![AuctionType::Vickrey]));
+    for _ in 0..20 {
+        engine.step();
+    }
+    let before = engine.get_current_result().auction_statistics.unwrap();
+
+    let checkpoint = NamedTempFile::new().unwrap();
+    engine.save_checkpoint(checkpoint.path()).unwrap();
+    let restored = SimulationEngine::load_checkpoint(checkpoint.path()).unwrap();
+    let after = restored.get_current_result().auction_statistics.unwrap();
+
+    assert_eq!(before.total_auctions, after.total_auctions);
+    assert_eq!(before.total_sold, after.total_sold);
+    assert!((before.total_seller_surplus - after.total_seller_surplus).abs() < 1e-6);
+}
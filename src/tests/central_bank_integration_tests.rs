@@ -0,0 +1,158 @@
+use crate::central_bank::{MonetaryPolicyRule, MoneyOperation, PolicyPathEntry};
+use crate::tests::test_helpers::{test_config, TestConfigBuilder};
+use crate::SimulationEngine;
+use tempfile::NamedTempFile;
+
+fn central_bank_config() -> crate::SimulationConfig {
+    test_config().entity_count(20).max_steps(40).build_with(|cfg| {
+        cfg.enable_central_bank = true;
+    })
+}
+
+fn path_entry(step: usize, policy_rate: f64, money_injection: f64) -> PolicyPathEntry {
+    PolicyPathEntry { step, policy_rate, money_injection }
+}
+
+#[test]
+fn test_taylor_rule_sets_loan_interest_rate() {
+    let mut engine = SimulationEngine::new(central_bank_config());
+    let result = engine.run();
+
+    let stats = result.monetary_policy_statistics.expect("monetary policy statistics expected");
+    assert_eq!(stats.rule, MonetaryPolicyRule::TaylorRule);
+    assert_eq!(stats.neutral_rate, 0.01);
+    assert_eq!(stats.history.len(), 40);
+    assert!(stats.history.iter().all(|snapshot| (0.0..=1.0).contains(&snapshot.policy_rate)));
+    assert!(stats.max_policy_rate > stats.min_policy_rate);
+    assert_eq!(stats.policy_rate, stats.history.last().unwrap().policy_rate);
+    assert_eq!(engine.get_policy_rate(), stats.policy_rate);
+    assert_eq!(engine.get_config().loan_interest_rate, stats.policy_rate);
+
+    // Without a money operation the money supply is untouched
+    assert_eq!(stats.money_operation, MoneyOperation::None);
+    assert_eq!(stats.total_money_created, 0.0);
+    assert_eq!(stats.total_money_withdrawn, 0.0);
+}
+
+#[test]
+fn test_policy_rate_prices_p2p_loans() {
+    let lending = |cfg: &mut crate::SimulationConfig| {
+        cfg.entity_count = 30;
+        cfg.max_steps = 60;
+        cfg.enable_loans = true;
+        cfg.enable_credit_rating = true;
+        cfg.enable_p2p_lending = true;
+    };
+    let mut baseline = central_bank_config();
+    baseline.enable_central_bank = false;
+    lending(&mut baseline);
+    let baseline_rate =
+        SimulationEngine::new(baseline).run().lending_marketplace_statistics.unwrap();
+
+    let mut config = central_bank_config();
+    lending(&mut config);
+    config.monetary_policy_rule = MonetaryPolicyRule::Scripted;
+    config.monetary_policy_path = vec![path_entry(0, 0.2, 0.0)];
+    let stats = SimulationEngine::new(config).run().lending_marketplace_statistics.unwrap();
+
+    // Credit scores scale the policy rate between 0.5x and 2.5x
+    assert!(baseline_rate.total_matched_loans > 0);
+    assert!(stats.total_matched_loans > 0);
+    assert!(baseline_rate.avg_interest_rate < 0.05);
+    assert!(stats.avg_interest_rate >= 0.1 - 1e-9);
+    assert!(stats.avg_interest_rate <= 0.5 + 1e-9);
+}
+
+#[test]
+fn test_scripted_helicopter_money_is_authorized() {
+    let mut config = TestConfigBuilder::from(central_bank_config()).strict_invariants().build();
+    config.monetary_policy_rule = MonetaryPolicyRule::Scripted;
+    config.money_operation = MoneyOperation::Helicopter;
+    config.monetary_policy_path = vec![path_entry(5, 0.0, 20.0), path_entry(25, 0.02, 0.0)];
+    let mut engine = SimulationEngine::new(config);
+    let result = engine.run();
+
+    // Money created from step 5 to 24 does not break money conservation
+    assert_eq!(result.failed_steps, 0);
+    let stats = result.monetary_policy_statistics.unwrap();
+    assert!((stats.total_money_created - 20.0 * 20.0).abs() < 1e-9);
+    assert_eq!(stats.total_money_withdrawn, 0.0);
+    assert_eq!(stats.bond_holdings, 0.0);
+    assert_eq!(stats.history[4].policy_rate, 0.01);
+    assert_eq!(stats.history[4].money_created, 0.0);
+    assert_eq!(stats.history[5].policy_rate, 0.0);
+    assert_eq!(stats.history[5].money_created, 20.0);
+    assert_eq!(stats.policy_rate, 0.02);
+    assert_eq!(engine.get_total_central_bank_money_issued(), stats.net_money_issued);
+}
+
+#[test]
+fn test_helicopter_levy_withdraws_money() {
+    let mut config = TestConfigBuilder::from(central_bank_config()).strict_invariants().build();
+    config.monetary_policy_rule = MonetaryPolicyRule::Scripted;
+    config.money_operation = MoneyOperation::Helicopter;
+    config.monetary_policy_path = vec![path_entry(0, 0.01, -10.0)];
+    let mut engine = SimulationEngine::new(config);
+    let result = engine.run();
+
+    assert_eq!(result.failed_steps, 0);
+    let stats = result.monetary_policy_statistics.unwrap();
+    assert_eq!(stats.total_money_created, 0.0);
+    assert!(stats.total_money_withdrawn > 0.0);
+    assert!(stats.total_money_withdrawn <= 10.0 * 40.0 + 1e-9);
+    assert!(stats.money_supply < 20.0 * 100.0);
+}
+
+#[test]
+fn test_open_market_operations_follow_inflation_gap() {
+    let mut config = TestConfigBuilder::from(central_bank_config()).strict_invariants().build();
+    config.money_operation = MoneyOperation::OpenMarket;
+    let mut engine = SimulationEngine::new(config);
+    let result = engine.run();
+
+    assert_eq!(result.failed_steps, 0);
+    let stats = result.monetary_policy_statistics.unwrap();
+    assert!(stats.total_money_created + stats.total_money_withdrawn > 0.0);
+    assert!((stats.bond_holdings - stats.net_money_issued).abs() < 1e-9);
+    for snapshot in &stats.history {
+        // Money is created only below the inflation target and withdrawn only above it
+        if snapshot.money_created > 0.0 {
+            assert!(snapshot.inflation < 0.0);
+        } else if snapshot.money_created < 0.0 {
+            assert!(snapshot.inflation > 0.0);
+        }
+        // Each operation moves at most 5% of the money supply before it
+        assert!(snapshot.money_created.abs() <= 0.06 * snapshot.money_supply);
+    }
+}
+
+#[test]
+fn test_monetary_policy_survives_checkpoint() {
+    let mut config = central_bank_config();
+    config.money_operation = MoneyOperation::Helicopter;
+    let mut engine = SimulationEngine::new(config);
+    for _ in 0..20 {
+        engine.step();
+    }
+    let file = NamedTempFile::new().unwrap();
+    engine.save_checkpoint(file.path()).unwrap();
+    let before = engine.get_current_result().monetary_policy_statistics.unwrap();
+
+    let restored = SimulationEngine::load_checkpoint(file.path()).unwrap();
+    let after = restored.get_current_result().monetary_policy_statistics.unwrap();
+    assert_eq!(after.history.len(), before.history.len());
+    assert!((after.policy_rate - before.policy_rate).abs() < 1e-12);
+    assert!((after.net_money_issued - before.net_money_issued).abs() < 1e-9);
+    assert!((after.bond_holdings - before.bond_holdings).abs() < 1e-9);
+    assert_eq!(restored.get_config().loan_interest_rate, after.policy_rate);
+}
+
+#[test]
+fn test_no_monetary_policy_without_central_bank() {
+    let config = test_config().entity_count(10).max_steps(10).build();
+    let mut engine = SimulationEngine::new(config);
+    let result = engine.run();
+    assert!(result.monetary_policy_statistics.is_none());
+    assert_eq!(engine.get_config().loan_interest_rate, 0.01);
+    assert_eq!(engine.get_total_central_bank_money_issued(), 0.0);
+}
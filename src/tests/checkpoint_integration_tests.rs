@@ -0,0 +1,206 @@
+use crate::checkpoint::{
+    checkpoint_steps, delta_log_path, rebuild_checkpoint, CHECKPOINT_SCHEMA_VERSION,
+};
+use crate::tests::test_helpers::{test_config, TestConfigBuilder};
+use crate::{SimulationEngine, SimulationError};
+use serde_json::Value;
+use std::path::Path;
+use tempfile::tempdir;
+
+fn incremental_config() -> TestConfigBuilder {
+    test_config().entity_count(20).max_steps(60)
+}
+
+#[test]
+fn test_every_checkpointed_step_can_be_restored() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("checkpoint.json");
+    let mut engine = SimulationEngine::new(incremental_config().build());
+
+    let mut snapshots = Vec::new();
+    for _ in 0..5 {
+        for _ in 0..10 {
+            engine.step();
+        }
+        engine.save_checkpoint_incremental(&path).unwrap();
+        snapshots.push((engine.current_step, engine.get_current_result().final_money_distribution));
+    }
+
+    assert_eq!(checkpoint_steps(&path).unwrap(), vec![10, 20, 30, 40, 50]);
+    for (step, money) in snapshots {
+        let restored = SimulationEngine::load_checkpoint_at_step(&path, step).unwrap();
+        assert_eq!(restored.current_step, step);
+        let restored_money = restored.get_current_result().final_money_distribution;
+        assert_eq!(restored_money.len(), money.len());
+        for (a, b) in restored_money.iter().zip(&money) {
+            assert!((a - b).abs() < 1e-6);
+        }
+    }
+    assert!(SimulationEngine::load_checkpoint_at_step(&path, 15).is_err());
+}
+
+#[test]
+fn test_deltas_are_smaller_than_full_snapshots() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("checkpoint.json");
+    let mut engine = SimulationEngine::new(incremental_config().build());
+
+    for _ in 0..5 {
+        for _ in 0..10 {
+            engine.step();
+        }
+        engine.save_checkpoint_incremental(&path).unwrap();
+    }
+
+    let base_size = std::fs::metadata(&path).unwrap().len();
+    let log_size = std::fs::metadata(delta_log_path(&path)).unwrap().len();
+    assert!(log_size < base_size, "4 deltas ({log_size} B) vs base ({base_size} B)");
+}
+
+#[test]
+fn test_auto_checkpoint_incremental_and_resume() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("checkpoint.json");
+    let config = incremental_config().build_with(|cfg| {
+        cfg.checkpoint_interval = 10;
+        cfg.checkpoint_file = Some(path.to_string_lossy().into_owned());
+        cfg.incremental_checkpoints = true;
+    });
+    let mut engine = SimulationEngine::new(config);
+    engine.run();
+
+    let steps = checkpoint_steps(&path).unwrap();
+    assert_eq!(steps, vec![10, 20, 30, 40, 50, 60]);
+    let latest = 60;
+
+    let mut resumed = SimulationEngine::load_checkpoint(&path).unwrap();
+    assert_eq!(resumed.current_step, latest);
+
+    // The resumed run keeps appending to the same delta log
+    for _ in 0..10 {
+        resumed.step();
+    }
+    resumed.save_checkpoint_incremental(&path).unwrap();
+    let mut expected = steps.clone();
+    expected.push(latest + 10);
+    assert_eq!(checkpoint_steps(&path).unwrap(), expected);
+}
+
+#[test]
+fn test_rebase_interval_starts_new_base() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("checkpoint.json");
+    let config = incremental_config().build_with(|cfg| cfg.checkpoint_rebase_interval = 2);
+    let mut engine = SimulationEngine::new(config);
+
+    for _ in 0..5 {
+        for _ in 0..10 {
+            engine.step();
+        }
+        engine.save_checkpoint_incremental(&path).unwrap();
+    }
+
+    // Base at 10 with deltas 20 and 30, then a new base at 40 with delta 50
+    assert_eq!(checkpoint_steps(&path).unwrap(), vec![40, 50]);
+}
+
+#[test]
+fn test_full_checkpoint_discards_delta_log() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("checkpoint.json");
+    let mut engine = SimulationEngine::new(incremental_config().build());
+
+    for _ in 0..3 {
+        engine.step();
+        engine.save_checkpoint_incremental(&path).unwrap();
+    }
+    engine.step();
+    engine.save_checkpoint(&path).unwrap();
+
+    assert!(!delta_log_path(&path).exists());
+    assert_eq!(checkpoint_steps(&path).unwrap(), vec![4]);
+    let rebuilt = rebuild_checkpoint(&path, None).unwrap();
+    assert_eq!(rebuilt["current_step"], 4);
+}
+
+/// Saves a checkpoint after a few steps and returns its JSON.
+fn saved_checkpoint_json(path: &Path) -> Value {
+    let mut engine = SimulationEngine::new(incremental_config().build());
+    for _ in 0..5 {
+        engine.step();
+    }
+    engine.save_checkpoint(path).unwrap();
+    serde_json::from_slice(&std::fs::read(path).unwrap()).unwrap()
+}
+
+#[test]
+fn test_checkpoint_records_schema_version() {
+    let dir = tempdir().unwrap();
+    let state = saved_checkpoint_json(&dir.path().join("checkpoint.json"));
+    assert_eq!(state["schema_version"], CHECKPOINT_SCHEMA_VERSION);
+}
+
+#[test]
+fn test_legacy_checkpoint_loads() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("checkpoint.json");
+    let mut state = saved_checkpoint_json(&path);
+
+    // Checkpoints from before versioning lack the version and later-added state
+    let map = state.as_object_mut().unwrap();
+    for field in [
+        "schema_version",
+        "total_plugin_money_injected",
+        "investments",
+        "foreign_exchange",
+    ] {
+        map.remove(field);
+    }
+    std::fs::write(&path, serde_json::to_vec(&state).unwrap()).unwrap();
+
+    let engine = SimulationEngine::load_checkpoint(&path).unwrap();
+    assert_eq!(engine.current_step, 5);
+    assert_eq!(engine.get_total_plugin_money_injected(), 0.0);
+}
+
+#[test]
+fn test_checkpoint_written_before_versioning_resumes() {
+    // Saved by the simulation before checkpoints were versioned
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/src/tests/fixtures/legacy_checkpoint.json");
+    let mut engine = SimulationEngine::load_checkpoint(path).unwrap();
+    assert_eq!(engine.current_step, 4);
+    assert_eq!(engine.get_entities().len(), 3);
+
+    engine.step();
+    assert_eq!(engine.current_step, 5);
+}
+
+#[test]
+fn test_newer_checkpoint_is_incompatible() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("checkpoint.json");
+    let mut state = saved_checkpoint_json(&path);
+    state["schema_version"] = Value::from(CHECKPOINT_SCHEMA_VERSION + 1);
+    std::fs::write(&path, serde_json::to_vec(&state).unwrap()).unwrap();
+
+    match SimulationEngine::load_checkpoint(&path) {
+        Err(SimulationError::CheckpointIncompatible { version, .. }) => {
+            assert_eq!(version, CHECKPOINT_SCHEMA_VERSION + 1);
+        },
+        Err(e) => panic!("expected CheckpointIncompatible, got {}", e),
+        Ok(_) => panic!("expected CheckpointIncompatible"),
+    }
+}
+
+#[test]
+fn test_mismatched_checkpoint_state_is_incompatible() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("checkpoint.json");
+    let mut state = saved_checkpoint_json(&path);
+    state.as_object_mut().unwrap().remove("entities");
+    std::fs::write(&path, serde_json::to_vec(&state).unwrap()).unwrap();
+
+    let err = SimulationEngine::load_checkpoint(&path).err().unwrap();
+    assert!(matches!(err, SimulationError::CheckpointIncompatible { .. }));
+    assert!(err.to_string().contains("entities"));
+}
@@ -0,0 +1,221 @@
+use crate::crisis::{CrisisDecay, CrisisDefinition, CrisisEffects, CrisisTargets};
+use crate::currency::{Currency, CurrencySystem};
+use crate::replay::replay_action_log;
+use crate::tests::test_helpers::test_config;
+use crate::SimulationEngine;
+use tempfile::NamedTempFile;
+
+fn crisis(
+    name: &str,
+    start_step: usize,
+    duration: usize,
+    effects: CrisisEffects,
+) -> CrisisDefinition {
+    CrisisDefinition {
+        name: name.to_string(),
+        start_step: Some(start_step),
+        probability: 0.0,
+        duration,
+        decay: CrisisDecay::Constant,
+        half_life: 5.0,
+        targets: CrisisTargets::default(),
+        effects,
+        contagion: 0.0,
+        contagion_intensity: 0.5,
+    }
+}
+
+fn price(engine: &SimulationEngine, skill: &str) -> f64 {
+    engine.get_market().skills[skill].current_price
+}
+
+#[test]
+fn test_price_effect_hits_targeted_skills_and_reverses() {
+    let mut definition =
+        crisis("Shortage", 5, 4, CrisisEffects { prices: 1.0, ..Default::default() });
+    definition.targets.skills = vec!["Skill0".to_string()];
+    let config = test_config().entity_count(10).max_steps(20).build();
+    let crisis_config =
+        crate::SimulationConfig { custom_crises: vec![definition], ..config.clone() };
+    let mut control = SimulationEngine::new(config);
+    let mut engine = SimulationEngine::new(crisis_config);
+    for _ in 0..6 {
+        control.step();
+        engine.step();
+    }
+
+    // The crisis doubled the price of Skill0 in the step it started, and no other
+    assert!((price(&engine, "Skill0") - 2.0 * price(&control, "Skill0")).abs() < 1e-9);
+    assert_eq!(price(&engine, "Skill1"), price(&control, "Skill1"));
+
+    for _ in 6..20 {
+        engine.step();
+    }
+    let stats = engine.get_current_result().custom_crisis_statistics.unwrap();
+    assert_eq!(stats.crises.len(), 1);
+    assert_eq!(stats.crises[0].name, "Shortage");
+    assert_eq!(stats.crises[0].end_step, 9);
+    assert_eq!(stats.contagions, 0);
+}
+
+#[test]
+fn test_demand_collapse_and_recovery() {
+    let definition = crisis("Lockdown", 10, 5, CrisisEffects { demand: 1.0, ..Default::default() });
+    let config =
+        test_config()
+            .entity_count(20)
+            .max_steps(40)
+            .initial_money(1000.0)
+            .build_with(|cfg| {
+                cfg.custom_crises = vec![definition];
+            });
+    let mut engine = SimulationEngine::new(config);
+    let result = engine.run();
+
+    // Every need is dropped while the crisis lasts, so nobody trades
+    assert!(result.trades_per_step[10..15].iter().all(|&trades| trades == 0));
+    assert!(result.trades_per_step[15..].iter().any(|&trades| trades > 0));
+    let stats = result.custom_crisis_statistics.expect("custom crisis statistics expected");
+    let recovery = &stats.crises[0];
+    assert!(recovery.pre_crisis_gdp > 0.0);
+    assert_eq!(recovery.trough_gdp, 0.0);
+    assert!(recovery.output_loss >= 5.0 * recovery.pre_crisis_gdp - 1e-9);
+    if let Some(steps) = recovery.recovery_steps {
+        assert!(steps >= 5);
+    }
+}
+
+#[test]
+fn test_money_effect_on_targeted_currency_is_accounted() {
+    let mut definition =
+        crisis("Bank Run", 3, 6, CrisisEffects { money: -0.1, ..Default::default() });
+    definition.decay = CrisisDecay::Exponential;
+    definition.half_life = 2.0;
+    definition.targets.currencies = vec!["BASE".to_string()];
+    let config =
+        test_config()
+            .entity_count(20)
+            .max_steps(20)
+            .strict_invariants()
+            .build_with(|cfg| {
+                cfg.custom_crises = vec![definition];
+            });
+    let mut engine = SimulationEngine::new(config);
+    let result = engine.run();
+
+    // Everyone holds the base currency and loses a tenth of their money at the onset,
+    // then less as the crisis decays; the invariant accounts for the money destroyed
+    assert_eq!(result.failed_steps, 0);
+    let stats = result.custom_crisis_statistics.unwrap();
+    assert!(stats.money_changed < -0.1 * 20.0 * 100.0);
+    assert_eq!(engine.get_total_crisis_money_change(), stats.money_changed);
+}
+
+#[test]
+fn test_money_effect_on_foreign_currency_is_booked_in_base_units() {
+    let mut definition =
+        crisis("Yen Run", 3, 4, CrisisEffects { money: -0.1, ..Default::default() });
+    definition.targets.currencies = vec!["JPY".to_string()];
+    let mut currency_system = CurrencySystem::default();
+    currency_system.add_currency(Currency::new("JPY".to_string(), 150.0));
+    let config =
+        test_config()
+            .entity_count(20)
+            .max_steps(20)
+            .strict_invariants()
+            .build_with(|cfg| {
+                cfg.custom_crises = vec![definition];
+                cfg.enable_multi_currency = true;
+                cfg.currency_system = currency_system;
+            });
+    let mut engine = SimulationEngine::new(config);
+    let result = engine.run();
+
+    // Half the persons hold yen; what they lose counts at its base value
+    assert_eq!(result.failed_steps, 0);
+    let stats = result.custom_crisis_statistics.unwrap();
+    assert!(stats.money_changed < 0.0);
+    assert!(stats.money_changed > -20.0 * 100.0);
+}
+
+#[test]
+fn test_health_effect_makes_targeted_persons_sick() {
+    let mut definition =
+        crisis("Outbreak", 3, 2, CrisisEffects { health: 1.0, ..Default::default() });
+    definition.targets.groups = vec![1];
+    let config = test_config().entity_count(20).max_steps(10).build_with(|cfg| {
+        cfg.custom_crises = vec![definition];
+        cfg.enable_health = true;
+        cfg.disease_transmission_rate = 0.0;
+        cfg.num_groups = Some(2);
+    });
+    let mut engine = SimulationEngine::new(config);
+    for _ in 0..4 {
+        engine.step();
+    }
+    for entity in engine.get_entities() {
+        let in_group = entity.person_data.group_id == Some(1);
+        assert_eq!(entity.person_data.is_susceptible(), !in_group, "person {}", entity.id);
+    }
+}
+
+#[test]
+fn test_contagion_spreads_to_neighboring_groups() {
+    let mut definition = crisis(
+        "Flood",
+        5,
+        10,
+        CrisisEffects { demand: 0.5, prices: -0.2, ..Default::default() },
+    );
+    definition.targets.groups = vec![0];
+    definition.contagion = 1.0;
+    let config = test_config().entity_count(20).max_steps(30).build_with(|cfg| {
+        cfg.num_groups = Some(5);
+        cfg.custom_crises = vec![definition];
+    });
+    let mut engine = SimulationEngine::new(config.clone());
+    engine.enable_action_recording();
+    for _ in 0..30 {
+        engine.step();
+    }
+
+    let stats = engine.get_current_result().custom_crisis_statistics.unwrap();
+    assert_eq!(stats.crises[0].groups, vec![0]);
+    // Groups 1 and 4 are next to group 0; the spread crises can reach 2 and 3 later
+    let spread: Vec<_> = stats.crises.iter().filter(|c| c.source == Some(0)).collect();
+    assert!(!spread.is_empty());
+    assert!(spread.iter().all(|c| c.groups == vec![1] || c.groups == vec![4]));
+    assert!(spread.iter().all(|c| c.start_step > 5));
+    assert_eq!(stats.contagions, stats.crises.len() - 1);
+    assert!(stats.crises[1..].iter().all(|c| c.source.is_some() && c.groups.len() == 1));
+
+    // Onsets and contagion draw from the seeded generator, so they replay exactly
+    let log = engine.get_action_log().unwrap();
+    let report = replay_action_log(log, &config).unwrap();
+    assert!(report.is_deterministic(), "unexpected divergence: {:?}", report.divergence);
+}
+
+#[test]
+fn test_custom_crises_survive_checkpoint() {
+    let mut definition =
+        crisis("Drought", 4, 10, CrisisEffects { prices: 0.5, ..Default::default() });
+    definition.decay = CrisisDecay::Linear;
+    let config = test_config().entity_count(10).max_steps(30).build_with(|cfg| {
+        cfg.custom_crises = vec![definition];
+    });
+    let mut engine = SimulationEngine::new(config);
+    for _ in 0..8 {
+        engine.step();
+    }
+    let file = NamedTempFile::new().unwrap();
+    engine.save_checkpoint(file.path()).unwrap();
+
+    // The crisis under way is neither restarted nor forgotten
+    let mut restored = SimulationEngine::load_checkpoint(file.path()).unwrap();
+    for _ in 8..30 {
+        restored.step();
+    }
+    let stats = restored.get_current_result().custom_crisis_statistics.unwrap();
+    assert_eq!(stats.crises.len(), 1);
+    assert_eq!(stats.crises[0].start_step, 4);
+}
@@ -1,5 +1,5 @@
 use crate::currency::{Currency, CurrencySystem};
-use crate::tests::test_helpers::test_config;
+use crate::tests::test_helpers::{test_config, TestConfigBuilder};
 use crate::SimulationEngine;
 
 #[test]
@@ -154,3 +154,212 @@ fn test_currency_backward_compatibility() {
         assert!(person_money.is_finite());
     }
 }
+
+fn multi_currency_config() -> crate::SimulationConfig {
+    let mut currency_system = CurrencySystem::default();
+    currency_system.add_currency(Currency::new("EUR".to_string(), 1.2));
+    currency_system.add_currency(Currency::new("JPY".to_string(), 150.0));
+
+    test_config()
+        .max_steps(100)
+        .entity_count(30)
+        .currency_system(currency_system)
+        .enable_multi_currency(true)
+        .build()
+}
+
+#[test]
+fn test_multi_currency_assigns_currencies_and_converts_initial_money() {
+    let config = multi_currency_config();
+    let initial_money = config.initial_money_per_person;
+    let engine = SimulationEngine::new(config);
+
+    for entity in engine.get_entities() {
+        let expected_currency = ["BASE", "EUR", "JPY"][entity.id % 3];
+        assert_eq!(entity.person_data.currency_id, expected_currency);
+        let rate = engine
+            .get_config()
+            .currency_system
+            .get_currency(expected_currency)
+            .unwrap()
+            .exchange_rate;
+        assert!((entity.person_data.money - initial_money * rate).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn test_cross_currency_trades_are_reported() {
+    let mut engine = SimulationEngine::new(multi_currency_config());
+    let result = engine.run();
+
+    let stats = result.currency_statistics.expect("currency statistics should be present");
+    assert_eq!(stats.base_currency_id, "BASE");
+    assert!(stats.cross_currency_trades > 0);
+    assert!(stats.cross_currency_volume > 0.0);
+
+    let ids: Vec<_> = stats.currencies.iter().map(|c| c.currency_id.as_str()).collect();
+    assert_eq!(ids, vec!["BASE", "EUR", "JPY"]);
+    assert_eq!(stats.currencies.iter().map(|c| c.persons).sum::<usize>(), 30);
+
+    // Every cross-currency export is some other zone's import
+    let exports: f64 = stats.currencies.iter().map(|c| c.total_exports).sum();
+    let imports: f64 = stats.currencies.iter().map(|c| c.total_imports).sum();
+    assert!((exports - imports).abs() < 1e-6);
+    assert!((exports - stats.cross_currency_volume).abs() < 1e-6);
+
+    for zone in &stats.currencies {
+        assert_eq!(stats.exchange_rate_history[&zone.currency_id].len(), 100);
+        assert!(
+            (zone.money_supply_in_base * zone.final_exchange_rate - zone.money_supply).abs() < 1e-6
+        );
+    }
+}
+
+#[test]
+fn test_exchange_rates_float_with_trade_balance() {
+    let mut engine = SimulationEngine::new(multi_currency_config());
+    let result = engine.run();
+    let stats = result.currency_statistics.unwrap();
+
+    let base = stats.currencies.iter().find(|c| c.currency_id == "BASE").unwrap();
+    assert_eq!(base.final_exchange_rate, 1.0);
+
+    let eur_history = &stats.exchange_rate_history["EUR"];
+    assert!(eur_history.iter().any(|&rate| (rate - 1.2).abs() > 1e-9));
+    assert!(eur_history.iter().all(|&rate| rate > 0.0));
+}
+
+#[test]
+fn test_fixed_exchange_rates_conserve_value() {
+    // With fixed rates and no fees/taxes, trades only move base value between persons
+    let mut config = multi_currency_config();
+    config.fx_adjustment_rate = 0.0;
+    let initial_base_total = config.initial_money_per_person * config.entity_count as f64;
+    let mut engine = SimulationEngine::new(config);
+    let result = engine.run();
+
+    let stats = result.currency_statistics.unwrap();
+    let final_base_total: f64 = stats.currencies.iter().map(|c| c.money_supply_in_base).sum();
+    assert!((final_base_total - initial_base_total).abs() < 1e-6);
+    assert_eq!(stats.exchange_rate_history["EUR"].last(), Some(&1.2));
+}
+
+#[test]
+fn test_devaluation_hits_single_currency() {
+    let mut config = multi_currency_config();
+    config.fx_adjustment_rate = 0.0;
+    config.enable_crisis_events = true;
+    config.crisis_probability = 1.0;
+    let initial_rates = config.currency_system.clone();
+    let mut engine = SimulationEngine::new(config);
+    let result = engine.run();
+
+    let stats = result.currency_statistics.unwrap();
+    let base = stats.currencies.iter().find(|c| c.currency_id == "BASE").unwrap();
+    assert_eq!(base.devaluations, 0);
+    assert_eq!(base.final_exchange_rate, 1.0);
+
+    // Rates are otherwise fixed, so only devalued currencies moved, and only upwards
+    assert!(stats.currencies.iter().any(|c| c.devaluations > 0));
+    for zone in &stats.currencies {
+        let initial = initial_rates.get_currency(&zone.currency_id).unwrap().exchange_rate;
+        if zone.devaluations > 0 {
+            assert!(zone.final_exchange_rate > initial);
+        } else {
+            assert_eq!(zone.final_exchange_rate, initial);
+        }
+    }
+}
+
+#[test]
+fn test_currency_statistics_absent_when_disabled() {
+    let mut engine = SimulationEngine::new(test_config().max_steps(10).build());
+    let result = engine.run();
+    assert!(result.currency_statistics.is_none());
+}
+
+#[test]
+fn test_wealth_statistics_are_in_base_currency() {
+    let mut config = multi_currency_config();
+    config.fx_adjustment_rate = 0.0;
+    let initial_money = config.initial_money_per_person;
+    let mut engine = SimulationEngine::new(config);
+    let result = engine.run();
+
+    // A JPY balance of 15,000 counts as 100 base units, not 150 times a base balance
+    let stats = result.currency_statistics.as_ref().unwrap();
+    let supply_in_base: f64 = stats.currencies.iter().map(|c| c.money_supply_in_base).sum();
+    let distributed: f64 = result.final_money_distribution.iter().sum();
+    assert!((distributed - supply_in_base).abs() < 1e-6);
+    assert!((result.money_statistics.average - initial_money).abs() < 1e-6);
+    assert!(result.money_statistics.max_money < 30.0 * initial_money);
+}
+
+#[test]
+fn test_money_is_conserved_across_currencies() {
+    // Rates float and devaluations hit single currencies; the invariant converts every
+    // balance into the base currency and books revaluations separately
+    for crisis_probability in [0.0, 0.3] {
+        let mut config =
+            TestConfigBuilder::from(multi_currency_config()).strict_invariants().build();
+        config.enable_crisis_events = crisis_probability > 0.0;
+        config.crisis_probability = crisis_probability;
+        let mut engine = SimulationEngine::new(config);
+        let result = engine.run();
+
+        assert_eq!(result.failed_steps, 0);
+        let stats = result.currency_statistics.unwrap();
+        assert!(stats.exchange_rate_history["EUR"].iter().any(|&rate| (rate - 1.2).abs() > 1e-9));
+        assert_ne!(engine.get_total_exchange_revaluation(), 0.0);
+    }
+}
+
+#[test]
+fn test_p2p_loans_convert_between_currencies() {
+    // Loans are held in the base currency, so lending across zones only loses the fees
+    let mut config = multi_currency_config();
+    config.fx_adjustment_rate = 0.0;
+    config.enable_loans = true;
+    config.enable_credit_rating = true;
+    config.enable_p2p_lending = true;
+    config.p2p_platform_fee_rate = 0.05;
+    let initial_base_total = config.initial_money_per_person * config.entity_count as f64;
+    let mut engine = SimulationEngine::new(config);
+    let result = engine.run();
+
+    let lending = result.lending_marketplace_statistics.unwrap();
+    assert!(lending.total_matched_loans > 0);
+    assert!(result.loan_statistics.unwrap().total_loans_repaid > 0);
+    let final_base_total: f64 = result
+        .currency_statistics
+        .unwrap()
+        .currencies
+        .iter()
+        .map(|c| c.money_supply_in_base)
+        .sum();
+    assert!(
+        (final_base_total + lending.total_platform_fees - initial_base_total).abs() < 1e-6,
+        "{} + {} != {}",
+        final_base_total,
+        lending.total_platform_fees,
+        initial_base_total
+    );
+}
+
+#[test]
+fn test_investments_conserve_money_across_currencies() {
+    // Principals and returns are in the base currency; investors and investees pay
+    // and receive them in their own currencies
+    let mut config = TestConfigBuilder::from(multi_currency_config()).strict_invariants().build();
+    config.enable_investments = true;
+    config.investment_probability = 0.2;
+    config.investment_duration = 10;
+    config.min_money_to_invest = 50.0;
+    let mut engine = SimulationEngine::new(config);
+    let result = engine.run();
+
+    assert_eq!(result.failed_steps, 0);
+    let stats = result.investment_statistics.unwrap();
+    assert!(stats.total_investments_created > 0);
+    assert!(stats.total_returns_paid > 0.0);
+}
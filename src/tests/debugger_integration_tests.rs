@@ -0,0 +1,199 @@
+use crate::crisis::CrisisEvent;
+use crate::debugger::{Condition, Debugger, Metric, MAX_SNAPSHOTS};
+use crate::replay::replay_action_log;
+use crate::tests::test_helpers::test_config;
+use crate::SimulationEngine;
+
+fn money(engine: &SimulationEngine) -> Vec<f64> {
+    engine.get_entities().iter().map(|e| e.person_data.money).collect()
+}
+
+#[test]
+fn test_rewind_replays_the_original_run() {
+    let config = test_config().entity_count(20).max_steps(40).build();
+    let mut engine = SimulationEngine::new(config);
+    let mut debugger = Debugger::new(&engine, 5);
+
+    let mut history = vec![money(&engine)];
+    for _ in 0..30 {
+        debugger.step(&mut engine);
+        history.push(money(&engine));
+    }
+    assert_eq!(debugger.snapshot_steps(), vec![0, 5, 10, 15, 20, 25, 30]);
+
+    // Step 13 is replayed from the snapshot of step 10
+    assert_eq!(debugger.rewind(&mut engine, 13), Ok(10));
+    assert_eq!(engine.get_current_step(), 13);
+    assert_eq!(money(&engine), history[13]);
+    assert_eq!(debugger.snapshot_steps(), vec![0, 5, 10]);
+
+    // Running forward again repeats the steps that were rewound
+    for expected in &history[14..] {
+        debugger.step(&mut engine);
+        assert_eq!(&money(&engine), expected);
+    }
+}
+
+#[test]
+fn test_rewind_restores_assets_mentorships_and_strategies() {
+    let features: [fn(&mut crate::SimulationConfig); 3] = [
+        |cfg| {
+            cfg.enable_assets = true;
+            cfg.asset_purchase_probability = 0.2;
+            cfg.min_money_for_asset_purchase = 0.0;
+        },
+        |cfg| {
+            cfg.enable_education = true;
+            cfg.enable_mentorship = true;
+        },
+        |cfg| {
+            cfg.enable_friendships = true;
+            cfg.enable_strategy_evolution = true;
+        },
+    ];
+    for feature in features {
+        let config = test_config().entity_count(20).max_steps(30).build_with(feature);
+        let mut engine = SimulationEngine::new(config);
+        let mut debugger = Debugger::new(&engine, 5);
+        let mut history = vec![money(&engine)];
+        for _ in 0..30 {
+            debugger.step(&mut engine);
+            history.push(money(&engine));
+        }
+        let expected = engine.get_current_result();
+
+        debugger.rewind(&mut engine, 12).unwrap();
+        for expected_money in &history[13..] {
+            debugger.step(&mut engine);
+            assert_eq!(&money(&engine), expected_money);
+        }
+        let replayed = engine.get_current_result();
+        assert_eq!(
+            serde_json::to_value(&replayed.asset_statistics).unwrap(),
+            serde_json::to_value(&expected.asset_statistics).unwrap()
+        );
+        assert_eq!(
+            serde_json::to_value(&replayed.mentorship_statistics).unwrap(),
+            serde_json::to_value(&expected.mentorship_statistics).unwrap()
+        );
+        assert_eq!(
+            serde_json::to_value(&replayed.strategy_evolution_statistics).unwrap(),
+            serde_json::to_value(&expected.strategy_evolution_statistics).unwrap()
+        );
+    }
+}
+
+#[test]
+fn test_debugged_run_matches_batch_run() {
+    let config = test_config().entity_count(20).max_steps(30).build();
+    let mut batch = SimulationEngine::new(config.clone());
+    batch.run();
+
+    // Taking snapshots, at construction and every 3 steps, does not change the run
+    let mut engine = SimulationEngine::new(config);
+    let mut debugger = Debugger::new(&engine, 3);
+    for _ in 0..30 {
+        debugger.step(&mut engine);
+    }
+    assert_eq!(money(&engine), money(&batch));
+}
+
+#[test]
+fn test_session_recorded_through_debugger_replays() {
+    let config = test_config().entity_count(20).max_steps(25).build();
+    let mut engine = SimulationEngine::new(config.clone());
+    engine.enable_action_recording();
+    let mut debugger = Debugger::new(&engine, 4);
+    for _ in 0..10 {
+        debugger.step(&mut engine);
+    }
+    engine.set_parameter("tax_rate", "0.2").unwrap();
+    engine.trigger_crisis(CrisisEvent::DemandShock, 0.5).unwrap();
+    for _ in 10..18 {
+        debugger.step(&mut engine);
+    }
+    // Rewinding drops what was recorded after the restored snapshot
+    debugger.rewind(&mut engine, 14).unwrap();
+    for _ in 14..25 {
+        debugger.step(&mut engine);
+    }
+
+    let log = engine.get_action_log().unwrap().clone();
+    assert_eq!(log.actions.iter().filter(|a| a.is_intervention()).count(), 2);
+    let report = replay_action_log(&log, &config).unwrap();
+    assert!(report.is_deterministic(), "unexpected divergence: {:?}", report.divergence);
+    assert_eq!(report.steps_replayed, 25);
+    assert_eq!(report.actions_verified, log.len());
+}
+
+#[test]
+fn test_rewind_rejects_unknown_steps() {
+    let config = test_config().entity_count(10).max_steps(300).build();
+    let mut engine = SimulationEngine::new(config);
+    let mut debugger = Debugger::new(&engine, 1);
+    for _ in 0..(MAX_SNAPSHOTS + 20) {
+        debugger.step(&mut engine);
+    }
+
+    // The oldest snapshots were dropped
+    let steps = debugger.snapshot_steps();
+    assert_eq!(steps.len(), MAX_SNAPSHOTS);
+    assert_eq!(steps[0], 21);
+    assert!(debugger
+        .rewind(&mut engine, 5)
+        .unwrap_err()
+        .contains("oldest snapshot: step 21"));
+    assert!(debugger.rewind(&mut engine, 500).is_err());
+    assert_eq!(engine.get_current_step(), MAX_SNAPSHOTS + 20);
+}
+
+#[test]
+fn test_breakpoint_stops_when_condition_becomes_true() {
+    let config = test_config().entity_count(20).max_steps(60).build();
+    let mut engine = SimulationEngine::new(config);
+    let mut debugger = Debugger::new(&engine, 10);
+
+    // Everyone starts with the same money, so inequality has to build up first
+    let condition: Condition = "gini > 0.05".parse().unwrap();
+    debugger.add_breakpoint(condition, &engine);
+    let mut hit_at = None;
+    for _ in 0..60 {
+        if !debugger.step(&mut engine).is_empty() {
+            hit_at = Some(engine.get_current_step());
+            break;
+        }
+    }
+    let hit_at = hit_at.expect("breakpoint expected to stop the run");
+    assert!(Metric::Gini.evaluate(&engine).unwrap() > 0.05);
+
+    // The condition already holds after rewinding, so it does not stop the next step
+    debugger.rewind(&mut engine, hit_at).unwrap();
+    assert!(Metric::Gini.evaluate(&engine).unwrap() > 0.05);
+    assert!(debugger.step(&mut engine).is_empty());
+}
+
+#[test]
+fn test_person_breakpoint_and_watches() {
+    let config = test_config().entity_count(10).max_steps(20).build();
+    let mut engine = SimulationEngine::new(config);
+    let mut debugger = Debugger::new(&engine, 10);
+
+    let watch: Metric = "person 3 savings".parse().unwrap();
+    debugger.add_watch(watch);
+    debugger.add_watch(Metric::TotalMoney);
+    assert_eq!(debugger.watches().len(), 2);
+    assert_eq!(watch.evaluate(&engine), Some(0.0));
+    assert_eq!(debugger.remove_watch(0), Some(watch));
+    assert_eq!(debugger.remove_watch(5), None);
+
+    // Savings never drop below zero, and unknown persons never match
+    debugger.add_breakpoint("person 3 savings < 0".parse().unwrap(), &engine);
+    debugger.add_breakpoint("person 99 >= 0".parse().unwrap(), &engine);
+    for _ in 0..20 {
+        assert!(debugger.step(&mut engine).is_empty());
+    }
+    assert_eq!(debugger.breakpoints().len(), 2);
+    assert!(debugger.remove_breakpoint(1).is_some());
+    debugger.clear_breakpoints();
+    assert!(debugger.breakpoints().is_empty());
+}
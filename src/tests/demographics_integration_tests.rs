@@ -0,0 +1,156 @@
+use crate::tests::test_helpers::test_config;
+use crate::SimulationEngine;
+use tempfile::NamedTempFile;
+
+fn demographics_config() -> crate::SimulationConfig {
+    test_config().entity_count(30).max_steps(80).build_with(|cfg| {
+        cfg.enable_demographics = true;
+        cfg.enable_friendships = true;
+        cfg.friendship_probability = 0.3;
+        cfg.retirement_age = 20;
+        cfg.mortality_rate = 0.2;
+        cfg.birth_rate = 0.05;
+        cfg.immigration_rate = 0.5;
+        cfg.exit_after_broke_steps = 5;
+    })
+}
+
+#[test]
+fn test_population_changes_over_time() {
+    let mut engine = SimulationEngine::new(demographics_config());
+    let result = engine.run();
+
+    let stats = result.demographics_statistics.expect("demographics statistics expected");
+    assert!(stats.total_births > 0);
+    assert!(stats.total_immigrants > 0);
+    assert!(stats.total_retirements > 0);
+    assert!(stats.total_deaths > 0);
+    assert_eq!(stats.population_history.len(), 80);
+    assert_eq!(*stats.population_history.last().unwrap(), stats.final_population);
+
+    // Persons are appended, so everyone who ever lived is still in the entity list
+    let entities = engine.get_entities();
+    assert_eq!(entities.len(), 30 + stats.total_births + stats.total_immigrants);
+    let departed = entities.iter().filter(|e| !e.active).count();
+    assert_eq!(departed, stats.total_deaths + stats.total_market_exits);
+    assert_eq!(stats.final_population, engine.get_active_entity_count());
+    assert!(entities.iter().enumerate().all(|(idx, e)| e.id == idx));
+}
+
+#[test]
+fn test_estates_go_to_heirs_or_tax_pool() {
+    let mut engine = SimulationEngine::new(demographics_config());
+    let result = engine.run();
+
+    let stats = result.demographics_statistics.unwrap();
+    assert_eq!(
+        stats.estates_to_heirs + stats.estates_to_tax_pool,
+        stats.total_deaths + stats.total_market_exits
+    );
+    assert!(stats.total_estate_tax_pool <= engine.get_total_taxes_collected() + 1e-6);
+
+    for entity in engine.get_entities().iter().filter(|e| !e.active) {
+        let person = &entity.person_data;
+        // The estate was handed on (only an overdraft stays behind) and the departed
+        // person dropped out of the social graph
+        assert!(person.money <= 0.0);
+        assert_eq!(person.savings, 0.0);
+        assert!(person.friends.is_empty());
+        assert!(person.owned_assets.is_empty());
+    }
+    for entity in engine.get_entities().iter().filter(|e| e.active) {
+        assert!(entity.person_data.friends.iter().all(|&id| engine.get_entities()[id].active));
+    }
+}
+
+#[test]
+fn test_demographics_conserve_money() {
+    let config =
+        test_config()
+            .entity_count(20)
+            .max_steps(60)
+            .strict_invariants()
+            .build_with(|cfg| {
+                cfg.enable_demographics = true;
+                cfg.enable_loans = true;
+                cfg.retirement_age = 15;
+                cfg.mortality_rate = 0.2;
+                cfg.birth_rate = 0.05;
+                cfg.immigration_rate = 0.5;
+                cfg.exit_after_broke_steps = 3;
+            });
+    let mut engine = SimulationEngine::new(config);
+    let result = engine.run();
+
+    // Estates and endowments only move money; immigrants are accounted for separately
+    assert_eq!(result.failed_steps, 0);
+    let stats = result.demographics_statistics.unwrap();
+    assert!(stats.total_deaths > 0);
+    assert!((engine.get_total_immigrant_money() - stats.total_immigrant_money).abs() < 1e-6);
+}
+
+#[test]
+fn test_retirees_stop_selling() {
+    let config = test_config().entity_count(20).max_steps(30).build_with(|cfg| {
+        cfg.enable_demographics = true;
+        cfg.retirement_age = 5;
+        cfg.mortality_rate = 0.0;
+        cfg.birth_rate = 0.0;
+        cfg.immigration_rate = 0.0;
+        cfg.exit_after_broke_steps = 0;
+    });
+    let mut engine = SimulationEngine::new(config);
+    for _ in 0..5 {
+        engine.step();
+    }
+    // Everyone has retired, so no new sales can happen
+    assert!(engine.get_entities().iter().all(|e| e.person_data.retired));
+    let sold_before: usize = engine
+        .get_entities()
+        .iter()
+        .map(|e| e.person_data.transaction_history.len())
+        .sum();
+    for _ in 0..5 {
+        engine.step();
+    }
+    let result = engine.get_current_result();
+    let stats = result.demographics_statistics.unwrap();
+    assert_eq!(stats.total_retirements, 20);
+    assert_eq!(stats.retired_population, 20);
+    let sold_after: usize = engine
+        .get_entities()
+        .iter()
+        .map(|e| e.person_data.transaction_history.len())
+        .sum();
+    assert_eq!(sold_after, sold_before);
+}
+
+#[test]
+fn test_demographics_statistics_survive_checkpoint() {
+    let mut engine = SimulationEngine::new(demographics_config());
+    for _ in 0..40 {
+        engine.step();
+    }
+    let file = NamedTempFile::new().unwrap();
+    engine.save_checkpoint(file.path()).unwrap();
+    let before = engine.get_current_result().demographics_statistics.unwrap();
+
+    let restored = SimulationEngine::load_checkpoint(file.path()).unwrap();
+    let after = restored.get_current_result().demographics_statistics.unwrap();
+    assert!(before.total_births + before.total_immigrants > 0);
+    assert_eq!(after.total_births, before.total_births);
+    assert_eq!(after.total_deaths, before.total_deaths);
+    assert_eq!(after.final_population, before.final_population);
+    assert_eq!(after.population_history, before.population_history);
+}
+
+#[test]
+fn test_population_is_fixed_without_demographics() {
+    let config = test_config().entity_count(15).max_steps(30).build();
+    let mut engine = SimulationEngine::new(config);
+    let result = engine.run();
+
+    assert!(result.demographics_statistics.is_none());
+    assert_eq!(engine.get_entities().len(), 15);
+    assert!(engine.get_entities().iter().all(|e| e.active && e.person_data.age == 0));
+}
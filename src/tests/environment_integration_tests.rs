@@ -0,0 +1,126 @@
+use crate::person::TransactionType;
+use crate::scenario::Scenario;
+use crate::tests::test_helpers::{test_config, TestConfigBuilder};
+use crate::SimulationEngine;
+use std::collections::HashMap;
+
+fn reserves(amount: f64) -> HashMap<String, f64> {
+    ["energy", "water", "materials", "land"]
+        .into_iter()
+        .map(|name| (name.to_string(), amount))
+        .collect()
+}
+
+fn environment_config(reserve: f64) -> crate::SimulationConfig {
+    test_config().entity_count(20).max_steps(60).build_with(|cfg| {
+        cfg.enable_environment = true;
+        cfg.custom_resource_reserves = Some(reserves(reserve));
+    })
+}
+
+#[test]
+fn test_scarcity_raises_skill_costs() {
+    let mut engine = SimulationEngine::new(environment_config(1_000.0));
+    let result = engine.run();
+
+    let stats = result.environment_statistics.expect("environment statistics expected");
+    assert!(stats.overall_sustainability_score < 0.5);
+    assert!(stats.avg_resource_cost_multiplier > 1.0);
+    assert!(stats.max_resource_cost_multiplier > stats.avg_resource_cost_multiplier);
+    assert!(stats.max_resource_cost_multiplier <= 2.0 * 2.0);
+
+    // Without feedback, prices ignore the depleted reserves
+    let config = environment_config(1_000.0);
+    let mut engine = SimulationEngine::new(crate::SimulationConfig {
+        resource_scarcity_price_factor: 0.0,
+        ..config
+    });
+    let stats = engine.run().environment_statistics.unwrap();
+    assert!(stats.overall_sustainability_score < 0.5);
+    assert_eq!(stats.avg_resource_cost_multiplier, 1.0);
+}
+
+#[test]
+fn test_quotas_limit_consumption_per_step() {
+    let mut config = TestConfigBuilder::from(environment_config(100_000.0))
+        .strict_invariants()
+        .build();
+    config.resource_quotas = HashMap::from([("energy".to_string(), 10.0)]);
+    let mut engine = SimulationEngine::new(config);
+    let result = engine.run();
+
+    assert_eq!(result.failed_steps, 0);
+    let stats = result.environment_statistics.unwrap();
+    assert!(stats.blocked_transactions > 0);
+    assert!(stats.total_consumption["Energy"] <= 10.0 * 60.0 + 1e-9);
+    assert!(result.trade_volume_statistics.total_trades > 0);
+
+    // Blocked trades never settle, so they are not counted as trades
+    let purchases: Vec<f64> = engine
+        .get_entities()
+        .iter()
+        .flat_map(|e| &e.person_data.transaction_history)
+        .filter(|t| matches!(t.transaction_type, TransactionType::Buy))
+        .map(|t| t.amount)
+        .collect();
+    assert_eq!(result.trade_volume_statistics.total_trades, purchases.len());
+    let volume = result.trade_volume_statistics.total_volume;
+    assert!((volume - purchases.iter().sum::<f64>()).abs() < 1e-6);
+}
+
+#[test]
+fn test_enforced_reserves_are_never_overconsumed() {
+    let mut config = environment_config(500.0);
+    config.enforce_resource_reserves = true;
+    let mut engine = SimulationEngine::new(config);
+    let result = engine.run();
+
+    let stats = result.environment_statistics.unwrap();
+    assert!(stats.blocked_transactions > 0);
+    assert!(stats.is_sustainable);
+    assert!(stats.remaining_reserves.values().all(|&remaining| remaining >= -1e-9));
+
+    // The same economy overconsumes its reserves when they are not enforced
+    let stats = SimulationEngine::new(environment_config(500.0))
+        .run()
+        .environment_statistics
+        .unwrap();
+    assert_eq!(stats.blocked_transactions, 0);
+    assert!(!stats.is_sustainable);
+}
+
+#[test]
+fn test_regeneration_restores_reserves() {
+    let without = SimulationEngine::new(environment_config(2_000.0))
+        .run()
+        .environment_statistics
+        .unwrap();
+
+    let mut config = environment_config(2_000.0);
+    config.resource_regeneration_rates =
+        HashMap::from([("water".to_string(), 0.2), ("energy".to_string(), 0.01)]);
+    let with = SimulationEngine::new(config).run().environment_statistics.unwrap();
+
+    assert!(with.total_regeneration["Water"] > 0.0);
+    assert!(with.total_regeneration["Energy"] > 0.0);
+    assert!(!with.total_regeneration.contains_key("Materials"));
+    assert!(with.remaining_reserves["Water"] > without.remaining_reserves["Water"]);
+    assert!(with.remaining_reserves["Water"] <= 2_000.0);
+}
+
+#[test]
+fn test_climate_change_erodes_carrying_capacity() {
+    let mut config = environment_config(100_000.0);
+    config.scenario = Scenario::ClimateChange;
+    let stats = SimulationEngine::new(config).run().environment_statistics.unwrap();
+
+    assert!(stats.carrying_capacities["Water"] < 100_000.0);
+    assert!(stats.carrying_capacities["Land"] < 100_000.0);
+    assert!(!stats.carrying_capacities.contains_key("Energy"));
+
+    let stats = SimulationEngine::new(environment_config(100_000.0))
+        .run()
+        .environment_statistics
+        .unwrap();
+    assert_eq!(stats.carrying_capacities["Water"], 100_000.0);
+}
@@ -0,0 +1,169 @@
+use crate::person::TransactionType;
+use crate::result::StepData;
+use crate::tests::test_helpers::test_config;
+use crate::SimulationEngine;
+use tempfile::NamedTempFile;
+
+fn epidemic_config() -> crate::SimulationConfig {
+    test_config().entity_count(30).max_steps(60).build_with(|cfg| {
+        cfg.enable_health = true;
+        cfg.initial_sick_persons = 3;
+        cfg.disease_transmission_rate = 0.3;
+        cfg.disease_recovery_duration = 5;
+        cfg.disease_incubation_period = 2;
+    })
+}
+
+#[test]
+fn test_seir_curve_is_recorded() {
+    let mut engine = SimulationEngine::new(epidemic_config());
+    let result = engine.run();
+
+    let stats = result.epidemic_statistics.expect("epidemic statistics expected");
+    assert_eq!(stats.history.len(), 60);
+    assert!(stats.history.iter().all(|snapshot| snapshot.counts.total() == 30));
+    assert!(stats.history.iter().any(|snapshot| snapshot.counts.exposed > 0));
+    assert!(stats.trade_exposures > 0);
+    assert!(stats.recoveries >= 3);
+    assert!(stats.peak_infected >= 3);
+    assert_eq!(stats.history[stats.peak_step].counts.infected, stats.peak_infected);
+
+    // Immunity is permanent by default, so everyone who recovered stays recovered
+    assert_eq!(stats.immunity_losses, 0);
+    assert_eq!(stats.final_counts.recovered, stats.recoveries);
+    assert_eq!(stats.final_counts, stats.history.last().unwrap().counts);
+}
+
+#[test]
+fn test_waning_immunity_reinfects() {
+    let mut config = epidemic_config();
+    config.immunity_duration = 5;
+    let stats = SimulationEngine::new(config).run().epidemic_statistics.unwrap();
+
+    assert!(stats.immunity_losses > 0);
+    assert!(stats.recoveries >= stats.immunity_losses);
+    assert!(stats.final_counts.susceptible > 0);
+}
+
+#[test]
+fn test_disease_spreads_along_friendships() {
+    let mut config = epidemic_config();
+    config.disease_transmission_rate = 0.0;
+    config.enable_friendships = true;
+    config.friendship_probability = 1.0;
+    config.contact_transmission_rate = 0.5;
+    let stats = SimulationEngine::new(config).run().epidemic_statistics.unwrap();
+
+    assert_eq!(stats.trade_exposures, 0);
+    assert!(stats.contact_exposures > 0);
+}
+
+#[test]
+fn test_lockdown_cancels_trades() {
+    let mut config = epidemic_config();
+    config.lockdown_threshold = 0.05;
+    config.lockdown_trade_reduction = 0.8;
+    let mut engine = SimulationEngine::new(config);
+    let result = engine.run();
+
+    let stats = result.epidemic_statistics.unwrap();
+    assert!(stats.lockdown_steps > 0);
+    assert!(stats.lockdown_cancelled_trades > 0);
+
+    // Cancelled trades never settle, so only the purchases made count as trades
+    let purchases = engine
+        .get_entities()
+        .iter()
+        .flat_map(|e| &e.person_data.transaction_history)
+        .filter(|t| matches!(t.transaction_type, TransactionType::Buy))
+        .count();
+    assert_eq!(result.trade_volume_statistics.total_trades, purchases);
+
+    let baseline = SimulationEngine::new(epidemic_config()).run();
+    let baseline_stats = baseline.epidemic_statistics.unwrap();
+    assert!(
+        result.trade_volume_statistics.total_trades < baseline.trade_volume_statistics.total_trades
+    );
+    assert_eq!(baseline_stats.lockdown_steps, 0);
+    assert_eq!(baseline_stats.lockdown_cancelled_trades, 0);
+}
+
+#[test]
+fn test_vaccination_protects_susceptible_persons() {
+    let mut config = epidemic_config();
+    config.vaccination_rate = 1.0;
+    let stats = SimulationEngine::new(config).run().epidemic_statistics.unwrap();
+
+    // Everyone still susceptible after the first step's trades is vaccinated, and the
+    // disease cannot spread any further
+    let first = stats.history[0].counts;
+    assert_eq!(first.susceptible, 0);
+    assert_eq!(stats.vaccinations + stats.trade_exposures, 27);
+    assert!(stats.history.iter().all(|snapshot| snapshot.counts.susceptible == 0));
+    assert_eq!(stats.final_counts.recovered, 30);
+
+    let mut config = epidemic_config();
+    config.vaccination_rate = 1.0;
+    config.vaccination_start_step = 1_000;
+    let stats = SimulationEngine::new(config).run().epidemic_statistics.unwrap();
+    assert_eq!(stats.vaccinations, 0);
+}
+
+#[test]
+fn test_sick_pay_comes_from_tax_pool() {
+    let mut config = epidemic_config();
+    config.tax_rate = 0.2;
+    config.sick_pay = 2.0;
+    let mut engine = SimulationEngine::new(config);
+    let result = engine.run();
+
+    let stats = result.epidemic_statistics.unwrap();
+    assert!(stats.total_sick_pay > 0.0);
+    assert_eq!(stats.total_sick_pay, engine.get_total_sick_pay());
+    assert!(stats.total_sick_pay <= engine.get_total_taxes_collected() + 1e-9);
+
+    // Without taxes there is nothing to pay sick pay from
+    let mut config = epidemic_config();
+    config.sick_pay = 2.0;
+    let stats = SimulationEngine::new(config).run().epidemic_statistics.unwrap();
+    assert_eq!(stats.total_sick_pay, 0.0);
+}
+
+#[test]
+fn test_step_data_streams_compartments() {
+    let file = NamedTempFile::new().unwrap();
+    let mut config = epidemic_config();
+    config.max_steps = 10;
+    config.stream_output_path = Some(file.path().to_str().unwrap().to_string());
+    SimulationEngine::new(config).run();
+
+    let contents = std::fs::read_to_string(file.path()).unwrap();
+    let steps: Vec<StepData> =
+        contents.lines().map(|line| serde_json::from_str(line).unwrap()).collect();
+    assert_eq!(steps.len(), 10);
+    assert!(steps.iter().all(|step| step.epidemic.unwrap().total() == 30));
+}
+
+#[test]
+fn test_epidemic_statistics_survive_checkpoint() {
+    let mut engine = SimulationEngine::new(epidemic_config());
+    for _ in 0..20 {
+        engine.step();
+    }
+    let file = NamedTempFile::new().unwrap();
+    engine.save_checkpoint(file.path()).unwrap();
+    let before = engine.get_current_result().epidemic_statistics.unwrap();
+
+    let restored = SimulationEngine::load_checkpoint(file.path()).unwrap();
+    let after = restored.get_current_result().epidemic_statistics.unwrap();
+    assert_eq!(after.history, before.history);
+    assert_eq!(after.trade_exposures, before.trade_exposures);
+    assert_eq!(after.final_counts, before.final_counts);
+}
+
+#[test]
+fn test_no_epidemic_statistics_without_health() {
+    let config = test_config().entity_count(10).max_steps(10).build();
+    let result = SimulationEngine::new(config).run();
+    assert!(result.epidemic_statistics.is_none());
+}
@@ -0,0 +1,133 @@
+use crate::tests::test_helpers::{test_config, TestConfigBuilder};
+use crate::SimulationEngine;
+use tempfile::NamedTempFile;
+
+fn firm_config() -> crate::SimulationConfig {
+    // Products are priced far above single skills, so buyers need more than the
+    // default starting money
+    test_config()
+        .entity_count(40)
+        .max_steps(40)
+        .initial_money(1000.0)
+        .build_with(|cfg| {
+            cfg.enable_firms = true;
+            cfg.firm_count = 3;
+            cfg.firm_founding_rate = 0.1;
+        })
+}
+
+#[test]
+fn test_firms_hire_produce_and_sell() {
+    let mut engine = SimulationEngine::new(firm_config());
+    let result = engine.run();
+
+    let stats = result.firm_statistics.expect("firm statistics expected");
+    assert!(stats.total_firms_founded >= 3);
+    assert!(stats.total_hires > 0);
+    assert!(stats.total_units_sold > 0);
+    assert!(stats.total_revenue > 0.0);
+    assert!(stats.total_wages_paid > 0.0);
+    assert_eq!(stats.employment_history.len(), 40);
+    assert_eq!(stats.unemployment_history.len(), 40);
+    assert!(stats.unemployment_history.iter().all(|rate| (0.0..=1.0).contains(rate)));
+    assert_eq!(stats.active_firms + stats.bankruptcies, stats.total_firms_founded);
+
+    // Every employee works for exactly the firm that lists them
+    let entities = engine.get_entities();
+    for firm in engine.get_firms() {
+        assert!(firm.employees.len() <= 10);
+        for employee in &firm.employees {
+            let person = &entities[employee.person_id].person_data;
+            assert_eq!(person.employer, Some(firm.id));
+            assert!(person.has_skill(&employee.skill_id));
+            assert_ne!(employee.person_id, firm.owner_id);
+        }
+    }
+    let employed = entities.iter().filter(|e| e.person_data.is_employed()).count();
+    assert_eq!(employed, stats.employed_persons);
+    assert_eq!(*stats.employment_history.last().unwrap(), employed);
+}
+
+#[test]
+fn test_firms_conserve_money() {
+    let config = TestConfigBuilder::from(firm_config()).strict_invariants().build();
+    let mut engine = SimulationEngine::new(config);
+    let result = engine.run();
+
+    // Capital, sales, wages and dividends only move money between persons and firms
+    assert_eq!(result.failed_steps, 0);
+    let stats = result.firm_statistics.unwrap();
+    assert!(stats.total_wages_paid > 0.0);
+    assert!((stats.firm_cash - engine.get_total_firm_cash()).abs() < 1e-9);
+}
+
+#[test]
+fn test_firm_that_cannot_pay_wages_goes_bankrupt() {
+    let mut config = firm_config();
+    config.firm_count = 2;
+    config.firm_founding_rate = 0.0;
+    config.firm_initial_capital = 60.0;
+    config.firm_wage = 30.0;
+    let mut engine = SimulationEngine::new(config);
+    let result = engine.run();
+
+    let stats = result.firm_statistics.unwrap();
+    assert_eq!(stats.total_firms_founded, 2);
+    assert_eq!(stats.bankruptcies, 2);
+    assert_eq!(stats.active_firms, 0);
+    assert!(stats.bankruptcy_job_losses > 0);
+    assert_eq!(stats.employed_persons, 0);
+    assert!(engine.get_entities().iter().all(|e| !e.person_data.is_employed()));
+    for firm in &stats.firms {
+        assert!(firm.bankrupt_at_step.is_some());
+        assert_eq!(firm.employees, 0);
+        assert!(firm.balance_sheet.liabilities > 0.0);
+        assert!(firm.balance_sheet.equity < 0.0);
+    }
+}
+
+#[test]
+fn test_retired_employees_leave_their_firm() {
+    let mut config = firm_config();
+    config.enable_demographics = true;
+    config.retirement_age = 10;
+    config.mortality_rate = 0.0;
+    config.birth_rate = 0.0;
+    config.immigration_rate = 0.0;
+    config.exit_after_broke_steps = 0;
+    let mut engine = SimulationEngine::new(config);
+    engine.run();
+
+    // Everyone retires within 10 steps, so nobody is left to work for a firm
+    assert!(engine.get_entities().iter().all(|e| e.person_data.retired));
+    assert!(engine.get_entities().iter().all(|e| !e.person_data.is_employed()));
+    assert!(engine.get_firms().iter().all(|firm| firm.employees.is_empty()));
+}
+
+#[test]
+fn test_firm_statistics_survive_checkpoint() {
+    let mut engine = SimulationEngine::new(firm_config());
+    for _ in 0..20 {
+        engine.step();
+    }
+    let file = NamedTempFile::new().unwrap();
+    engine.save_checkpoint(file.path()).unwrap();
+    let before = engine.get_current_result().firm_statistics.unwrap();
+
+    let restored = SimulationEngine::load_checkpoint(file.path()).unwrap();
+    let after = restored.get_current_result().firm_statistics.unwrap();
+    assert!(before.total_hires > 0);
+    assert_eq!(after.total_hires, before.total_hires);
+    assert_eq!(after.employed_persons, before.employed_persons);
+    assert_eq!(after.employment_history, before.employment_history);
+    assert_eq!(after.firm_cash, before.firm_cash);
+}
+
+#[test]
+fn test_no_firms_without_firm_system() {
+    let config = test_config().entity_count(10).max_steps(10).build();
+    let mut engine = SimulationEngine::new(config);
+    let result = engine.run();
+    assert!(result.firm_statistics.is_none());
+    assert!(engine.get_firms().is_empty());
+}
@@ -0,0 +1,159 @@
+use crate::government::TaxBracket;
+use crate::person::SocialClass;
+use crate::tests::test_helpers::{test_config, TestConfigBuilder};
+use crate::SimulationEngine;
+use std::collections::HashMap;
+use tempfile::NamedTempFile;
+
+fn government_config() -> crate::SimulationConfig {
+    test_config().entity_count(20).max_steps(30).tax_rate(0.1).build_with(|cfg| {
+        cfg.enable_government = true;
+    })
+}
+
+#[test]
+fn test_government_collects_revenue_by_source() {
+    let mut config = government_config();
+    config.vat_rate = 0.05;
+    config.income_tax_brackets = vec![
+        TaxBracket { threshold: 0.0, rate: 0.05 },
+        TaxBracket { threshold: 60.0, rate: 0.2 },
+    ];
+    config.wealth_tax_rate = 0.01;
+    config.wealth_tax_threshold = 50.0;
+    let mut engine = SimulationEngine::new(config);
+    let result = engine.run();
+
+    let stats = result.government_statistics.expect("government statistics expected");
+    assert!(stats.trade_tax_revenue > 0.0);
+    assert!(stats.vat_revenue > 0.0);
+    assert!(stats.income_tax_revenue > 0.0);
+    assert!(stats.wealth_tax_revenue > 0.0);
+    let by_source = stats.trade_tax_revenue
+        + stats.vat_revenue
+        + stats.income_tax_revenue
+        + stats.wealth_tax_revenue;
+    assert!((stats.total_revenue - by_source).abs() < 1e-9);
+    assert!((stats.total_revenue - result.total_taxes_collected.unwrap()).abs() < 1e-6);
+    assert_eq!(stats.history.len(), 30);
+
+    // Without spending the whole revenue stays in the treasury
+    assert_eq!(stats.total_spending, 0.0);
+    assert_eq!(stats.debt, 0.0);
+    assert!((stats.treasury - stats.total_revenue).abs() < 1e-6);
+}
+
+#[test]
+fn test_deficits_are_financed_with_debt() {
+    let mut config = TestConfigBuilder::from(government_config()).strict_invariants().build();
+    config.public_goods_spending = 100.0;
+    let mut engine = SimulationEngine::new(config);
+    let result = engine.run();
+
+    // Spending far above revenue is borrowed, and the money still adds up
+    assert_eq!(result.failed_steps, 0);
+    let stats = result.government_statistics.unwrap();
+    assert!((stats.public_goods_spending - 100.0 * 30.0).abs() < 1e-6);
+    assert!(stats.debt > 0.0);
+    assert!(stats.debt_issued > 0.0);
+    assert!(stats.interest_due > 0.0);
+    assert!(stats.deficit_steps > 0);
+    assert!(stats.peak_debt >= stats.debt);
+    assert_eq!(engine.get_government_debt(), stats.debt);
+    let balance: f64 = stats.history.iter().map(|snapshot| snapshot.balance).sum();
+    assert!((stats.debt - stats.treasury + balance).abs() < 1e-6);
+}
+
+#[test]
+fn test_zero_debt_limit_balances_the_budget() {
+    let mut config = TestConfigBuilder::from(government_config()).strict_invariants().build();
+    config.public_goods_spending = 100.0;
+    config.government_debt_limit = Some(0.0);
+    let mut engine = SimulationEngine::new(config);
+    let result = engine.run();
+
+    assert_eq!(result.failed_steps, 0);
+    let stats = result.government_statistics.unwrap();
+    assert_eq!(stats.debt, 0.0);
+    assert_eq!(stats.debt_issued, 0.0);
+    assert!(stats.total_spending > 0.0);
+    assert!(stats.total_spending <= stats.total_revenue + 1e-6);
+    assert!(stats.public_goods_spending < 100.0 * 30.0);
+}
+
+#[test]
+fn test_public_goods_raise_skill_efficiency() {
+    let mut config = government_config();
+    config.public_goods_spending = 50.0;
+    config.public_goods_efficiency = 0.1;
+    let mut engine = SimulationEngine::new(config);
+    engine.run();
+
+    // 50 per step for 20 persons at a base price of 50 adds 0.5% per step
+    let expected = 1.005f64.powi(30);
+    for skill in engine.get_market().skills.values() {
+        assert!((skill.efficiency_multiplier - expected).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn test_unemployment_benefits_reach_persons_without_sales() {
+    let mut config = TestConfigBuilder::from(government_config()).strict_invariants().build();
+    config.unemployment_benefit = 5.0;
+    let mut engine = SimulationEngine::new(config);
+    let result = engine.run();
+
+    assert_eq!(result.failed_steps, 0);
+    let stats = result.government_statistics.unwrap();
+    assert!(stats.unemployment_benefits_paid > 0.0);
+    // Benefits are paid in whole amounts of 5 per unemployed person
+    let payments = stats.unemployment_benefits_paid / 5.0;
+    assert!((payments - payments.round()).abs() < 1e-9);
+    assert!(payments <= 20.0 * 30.0);
+    assert_eq!(stats.total_spending, stats.unemployment_benefits_paid);
+}
+
+#[test]
+fn test_class_transfers_go_to_the_configured_class() {
+    let mut config = TestConfigBuilder::from(government_config()).strict_invariants().build();
+    config.class_transfers = HashMap::from([(SocialClass::Lower, 3.0)]);
+    let mut engine = SimulationEngine::new(config);
+    let result = engine.run();
+
+    assert_eq!(result.failed_steps, 0);
+    let stats = result.government_statistics.unwrap();
+    assert_eq!(stats.class_transfers_paid.len(), 1);
+    assert!(stats.class_transfers_paid[&SocialClass::Lower] > 0.0);
+    assert_eq!(stats.total_spending, stats.class_transfers_paid[&SocialClass::Lower]);
+}
+
+#[test]
+fn test_government_budget_survives_checkpoint() {
+    let mut config = government_config();
+    config.public_goods_spending = 20.0;
+    config.vat_rate = 0.05;
+    let mut engine = SimulationEngine::new(config);
+    for _ in 0..15 {
+        engine.step();
+    }
+    let file = NamedTempFile::new().unwrap();
+    engine.save_checkpoint(file.path()).unwrap();
+    let before = engine.get_current_result().government_statistics.unwrap();
+
+    let restored = SimulationEngine::load_checkpoint(file.path()).unwrap();
+    let after = restored.get_current_result().government_statistics.unwrap();
+    assert_eq!(after.history.len(), before.history.len());
+    assert!((after.total_revenue - before.total_revenue).abs() < 1e-9);
+    assert!((after.total_spending - before.total_spending).abs() < 1e-9);
+    assert!((after.debt - before.debt).abs() < 1e-9);
+}
+
+#[test]
+fn test_no_government_without_government_system() {
+    let config = test_config().entity_count(10).max_steps(10).tax_rate(0.1).build();
+    let mut engine = SimulationEngine::new(config);
+    let result = engine.run();
+    assert!(result.government_statistics.is_none());
+    assert_eq!(engine.get_total_government_spending(), 0.0);
+    assert_eq!(engine.get_government_debt(), 0.0);
+}
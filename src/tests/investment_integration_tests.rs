@@ -0,0 +1,120 @@
+use crate::tests::test_helpers::{test_config, TestConfigBuilder};
+use crate::SimulationEngine;
+use tempfile::NamedTempFile;
+
+fn investment_config() -> crate::SimulationConfig {
+    test_config()
+        .entity_count(20)
+        .max_steps(100)
+        .initial_money(300.0)
+        .build_with(|cfg| {
+            cfg.enable_investments = true;
+            cfg.investment_probability = 0.2;
+            cfg.investment_duration = 10;
+            cfg.investment_return_rate = 0.02;
+            cfg.min_money_to_invest = 50.0;
+        })
+}
+
+#[test]
+fn test_investments_created_and_completed() {
+    let mut engine = SimulationEngine::new(investment_config());
+    let result = engine.run();
+
+    let stats = result.investment_statistics.expect("investment statistics should be present");
+    assert!(stats.total_investments_created > 0);
+    assert!(stats.total_invested > 0.0);
+    assert!(stats.total_returns_paid > 0.0);
+    assert_eq!(
+        stats.total_investments_created,
+        stats.total_investments_completed
+            + stats.total_investments_defaulted
+            + stats.active_investments
+    );
+    assert!(stats.total_losses >= 0.0);
+}
+
+#[test]
+fn test_completed_investments_earn_configured_roi() {
+    let mut engine = SimulationEngine::new(investment_config());
+    let result = engine.run();
+
+    let stats = result.investment_statistics.unwrap();
+    assert!(stats.total_investments_completed > 0);
+    // rate 0.02 over 10 steps = 20% profit on every completed investment
+    assert!((stats.avg_roi_percentage - 20.0).abs() < 1e-6);
+}
+
+#[test]
+fn test_investments_conserve_money() {
+    // Without fees, taxes or learning sinks, money only moves between persons,
+    // except for the learning cost investors pay for education investments
+    let config = investment_config();
+    let initial_total = config.initial_money_per_person * config.entity_count as f64;
+    let mut engine =
+        SimulationEngine::new(TestConfigBuilder::from(config).strict_invariants().build());
+    let result = engine.run();
+
+    // Strict invariant mode would have panicked if the education principal were unaccounted for
+    assert_eq!(result.failed_steps, 0);
+    let education = engine.get_total_investment_education_spending();
+    assert!(education > 0.0);
+    let final_total: f64 = result.final_money_distribution.iter().sum();
+    assert!((final_total + education - initial_total).abs() < 1e-6);
+}
+
+#[test]
+fn test_broke_investees_default() {
+    // Investees start with little money and returns are steep, so some must default
+    let config = investment_config();
+    let mut engine = SimulationEngine::new(crate::SimulationConfig {
+        initial_money_per_person: 60.0,
+        investment_return_rate: 0.5,
+        ..config
+    });
+    let result = engine.run();
+
+    let stats = result.investment_statistics.unwrap();
+    assert!(stats.total_investments_defaulted > 0);
+    assert!(stats.total_losses > 0.0);
+}
+
+#[test]
+fn test_investor_tracks_active_investments() {
+    let mut engine = SimulationEngine::new(investment_config());
+    engine.run();
+
+    let tracked: usize = engine
+        .get_entities()
+        .iter()
+        .map(|e| e.person_data.active_investments.len())
+        .sum();
+    let result = engine.get_current_result();
+    assert_eq!(tracked, result.investment_statistics.unwrap().active_investments);
+}
+
+#[test]
+fn test_investments_survive_checkpoint() {
+    let mut engine = SimulationEngine::new(investment_config());
+    for _ in 0..50 {
+        engine.step();
+    }
+    let before = engine.get_current_result().investment_statistics.unwrap();
+
+    let checkpoint = NamedTempFile::new().unwrap();
+    engine.save_checkpoint(checkpoint.path()).unwrap();
+    let restored = SimulationEngine::load_checkpoint(checkpoint.path()).unwrap();
+    let after = restored.get_current_result().investment_statistics.unwrap();
+
+    assert_eq!(before.total_investments_created, after.total_investments_created);
+    assert_eq!(before.active_investments, after.active_investments);
+    assert!((before.total_returns_paid - after.total_returns_paid).abs() < 1e-6);
+    assert!((before.total_losses - after.total_losses).abs() < 1e-6);
+}
+
+#[test]
+fn test_investments_disabled_has_no_statistics() {
+    let mut engine = SimulationEngine::new(test_config().max_steps(10).build());
+    let result = engine.run();
+    assert!(result.investment_statistics.is_none());
+}
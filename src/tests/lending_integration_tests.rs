@@ -0,0 +1,102 @@
+use crate::tests::test_helpers::test_config;
+use crate::SimulationEngine;
+use tempfile::NamedTempFile;
+
+fn lending_config(fee_rate: f64) -> crate::SimulationConfig {
+    test_config().entity_count(30).max_steps(60).build_with(|cfg| {
+        cfg.enable_loans = true;
+        cfg.enable_credit_rating = true;
+        cfg.enable_p2p_lending = true;
+        cfg.p2p_platform_fee_rate = fee_rate;
+    })
+}
+
+#[test]
+fn test_marketplace_disabled_has_no_statistics() {
+    let config = test_config().max_steps(20).build_with(|cfg| cfg.enable_loans = true);
+    let mut engine = SimulationEngine::new(config);
+    let result = engine.run();
+
+    assert!(result.loan_statistics.is_some());
+    assert!(result.lending_marketplace_statistics.is_none());
+    assert_eq!(result.loan_statistics.unwrap().total_loans_issued, 0);
+}
+
+#[test]
+fn test_borrowers_are_matched_with_offers() {
+    let mut engine = SimulationEngine::new(lending_config(0.01));
+    let result = engine.run();
+
+    let stats = result.lending_marketplace_statistics.expect("marketplace statistics expected");
+    let loan_stats = result.loan_statistics.unwrap();
+    assert!(stats.total_offers_posted > 0);
+    assert!(stats.avg_offer_book_depth > 0.0);
+    assert!(stats.total_matched_loans > 0);
+    assert_eq!(loan_stats.total_loans_issued, stats.total_matched_loans);
+    assert_eq!(
+        loan_stats.total_loans_repaid + loan_stats.total_loans_defaulted + loan_stats.active_loans,
+        loan_stats.total_loans_issued
+    );
+
+    let band_loans: usize = stats.by_credit_band.iter().map(|band| band.loans).sum();
+    let band_volume: f64 = stats.by_credit_band.iter().map(|band| band.volume).sum();
+    assert_eq!(band_loans, stats.total_matched_loans);
+    assert!((band_volume - stats.total_matched_volume).abs() < 1e-6);
+    for band in &stats.by_credit_band {
+        assert!(band.avg_interest_rate > 0.0, "{} band has no rate", band.credit_band);
+    }
+}
+
+#[test]
+fn test_platform_fees_are_collected() {
+    let mut engine = SimulationEngine::new(lending_config(0.05));
+    let result = engine.run();
+
+    let stats = result.lending_marketplace_statistics.unwrap();
+    assert!(stats.total_matched_volume > 0.0);
+    assert!((stats.total_platform_fees - stats.total_matched_volume * 0.05).abs() < 1e-6);
+    assert_eq!(engine.get_total_lending_fees_collected(), stats.total_platform_fees);
+
+    let mut free = SimulationEngine::new(lending_config(0.0));
+    let free_stats = free.run().lending_marketplace_statistics.unwrap();
+    assert_eq!(free_stats.total_platform_fees, 0.0);
+}
+
+#[test]
+fn test_lending_conserves_money_net_of_fees() {
+    let config =
+        test_config()
+            .entity_count(20)
+            .max_steps(40)
+            .strict_invariants()
+            .build_with(|cfg| {
+                cfg.enable_loans = true;
+                cfg.enable_p2p_lending = true;
+                cfg.p2p_platform_fee_rate = 0.02;
+            });
+    let mut engine = SimulationEngine::new(config);
+    let result = engine.run();
+
+    // Strict invariant mode would have panicked if the platform fees were unaccounted for
+    assert_eq!(result.failed_steps, 0);
+    assert!(result.lending_marketplace_statistics.unwrap().total_matched_loans > 0);
+}
+
+#[test]
+fn test_marketplace_statistics_survive_checkpoint() {
+    let mut engine = SimulationEngine::new(lending_config(0.01));
+    for _ in 0..30 {
+        engine.step();
+    }
+    let file = NamedTempFile::new().unwrap();
+    engine.save_checkpoint(file.path()).unwrap();
+    let before = engine.get_current_result().lending_marketplace_statistics.unwrap();
+
+    let restored = SimulationEngine::load_checkpoint(file.path()).unwrap();
+    let after = restored.get_current_result().lending_marketplace_statistics.unwrap();
+    assert!(before.total_matched_loans > 0);
+    assert_eq!(after.total_matched_loans, before.total_matched_loans);
+    assert_eq!(after.total_offers_posted, before.total_offers_posted);
+    assert_eq!(after.by_credit_band.len(), before.by_credit_band.len());
+    assert_eq!(after.total_platform_fees, before.total_platform_fees);
+}
@@ -0,0 +1,204 @@
+use crate::phase::{BuiltinPhase, StepPhase};
+use crate::tests::test_helpers::test_config;
+use crate::SimulationEngine;
+use std::sync::{Arc, Mutex};
+use tempfile::NamedTempFile;
+
+fn default_config(bankruptcy: bool) -> crate::SimulationConfig {
+    test_config().entity_count(30).max_steps(80).build_with(|cfg| {
+        cfg.enable_loans = true;
+        cfg.enable_credit_rating = true;
+        cfg.enable_p2p_lending = true;
+        cfg.loan_interest_rate = 0.1;
+        cfg.loan_repayment_period = 5;
+        cfg.loan_default_threshold = 1;
+        cfg.enable_bankruptcy = bankruptcy;
+    })
+}
+
+#[test]
+fn test_loans_default_and_are_written_off() {
+    let mut engine = SimulationEngine::new(default_config(false));
+    let result = engine.run();
+
+    let stats = result.loan_statistics.expect("loan statistics expected");
+    assert!(stats.total_loans_defaulted > 0);
+    assert!(stats.total_written_off > 0.0);
+    assert_eq!(stats.bankruptcies_restructured + stats.bankruptcies_discharged, 0);
+    // Every issued loan is repaid, written off or still running
+    assert_eq!(
+        stats.total_loans_repaid + stats.total_loans_defaulted + stats.active_loans,
+        stats.total_loans_issued
+    );
+
+    let lender_losses: f64 = engine.get_entities().iter().map(|e| e.person_data.loan_losses).sum();
+    assert!((lender_losses - stats.total_written_off).abs() < 1e-6);
+}
+
+#[test]
+fn test_bankruptcy_restructures_before_discharging() {
+    let mut engine = SimulationEngine::new(default_config(true));
+    let result = engine.run();
+
+    let stats = result.loan_statistics.unwrap();
+    assert!(stats.total_loans_defaulted > 0);
+    assert!(stats.bankruptcies_restructured > 0);
+
+    let bankrupt: Vec<usize> = engine
+        .get_entities()
+        .iter()
+        .map(|e| e.person_data.bankruptcies)
+        .filter(|&count| count > 0)
+        .collect();
+    // Each bankrupt person was restructured once; any further bankruptcy was a discharge
+    assert_eq!(bankrupt.len(), stats.bankruptcies_restructured);
+    assert_eq!(
+        bankrupt.iter().map(|count| count - 1).sum::<usize>(),
+        stats.bankruptcies_discharged
+    );
+}
+
+#[test]
+fn test_defaults_conserve_money() {
+    let config =
+        test_config()
+            .entity_count(20)
+            .max_steps(50)
+            .strict_invariants()
+            .build_with(|cfg| {
+                cfg.enable_loans = true;
+                cfg.enable_p2p_lending = true;
+                cfg.loan_interest_rate = 0.1;
+                cfg.loan_repayment_period = 5;
+                cfg.loan_default_threshold = 1;
+                cfg.enable_bankruptcy = true;
+            });
+    let mut engine = SimulationEngine::new(config);
+    let result = engine.run();
+
+    // Write-offs and collateral seizure move no money, so strict mode must not panic
+    assert_eq!(result.failed_steps, 0);
+    assert!(result.loan_statistics.unwrap().total_loans_defaulted > 0);
+}
+
+#[test]
+fn test_default_statistics_survive_checkpoint() {
+    let mut engine = SimulationEngine::new(default_config(true));
+    for _ in 0..60 {
+        engine.step();
+    }
+    let file = NamedTempFile::new().unwrap();
+    engine.save_checkpoint(file.path()).unwrap();
+    let before = engine.get_current_result().loan_statistics.unwrap();
+
+    let restored = SimulationEngine::load_checkpoint(file.path()).unwrap();
+    let after = restored.get_current_result().loan_statistics.unwrap();
+    assert!(before.total_loans_defaulted > 0);
+    assert_eq!(after.total_loans_defaulted, before.total_loans_defaulted);
+    assert_eq!(after.total_written_off, before.total_written_off);
+    assert_eq!(after.bankruptcies_restructured, before.bankruptcies_restructured);
+}
+
+#[test]
+fn test_collateral_seizure_recovers_at_most_the_debt() {
+    let config = test_config().entity_count(30).max_steps(80).build_with(|cfg| {
+        cfg.enable_loans = true;
+        cfg.enable_p2p_lending = true;
+        cfg.loan_interest_rate = 0.1;
+        cfg.loan_repayment_period = 5;
+        cfg.loan_default_threshold = 1;
+        cfg.enable_assets = true;
+        cfg.asset_purchase_probability = 0.5;
+        cfg.min_money_for_asset_purchase = 0.0;
+    });
+    let mut engine = SimulationEngine::new(config);
+    let result = engine.run();
+
+    let stats = result.loan_statistics.unwrap();
+    let lent = result.lending_marketplace_statistics.unwrap().total_matched_volume;
+    assert!(stats.assets_seized > 0);
+    // Assets cost far more than these loans; only the balances owed, which interest
+    // cannot double within the repayment period, count as recovered
+    assert!(stats.collateral_seized_value <= lent * 2.0);
+}
+
+/// Leaves every lender without money before the loans phase.
+struct DrainLenders {
+    drained: Arc<Mutex<Vec<usize>>>,
+}
+
+impl StepPhase for DrainLenders {
+    fn name(&self) -> &str {
+        "drain_lenders"
+    }
+
+    fn run(&mut self, engine: &mut SimulationEngine) {
+        let mut drained = self.drained.lock().unwrap();
+        engine.with_engine_view(|view| {
+            let mut lenders: Vec<_> = view.loans().values().map(|loan| loan.lender_id).collect();
+            lenders.sort_unstable();
+            lenders.dedup();
+            for &id in &lenders {
+                let money = view.person(id).map_or(0.0, |lender| lender.money);
+                view.inject_money(id, -money);
+            }
+            *drained = lenders;
+        });
+    }
+}
+
+/// Records the lowest balance of the drained lenders after the loans phase.
+struct LowestLenderBalance {
+    drained: Arc<Mutex<Vec<usize>>>,
+    lowest: Arc<Mutex<f64>>,
+}
+
+impl StepPhase for LowestLenderBalance {
+    fn name(&self) -> &str {
+        "lowest_lender_balance"
+    }
+
+    fn run(&mut self, engine: &mut SimulationEngine) {
+        let mut lowest = self.lowest.lock().unwrap();
+        for &id in self.drained.lock().unwrap().iter() {
+            *lowest = lowest.min(engine.get_entities()[id].person_data.money);
+        }
+    }
+}
+
+#[test]
+fn test_collateral_surplus_refund_is_capped_at_lender_money() {
+    // Lenders hold no money when loans default, so they cannot refund any surplus
+    let step_phases: Vec<String> = BuiltinPhase::ALL
+        .iter()
+        .flat_map(|phase| match phase {
+            BuiltinPhase::Loans => vec!["drain_lenders", "loans", "lowest_lender_balance"],
+            _ => vec![phase.as_str()],
+        })
+        .map(String::from)
+        .collect();
+    let config = test_config().entity_count(30).max_steps(80).build_with(|cfg| {
+        cfg.enable_loans = true;
+        cfg.enable_p2p_lending = true;
+        cfg.loan_interest_rate = 0.1;
+        cfg.loan_repayment_period = 5;
+        cfg.loan_default_threshold = 1;
+        cfg.enable_assets = true;
+        cfg.asset_purchase_probability = 0.5;
+        cfg.min_money_for_asset_purchase = 0.0;
+        cfg.step_phases = step_phases;
+    });
+    let drained = Arc::new(Mutex::new(Vec::new()));
+    let lowest = Arc::new(Mutex::new(f64::INFINITY));
+    let mut engine = SimulationEngine::new(config);
+    engine
+        .register_phase(Box::new(DrainLenders { drained: Arc::clone(&drained) }))
+        .unwrap();
+    engine
+        .register_phase(Box::new(LowestLenderBalance { drained, lowest: Arc::clone(&lowest) }))
+        .unwrap();
+    let result = engine.run();
+
+    assert!(result.loan_statistics.unwrap().assets_seized > 0);
+    assert!(*lowest.lock().unwrap() >= 0.0);
+}
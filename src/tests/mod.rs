@@ -1,21 +1,46 @@
+// Count allocations so profiler tests can check allocation tracking
+#[global_allocator]
+static ALLOCATOR: crate::profiler::CountingAllocator = crate::profiler::CountingAllocator;
+
+mod auction_integration_tests;
+mod central_bank_integration_tests;
+mod checkpoint_integration_tests;
 mod comprehensive_scenario_tests;
 mod coverage_80_breakthrough;
 mod coverage_boost_tests;
 mod coverage_push_tests;
 mod coverage_sprint_80;
+mod crisis_integration_tests;
 mod currency_integration_tests;
+mod debugger_integration_tests;
+mod demographics_integration_tests;
 mod engine_focused_coverage;
+mod environment_integration_tests;
+mod epidemic_integration_tests;
 mod final_80_breakthrough;
 mod final_80_percent_push;
 mod final_push_tests;
+mod firm_integration_tests;
+mod geography_integration_tests;
+mod government_integration_tests;
+mod investment_integration_tests;
 mod laser_focus_80;
+mod lending_integration_tests;
+mod loan_default_tests;
+mod parameter_mutation_tests;
+mod phase_integration_tests;
+mod plugin_integration_tests;
+mod profiler_integration_tests;
 mod proptest_tests;
 mod reinforcement_learning_tests;
 mod satisficing_tests;
 mod scenario_integration_tests;
 pub mod test_helpers;
+mod timeline_integration_tests;
+mod transfer_integration_tests;
 mod ultimate_coverage_tests;
 mod ultra_final_tests;
+mod voting_integration_tests;
 
 #[cfg(test)]
 mod engine_tests {
@@ -0,0 +1,119 @@
+use crate::crisis::CrisisEvent;
+use crate::replay::{replay_action_log, SimulationAction};
+use crate::tests::test_helpers::test_config;
+use crate::transfers::TransferScheme;
+use crate::{SimulationEngine, SimulationError};
+
+#[test]
+fn test_parameter_changes_take_effect_mid_run() {
+    let config = test_config().entity_count(20).max_steps(20).build();
+    let mut engine = SimulationEngine::new(config);
+    for _ in 0..5 {
+        engine.step();
+    }
+    assert_eq!(engine.get_total_taxes_collected(), 0.0);
+
+    engine.set_parameter("tax_rate", "0.2").unwrap();
+    engine.set_parameter("transfer_scheme", "MeansTested").unwrap();
+    engine.set_parameter("government_debt_limit", "100").unwrap();
+    assert_eq!(engine.get_config().transfer_scheme, TransferScheme::MeansTested);
+    assert_eq!(engine.get_config().government_debt_limit, Some(100.0));
+    for _ in 0..5 {
+        engine.step();
+    }
+    assert!(engine.get_total_taxes_collected() > 0.0);
+}
+
+#[test]
+fn test_phase_disabled_mid_run_stops_running() {
+    // Without taxes and fees, only trades move money between persons
+    let config = test_config().entity_count(20).max_steps(20).build();
+    let initial_money = config.initial_money_per_person;
+    let mut engine = SimulationEngine::new(config);
+    let money = |engine: &SimulationEngine| -> Vec<f64> {
+        engine.get_entities().iter().map(|e| e.person_data.money).collect()
+    };
+    for _ in 0..10 {
+        engine.step();
+    }
+    let before = money(&engine);
+    assert!(before.iter().any(|&m| m != initial_money));
+
+    engine.set_parameter("disabled_step_phases", r#"["trades"]"#).unwrap();
+    for _ in 10..20 {
+        engine.step();
+    }
+    assert_eq!(money(&engine), before);
+    assert!(!engine.phase_names().iter().any(|name| name == "trades"));
+}
+
+#[test]
+fn test_rejected_parameter_changes_leave_config_untouched() {
+    let config = test_config().entity_count(10).max_steps(10).tax_rate(0.1).build();
+    let mut engine = SimulationEngine::new(config);
+    let before = serde_json::to_value(engine.get_config()).unwrap();
+
+    for (name, value) in [
+        ("tax_rate", "1.5"),
+        ("tax_rate", "high"),
+        ("entity_count", "50"),
+        ("enable_black_market", "true"),
+        ("no_such_parameter", "1"),
+        ("transfer_scheme", "Lottery"),
+        ("strict_invariant_mode", "true"),
+    ] {
+        let error = engine.set_parameter(name, value).unwrap_err();
+        assert!(matches!(error, SimulationError::ValidationError(_)), "{}: {}", name, error);
+    }
+    assert_eq!(serde_json::to_value(engine.get_config()).unwrap(), before);
+}
+
+#[test]
+fn test_triggered_crisis_lowers_prices() {
+    let config = test_config().entity_count(10).max_steps(10).build();
+    let mut engine = SimulationEngine::new(config);
+    engine.step();
+    let prices: f64 = engine.get_market().skills.values().map(|s| s.current_price).sum();
+
+    engine.trigger_crisis(CrisisEvent::MarketCrash, 0.8).unwrap();
+    let crashed: f64 = engine.get_market().skills.values().map(|s| s.current_price).sum();
+    assert!(crashed < prices);
+    assert!(engine.trigger_crisis(CrisisEvent::MarketCrash, 1.5).is_err());
+}
+
+#[test]
+fn test_interventions_are_recorded_and_replayed() {
+    let config = test_config().entity_count(10).max_steps(15).build();
+    let mut engine = SimulationEngine::new(config.clone());
+    engine.enable_action_recording();
+    for step in 0..15 {
+        if step == 4 {
+            engine.set_parameter("tax_rate", "0.3").unwrap();
+            engine.set_parameter("enable_tax_redistribution", "true").unwrap();
+        }
+        if step == 8 {
+            engine.trigger_crisis(CrisisEvent::DemandShock, 0.6).unwrap();
+        }
+        engine.step();
+    }
+    // A rejected change is not recorded
+    assert!(engine.set_parameter("tax_rate", "-1").is_err());
+
+    let log = engine.get_action_log().unwrap().clone();
+    let interventions: Vec<_> = log.actions.iter().filter(|a| a.is_intervention()).collect();
+    assert_eq!(interventions.len(), 3);
+    assert!(matches!(
+        interventions[0],
+        SimulationAction::ParameterChange { step: 4, parameter, value }
+            if parameter == "tax_rate" && value == "0.3"
+    ));
+    assert!(matches!(
+        interventions[2],
+        SimulationAction::TriggeredCrisis { step: 8, crisis: CrisisEvent::DemandShock, .. }
+    ));
+
+    // Replaying from the original configuration applies the same interventions
+    let report = replay_action_log(&log, &config).unwrap();
+    assert!(report.is_deterministic(), "unexpected divergence: {:?}", report.divergence);
+    assert_eq!(report.actions_verified, log.len());
+}
@@ -0,0 +1,183 @@
+use crate::phase::{BuiltinPhase, StepPhase};
+use crate::tests::test_helpers::test_config;
+use crate::SimulationEngine;
+
+/// Counts its runs and checks it sees every step exactly once.
+#[derive(Default)]
+struct Counter {
+    runs: usize,
+}
+
+impl StepPhase for Counter {
+    fn name(&self) -> &str {
+        "counter"
+    }
+
+    fn run(&mut self, engine: &mut SimulationEngine) {
+        assert_eq!(engine.current_step, self.runs);
+        self.runs += 1;
+    }
+}
+
+/// Pays every person a fixed amount through the engine view.
+struct Payout {
+    amount: f64,
+}
+
+impl StepPhase for Payout {
+    fn name(&self) -> &str {
+        "payout"
+    }
+
+    fn run(&mut self, engine: &mut SimulationEngine) {
+        let amount = self.amount;
+        engine.with_engine_view(|view| {
+            let ids: Vec<_> = view.persons().map(|p| p.id).collect();
+            for id in ids {
+                view.inject_money(id, amount);
+            }
+        });
+    }
+}
+
+/// Panics on the first step only.
+#[derive(Default)]
+struct Flaky {
+    runs: usize,
+}
+
+impl StepPhase for Flaky {
+    fn name(&self) -> &str {
+        "flaky"
+    }
+
+    fn run(&mut self, _engine: &mut SimulationEngine) {
+        self.runs += 1;
+        if self.runs == 1 {
+            panic!("flaky phase failure");
+        }
+    }
+}
+
+fn default_order() -> Vec<String> {
+    BuiltinPhase::ALL.iter().map(|phase| phase.as_str().to_string()).collect()
+}
+
+#[test]
+fn test_default_pipeline_runs_all_builtin_phases() {
+    let mut engine = SimulationEngine::new(test_config().build());
+    assert_eq!(engine.phase_names(), default_order());
+}
+
+#[test]
+fn test_explicit_default_order_matches_default_run() {
+    let config = test_config().max_steps(30).build();
+    let mut default_engine = SimulationEngine::new(config.clone());
+    let mut explicit_engine =
+        SimulationEngine::new(crate::SimulationConfig { step_phases: default_order(), ..config });
+
+    let default_result = default_engine.run();
+    let explicit_result = explicit_engine.run();
+
+    assert_eq!(
+        default_result.final_money_distribution,
+        explicit_result.final_money_distribution
+    );
+    assert_eq!(default_result.trades_per_step, explicit_result.trades_per_step);
+}
+
+#[test]
+fn test_disabled_trades_phase_records_zero_trades() {
+    let config = test_config()
+        .max_steps(10)
+        .build_with(|cfg| cfg.disabled_step_phases = vec!["trades".into()]);
+    let mut engine = SimulationEngine::new(config);
+    let result = engine.run();
+
+    assert_eq!(result.trade_volume_statistics.total_trades, 0);
+    assert_eq!(result.trades_per_step.len(), 10);
+    assert!(result.phase_timings.iter().all(|timing| timing.name != "trades"));
+}
+
+#[test]
+fn test_reordered_taxes_run_before_trades() {
+    let base = test_config().max_steps(20).tax_rate(0.1).enable_tax_redistribution(true);
+
+    let mut default_engine = SimulationEngine::new(base.clone().build());
+    let redistributed = default_engine.run().total_taxes_redistributed.unwrap_or(0.0);
+    assert!(redistributed > 0.0);
+
+    // Redistributing before any trade of the step leaves nothing to hand out
+    let mut reordered = SimulationEngine::new(base.build_with(|cfg| {
+        cfg.step_phases = ["demand", "pricing", "tax_redistribution", "trades"]
+            .iter()
+            .map(|name| name.to_string())
+            .collect();
+    }));
+    let result = reordered.run();
+    assert_eq!(result.total_taxes_redistributed.unwrap_or(0.0), 0.0);
+    assert!(result.trade_volume_statistics.total_trades > 0);
+}
+
+#[test]
+fn test_custom_phase_runs_every_step_with_timings() {
+    let mut engine = SimulationEngine::new(test_config().max_steps(15).build());
+    engine.register_phase(Box::new(Counter::default())).unwrap();
+    let result = engine.run();
+
+    assert_eq!(result.failed_steps, 0);
+    assert_eq!(engine.phase_names().last().map(String::as_str), Some("counter"));
+    let timing = result.phase_timings.iter().find(|t| t.name == "counter").unwrap();
+    assert_eq!(timing.calls, 15);
+    for timing in &result.phase_timings {
+        assert_eq!(timing.calls, 15);
+        assert!(timing.total_ms >= 0.0);
+    }
+}
+
+#[test]
+fn test_custom_phase_placed_by_step_phases() {
+    let config = test_config().max_steps(5).build_with(|cfg| {
+        cfg.step_phases = vec!["demand".into(), "payout".into(), "pricing".into(), "trades".into()];
+    });
+    let initial_total = config.initial_money_per_person * config.entity_count as f64;
+    let mut engine = SimulationEngine::new(config);
+    engine.register_phase(Box::new(Payout { amount: 1.0 })).unwrap();
+
+    assert_eq!(engine.phase_names(), vec!["demand", "payout", "pricing", "trades"]);
+    let result = engine.run();
+
+    let injected = engine.get_total_plugin_money_injected();
+    assert!((injected - 5.0 * 10.0).abs() < 1e-9);
+    let final_total: f64 = result.final_money_distribution.iter().sum();
+    assert!((final_total - initial_total - injected).abs() < 1e-6);
+}
+
+#[test]
+fn test_register_phase_rejects_builtin_names() {
+    let mut engine = SimulationEngine::new(test_config().build());
+    assert!(engine.register_phase(Box::new(BuiltinPhase::Savings)).is_err());
+}
+
+#[test]
+fn test_unlisted_custom_phase_does_not_run() {
+    let config = test_config()
+        .max_steps(3)
+        .build_with(|cfg| cfg.step_phases = vec!["trades".into()]);
+    let mut engine = SimulationEngine::new(config);
+    engine.register_phase(Box::new(Counter::default())).unwrap();
+    let result = engine.run();
+
+    assert!(result.phase_timings.iter().all(|timing| timing.name != "counter"));
+}
+
+#[test]
+fn test_panicking_custom_phase_is_kept() {
+    let mut engine = SimulationEngine::new(test_config().max_steps(5).build());
+    engine.register_phase(Box::new(Flaky::default())).unwrap();
+    let result = engine.run();
+
+    assert_eq!(result.failed_steps, 1);
+    assert_eq!(result.trades_per_step.len(), 5);
+    assert!(engine.phase_names().contains(&"flaky".to_string()));
+}
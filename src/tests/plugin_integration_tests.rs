@@ -0,0 +1,211 @@
+use crate::currency::{Currency, CurrencySystem};
+use crate::plugin::{EngineView, Plugin};
+use crate::tests::test_helpers::test_config;
+use crate::{SimulationEngine, Skill};
+use std::any::Any;
+
+/// Pins every skill to a fixed price after each market update.
+struct PriceCap {
+    cap: f64,
+}
+
+impl Plugin for PriceCap {
+    fn name(&self) -> &str {
+        "PriceCap"
+    }
+
+    fn on_after_price_update(&mut self, view: &mut EngineView) {
+        let ids: Vec<_> = view.market().skills.keys().cloned().collect();
+        for id in ids {
+            view.set_skill_price(&id, self.cap);
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+/// Vetoes every matched trade and counts how many it saw.
+#[derive(Default)]
+struct Embargo {
+    proposed: usize,
+}
+
+impl Plugin for Embargo {
+    fn name(&self) -> &str {
+        "Embargo"
+    }
+
+    fn on_before_trades(&mut self, view: &mut EngineView) {
+        for index in 0..view.proposed_trades().len() {
+            self.proposed += 1;
+            assert!(view.veto_trade(index));
+        }
+        assert!(!view.veto_trade(view.proposed_trades().len()));
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+/// Pays a fixed subsidy to every person each step.
+struct Subsidy {
+    amount: f64,
+}
+
+impl Plugin for Subsidy {
+    fn name(&self) -> &str {
+        "Subsidy"
+    }
+
+    fn on_before_trades(&mut self, view: &mut EngineView) {
+        let ids: Vec<_> = view.persons().map(|p| p.id).collect();
+        for id in ids {
+            assert!(view.inject_money(id, self.amount));
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+/// Introduces a new skill taught to person 0 on the first step.
+struct Innovator;
+
+impl Plugin for Innovator {
+    fn name(&self) -> &str {
+        "Innovator"
+    }
+
+    fn on_after_price_update(&mut self, view: &mut EngineView) {
+        if view.current_step() == 0 {
+            assert!(view.add_skill(Skill::new("Robotics".to_string(), 20.0), Some(0)));
+            // Adding the same skill twice is rejected
+            assert!(!view.add_skill(Skill::new("Robotics".to_string(), 20.0), None));
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+#[test]
+fn test_plugin_adjusts_prices() {
+    let mut engine = SimulationEngine::new(test_config().max_steps(20).build());
+    engine.register_plugin(Box::new(PriceCap { cap: 7.5 }));
+    engine.run();
+
+    for skill in engine.get_market().skills.values() {
+        assert_eq!(skill.current_price, 7.5);
+    }
+}
+
+#[test]
+fn test_plugin_price_is_clamped_to_limits() {
+    let config = test_config().max_steps(5).build();
+    let min_price = config.min_skill_price;
+    let mut engine = SimulationEngine::new(config);
+    engine.register_plugin(Box::new(PriceCap { cap: -100.0 }));
+    engine.run();
+
+    for skill in engine.get_market().skills.values() {
+        assert_eq!(skill.current_price, min_price);
+    }
+}
+
+#[test]
+fn test_plugin_vetoes_trades() {
+    let mut engine = SimulationEngine::new(test_config().max_steps(20).build());
+    engine.register_plugin(Box::new(Embargo::default()));
+    let result = engine.run();
+
+    assert_eq!(result.trade_volume_statistics.total_trades, 0);
+    let embargo = engine.plugin_registry_mut().get_mut("Embargo").unwrap();
+    let embargo = embargo.as_any_mut().downcast_mut::<Embargo>().unwrap();
+    assert!(embargo.proposed > 0);
+}
+
+#[test]
+fn test_plugin_money_injection_is_tracked() {
+    let config = test_config().entity_count(10).max_steps(20).strict_invariants().build();
+    let initial_total = config.initial_money_per_person * config.entity_count as f64;
+    let mut engine = SimulationEngine::new(config);
+    engine.register_plugin(Box::new(Subsidy { amount: 2.0 }));
+    let result = engine.run();
+
+    // Strict invariant mode would have panicked on an unexplained money change
+    assert_eq!(result.failed_steps, 0);
+    let injected = engine.get_total_plugin_money_injected();
+    assert!(injected > 0.0);
+    let final_total: f64 = result.final_money_distribution.iter().sum();
+    assert!((final_total - initial_total - injected).abs() < 1e-6);
+}
+
+#[test]
+fn test_plugin_money_injection_is_in_base_currency() {
+    let mut currency_system = CurrencySystem::default();
+    currency_system.add_currency(Currency::new("JPY".to_string(), 150.0));
+    let config = test_config()
+        .entity_count(10)
+        .max_steps(20)
+        .currency_system(currency_system)
+        .enable_multi_currency(true)
+        .strict_invariants()
+        .build_with(|cfg| cfg.fx_adjustment_rate = 0.0);
+    let initial_total = config.initial_money_per_person * config.entity_count as f64;
+    let mut engine = SimulationEngine::new(config);
+    engine.register_plugin(Box::new(Subsidy { amount: 2.0 }));
+    let result = engine.run();
+
+    // A JPY holder receives 300 yen, which is worth the same 2 base units
+    assert_eq!(result.failed_steps, 0);
+    let injected = engine.get_total_plugin_money_injected();
+    assert!(injected > 0.0);
+    let final_total: f64 = result.final_money_distribution.iter().sum();
+    assert!((final_total - initial_total - injected).abs() < 1e-6);
+}
+
+#[test]
+fn test_plugin_adds_skill() {
+    let mut engine = SimulationEngine::new(test_config().max_steps(30).build());
+    engine.register_plugin(Box::new(Innovator));
+    engine.run();
+
+    assert!(engine.get_market().skills.contains_key("Robotics"));
+    assert!(engine.get_entities()[0].person_data.has_skill(&"Robotics".to_string()));
+    // The provider counts towards the new skill's supply like any other seller
+    let supply = engine.get_market().supply_counts.get("Robotics").copied();
+    let providing = engine.get_entities()[0].active as usize;
+    assert_eq!(supply, Some(providing));
+}
+
+#[test]
+fn test_mutating_hooks_fire_on_manual_steps() {
+    let mut engine = SimulationEngine::new(test_config().build());
+    engine.register_plugin(Box::new(PriceCap { cap: 12.0 }));
+    engine.step();
+
+    for skill in engine.get_market().skills.values() {
+        assert_eq!(skill.current_price, 12.0);
+    }
+}
@@ -0,0 +1,97 @@
+use crate::profiler::ProfileReport;
+use crate::tests::test_helpers::test_config;
+use crate::SimulationEngine;
+use tempfile::NamedTempFile;
+
+fn profiled_config() -> crate::SimulationConfig {
+    test_config().max_steps(20).build_with(|cfg| cfg.enable_profiling = true)
+}
+
+#[test]
+fn test_profile_absent_by_default() {
+    let mut engine = SimulationEngine::new(test_config().max_steps(5).build());
+    let result = engine.run();
+    assert!(result.profile.is_none());
+    assert!(engine.get_profile_report().is_none());
+}
+
+#[test]
+fn test_profile_covers_every_phase() {
+    let mut engine = SimulationEngine::new(profiled_config());
+    let result = engine.run();
+    let profile = result.profile.expect("profile should be present");
+
+    assert_eq!(profile.steps, 20);
+    for name in engine.phase_names() {
+        let entry = profile.entry(&name).unwrap();
+        assert_eq!(entry.calls, 20, "phase {}", name);
+        assert!(entry.min_ms <= entry.avg_ms() && entry.avg_ms() <= entry.max_ms);
+    }
+    for name in ["step_setup", "step_bookkeeping", "trades/matching", "trades/execution"] {
+        assert_eq!(profile.entry(name).unwrap().calls, 20, "span {}", name);
+    }
+
+    // Nested spans are listed right after their parent and excluded from the total
+    let trades = profile.entries.iter().position(|e| e.name == "trades").unwrap();
+    assert_eq!(profile.entries[trades + 1].name, "trades/matching");
+    let top_level: f64 =
+        profile.entries.iter().filter(|e| e.depth() == 0).map(|e| e.total_ms).sum();
+    assert!((profile.total_ms - top_level).abs() < 1e-9);
+}
+
+#[test]
+fn test_profile_counts_allocations() {
+    let mut engine = SimulationEngine::new(profiled_config());
+    let profile = engine.run().profile.unwrap();
+
+    assert!(profile.allocation_tracking);
+    let allocations: u64 = profile.entries.iter().map(|e| e.allocations).sum();
+    assert!(allocations > 0);
+    assert!(profile.entry("demand").unwrap().allocated_bytes > 0);
+}
+
+#[test]
+fn test_profiling_does_not_change_results() {
+    let mut plain = SimulationEngine::new(test_config().max_steps(20).build());
+    let mut profiled = SimulationEngine::new(profiled_config());
+
+    assert_eq!(plain.run().final_money_distribution, profiled.run().final_money_distribution);
+}
+
+#[test]
+fn test_profile_json_round_trip() {
+    let mut engine = SimulationEngine::new(profiled_config());
+    let profile = engine.run().profile.unwrap();
+
+    let file = NamedTempFile::new().unwrap();
+    let path = file.path().to_str().unwrap();
+    profile.save_to_json(path).unwrap();
+    let loaded: ProfileReport =
+        serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap();
+    assert_eq!(loaded.steps, profile.steps);
+    assert_eq!(loaded.entries.len(), profile.entries.len());
+    for (loaded, original) in loaded.entries.iter().zip(&profile.entries) {
+        assert_eq!(loaded.name, original.name);
+        assert_eq!(loaded.calls, original.calls);
+        assert_eq!(loaded.allocations, original.allocations);
+        assert!((loaded.total_ms - original.total_ms).abs() < 1e-9);
+    }
+
+    // The table must render without panicking
+    profile.print_table();
+}
+
+#[test]
+fn test_profiling_resumes_after_checkpoint() {
+    let mut engine = SimulationEngine::new(profiled_config());
+    for _ in 0..5 {
+        engine.step();
+    }
+    let checkpoint = NamedTempFile::new().unwrap();
+    engine.save_checkpoint(checkpoint.path()).unwrap();
+
+    let mut restored = SimulationEngine::load_checkpoint(checkpoint.path()).unwrap();
+    restored.step();
+    // Profiles cover the steps run since the engine was created
+    assert_eq!(restored.get_profile_report().unwrap().steps, 1);
+}
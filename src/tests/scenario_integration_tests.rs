@@ -196,11 +196,7 @@ mod integration_tests {
     }
 
     /// Test determinism: same seed should produce same results
-    /// NOTE: This test is currently disabled because the simulation uses
-    /// non-deterministic elements (e.g., HashMap iteration order, parallel execution).
-    /// Consider this a known limitation for future improvement.
     #[test]
-    #[ignore]
     fn test_determinism_with_seed() {
         let config = SimulationConfig {
             entity_count: 10,
@@ -2211,3 +2207,124 @@ mod integration_tests {
         config.validate().unwrap();
     }
 }
+
+/// Integration tests for custom price update strategies
+#[cfg(test)]
+mod price_strategy_tests {
+    use crate::scenario::{PriceUpdateStrategy, Scenario};
+    use crate::tests::test_helpers::test_config;
+    use crate::{Market, SimulationEngine};
+    use rand::Rng;
+    use tempfile::NamedTempFile;
+
+    /// Posted-price learning: each skill's price moves toward a target sell-through
+    /// rate, remembering how many steps each skill went unsold.
+    #[derive(Debug, Clone, Default)]
+    struct PostedPriceLearning {
+        unsold_streak: std::collections::HashMap<String, u64>,
+    }
+
+    impl PriceUpdateStrategy for PostedPriceLearning {
+        fn name(&self) -> &str {
+            "PostedPriceLearning"
+        }
+
+        fn update_prices(&mut self, market: &mut Market, _rng: &mut dyn Rng) {
+            let mut ids: Vec<_> = market.skills.keys().cloned().collect();
+            ids.sort();
+            for id in ids {
+                let sold = market.sales_this_step.get(&id).copied().unwrap_or(0) > 0;
+                let streak = self.unsold_streak.entry(id.clone()).or_default();
+                *streak = if sold { 0 } else { *streak + 1 };
+                let factor = if sold {
+                    1.05
+                } else {
+                    1.0 - 0.01 * (*streak).min(10) as f64
+                };
+                let min = market.get_effective_min_price(&id);
+                let max = market.get_effective_max_price(&id);
+                let skill = market.skills.get_mut(&id).unwrap();
+                skill.current_price = (skill.current_price * factor).clamp(min, max);
+            }
+        }
+
+        fn checkpoint_state(&self) -> Option<serde_json::Value> {
+            serde_json::to_value(&self.unsold_streak).ok()
+        }
+
+        fn restore_state(&mut self, state: &serde_json::Value) {
+            if let Ok(streaks) = serde_json::from_value(state.clone()) {
+                self.unsold_streak = streaks;
+            }
+        }
+    }
+
+    #[test]
+    fn test_custom_strategy_drives_engine_prices() {
+        let config = test_config().entity_count(10).max_steps(30).build();
+        let mut baseline = SimulationEngine::new(config.clone());
+        let mut custom = SimulationEngine::new(config);
+        custom.set_price_strategy(Box::new(PostedPriceLearning::default()));
+
+        let baseline_result = baseline.run();
+        let custom_result = custom.run();
+
+        assert_eq!(custom.get_market().price_strategy_name(), Some("PostedPriceLearning"));
+        assert_ne!(
+            baseline_result.final_skill_prices.iter().map(|s| s.price).collect::<Vec<_>>(),
+            custom_result.final_skill_prices.iter().map(|s| s.price).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_custom_strategy_survives_checkpoint_by_reregistration() {
+        let config = test_config().entity_count(10).max_steps(30).build();
+        let mut engine = SimulationEngine::new(config);
+        engine.set_price_strategy(Box::new(PostedPriceLearning::default()));
+        for _ in 0..10 {
+            engine.step();
+        }
+
+        let checkpoint = NamedTempFile::new().unwrap();
+        engine.save_checkpoint(checkpoint.path()).unwrap();
+        let mut restored = SimulationEngine::load_checkpoint(checkpoint.path()).unwrap();
+        assert_eq!(restored.get_market().pending_price_strategy(), Some("PostedPriceLearning"));
+
+        // Stepping without the strategy falls back to the scenario updater
+        restored.step();
+
+        restored.set_price_strategy(Box::new(PostedPriceLearning::default()));
+        assert_eq!(restored.get_market().pending_price_strategy(), None);
+        assert_eq!(restored.get_market().price_strategy_name(), Some("PostedPriceLearning"));
+        restored.step();
+    }
+
+    #[test]
+    fn test_checkpoint_restores_scenario_price_updater() {
+        // DynamicPricing lowers prices of unsold skills; the restored engine must keep it
+        let config = test_config()
+            .entity_count(10)
+            .max_steps(20)
+            .scenario(Scenario::DynamicPricing)
+            .build();
+        let mut engine = SimulationEngine::new(config);
+        for _ in 0..5 {
+            engine.step();
+        }
+        let checkpoint = NamedTempFile::new().unwrap();
+        engine.save_checkpoint(checkpoint.path()).unwrap();
+        let mut restored = SimulationEngine::load_checkpoint(checkpoint.path()).unwrap();
+
+        engine.step();
+        restored.step();
+        let mut original_prices: Vec<_> =
+            engine.get_market().get_all_skill_prices().into_iter().collect();
+        let mut restored_prices: Vec<_> =
+            restored.get_market().get_all_skill_prices().into_iter().collect();
+        original_prices.sort_by(|a, b| a.0.cmp(&b.0));
+        restored_prices.sort_by(|a, b| a.0.cmp(&b.0));
+        for ((_, a), (_, b)) in original_prices.iter().zip(&restored_prices) {
+            assert!((a - b).abs() < 1e-6);
+        }
+    }
+}
@@ -98,6 +98,13 @@ impl Default for TestConfigBuilder {
     }
 }
 
+/// Starts from an existing configuration, such as one built by a test module's helper.
+impl From<SimulationConfig> for TestConfigBuilder {
+    fn from(config: SimulationConfig) -> Self {
+        Self { config }
+    }
+}
+
 #[allow(dead_code)] // Helper methods may not all be used immediately in tests
 impl TestConfigBuilder {
     /// Set the number of entities (persons) in the simulation
@@ -296,6 +303,17 @@ impl TestConfigBuilder {
         self
     }
 
+    /// Check money conservation after every step and panic on the first violation
+    ///
+    /// Non-negative wealth is not checked, as loans and sinks can leave balances negative.
+    pub fn strict_invariants(mut self) -> Self {
+        self.config.enable_invariant_checking = true;
+        self.config.strict_invariant_mode = true;
+        self.config.check_money_conservation = true;
+        self.config.check_non_negative_wealth = false;
+        self
+    }
+
     /// Build and return the final SimulationConfig
     pub fn build(self) -> SimulationConfig {
         self.config
@@ -0,0 +1,167 @@
+use crate::crisis::CrisisEvent;
+use crate::replay::{replay_action_log, SimulationAction};
+use crate::tests::test_helpers::test_config;
+use crate::timeline::{TimelineAction, TimelineEvent, IMPACT_WINDOW};
+use crate::SimulationEngine;
+use tempfile::NamedTempFile;
+
+fn set(step: usize, parameter: &str, value: serde_json::Value) -> TimelineEvent {
+    TimelineEvent {
+        step,
+        action: TimelineAction::SetParameter { parameter: parameter.to_string(), value },
+    }
+}
+
+fn price_cap(step: usize, skill: &str, max_price: f64, until_step: usize) -> TimelineEvent {
+    TimelineEvent {
+        step,
+        action: TimelineAction::PriceCap { skill: skill.to_string(), max_price, until_step },
+    }
+}
+
+#[test]
+fn test_parameter_changes_apply_at_their_step() {
+    let config = test_config().entity_count(20).max_steps(30).build_with(|cfg| {
+        cfg.timeline = vec![
+            set(10, "tax_rate", serde_json::json!(0.2)),
+            set(20, "enable_education", serde_json::json!(true)),
+        ];
+    });
+    let mut engine = SimulationEngine::new(config);
+    for _ in 0..10 {
+        engine.step();
+    }
+    assert_eq!(engine.get_total_taxes_collected(), 0.0);
+    assert_eq!(engine.get_config().tax_rate, 0.0);
+
+    engine.step();
+    assert_eq!(engine.get_config().tax_rate, 0.2);
+    assert!(engine.get_total_taxes_collected() > 0.0);
+    assert!(!engine.get_config().enable_education);
+
+    for _ in 11..30 {
+        engine.step();
+    }
+    assert!(engine.get_config().enable_education);
+    assert_eq!(engine.get_config().timeline.len(), 2);
+
+    // Both events were applied, so both are compared before and after
+    let stats = engine
+        .get_current_result()
+        .timeline_statistics
+        .expect("timeline statistics expected");
+    assert_eq!(stats.window, IMPACT_WINDOW);
+    assert_eq!(stats.interventions.len(), 2);
+    assert_eq!(stats.interventions[0].description, "step 10: set tax_rate = 0.2");
+    assert!(stats.interventions[0].trades_before > 0.0);
+}
+
+#[test]
+fn test_timeline_disables_phase_from_its_step() {
+    // Without taxes and fees, only trades move money between persons
+    let config = test_config().entity_count(20).max_steps(20).build_with(|cfg| {
+        cfg.timeline = vec![set(10, "disabled_step_phases", serde_json::json!(["trades"]))];
+    });
+    let initial_money = config.initial_money_per_person;
+    let mut engine = SimulationEngine::new(config);
+    let money = |engine: &SimulationEngine| -> Vec<f64> {
+        engine.get_entities().iter().map(|e| e.person_data.money).collect()
+    };
+    for _ in 0..10 {
+        engine.step();
+    }
+    let before = money(&engine);
+    assert!(before.iter().any(|&m| m != initial_money));
+
+    for _ in 10..20 {
+        engine.step();
+    }
+    assert_eq!(money(&engine), before);
+}
+
+#[test]
+fn test_forced_crisis_is_recorded_and_replays() {
+    let config = test_config().entity_count(10).max_steps(20).build_with(|cfg| {
+        cfg.timeline = vec![
+            TimelineEvent {
+                step: 8,
+                action: TimelineAction::Crisis {
+                    crisis: CrisisEvent::MarketCrash,
+                    severity: Some(0.8),
+                },
+            },
+            set(12, "tax_rate", serde_json::json!(0.1)),
+        ];
+    });
+    let mut engine = SimulationEngine::new(config.clone());
+    engine.enable_action_recording();
+    let mut prices = 0.0;
+    for step in 0..20 {
+        if step == 8 {
+            prices = engine.get_market().skills.values().map(|s| s.current_price).sum();
+        }
+        engine.step();
+    }
+
+    let log = engine.get_action_log().unwrap().clone();
+    assert!(log.actions.iter().any(|a| matches!(
+        a,
+        SimulationAction::CrisisEvent { step: 8, event_type, .. } if event_type == "Market Crash"
+    )));
+    // The timeline is part of the configuration, so it is not logged as an intervention
+    assert!(!log.actions.iter().any(|a| a.is_intervention()));
+
+    let report = replay_action_log(&log, &config).unwrap();
+    assert!(report.is_deterministic(), "unexpected divergence: {:?}", report.divergence);
+
+    let mut rerun = SimulationEngine::new(config);
+    for _ in 0..9 {
+        rerun.step();
+    }
+    let crashed: f64 = rerun.get_market().skills.values().map(|s| s.current_price).sum();
+    assert!(crashed < prices);
+}
+
+#[test]
+fn test_price_cap_holds_until_its_end_step() {
+    let config = test_config().entity_count(10).max_steps(40).build_with(|cfg| {
+        cfg.per_skill_price_limits.insert("Skill0".to_string(), (Some(2.0), None));
+        cfg.timeline = vec![price_cap(5, "Skill0", 6.0, 15), price_cap(5, "NoSuchSkill", 1.0, 6)];
+    });
+    let mut engine = SimulationEngine::new(config);
+    let skill = "Skill0".to_string();
+    for step in 0..30 {
+        engine.step();
+        let market = engine.get_market();
+        if (5..15).contains(&step) {
+            assert!(market.skills[&skill].current_price <= 6.0);
+            assert_eq!(market.per_skill_price_limits[&skill], (Some(2.0), Some(6.0)));
+        } else {
+            assert_eq!(market.per_skill_price_limits[&skill], (Some(2.0), None));
+        }
+    }
+}
+
+#[test]
+fn test_timeline_survives_checkpoint() {
+    let config = test_config().entity_count(10).max_steps(30).build_with(|cfg| {
+        cfg.timeline =
+            vec![price_cap(5, "Skill0", 6.0, 20), set(15, "tax_rate", serde_json::json!(0.25))];
+    });
+    let mut engine = SimulationEngine::new(config);
+    for _ in 0..10 {
+        engine.step();
+    }
+    let file = NamedTempFile::new().unwrap();
+    engine.save_checkpoint(file.path()).unwrap();
+
+    let mut restored = SimulationEngine::load_checkpoint(file.path()).unwrap();
+    assert_eq!(restored.get_config().timeline, engine.get_config().timeline);
+    assert_eq!(restored.get_market().per_skill_price_limits["Skill0"], (None, Some(6.0)));
+    for _ in 10..30 {
+        restored.step();
+    }
+    assert_eq!(restored.get_config().tax_rate, 0.25);
+    assert!(restored.get_total_taxes_collected() > 0.0);
+    assert!(!restored.get_market().per_skill_price_limits.contains_key("Skill0"));
+}
@@ -0,0 +1,171 @@
+use crate::currency::{Currency, CurrencySystem};
+use crate::tests::test_helpers::test_config;
+use crate::transfers::{self, TransferCondition, TransferFunding, TransferScheme};
+use crate::SimulationEngine;
+use tempfile::NamedTempFile;
+
+fn transfer_config(scheme: TransferScheme, funding: TransferFunding) -> crate::SimulationConfig {
+    test_config()
+        .entity_count(20)
+        .max_steps(30)
+        .tax_rate(0.1)
+        .strict_invariants()
+        .build_with(|cfg| {
+            cfg.enable_tax_redistribution = true;
+            cfg.transfer_scheme = scheme;
+            cfg.transfer_funding = funding;
+        })
+}
+
+#[test]
+fn test_equal_share_redistributes_every_tax() {
+    let config = transfer_config(TransferScheme::EqualShare, TransferFunding::Taxes);
+    let mut engine = SimulationEngine::new(config);
+    let result = engine.run();
+
+    // Redistributed taxes are back with persons, which conserves money
+    assert_eq!(result.failed_steps, 0);
+    let stats = result.transfer_statistics.expect("transfer statistics expected");
+    let collected = result.total_taxes_collected.unwrap();
+    assert!(collected > 0.0);
+    assert!((stats.total_paid - collected).abs() < 1e-6);
+    assert!((result.total_taxes_redistributed.unwrap() - stats.paid_from_taxes).abs() < 1e-9);
+    assert_eq!(stats.money_created, 0.0);
+    assert_eq!(stats.underfunded_steps, 0);
+    let received: f64 =
+        engine.get_entities().iter().map(|e| e.person_data.transfers_received).sum();
+    assert!((received - stats.total_paid).abs() < 1e-6);
+}
+
+#[test]
+fn test_tax_funded_basic_income_is_capped_by_taxes() {
+    let mut config = transfer_config(TransferScheme::UniversalBasicIncome, TransferFunding::Taxes);
+    config.transfer_amount = 50.0;
+    let mut engine = SimulationEngine::new(config);
+    let result = engine.run();
+
+    assert_eq!(result.failed_steps, 0);
+    let stats = result.transfer_statistics.unwrap();
+    assert!(stats.total_paid > 0.0);
+    assert!(stats.total_paid <= result.total_taxes_collected.unwrap() + 1e-6);
+    assert_eq!(stats.underfunded_steps, 30);
+    assert_eq!(stats.history.len(), 30);
+}
+
+#[test]
+fn test_money_financed_basic_income_lowers_inequality() {
+    let config =
+        transfer_config(TransferScheme::UniversalBasicIncome, TransferFunding::MoneyCreation);
+    let mut engine = SimulationEngine::new(config);
+    let result = engine.run();
+
+    // Every person receives 5 per step in new money, which the invariant accounts for
+    assert_eq!(result.failed_steps, 0);
+    let stats = result.transfer_statistics.unwrap();
+    assert!((stats.total_paid - 5.0 * 20.0 * 30.0).abs() < 1e-6);
+    assert_eq!(stats.money_created, stats.total_paid);
+    assert_eq!(stats.paid_from_taxes, 0.0);
+    assert_eq!(stats.payments, 20 * 30);
+    assert_eq!(stats.avg_payment, 5.0);
+    assert_eq!(engine.get_total_transfer_money_created(), stats.money_created);
+    assert!(stats.gini_after < stats.gini_before);
+    assert!(stats.poverty_rate_after <= stats.poverty_rate_before);
+}
+
+#[test]
+fn test_negative_income_tax_tops_up_low_incomes() {
+    let config = transfer_config(TransferScheme::NegativeIncomeTax, TransferFunding::MoneyCreation);
+    let mut engine = SimulationEngine::new(config);
+    let result = engine.run();
+
+    assert_eq!(result.failed_steps, 0);
+    let stats = result.transfer_statistics.unwrap();
+    assert!(stats.payments > 0);
+    // Nobody receives more than half the threshold in a step
+    assert!(stats.avg_payment <= 25.0 + 1e-9);
+    assert!(stats.history.iter().all(|&paid| paid <= 20.0 * 25.0 + 1e-9));
+}
+
+#[test]
+fn test_means_tested_transfers_reach_the_poorest_quarter() {
+    let config = transfer_config(TransferScheme::MeansTested, TransferFunding::MoneyCreation);
+    let mut engine = SimulationEngine::new(config);
+    let result = engine.run();
+
+    // Ranks 0 to 4 of 20 are below the 25th percentile
+    assert_eq!(result.failed_steps, 0);
+    let stats = result.transfer_statistics.unwrap();
+    assert_eq!(stats.payments, 5 * 30);
+    assert!(stats.history.iter().all(|&paid| (paid - 25.0).abs() < 1e-9));
+    let recipients = engine
+        .get_entities()
+        .iter()
+        .filter(|e| e.person_data.transfers_received > 0.0)
+        .count();
+    assert!(recipients >= 5);
+}
+
+#[test]
+fn test_transfer_statistics_are_in_base_currency() {
+    let mut currency_system = CurrencySystem::default();
+    currency_system.add_currency(Currency::new("GOLD".to_string(), 0.01));
+    let mut config = transfer_config(TransferScheme::MeansTested, TransferFunding::MoneyCreation);
+    config.enable_multi_currency = true;
+    config.currency_system = currency_system;
+    config.fx_adjustment_rate = 0.0;
+    let mut engine = SimulationEngine::new(config);
+    let result = engine.run();
+
+    // Wealth after transfers is the final distribution, which is in base units
+    let stats = result.transfer_statistics.unwrap();
+    let after = &result.final_money_distribution;
+    assert!((stats.gini_after - transfers::gini(after)).abs() < 1e-9);
+    assert_eq!(stats.poverty_rate_after, transfers::poverty_rate(after, stats.poverty_line));
+    assert!(stats.poverty_line > 1.0);
+}
+
+#[test]
+fn test_conditional_transfers_require_the_condition() {
+    // Nobody learns a skill without the education system
+    let config = transfer_config(TransferScheme::Conditional, TransferFunding::MoneyCreation);
+    let result = SimulationEngine::new(config).run();
+    let stats = result.transfer_statistics.unwrap();
+    assert_eq!(stats.payments, 0);
+    assert_eq!(stats.total_paid, 0.0);
+
+    // Without an epidemic everyone is healthy, so the means test alone decides
+    let mut config = transfer_config(TransferScheme::Conditional, TransferFunding::MoneyCreation);
+    config.transfer_condition = TransferCondition::Health;
+    let stats = SimulationEngine::new(config).run().transfer_statistics.unwrap();
+    assert_eq!(stats.payments, 5 * 30);
+}
+
+#[test]
+fn test_transfers_survive_checkpoint() {
+    let config =
+        transfer_config(TransferScheme::UniversalBasicIncome, TransferFunding::MoneyCreation);
+    let mut engine = SimulationEngine::new(config);
+    for _ in 0..10 {
+        engine.step();
+    }
+    let file = NamedTempFile::new().unwrap();
+    engine.save_checkpoint(file.path()).unwrap();
+    let before = engine.get_current_result().transfer_statistics.unwrap();
+
+    let restored = SimulationEngine::load_checkpoint(file.path()).unwrap();
+    let after = restored.get_current_result().transfer_statistics.unwrap();
+    assert_eq!(after.payments, before.payments);
+    assert_eq!(after.history.len(), 10);
+    assert!((after.total_paid - before.total_paid).abs() < 1e-9);
+    assert!((after.gini_before - before.gini_before).abs() < 1e-9);
+    assert!(restored.get_entities().iter().all(|e| e.person_data.transfers_received == 50.0));
+}
+
+#[test]
+fn test_no_transfer_statistics_without_redistribution() {
+    let config = test_config().entity_count(10).max_steps(10).tax_rate(0.1).build();
+    let mut engine = SimulationEngine::new(config);
+    let result = engine.run();
+    assert!(result.transfer_statistics.is_none());
+    assert!(engine.get_entities().iter().all(|e| e.person_data.transfers_received == 0.0));
+}
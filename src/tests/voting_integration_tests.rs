@@ -0,0 +1,122 @@
+use crate::tests::test_helpers::test_config;
+use crate::voting::{ProposalType, VotingMethod};
+use crate::SimulationEngine;
+
+fn voting_config(method: VotingMethod) -> crate::SimulationConfig {
+    test_config()
+        .entity_count(30)
+        .max_steps(200)
+        .tax_rate(0.1)
+        .transaction_fee(0.05)
+        .build_with(|cfg| {
+            cfg.enable_voting = true;
+            cfg.voting_method = method;
+            cfg.proposal_probability = 0.5;
+            cfg.proposal_duration = 5;
+            cfg.voting_participation_rate = 0.5;
+        })
+}
+
+#[test]
+fn test_voting_creates_and_tallies_proposals() {
+    let mut engine = SimulationEngine::new(voting_config(VotingMethod::SimpleMajority));
+    let result = engine.run();
+
+    let stats = result.voting_statistics.expect("voting statistics should be present");
+    assert_eq!(stats.voting_method, VotingMethod::SimpleMajority);
+    assert!(stats.total_proposals > 0);
+    assert!(stats.completed_proposals > 0);
+    assert!(stats.total_votes_cast > 0);
+    assert_eq!(stats.completed_proposals, stats.passed_proposals + stats.failed_proposals);
+    assert!((-1.0..=1.0).contains(&stats.avg_vote_margin));
+}
+
+#[test]
+fn test_passed_proposals_change_live_policy() {
+    let mut engine = SimulationEngine::new(voting_config(VotingMethod::SimpleMajority));
+    let result = engine.run();
+
+    let stats = result.voting_statistics.unwrap();
+    assert!(!stats.policy_changes.is_empty(), "expected at least one enacted policy");
+
+    for change in &stats.policy_changes {
+        assert!(change.votes_in_favor > change.votes_against);
+        assert!(change.vote_margin > 0.0 && change.vote_margin <= 1.0);
+    }
+
+    // The final configuration reflects the last enacted change of each kind
+    let config = engine.get_config();
+    let last_value = |name: &str| {
+        stats
+            .policy_changes
+            .iter()
+            .rev()
+            .find(|c| c.proposal_type.parameter_name() == Some(name))
+            .map(|c| c.new_value)
+    };
+    if let Some(tax_rate) = last_value("tax_rate") {
+        assert_eq!(config.tax_rate, tax_rate);
+    }
+    if let Some(base_price) = last_value("base_skill_price") {
+        assert_eq!(config.base_skill_price, base_price);
+    }
+    if let Some(fee) = last_value("transaction_fee") {
+        assert_eq!(config.transaction_fee, fee);
+    }
+}
+
+#[test]
+fn test_policy_changes_respect_bounds() {
+    let mut engine = SimulationEngine::new(voting_config(VotingMethod::WeightedByWealth));
+    let result = engine.run();
+
+    for change in result.voting_statistics.unwrap().policy_changes {
+        match change.proposal_type {
+            ProposalType::TaxRateChange { .. } => {
+                assert!((0.0..=0.5).contains(&change.new_value))
+            },
+            ProposalType::TransactionFeeChange { .. } => {
+                assert!((0.0..=0.2).contains(&change.new_value))
+            },
+            ProposalType::BasePriceChange { .. } => assert!(change.new_value >= 1.0),
+            ProposalType::Generic { .. } => panic!("generic proposals have no policy effect"),
+        }
+    }
+}
+
+#[test]
+fn test_voting_methods_run_to_completion() {
+    for method in [
+        VotingMethod::SimpleMajority,
+        VotingMethod::WeightedByWealth,
+        VotingMethod::QuadraticVoting,
+    ] {
+        let mut engine = SimulationEngine::new(voting_config(method));
+        let result = engine.run();
+        assert_eq!(result.total_steps, 200);
+        assert_eq!(result.voting_statistics.unwrap().voting_method, method);
+    }
+}
+
+#[test]
+fn test_voting_is_deterministic() {
+    let run = || {
+        let mut engine = SimulationEngine::new(voting_config(VotingMethod::QuadraticVoting));
+        engine.run().voting_statistics.unwrap()
+    };
+    let first = run();
+    let second = run();
+    assert_eq!(first.total_votes_cast, second.total_votes_cast);
+    assert_eq!(first.policy_changes.len(), second.policy_changes.len());
+    for (a, b) in first.policy_changes.iter().zip(&second.policy_changes) {
+        assert_eq!(a.step, b.step);
+        assert_eq!(a.new_value, b.new_value);
+    }
+}
+
+#[test]
+fn test_voting_disabled_has_no_statistics() {
+    let mut engine = SimulationEngine::new(test_config().max_steps(10).build());
+    let result = engine.run();
+    assert!(result.voting_statistics.is_none());
+}
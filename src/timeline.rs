@@ -0,0 +1,232 @@
+//! # Timeline Module
+//!
+//! Interventions scheduled at given steps of a run, listed under `timeline` in the
+//! configuration. They model policies introduced mid-simulation:
+//!
+//! - [`TimelineAction::SetParameter`]: change a configuration parameter, such as
+//!   `tax_rate` or `enable_education`, from the step on
+//! - [`TimelineAction::Crisis`]: force a crisis at the step
+//! - [`TimelineAction::PriceCap`]: cap the price of one skill from the step until
+//!   `until_step`
+//!
+//! The engine applies the events due at a step before running it, in the order they
+//! are listed, so a timeline replays exactly with the same seed. For before/after
+//! analysis, [`intervention_impact`] compares trading activity and wealth over the
+//! [`IMPACT_WINDOW`] steps before and after an event.
+//!
+//! # Examples
+//!
+//! ```
+//! use community_simulation::timeline::{TimelineAction, TimelineEvent};
+//!
+//! let yaml = "\
+//! - { step: 200, action: set_parameter, parameter: tax_rate, value: 0.3 }
+//! - { step: 350, action: crisis, crisis: DemandShock }
+//! - { step: 100, action: price_cap, skill: Skill3, max_price: 40.0, until_step: 300 }
+//! ";
+//! let timeline: Vec<TimelineEvent> = serde_yaml::from_str(yaml).unwrap();
+//! assert_eq!(timeline[0].step, 200);
+//! assert!(matches!(timeline[1].action, TimelineAction::Crisis { severity: None, .. }));
+//! assert_eq!(timeline[2].to_string(), "step 100: cap Skill3 at 40.00 until step 300");
+//! ```
+
+use crate::crisis::CrisisEvent;
+use crate::result::WealthStatsSnapshot;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Number of steps before and after an intervention compared by [`intervention_impact`].
+pub const IMPACT_WINDOW: usize = 20;
+
+/// An intervention scheduled at a step.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TimelineEvent {
+    /// Step before which the intervention is applied
+    pub step: usize,
+    /// What happens at the step
+    #[serde(flatten)]
+    pub action: TimelineAction,
+}
+
+/// What a timeline event does.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum TimelineAction {
+    /// Change a configuration parameter, as `SimulationEngine::set_parameter` does
+    SetParameter { parameter: String, value: serde_json::Value },
+    /// Force a crisis; the severity defaults to `crisis_severity`
+    Crisis {
+        crisis: CrisisEvent,
+        #[serde(default)]
+        severity: Option<f64>,
+    },
+    /// Cap the price of a skill from the event's step until `until_step`, when the
+    /// skill's configured price limits apply again
+    PriceCap { skill: String, max_price: f64, until_step: usize },
+}
+
+impl fmt::Display for TimelineEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "step {}: ", self.step)?;
+        match &self.action {
+            TimelineAction::SetParameter { parameter, value } => {
+                write!(f, "set {} = {}", parameter, value)
+            },
+            TimelineAction::Crisis { crisis, severity: Some(severity) } => {
+                write!(f, "{} (severity {:.2})", crisis.name(), severity)
+            },
+            TimelineAction::Crisis { crisis, severity: None } => write!(f, "{}", crisis.name()),
+            TimelineAction::PriceCap { skill, max_price, until_step } => {
+                write!(f, "cap {} at {:.2} until step {}", skill, max_price, until_step)
+            },
+        }
+    }
+}
+
+/// Trading activity and wealth before and after an intervention, averaged per step.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct InterventionImpact {
+    /// Step at which the intervention was applied
+    pub step: usize,
+    /// The intervention, as in the configuration
+    pub description: String,
+    /// Trades per step before the intervention
+    pub trades_before: f64,
+    /// Trades per step from the intervention on
+    pub trades_after: f64,
+    /// Trade volume per step before the intervention
+    pub volume_before: f64,
+    /// Trade volume per step from the intervention on
+    pub volume_after: f64,
+    /// Average money per person before the intervention
+    pub avg_money_before: f64,
+    /// Average money per person from the intervention on
+    pub avg_money_after: f64,
+    /// Gini coefficient of wealth before the intervention
+    pub gini_before: f64,
+    /// Gini coefficient of wealth from the intervention on
+    pub gini_after: f64,
+}
+
+/// Compares the `window` steps before an event with the `window` steps starting at it.
+///
+/// `trades_per_step` and `volume_per_step` are indexed by step. Steps that have not
+/// run yet are left out of the averages, which are 0.0 when no step is left.
+pub fn intervention_impact(
+    event: &TimelineEvent,
+    trades_per_step: &[usize],
+    volume_per_step: &[f64],
+    wealth_history: &[WealthStatsSnapshot],
+    window: usize,
+) -> InterventionImpact {
+    let before = event.step.saturating_sub(window)..event.step;
+    let after = event.step..event.step + window;
+    let per_step = |values: &[f64], steps: &std::ops::Range<usize>| {
+        let end = steps.end.min(values.len());
+        let start = steps.start.min(end);
+        mean(&values[start..end])
+    };
+    let wealth = |steps: &std::ops::Range<usize>, field: fn(&WealthStatsSnapshot) -> f64| {
+        let values: Vec<f64> =
+            wealth_history.iter().filter(|s| steps.contains(&s.step)).map(field).collect();
+        mean(&values)
+    };
+    let trades: Vec<f64> = trades_per_step.iter().map(|&t| t as f64).collect();
+
+    InterventionImpact {
+        step: event.step,
+        description: event.to_string(),
+        trades_before: per_step(&trades, &before),
+        trades_after: per_step(&trades, &after),
+        volume_before: per_step(volume_per_step, &before),
+        volume_after: per_step(volume_per_step, &after),
+        avg_money_before: wealth(&before, |s| s.average),
+        avg_money_after: wealth(&after, |s| s.average),
+        gini_before: wealth(&before, |s| s.gini_coefficient),
+        gini_after: wealth(&after, |s| s.gini_coefficient),
+    }
+}
+
+fn mean(values: &[f64]) -> f64 {
+    if values.is_empty() {
+        0.0
+    } else {
+        values.iter().sum::<f64>() / values.len() as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_intervention_impact_windows() {
+        let event = TimelineEvent {
+            step: 4,
+            action: TimelineAction::Crisis { crisis: CrisisEvent::MarketCrash, severity: None },
+        };
+        let trades = [10, 10, 8, 12, 2, 4, 3];
+        let volume = [100.0, 100.0, 80.0, 120.0, 20.0, 40.0, 30.0];
+        let history: Vec<WealthStatsSnapshot> = (0..7)
+            .map(|step| WealthStatsSnapshot {
+                step,
+                average: if step < 4 { 100.0 } else { 80.0 },
+                median: 0.0,
+                std_dev: 0.0,
+                min_money: 0.0,
+                max_money: 0.0,
+                gini_coefficient: step as f64 / 10.0,
+                herfindahl_index: 0.0,
+                top_10_percent_share: 0.0,
+                top_1_percent_share: 0.0,
+                bottom_50_percent_share: 0.0,
+            })
+            .collect();
+
+        let impact = intervention_impact(&event, &trades, &volume, &history, 3);
+        assert_eq!(impact.description, "step 4: Market Crash");
+        assert_eq!(impact.trades_before, 10.0);
+        assert_eq!(impact.trades_after, 3.0);
+        assert_eq!(impact.volume_after, 30.0);
+        assert_eq!(impact.avg_money_before, 100.0);
+        assert_eq!(impact.avg_money_after, 80.0);
+        assert!((impact.gini_before - 0.2).abs() < 1e-12);
+        assert!((impact.gini_after - 0.5).abs() < 1e-12);
+
+        // Nothing has run from step 10 on
+        let late = TimelineEvent { step: 10, ..event };
+        let impact = intervention_impact(&late, &trades, &volume, &history, 3);
+        assert_eq!(impact.trades_after, 0.0);
+        assert_eq!(impact.trades_before, 0.0);
+    }
+
+    #[test]
+    fn test_timeline_event_parses_from_toml() {
+        let toml = r#"
+[[timeline]]
+step = 500
+action = "set_parameter"
+parameter = "enable_education"
+value = true
+
+[[timeline]]
+step = 350
+action = "crisis"
+crisis = "DemandShock"
+severity = 0.5
+"#;
+        #[derive(Deserialize)]
+        struct Config {
+            timeline: Vec<TimelineEvent>,
+        }
+        let config: Config = toml::from_str(toml).unwrap();
+        assert_eq!(
+            config.timeline[0].action,
+            TimelineAction::SetParameter {
+                parameter: "enable_education".to_string(),
+                value: serde_json::Value::Bool(true),
+            }
+        );
+        assert_eq!(config.timeline[1].to_string(), "step 350: Demand Shock (severity 0.50)");
+    }
+}
@@ -0,0 +1,168 @@
+//! # Transfers Module
+//!
+//! Transfer schemes that decide who receives the taxes redistributed each step when
+//! `enable_tax_redistribution` is set:
+//!
+//! - [`TransferScheme::EqualShare`]: the step's taxes are shared equally (default)
+//! - [`TransferScheme::UniversalBasicIncome`]: every person receives the same amount
+//! - [`TransferScheme::NegativeIncomeTax`]: persons earning below a threshold receive
+//!   a share of the gap
+//! - [`TransferScheme::MeansTested`]: persons below a wealth percentile receive an amount
+//! - [`TransferScheme::Conditional`]: means-tested persons receive an amount if they
+//!   meet a [`TransferCondition`]
+//!
+//! Transfers are funded by the step's taxes, and scaled down when the taxes do not
+//! cover them, or paid in full with newly created money ([`TransferFunding`]). The
+//! helpers here measure their effect: the poverty rate and the Gini coefficient of
+//! wealth before and after the transfers each person received.
+//!
+//! # Examples
+//!
+//! ```
+//! use community_simulation::transfers::{negative_income_tax, poverty_rate};
+//!
+//! // Half of the gap between income and the threshold is paid out
+//! assert_eq!(negative_income_tax(20.0, 50.0, 0.5), 15.0);
+//! assert_eq!(negative_income_tax(80.0, 50.0, 0.5), 0.0);
+//!
+//! // One of four persons holds less than the poverty line
+//! assert_eq!(poverty_rate(&[10.0, 60.0, 80.0, 120.0], 40.0), 0.25);
+//! ```
+
+use crate::result::calculate_gini_coefficient;
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumString;
+
+/// Share of the median wealth below which a person counts as poor.
+pub const POVERTY_LINE_SHARE: f64 = 0.5;
+
+/// Who receives the redistributed taxes.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, EnumString, PartialEq, Eq, Default)]
+pub enum TransferScheme {
+    /// Share the step's taxes equally among all persons (default)
+    #[default]
+    EqualShare,
+    /// Pay `transfer_amount` to every person
+    UniversalBasicIncome,
+    /// Pay `negative_income_tax_rate` of the gap between a person's sales in the step
+    /// and `negative_income_tax_threshold`
+    NegativeIncomeTax,
+    /// Pay `transfer_amount` to persons below `means_test_percentile` of wealth
+    MeansTested,
+    /// Pay `transfer_amount` to means-tested persons who meet `transfer_condition`
+    Conditional,
+}
+
+/// How transfers are paid for.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, EnumString, PartialEq, Eq, Default)]
+pub enum TransferFunding {
+    /// Out of the taxes collected in the step, scaled down when they fall short (default)
+    #[default]
+    Taxes,
+    /// In full, with newly created money
+    MoneyCreation,
+}
+
+/// What a conditional transfer requires of its recipients.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, EnumString, PartialEq, Eq, Default)]
+pub enum TransferCondition {
+    /// The person has learned at least one skill through education (default)
+    #[default]
+    Education,
+    /// The person is healthy: susceptible, recovered or vaccinated
+    Health,
+}
+
+/// Returns the negative income tax paid to a person with the given income.
+pub fn negative_income_tax(income: f64, threshold: f64, rate: f64) -> f64 {
+    (threshold - income).max(0.0) * rate
+}
+
+/// Returns the poverty line for the given wealth distribution:
+/// [`POVERTY_LINE_SHARE`] of the median.
+pub fn poverty_line(wealth: &[f64]) -> f64 {
+    if wealth.is_empty() {
+        return 0.0;
+    }
+    let mut sorted = wealth.to_vec();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+    let mid = sorted.len() / 2;
+    let median = if sorted.len().is_multiple_of(2) {
+        (sorted[mid - 1] + sorted[mid]) / 2.0
+    } else {
+        sorted[mid]
+    };
+    median * POVERTY_LINE_SHARE
+}
+
+/// Returns the share of persons whose wealth is below the poverty line
+/// (0.0 for an empty distribution).
+pub fn poverty_rate(wealth: &[f64], line: f64) -> f64 {
+    if wealth.is_empty() {
+        return 0.0;
+    }
+    wealth.iter().filter(|&&w| w < line).count() as f64 / wealth.len() as f64
+}
+
+/// Returns the Gini coefficient of an unsorted wealth distribution.
+pub fn gini(wealth: &[f64]) -> f64 {
+    let mut sorted = wealth.to_vec();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+    calculate_gini_coefficient(&sorted, sorted.iter().sum())
+}
+
+/// Running totals of the transfers paid.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TransferLedger {
+    /// Transfers paid out of taxes
+    pub paid_from_taxes: f64,
+    /// Transfers paid with newly created money
+    pub money_created: f64,
+    /// Number of payments made to persons
+    pub payments: usize,
+    /// Steps in which transfers were wanted but the taxes could not cover them in full
+    pub underfunded_steps: usize,
+    /// Transfers paid in each step
+    pub history: Vec<f64>,
+}
+
+impl TransferLedger {
+    /// Returns the fiscal cost of the transfers: everything paid, however funded.
+    pub fn total_paid(&self) -> f64 {
+        self.paid_from_taxes + self.money_created
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_negative_income_tax() {
+        assert_eq!(negative_income_tax(0.0, 50.0, 0.5), 25.0);
+        assert_eq!(negative_income_tax(50.0, 50.0, 0.5), 0.0);
+        assert_eq!(negative_income_tax(10.0, 50.0, 0.0), 0.0);
+    }
+
+    #[test]
+    fn test_poverty_line_and_rate() {
+        assert_eq!(poverty_line(&[]), 0.0);
+        assert_eq!(poverty_line(&[100.0, 20.0, 60.0]), 30.0);
+        assert_eq!(poverty_line(&[100.0, 20.0, 60.0, 80.0]), 35.0);
+        assert_eq!(poverty_rate(&[], 10.0), 0.0);
+        assert_eq!(poverty_rate(&[5.0, 10.0, 20.0, -3.0], 10.0), 0.5);
+    }
+
+    #[test]
+    fn test_gini_of_unsorted_wealth() {
+        assert_eq!(gini(&[50.0, 50.0, 50.0]), 0.0);
+        assert!(gini(&[100.0, 0.0, 0.0, 0.0]) > gini(&[40.0, 20.0, 20.0, 20.0]));
+    }
+
+    #[test]
+    fn test_total_paid() {
+        let ledger =
+            TransferLedger { paid_from_taxes: 12.0, money_created: 8.5, ..Default::default() };
+        assert_eq!(ledger.total_paid(), 20.5);
+    }
+}
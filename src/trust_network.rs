@@ -1,6 +1,7 @@
 use crate::person::PersonId;
 use petgraph::algo::dijkstra;
 use petgraph::graph::{NodeIndex, UnGraph};
+use petgraph::visit::EdgeRef;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -124,6 +125,24 @@ impl TrustNetwork {
         }
     }
 
+    /// Removes a person who left the simulation, together with their friendships.
+    ///
+    /// Trust no longer propagates through the removed person. Their graph node is
+    /// kept without edges so the indices of the other persons' nodes stay valid.
+    ///
+    /// # Arguments
+    /// * `person_id` - The ID of the person to remove
+    pub fn remove_person(&mut self, person_id: PersonId) {
+        let Some(node) = self.person_to_node.remove(&person_id) else {
+            return;
+        };
+        let edges: Vec<_> = self.graph.edges(node).map(|edge| edge.id()).collect();
+        for edge in edges {
+            self.graph.remove_edge(edge);
+        }
+        self.trust_cache.clear();
+    }
+
     /// Calculates the trust level between two persons based on their social distance.
     /// Uses cached results when available to improve performance.
     ///
@@ -317,6 +336,28 @@ mod tests {
         assert_eq!(network.get_trust_level(4, 1), TrustLevel::ThirdDegree);
     }
 
+    #[test]
+    fn test_removed_person_breaks_trust_paths() {
+        let mut network = TrustNetwork::new();
+        // 1 -- 2 -- 3, then 2 leaves
+        network.add_friendship(1, 2);
+        network.add_friendship(2, 3);
+        network.add_person(4);
+        assert_eq!(network.get_trust_level(1, 3), TrustLevel::SecondDegree);
+
+        network.remove_person(2);
+        assert_eq!(network.get_trust_level(1, 3), TrustLevel::None);
+        assert_eq!(network.get_trust_level(1, 2), TrustLevel::None);
+
+        let stats = network.get_statistics();
+        assert_eq!(stats.total_persons, 3);
+        assert_eq!(stats.total_friendships, 0);
+
+        // Remaining persons can still become friends
+        network.add_friendship(3, 4);
+        assert_eq!(network.get_trust_level(3, 4), TrustLevel::Direct);
+    }
+
     #[test]
     fn test_no_trust_beyond_third_degree() {
         let mut network = TrustNetwork::new();
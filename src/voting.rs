@@ -11,6 +11,7 @@
 //!   - Weighted by Wealth: Voting power proportional to money
 //!   - Quadratic Voting: Square root of wealth for balanced influence
 //! - **Voting Statistics**: Track participation, outcomes, and voting patterns
+//! - **Policy Changes**: Record enacted proposals with their vote margins
 //!
 //! ## Example
 //!
@@ -69,6 +70,18 @@ pub enum ProposalType {
     Generic { description: String },
 }
 
+impl ProposalType {
+    /// Name of the configuration parameter this proposal changes (`None` for generic proposals)
+    pub fn parameter_name(&self) -> Option<&'static str> {
+        match self {
+            ProposalType::TaxRateChange { .. } => Some("tax_rate"),
+            ProposalType::BasePriceChange { .. } => Some("base_skill_price"),
+            ProposalType::TransactionFeeChange { .. } => Some("transaction_fee"),
+            ProposalType::Generic { .. } => None,
+        }
+    }
+}
+
 /// A single vote cast by a person
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Vote {
@@ -118,6 +131,44 @@ pub struct VotingResult {
     pub tallied_at: usize,
 }
 
+impl VotingResult {
+    /// Normalized vote margin: `(favor - against) / (favor + against)`.
+    ///
+    /// Ranges from -1.0 (unanimous rejection) to 1.0 (unanimous approval).
+    /// Returns 0.0 when no voting power was cast.
+    pub fn margin(&self) -> f64 {
+        let total = self.votes_in_favor + self.votes_against;
+        if total > 0.0 {
+            (self.votes_in_favor - self.votes_against) / total
+        } else {
+            0.0
+        }
+    }
+}
+
+/// A policy parameter change enacted by a passed proposal
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PolicyChange {
+    /// Simulation step when the change took effect
+    pub step: usize,
+    /// Proposal that enacted the change
+    pub proposal_id: ProposalId,
+    /// Type of the enacted proposal
+    pub proposal_type: ProposalType,
+    /// Parameter value before the change
+    pub old_value: f64,
+    /// Parameter value after the change
+    pub new_value: f64,
+    /// Total voting power in favor
+    pub votes_in_favor: f64,
+    /// Total voting power against
+    pub votes_against: f64,
+    /// Number of persons who voted
+    pub total_voters: usize,
+    /// Normalized vote margin (see [`VotingResult::margin`])
+    pub vote_margin: f64,
+}
+
 /// Main voting system managing all proposals and votes
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VotingSystem {
@@ -131,6 +182,9 @@ pub struct VotingSystem {
     next_proposal_id: ProposalId,
     /// Total number of votes cast across all proposals
     total_votes_cast: usize,
+    /// Policy changes enacted by passed proposals, in chronological order
+    #[serde(default)]
+    policy_changes: Vec<PolicyChange>,
 }
 
 impl VotingSystem {
@@ -142,6 +196,7 @@ impl VotingSystem {
             results: Vec::new(),
             next_proposal_id: 1,
             total_votes_cast: 0,
+            policy_changes: Vec::new(),
         }
     }
 
@@ -306,7 +361,7 @@ impl VotingSystem {
     ///
     /// Vector of results from newly tallied proposals
     pub fn tally_expired_proposals(&mut self, current_step: usize) -> Vec<VotingResult> {
-        let expired_ids: Vec<ProposalId> = self
+        let mut expired_ids: Vec<ProposalId> = self
             .proposals
             .values()
             .filter(|p| {
@@ -314,6 +369,8 @@ impl VotingSystem {
             })
             .map(|p| p.id)
             .collect();
+        // Tally in creation order so that policies take effect deterministically
+        expired_ids.sort_unstable();
 
         expired_ids
             .into_iter()
@@ -331,6 +388,16 @@ impl VotingSystem {
         &self.results
     }
 
+    /// Record a policy change enacted by a passed proposal
+    pub fn record_policy_change(&mut self, change: PolicyChange) {
+        self.policy_changes.push(change);
+    }
+
+    /// Get all enacted policy changes in chronological order
+    pub fn policy_changes(&self) -> &[PolicyChange] {
+        &self.policy_changes
+    }
+
     /// Get a specific proposal by ID
     pub fn get_proposal(&self, proposal_id: ProposalId) -> Option<&Proposal> {
         self.proposals.get(&proposal_id)
@@ -351,6 +418,11 @@ impl VotingSystem {
         } else {
             0.0
         };
+        let avg_vote_margin = if completed_proposals > 0 {
+            self.results.iter().map(|r| r.margin()).sum::<f64>() / completed_proposals as f64
+        } else {
+            0.0
+        };
 
         VotingStatistics {
             total_proposals,
@@ -360,7 +432,9 @@ impl VotingSystem {
             failed_proposals,
             total_votes_cast: self.total_votes_cast,
             avg_participation,
+            avg_vote_margin,
             voting_method: self.method,
+            policy_changes: self.policy_changes.clone(),
         }
     }
 }
@@ -382,8 +456,14 @@ pub struct VotingStatistics {
     pub total_votes_cast: usize,
     /// Average number of voters per proposal
     pub avg_participation: f64,
+    /// Average normalized vote margin across completed proposals
+    #[serde(default)]
+    pub avg_vote_margin: f64,
     /// Voting method used
     pub voting_method: VotingMethod,
+    /// Policy changes enacted by passed proposals
+    #[serde(default)]
+    pub policy_changes: Vec<PolicyChange>,
 }
 
 #[cfg(test)]
@@ -593,5 +673,61 @@ mod tests {
         assert_eq!(stats.failed_proposals, 1);
         assert_eq!(stats.total_votes_cast, 5);
         assert_eq!(stats.avg_participation, 2.5);
+        // Margins: (2 - 1) / 3 and (0 - 2) / 2
+        assert!((stats.avg_vote_margin - (1.0 / 3.0 - 1.0) / 2.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_proposal_parameter_name() {
+        assert_eq!(
+            ProposalType::TaxRateChange { new_rate: 0.1 }.parameter_name(),
+            Some("tax_rate")
+        );
+        assert_eq!(
+            ProposalType::BasePriceChange { new_price: 5.0 }.parameter_name(),
+            Some("base_skill_price")
+        );
+        assert_eq!(
+            ProposalType::TransactionFeeChange { new_fee: 0.01 }.parameter_name(),
+            Some("transaction_fee")
+        );
+        assert_eq!(ProposalType::Generic { description: String::new() }.parameter_name(), None);
+    }
+
+    #[test]
+    fn test_vote_margin() {
+        let result = VotingResult {
+            proposal_id: 1,
+            votes_in_favor: 3.0,
+            votes_against: 1.0,
+            total_voters: 4,
+            passed: true,
+            tallied_at: 5,
+        };
+        assert_eq!(result.margin(), 0.5);
+
+        let empty = VotingResult { votes_in_favor: 0.0, votes_against: 0.0, ..result };
+        assert_eq!(empty.margin(), 0.0);
+    }
+
+    #[test]
+    fn test_record_policy_change() {
+        let mut voting_system = VotingSystem::new(VotingMethod::SimpleMajority);
+        voting_system.record_policy_change(PolicyChange {
+            step: 10,
+            proposal_id: 1,
+            proposal_type: ProposalType::TaxRateChange { new_rate: 0.2 },
+            old_value: 0.1,
+            new_value: 0.2,
+            votes_in_favor: 3.0,
+            votes_against: 1.0,
+            total_voters: 4,
+            vote_margin: 0.5,
+        });
+
+        assert_eq!(voting_system.policy_changes().len(), 1);
+        let stats = voting_system.statistics();
+        assert_eq!(stats.policy_changes.len(), 1);
+        assert_eq!(stats.policy_changes[0].new_value, 0.2);
     }
 }